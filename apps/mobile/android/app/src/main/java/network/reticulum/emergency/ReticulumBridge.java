@@ -20,15 +20,29 @@ public final class ReticulumBridge {
     public static native String sendLxmfJson(String payloadJson);
     public static native int retryLxmfJson(String payloadJson);
     public static native int cancelLxmfJson(String payloadJson);
+    public static native int editMessageJson(String payloadJson);
+    public static native int deleteMessageJson(String payloadJson);
+    public static native int reactToMessageJson(String payloadJson);
     public static native int broadcastBase64(String bytesBase64);
     public static native int setActivePropagationNodeJson(String payloadJson);
     public static native int requestLxmfSyncJson(String payloadJson);
+    public static native String listPropagationNodesJson();
+    public static native int setNetworkAvailableJson(String payloadJson);
     public static native String listAnnouncesJson();
     public static native String listPeersJson();
     public static native String listConversationsJson();
     public static native String listMessagesJson(String payloadJson);
+    public static native String listMessagesPageJson(String payloadJson);
+    public static native String searchMessagesJson(String payloadJson);
     public static native int deleteConversationJson(String payloadJson);
+    public static native int markConversationReadJson(String payloadJson);
+    public static native String upsertGroupConversationJson(String payloadJson);
+    public static native String listGroupConversationsJson();
+    public static native int deleteGroupConversationJson(String payloadJson);
+    public static native String sendGroupMessageJson(String payloadJson);
+    public static native String listGroupDeliveriesJson(String payloadJson);
     public static native String getLxmfSyncStatusJson();
+    public static native String getSendQueueMetricsJson();
     public static native String listTelemetryDestinationsJson();
     public static native String legacyImportCompletedJson();
     public static native int importLegacyStateJson(String payloadJson);
@@ -56,10 +70,26 @@ public final class ReticulumBridge {
     public static native int upsertEamJson(String payloadJson);
     public static native int deleteEamJson(String payloadJson);
     public static native String getEamTeamSummaryJson(String payloadJson);
+    public static native String getEamHistoryJson(String payloadJson);
+    public static native String listEamSchemasJson();
+    public static native String getActiveEamSchemaJson();
+    public static native int upsertEamSchemaJson(String payloadJson);
+    public static native int setActiveEamSchemaJson(String payloadJson);
+    public static native int publishEamSchemaJson(String payloadJson);
+    public static native String requestEamRollCallJson(String payloadJson);
+    public static native int respondToEamRollCallJson(String payloadJson);
+    public static native String getEamRollCallJson(String payloadJson);
+    public static native String listEamRollCallsJson();
+    public static native String listTeamsJson();
+    public static native int upsertTeamJson(String payloadJson);
+    public static native int deleteTeamJson(String payloadJson);
+    public static native String getTeamRollupsJson();
     public static native String getEventsJson();
     public static native int upsertEventJson(String payloadJson);
     public static native int deleteEventJson(String payloadJson);
     public static native String getTelemetryPositionsJson();
+    public static native String getTelemetryTrackJson(String payloadJson);
+    public static native String exportTelemetryTrackJson(String payloadJson);
     public static native int recordLocalTelemetryFixJson(String payloadJson);
     public static native int deleteLocalTelemetryJson(String payloadJson);
     public static native String getSosSettingsJson();
@@ -71,6 +101,9 @@ public final class ReticulumBridge {
     public static native int submitSosTelemetryJson(String payloadJson);
     public static native String submitSosAccelerometerJson(String payloadJson);
     public static native String submitSosScreenEventJson(String payloadJson);
+    public static native String getSosDeliveryReportJson(String payloadJson);
+    public static native int respondToSosJson(String payloadJson);
+    public static native String listSosRespondersJson(String payloadJson);
     public static native String listSosAlertsJson();
     public static native String listSosLocationsJson();
     public static native String listSosAudioJson();
@@ -800,6 +800,7 @@ public final class ReticulumNodeService extends Service {
             "Checklists",
             "ChecklistDetail",
             "Eams",
+            "Teams",
             "Events",
             "Conversations",
             "Messages",
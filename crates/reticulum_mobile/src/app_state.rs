@@ -1,12 +1,22 @@
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use fs_err as fs;
 use rusqlite::{params, Connection, OptionalExtension, Transaction};
 use serde::Serialize;
 
+use crate::eam_freshness::{is_eam_expired, is_eam_reporting, is_low_confidence};
+use crate::eam_history::{build_eam_history, eam_field_changes, StoredEamRevision};
+use crate::eam_roll_call::{mark_roll_call_overdue, record_roll_call_response};
+use crate::eam_schema::{aggregate_eam_status, built_in_eam_schema, validate_eam_schema};
+use crate::group_chat::is_group_conversation_id;
+use crate::message_action_fields::carry_message_actions;
+use crate::outbound_queue::{outbound_retry_delay_ms, OutboundQueueRecord};
+use crate::propagation_health::PropagationNodeHealth;
 use crate::runtime::now_ms;
+use crate::sos::sos_kind_label;
+use crate::team_registry::{color_team_records, team_depth, team_subtree_uids};
 use crate::types::{
     AppSettingsRecord, ChecklistCellRecord, ChecklistColumnRecord, ChecklistColumnType,
     ChecklistCreateFromTemplateRequest, ChecklistCreateOnlineRequest, ChecklistMode,
@@ -14,15 +24,23 @@ use crate::types::{
     ChecklistTaskRecord, ChecklistTaskRowAddRequest, ChecklistTaskRowDeleteRequest,
     ChecklistTaskRowStyleSetRequest, ChecklistTaskStatus, ChecklistTaskStatusSetRequest,
     ChecklistTemplateImportCsvRequest, ChecklistTemplateRecord, ChecklistUpdateRequest,
-    ChecklistUserTaskStatus, ConversationRecord, EamProjectionRecord, EamTeamSummaryRecord,
-    EventProjectionRecord, LegacyImportPayload, MessageDirection, MessageRecord, NodeError,
+    ChecklistUserTaskStatus, ConversationRecord, EamProjectionRecord, EamRevisionRecord,
+    EamRollCallDirection, EamRollCallRecord, EamSchemaRecord, EamStatusTotal, EamTeamSummaryRecord,
+    EventProjectionRecord, GroupConversationRecord, GroupMemberDeliveryRecord, LegacyImportPayload,
+    MessageDirection, MessagePriority, MessageRecord, MessageState, NodeError, PeerRecord,
     ProjectionInvalidation, ProjectionScope, SavedPeerRecord, SosAlertRecord, SosAudioRecord,
-    SosLocationRecord, SosSettingsRecord, SosStatusRecord, TelemetryPositionRecord,
+    SosDeliveryRecord, SosDeliveryState, SosLocationRecord, SosMessageKind, SosResponderRecord,
+    SosSettingsRecord, SosStatusRecord, TeamRecord, TeamRollupRecord, TelemetryPositionRecord,
     DEFAULT_CHECKLIST_TASK_DUE_STEP_MINUTES,
 };
 
 const DEFAULT_STORAGE_DIR: &str = "reticulum-mobile";
 const DB_FILE_NAME: &str = "app_state.db";
+const SOS_AUDIO_DIR_NAME: &str = "sos_audio";
+const MESSAGE_ATTACHMENTS_DIR_NAME: &str = "attachments";
+const MESSAGE_PAGE_DEFAULT_LIMIT: u32 = 50;
+const MESSAGE_PAGE_MAX_LIMIT: u32 = 500;
+const TELEMETRY_TRACK_MAX_POINTS: usize = 2_000;
 
 #[derive(Debug, Clone)]
 pub struct AppStateStore {
@@ -43,6 +61,43 @@ pub(crate) struct ConversationPeerRecord {
 }
 
 impl ConversationPeerResolver {
+    // Each peer's conversation is keyed by its identity when known, so its
+    // app and LXMF destinations fold into one thread.
+    pub(crate) fn from_peers(peers: &[PeerRecord]) -> Self {
+        let mut resolver = Self::default();
+        for peer in peers {
+            let Some(destination_hex) =
+                normalize_optional_string(Some(peer.destination_hex.as_str()))
+            else {
+                continue;
+            };
+            let lxmf_destination_hex =
+                normalize_optional_string(peer.lxmf_destination_hex.as_deref());
+            let identity_hex = normalize_optional_string(peer.identity_hex.as_deref());
+            let canonical_id = identity_hex
+                .clone()
+                .or_else(|| lxmf_destination_hex.clone())
+                .unwrap_or_else(|| destination_hex.clone());
+            let peer_destination_hex = lxmf_destination_hex
+                .clone()
+                .unwrap_or_else(|| destination_hex.clone());
+            let mut aliases = vec![destination_hex];
+            if let Some(lxmf_destination_hex) = lxmf_destination_hex {
+                aliases.push(lxmf_destination_hex);
+            }
+            if let Some(identity_hex) = identity_hex {
+                aliases.push(identity_hex);
+            }
+            resolver.insert(
+                aliases,
+                canonical_id,
+                peer_destination_hex,
+                peer.display_name.clone(),
+            );
+        }
+        resolver
+    }
+
     pub(crate) fn insert(
         &mut self,
         aliases: impl IntoIterator<Item = String>,
@@ -115,6 +170,7 @@ impl AppStateStore {
         };
         store.initialize()?;
         store.seed_default_checklist_templates()?;
+        store.seed_color_teams()?;
         Ok(store)
     }
 
@@ -155,6 +211,31 @@ impl AppStateStore {
                     deleted_at_ms INTEGER,
                     json TEXT NOT NULL
                 );
+                CREATE TABLE IF NOT EXISTS eam_revisions (
+                    revision INTEGER PRIMARY KEY AUTOINCREMENT,
+                    callsign_key TEXT NOT NULL,
+                    recorded_at_ms INTEGER NOT NULL,
+                    json TEXT NOT NULL
+                );
+                CREATE INDEX IF NOT EXISTS eam_revisions_callsign
+                    ON eam_revisions (callsign_key, revision);
+                CREATE TABLE IF NOT EXISTS eam_schemas (
+                    schema_uid TEXT PRIMARY KEY,
+                    updated_at_ms INTEGER NOT NULL,
+                    json TEXT NOT NULL
+                );
+                CREATE TABLE IF NOT EXISTS eam_roll_calls (
+                    roll_call_uid TEXT PRIMARY KEY,
+                    updated_at_ms INTEGER NOT NULL,
+                    json TEXT NOT NULL
+                );
+                CREATE TABLE IF NOT EXISTS teams (
+                    team_uid TEXT PRIMARY KEY,
+                    parent_team_uid TEXT,
+                    updated_at_ms INTEGER NOT NULL,
+                    deleted_at_ms INTEGER,
+                    json TEXT NOT NULL
+                );
                 CREATE TABLE IF NOT EXISTS events (
                     uid TEXT PRIMARY KEY,
                     mission_uid TEXT NOT NULL,
@@ -179,6 +260,51 @@ impl AppStateStore {
                     message_id_hex TEXT PRIMARY KEY,
                     conversation_id TEXT NOT NULL,
                     updated_at_ms INTEGER NOT NULL,
+                    json TEXT NOT NULL,
+                    created_at_ms INTEGER NOT NULL DEFAULT 0,
+                    search_rowid INTEGER,
+                    priority TEXT
+                );
+                CREATE INDEX IF NOT EXISTS messages_conversation_updated
+                    ON messages (conversation_id, updated_at_ms);
+                CREATE VIRTUAL TABLE IF NOT EXISTS messages_fts USING fts5(
+                    title,
+                    body,
+                    tokenize = 'unicode61 remove_diacritics 2'
+                );
+                CREATE TABLE IF NOT EXISTS conversation_reads (
+                    conversation_id TEXT PRIMARY KEY,
+                    read_at_ms INTEGER NOT NULL
+                );
+                CREATE TABLE IF NOT EXISTS group_conversations (
+                    conversation_id TEXT PRIMARY KEY,
+                    updated_at_ms INTEGER NOT NULL,
+                    json TEXT NOT NULL
+                );
+                CREATE TABLE IF NOT EXISTS group_deliveries (
+                    message_id_hex TEXT NOT NULL,
+                    member_hex TEXT NOT NULL,
+                    lxmf_message_id_hex TEXT,
+                    updated_at_ms INTEGER NOT NULL,
+                    json TEXT NOT NULL,
+                    PRIMARY KEY (message_id_hex, member_hex)
+                );
+                CREATE INDEX IF NOT EXISTS group_deliveries_lxmf_message
+                    ON group_deliveries (lxmf_message_id_hex);
+                CREATE TABLE IF NOT EXISTS outbound_queue (
+                    queue_id TEXT PRIMARY KEY,
+                    destination_hex TEXT NOT NULL,
+                    attempts INTEGER NOT NULL,
+                    enqueued_at_ms INTEGER NOT NULL,
+                    json TEXT NOT NULL
+                );
+                CREATE TABLE IF NOT EXISTS propagation_node_health (
+                    destination_hex TEXT PRIMARY KEY,
+                    json TEXT NOT NULL
+                );
+                CREATE TABLE IF NOT EXISTS pending_lxmf_deliveries (
+                    tracking_key TEXT PRIMARY KEY,
+                    sent_at_ms INTEGER NOT NULL,
                     json TEXT NOT NULL
                 );
                 CREATE TABLE IF NOT EXISTS telemetry_positions (
@@ -186,6 +312,12 @@ impl AppStateStore {
                     updated_at_ms INTEGER NOT NULL,
                     json TEXT NOT NULL
                 );
+                CREATE TABLE IF NOT EXISTS telemetry_tracks (
+                    callsign_key TEXT NOT NULL,
+                    updated_at_ms INTEGER NOT NULL,
+                    json TEXT NOT NULL,
+                    PRIMARY KEY (callsign_key, updated_at_ms)
+                );
                 CREATE TABLE IF NOT EXISTS sos_settings (
                     id INTEGER PRIMARY KEY CHECK (id = 1),
                     json TEXT NOT NULL,
@@ -218,6 +350,22 @@ impl AppStateStore {
                     created_at_ms INTEGER NOT NULL,
                     json TEXT NOT NULL
                 );
+                CREATE TABLE IF NOT EXISTS sos_deliveries (
+                    incident_id TEXT NOT NULL,
+                    destination_hex TEXT NOT NULL,
+                    kind TEXT NOT NULL,
+                    lxmf_message_id_hex TEXT,
+                    updated_at_ms INTEGER NOT NULL,
+                    json TEXT NOT NULL,
+                    PRIMARY KEY (incident_id, destination_hex, kind)
+                );
+                CREATE TABLE IF NOT EXISTS sos_responders (
+                    incident_id TEXT NOT NULL,
+                    responder_hex TEXT NOT NULL,
+                    updated_at_ms INTEGER NOT NULL,
+                    json TEXT NOT NULL,
+                    PRIMARY KEY (incident_id, responder_hex)
+                );
                 CREATE TABLE IF NOT EXISTS projection_versions (
                     scope TEXT PRIMARY KEY,
                     revision INTEGER NOT NULL,
@@ -230,6 +378,49 @@ impl AppStateStore {
                 ",
             )
             .map_err(|_| NodeError::IoError {})?;
+        upgrade_messages_table(&connection)?;
+        // The search index is keyed on `search_rowid` rather than the implicit
+        // rowid, which SQLite may renumber for a table with a TEXT primary key.
+        connection
+            .execute_batch(
+                "
+                CREATE UNIQUE INDEX IF NOT EXISTS messages_search_rowid
+                    ON messages (search_rowid);
+                CREATE INDEX IF NOT EXISTS messages_conversation_created
+                    ON messages (conversation_id, created_at_ms, message_id_hex);
+                CREATE TRIGGER IF NOT EXISTS messages_fts_insert AFTER INSERT ON messages BEGIN
+                    INSERT INTO messages_fts (rowid, title, body) VALUES (
+                        new.search_rowid,
+                        coalesce(json_extract(new.json, '$.title'), ''),
+                        coalesce(json_extract(new.json, '$.body_utf8'), '')
+                    );
+                END;
+                CREATE TRIGGER IF NOT EXISTS messages_fts_update AFTER UPDATE ON messages BEGIN
+                    DELETE FROM messages_fts WHERE rowid = old.search_rowid;
+                    INSERT INTO messages_fts (rowid, title, body) VALUES (
+                        new.search_rowid,
+                        coalesce(json_extract(new.json, '$.title'), ''),
+                        coalesce(json_extract(new.json, '$.body_utf8'), '')
+                    );
+                END;
+                CREATE TRIGGER IF NOT EXISTS messages_fts_delete AFTER DELETE ON messages BEGIN
+                    DELETE FROM messages_fts WHERE rowid = old.search_rowid;
+                END;
+                ",
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        // Databases created before the search index existed are indexed once.
+        connection
+            .execute(
+                "INSERT INTO messages_fts (rowid, title, body)
+                 SELECT search_rowid,
+                        coalesce(json_extract(json, '$.title'), ''),
+                        coalesce(json_extract(json, '$.body_utf8'), '')
+                 FROM messages
+                 WHERE search_rowid NOT IN (SELECT rowid FROM messages_fts)",
+                [],
+            )
+            .map_err(|_| NodeError::IoError {})?;
         self.repair_message_conversations(&connection, &ConversationPeerResolver::default())?;
         Ok(())
     }
@@ -285,6 +476,7 @@ impl AppStateStore {
         if !payload.eams.is_empty() {
             for eam in &payload.eams {
                 self.write_eam_tx(&transaction, eam)?;
+                self.record_eam_revision_tx(&transaction, eam)?;
             }
             invalidations.push(self.bump_projection_revision_tx(
                 &transaction,
@@ -424,10 +616,11 @@ impl AppStateStore {
             .transaction()
             .map_err(|_| NodeError::IoError {})?;
         self.write_eam_tx(&transaction, record)?;
+        self.record_eam_revision_tx(&transaction, record)?;
         let invalidation = self.bump_projection_revision_tx(
             &transaction,
             ProjectionScope::Eams {},
-            Some(record.callsign.to_ascii_lowercase()),
+            Some(callsign_key(&record.callsign)),
             Some("eam-upserted".to_string()),
         )?;
         transaction.commit().map_err(|_| NodeError::IoError {})?;
@@ -446,7 +639,7 @@ impl AppStateStore {
         if let Some(raw) = transaction
             .query_row(
                 "SELECT json FROM eams WHERE callsign_key = ?1",
-                params![callsign.trim().to_ascii_lowercase()],
+                params![callsign_key(callsign)],
                 |row| row.get::<_, String>(0),
             )
             .optional()
@@ -456,17 +649,289 @@ impl AppStateStore {
             record.deleted_at_ms = Some(deleted_at_ms);
             record.updated_at_ms = deleted_at_ms;
             self.write_eam_tx(&transaction, &record)?;
+            self.record_eam_revision_tx(&transaction, &record)?;
         }
         let invalidation = self.bump_projection_revision_tx(
             &transaction,
             ProjectionScope::Eams {},
-            Some(callsign.trim().to_ascii_lowercase()),
+            Some(callsign_key(callsign)),
             Some("eam-deleted".to_string()),
         )?;
         transaction.commit().map_err(|_| NodeError::IoError {})?;
         Ok(invalidation)
     }
 
+    // Every recorded change to a callsign's EAM, oldest first, each diffed
+    // against the revision before it.
+    pub fn get_eam_history(&self, callsign: &str) -> Result<Vec<EamRevisionRecord>, NodeError> {
+        let connection = self.connect()?;
+        let mut statement = connection
+            .prepare(
+                "SELECT revision, recorded_at_ms, json FROM eam_revisions
+                 WHERE callsign_key = ?1 ORDER BY revision ASC",
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let rows = statement
+            .query_map(params![callsign_key(callsign)], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })
+            .map_err(|_| NodeError::IoError {})?;
+        let mut revisions = Vec::new();
+        for row in rows {
+            let (revision, recorded_at_ms, raw) = row.map_err(|_| NodeError::IoError {})?;
+            revisions.push(StoredEamRevision {
+                revision: revision as u64,
+                recorded_at_ms: recorded_at_ms as u64,
+                record: deserialize_json(&raw)?,
+            });
+        }
+        Ok(build_eam_history(revisions))
+    }
+
+    pub fn list_eam_schemas(&self) -> Result<Vec<EamSchemaRecord>, NodeError> {
+        query_json_records(
+            &self.connect()?,
+            "SELECT json FROM eam_schemas ORDER BY updated_at_ms DESC",
+        )
+    }
+
+    // The schema reports are validated and ranked against. `None` means no
+    // schema has been activated and the built-in taxonomy applies.
+    pub fn get_active_eam_schema(&self) -> Result<Option<EamSchemaRecord>, NodeError> {
+        Ok(self
+            .list_eam_schemas()?
+            .into_iter()
+            .find(|schema| schema.active))
+    }
+
+    // Stores a schema, keeping the active flag of any stored copy so that
+    // receiving a shared template never switches the local taxonomy.
+    pub fn upsert_eam_schema(
+        &self,
+        schema: &EamSchemaRecord,
+    ) -> Result<ProjectionInvalidation, NodeError> {
+        validate_eam_schema(schema)?;
+        let mut connection = self.connect()?;
+        let transaction = connection
+            .transaction()
+            .map_err(|_| NodeError::IoError {})?;
+        let existing = transaction
+            .query_row(
+                "SELECT json FROM eam_schemas WHERE schema_uid = ?1",
+                params![schema.schema_uid.trim()],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?
+            .map(|raw| deserialize_json::<EamSchemaRecord>(&raw))
+            .transpose()?;
+        let mut schema = schema.clone();
+        schema.schema_uid = schema.schema_uid.trim().to_string();
+        schema.active = existing.is_some_and(|existing| existing.active);
+        write_eam_schema_tx(&transaction, &schema)?;
+        let invalidation = self.bump_projection_revision_tx(
+            &transaction,
+            ProjectionScope::Eams {},
+            None,
+            Some("eam-schema-updated".to_string()),
+        )?;
+        transaction.commit().map_err(|_| NodeError::IoError {})?;
+        Ok(invalidation)
+    }
+
+    // Stores a template shared by a peer unless an equal or newer version is
+    // already here. A newer version of the schema in use is kept alongside it
+    // under its own uid, so the operator switches to it deliberately.
+    pub fn store_received_eam_schema(
+        &self,
+        schema: &EamSchemaRecord,
+    ) -> Result<Option<ProjectionInvalidation>, NodeError> {
+        let schema_uid = schema.schema_uid.trim();
+        let copy_uid = format!("{schema_uid}-v{}", schema.version);
+        let schemas = self.list_eam_schemas()?;
+        if schemas.iter().any(|stored| {
+            (stored.schema_uid == schema_uid || stored.schema_uid == copy_uid)
+                && stored.version >= schema.version
+        }) {
+            return Ok(None);
+        }
+        let mut schema = schema.clone();
+        schema.active = false;
+        if schemas
+            .iter()
+            .any(|stored| stored.schema_uid == schema_uid && stored.active)
+        {
+            schema.schema_uid = copy_uid;
+        }
+        self.upsert_eam_schema(&schema).map(Some)
+    }
+
+    // Activating the built-in schema uid clears any stored activation.
+    pub fn set_active_eam_schema(
+        &self,
+        schema_uid: &str,
+    ) -> Result<ProjectionInvalidation, NodeError> {
+        let schema_uid = schema_uid.trim();
+        let mut connection = self.connect()?;
+        let transaction = connection
+            .transaction()
+            .map_err(|_| NodeError::IoError {})?;
+        let schemas =
+            query_json_records::<EamSchemaRecord>(&transaction, "SELECT json FROM eam_schemas")?;
+        if schema_uid != built_in_eam_schema().schema_uid
+            && !schemas.iter().any(|schema| schema.schema_uid == schema_uid)
+        {
+            return Err(NodeError::InvalidConfig {});
+        }
+        for mut schema in schemas {
+            let active = schema.schema_uid == schema_uid;
+            if schema.active != active {
+                schema.active = active;
+                write_eam_schema_tx(&transaction, &schema)?;
+            }
+        }
+        let invalidation = self.bump_projection_revision_tx(
+            &transaction,
+            ProjectionScope::Eams {},
+            None,
+            Some("eam-schema-activated".to_string()),
+        )?;
+        transaction.commit().map_err(|_| NodeError::IoError {})?;
+        Ok(invalidation)
+    }
+
+    pub fn upsert_eam_roll_call(&self, record: &EamRollCallRecord) -> Result<(), NodeError> {
+        self.connect()?
+            .execute(
+                "INSERT INTO eam_roll_calls (roll_call_uid, updated_at_ms, json)
+                 VALUES (?1, ?2, ?3)
+                 ON CONFLICT(roll_call_uid) DO UPDATE SET
+                    updated_at_ms = excluded.updated_at_ms,
+                    json = excluded.json",
+                params![
+                    record.roll_call_uid,
+                    record.updated_at_ms as i64,
+                    serialize_json(record)?
+                ],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        Ok(())
+    }
+
+    pub fn get_eam_roll_call(
+        &self,
+        roll_call_uid: &str,
+    ) -> Result<Option<EamRollCallRecord>, NodeError> {
+        self.connect()?
+            .query_row(
+                "SELECT json FROM eam_roll_calls WHERE roll_call_uid = ?1",
+                params![roll_call_uid.trim()],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?
+            .map(|raw| deserialize_json(&raw))
+            .transpose()
+    }
+
+    pub fn list_eam_roll_calls(&self) -> Result<Vec<EamRollCallRecord>, NodeError> {
+        query_json_records(
+            &self.connect()?,
+            "SELECT json FROM eam_roll_calls ORDER BY updated_at_ms DESC",
+        )
+    }
+
+    // Applies a received EAM to the outbound roll calls it answers. A report
+    // naming one of them answers only that one; any other report updates
+    // every roll call that lists the reporting member.
+    pub fn record_eam_roll_call_response(
+        &self,
+        record: &EamProjectionRecord,
+        roll_call_uid: Option<&str>,
+        received_at_ms: u64,
+    ) -> Result<Vec<EamRollCallRecord>, NodeError> {
+        let outbound = self
+            .list_eam_roll_calls()?
+            .into_iter()
+            .filter(|roll_call| matches!(roll_call.direction, EamRollCallDirection::Outbound {}))
+            .collect::<Vec<_>>();
+        let answered = roll_call_uid.filter(|roll_call_uid| {
+            outbound
+                .iter()
+                .any(|roll_call| roll_call.roll_call_uid == *roll_call_uid)
+        });
+        let mut updated = Vec::new();
+        for mut roll_call in outbound {
+            if answered.is_some_and(|value| value != roll_call.roll_call_uid) {
+                continue;
+            }
+            if record_roll_call_response(
+                &mut roll_call,
+                record.team_member_uid.as_deref(),
+                record.callsign.as_str(),
+                received_at_ms,
+                answered.is_some(),
+            ) {
+                self.upsert_eam_roll_call(&roll_call)?;
+                updated.push(roll_call);
+            }
+        }
+        Ok(updated)
+    }
+
+    pub fn sweep_overdue_eam_roll_calls(
+        &self,
+        now_ms: u64,
+    ) -> Result<Vec<EamRollCallRecord>, NodeError> {
+        let mut updated = Vec::new();
+        for mut roll_call in self.list_eam_roll_calls()? {
+            if mark_roll_call_overdue(&mut roll_call, now_ms) {
+                self.upsert_eam_roll_call(&roll_call)?;
+                updated.push(roll_call);
+            }
+        }
+        Ok(updated)
+    }
+
+    // Flags reports whose TTL has run out, and clears the flag again if a
+    // corrected TTL brings one back into date.
+    pub fn sweep_expired_eams(
+        &self,
+        now_ms: u64,
+    ) -> Result<Option<ProjectionInvalidation>, NodeError> {
+        let mut connection = self.connect()?;
+        let transaction = connection
+            .transaction()
+            .map_err(|_| NodeError::IoError {})?;
+        let changed = query_json_records::<EamProjectionRecord>(
+            &transaction,
+            "SELECT json FROM eams WHERE deleted_at_ms IS NULL",
+        )?
+        .into_iter()
+        .filter(|record| record.expired != is_eam_expired(record, now_ms))
+        .collect::<Vec<_>>();
+        for mut record in changed.iter().cloned() {
+            record.expired = !record.expired;
+            self.write_eam_tx(&transaction, &record)?;
+        }
+        let invalidation = if changed.is_empty() {
+            None
+        } else {
+            Some(self.bump_projection_revision_tx(
+                &transaction,
+                ProjectionScope::Eams {},
+                None,
+                Some("eam-expired".to_string()),
+            )?)
+        };
+        transaction.commit().map_err(|_| NodeError::IoError {})?;
+        Ok(invalidation)
+    }
+
     pub fn get_eam_team_summary(
         &self,
         team_uid: &str,
@@ -476,44 +941,106 @@ impl AppStateStore {
             .into_iter()
             .filter(|record| record.team_uid.as_deref() == Some(team_uid))
             .collect();
-        if records.is_empty() {
+        let schema = self
+            .get_active_eam_schema()?
+            .unwrap_or_else(built_in_eam_schema);
+        Ok(summarize_eams(team_uid, records, &schema, now_ms()))
+    }
+
+    pub fn list_teams(&self) -> Result<Vec<TeamRecord>, NodeError> {
+        query_json_records(
+            &self.connect()?,
+            "SELECT json FROM teams ORDER BY updated_at_ms DESC",
+        )
+    }
+
+    // Last writer wins on `updated_at_ms`, so a replayed or out-of-order
+    // replication never rolls a team back. Returns `None` when the stored
+    // copy is newer.
+    pub fn upsert_team(
+        &self,
+        team: &TeamRecord,
+    ) -> Result<Option<ProjectionInvalidation>, NodeError> {
+        let mut connection = self.connect()?;
+        let transaction = connection
+            .transaction()
+            .map_err(|_| NodeError::IoError {})?;
+        let stored_updated_at_ms = transaction
+            .query_row(
+                "SELECT updated_at_ms FROM teams WHERE team_uid = ?1",
+                params![team.team_uid],
+                |row| row.get::<_, i64>(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?;
+        if stored_updated_at_ms.is_some_and(|stored| stored as u64 > team.updated_at_ms) {
             return Ok(None);
         }
-        let mut summary = EamTeamSummaryRecord {
-            team_uid: team_uid.to_string(),
-            total: records.len() as u32,
-            active_total: 0,
-            deleted_total: 0,
-            overall_status: None,
-            green_total: 0,
-            yellow_total: 0,
-            red_total: 0,
-            updated_at_ms: 0,
-        };
-        for record in records {
-            summary.updated_at_ms = summary.updated_at_ms.max(record.updated_at_ms);
-            if record.deleted_at_ms.is_some() {
-                summary.deleted_total += 1;
-                continue;
-            }
-            summary.active_total += 1;
-            match record.overall_status.as_deref() {
-                Some("Green") => summary.green_total += 1,
-                Some("Yellow") => summary.yellow_total += 1,
-                Some("Red") => summary.red_total += 1,
-                _ => {}
-            }
-        }
-        summary.overall_status = if summary.red_total > 0 {
-            Some("Red".to_string())
-        } else if summary.yellow_total > 0 {
-            Some("Yellow".to_string())
-        } else if summary.green_total > 0 {
-            Some("Green".to_string())
-        } else {
-            None
-        };
-        Ok(Some(summary))
+        write_team_tx(&transaction, team)?;
+        let invalidation = self.bump_projection_revision_tx(
+            &transaction,
+            ProjectionScope::Teams {},
+            Some(team.team_uid.clone()),
+            Some(
+                if team.deleted_at_ms.is_some() {
+                    "team-deleted"
+                } else {
+                    "team-upserted"
+                }
+                .to_string(),
+            ),
+        )?;
+        transaction.commit().map_err(|_| NodeError::IoError {})?;
+        Ok(Some(invalidation))
+    }
+
+    // One entry per live team. Each summary rolls up the EAMs filed under the
+    // team and under every team below it.
+    pub fn get_team_rollups(&self) -> Result<Vec<TeamRollupRecord>, NodeError> {
+        let teams = self
+            .list_teams()?
+            .into_iter()
+            .filter(|team| team.deleted_at_ms.is_none())
+            .collect::<Vec<_>>();
+        let eams = self.get_eams()?;
+        let schema = self
+            .get_active_eam_schema()?
+            .unwrap_or_else(built_in_eam_schema);
+        let now_ms = now_ms();
+        let mut rollups = teams
+            .iter()
+            .map(|team| {
+                let subtree = team_subtree_uids(&teams, team.team_uid.as_str());
+                let records = eams
+                    .iter()
+                    .filter(|record| {
+                        record
+                            .team_uid
+                            .as_deref()
+                            .is_some_and(|team_uid| subtree.iter().any(|uid| uid == team_uid))
+                    })
+                    .cloned()
+                    .collect();
+                TeamRollupRecord {
+                    team: team.clone(),
+                    child_team_uids: teams
+                        .iter()
+                        .filter(|child| {
+                            child.parent_team_uid.as_deref() == Some(team.team_uid.as_str())
+                        })
+                        .map(|child| child.team_uid.clone())
+                        .collect(),
+                    depth: team_depth(&teams, team.team_uid.as_str()),
+                    summary: summarize_eams(team.team_uid.as_str(), records, &schema, now_ms),
+                }
+            })
+            .collect::<Vec<_>>();
+        rollups.sort_by(|left, right| {
+            left.depth
+                .cmp(&right.depth)
+                .then_with(|| left.team.name.cmp(&right.team.name))
+        });
+        Ok(rollups)
     }
 
     pub fn get_events(&self) -> Result<Vec<EventProjectionRecord>, NodeError> {
@@ -1260,29 +1787,151 @@ impl AppStateStore {
         Ok(records)
     }
 
-    #[cfg(test)]
-    pub fn list_conversations(&self) -> Result<Vec<ConversationRecord>, NodeError> {
-        self.list_conversations_resolved(&ConversationPeerResolver::default())
-    }
-
-    pub(crate) fn list_conversations_resolved(
+    // Returns the newest `limit` messages created before the cursor, oldest
+    // first. Callers page backwards by passing the first item's id; `before_ms`
+    // alone starts from a point in time. Pages are keyed on the creation time,
+    // which state, read and edit updates never change.
+    pub(crate) fn list_messages_page_resolved(
         &self,
+        conversation_id: &str,
+        before_ms: Option<u64>,
+        before_message_id_hex: Option<&str>,
+        limit: u32,
         resolver: &ConversationPeerResolver,
-    ) -> Result<Vec<ConversationRecord>, NodeError> {
-        let messages = self.list_messages_resolved(None, resolver)?;
-        let labels = self
-            .get_saved_peers()?
-            .into_iter()
-            .map(|peer| {
-                (
-                    normalize_message_peer_key(peer.destination_hex.as_str()),
-                    peer.label,
+    ) -> Result<Vec<MessageRecord>, NodeError> {
+        let connection = self.connect()?;
+        let conversation_ids = conversation_ids_json(resolver, conversation_id)?;
+        let cursor_created_at_ms: Option<i64> = match before_message_id_hex {
+            Some(message_id_hex) => connection
+                .query_row(
+                    "SELECT created_at_ms FROM messages WHERE message_id_hex = ?1",
+                    params![message_id_hex],
+                    |row| row.get(0),
                 )
-            })
-            .collect::<std::collections::HashMap<_, _>>();
-        let mut conversations = std::collections::HashMap::<String, ConversationRecord>::new();
+                .optional()
+                .map_err(|_| NodeError::IoError {})?,
+            None => None,
+        };
+        let (before_ms, before_message_id_hex) = match cursor_created_at_ms {
+            Some(created_at_ms) => (created_at_ms, before_message_id_hex),
+            None => (
+                before_ms.map(|value| value as i64).unwrap_or(i64::MAX),
+                None,
+            ),
+        };
+        let mut statement = connection
+            .prepare(
+                "SELECT json FROM messages
+                 WHERE conversation_id IN (SELECT value FROM json_each(?1))
+                   AND (created_at_ms < ?2
+                        OR (created_at_ms = ?2 AND ?3 IS NOT NULL AND message_id_hex < ?3))
+                 ORDER BY created_at_ms DESC, message_id_hex DESC
+                 LIMIT ?4",
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let rows = statement
+            .query_map(
+                params![
+                    conversation_ids,
+                    before_ms,
+                    before_message_id_hex,
+                    message_page_limit(limit)
+                ],
+                |row| row.get::<_, String>(0),
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let mut records = Vec::new();
+        for row in rows {
+            let raw: String = row.map_err(|_| NodeError::IoError {})?;
+            let message: MessageRecord = deserialize_json(&raw)?;
+            records.push(canonicalize_chat_message_with_resolver(&message, resolver));
+        }
+        records.reverse();
+        Ok(records)
+    }
+
+    // Best matches first. Every whitespace-separated term must match, as a
+    // prefix, in either the title or the body.
+    pub(crate) fn search_messages_resolved(
+        &self,
+        query: &str,
+        conversation_id: Option<&str>,
+        limit: u32,
+        resolver: &ConversationPeerResolver,
+    ) -> Result<Vec<MessageRecord>, NodeError> {
+        let Some(match_expression) = fts_match_expression(query) else {
+            return Ok(Vec::new());
+        };
+        let connection = self.connect()?;
+        let conversation_ids = conversation_id
+            .map(|value| conversation_ids_json(resolver, value))
+            .transpose()?;
+        let mut statement = connection
+            .prepare(
+                "SELECT messages.json FROM messages_fts
+                 JOIN messages ON messages.search_rowid = messages_fts.rowid
+                 WHERE messages_fts MATCH ?1
+                   AND (?2 IS NULL
+                        OR messages.conversation_id IN (SELECT value FROM json_each(?2)))
+                 ORDER BY messages_fts.rank, messages.updated_at_ms DESC
+                 LIMIT ?3",
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let rows = statement
+            .query_map(
+                params![
+                    match_expression,
+                    conversation_ids,
+                    message_page_limit(limit)
+                ],
+                |row| row.get::<_, String>(0),
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let mut records = Vec::new();
+        for row in rows {
+            let raw: String = row.map_err(|_| NodeError::IoError {})?;
+            let message: MessageRecord = deserialize_json(&raw)?;
+            records.push(canonicalize_chat_message_with_resolver(&message, resolver));
+        }
+        Ok(records)
+    }
+
+    #[cfg(test)]
+    pub fn list_conversations(&self) -> Result<Vec<ConversationRecord>, NodeError> {
+        self.list_conversations_resolved(&ConversationPeerResolver::default())
+    }
+
+    pub(crate) fn list_conversations_resolved(
+        &self,
+        resolver: &ConversationPeerResolver,
+    ) -> Result<Vec<ConversationRecord>, NodeError> {
+        let messages = self.list_messages_resolved(None, resolver)?;
+        let labels = self
+            .get_saved_peers()?
+            .into_iter()
+            .map(|peer| {
+                (
+                    normalize_message_peer_key(peer.destination_hex.as_str()),
+                    peer.label,
+                )
+            })
+            .collect::<std::collections::HashMap<_, _>>();
+        let read_marks = self.conversation_read_marks_resolved(resolver)?;
+        let groups = self
+            .list_group_conversations()?
+            .into_iter()
+            .map(|group| (group.conversation_id.clone(), group))
+            .collect::<HashMap<_, _>>();
+        let mut conversations = std::collections::HashMap::<String, ConversationRecord>::new();
+        let mut unread_counts = std::collections::HashMap::<String, u32>::new();
 
         for message in messages {
+            if is_unread_message(&message, read_marks.get(&message.conversation_id).copied()) {
+                let count = unread_counts
+                    .entry(message.conversation_id.clone())
+                    .or_default();
+                *count = count.saturating_add(1);
+            }
             let updated_at_ms = message
                 .received_at_ms
                 .or(message.sent_at_ms)
@@ -1292,8 +1941,10 @@ impl AppStateStore {
                 .map(|peer| peer.peer_destination_hex.clone())
                 .unwrap_or_else(|| message.conversation_id.clone());
             let preview = truncate_preview(message.body_utf8.as_str());
-            let peer_display_name = resolved_peer
-                .and_then(|peer| peer.display_name.clone())
+            let peer_display_name = groups
+                .get(&message.conversation_id)
+                .map(|group| group.title.clone())
+                .or_else(|| resolved_peer.and_then(|peer| peer.display_name.clone()))
                 .or_else(|| labels.get(&peer_destination_hex).cloned().flatten());
             let next = ConversationRecord {
                 conversation_id: message.conversation_id.clone(),
@@ -1313,7 +1964,31 @@ impl AppStateStore {
             }
         }
 
-        let mut records = conversations.into_values().collect::<Vec<_>>();
+        // Rooms show up as soon as they exist, before anyone has posted.
+        for group in groups.into_values() {
+            conversations
+                .entry(group.conversation_id.clone())
+                .or_insert_with(|| ConversationRecord {
+                    conversation_id: group.conversation_id.clone(),
+                    peer_destination_hex: group.conversation_id.clone(),
+                    peer_display_name: Some(group.title.clone()),
+                    last_message_preview: None,
+                    last_message_at_ms: group.updated_at_ms,
+                    unread_count: 0,
+                    last_message_state: None,
+                });
+        }
+
+        let mut records = conversations
+            .into_values()
+            .map(|mut record| {
+                record.unread_count = unread_counts
+                    .get(&record.conversation_id)
+                    .copied()
+                    .unwrap_or(0);
+                record
+            })
+            .collect::<Vec<_>>();
         records.sort_by(|left, right| {
             right
                 .last_message_at_ms
@@ -1323,6 +1998,575 @@ impl AppStateStore {
         Ok(records)
     }
 
+    pub fn upsert_group_conversation(
+        &self,
+        group: &GroupConversationRecord,
+    ) -> Result<ProjectionInvalidation, NodeError> {
+        let mut connection = self.connect()?;
+        let transaction = connection
+            .transaction()
+            .map_err(|_| NodeError::IoError {})?;
+        let json = serialize_json(group)?;
+        transaction
+            .execute(
+                "INSERT INTO group_conversations (conversation_id, updated_at_ms, json)
+                 VALUES (?1, ?2, ?3)
+                 ON CONFLICT(conversation_id) DO UPDATE SET
+                    updated_at_ms = excluded.updated_at_ms,
+                    json = excluded.json",
+                params![group.conversation_id, group.updated_at_ms as i64, json],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let invalidation = self.bump_projection_revision_tx(
+            &transaction,
+            ProjectionScope::Conversations {},
+            Some(group.conversation_id.clone()),
+            Some("group-conversation-updated".to_string()),
+        )?;
+        transaction.commit().map_err(|_| NodeError::IoError {})?;
+        Ok(invalidation)
+    }
+
+    pub fn get_group_conversation(
+        &self,
+        conversation_id: &str,
+    ) -> Result<Option<GroupConversationRecord>, NodeError> {
+        let connection = self.connect()?;
+        let raw = connection
+            .query_row(
+                "SELECT json FROM group_conversations WHERE conversation_id = ?1",
+                params![conversation_id],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?;
+        raw.map(|raw| deserialize_json(&raw)).transpose()
+    }
+
+    pub fn list_group_conversations(&self) -> Result<Vec<GroupConversationRecord>, NodeError> {
+        let connection = self.connect()?;
+        query_json_records(
+            &connection,
+            "SELECT json FROM group_conversations ORDER BY updated_at_ms DESC, conversation_id ASC",
+        )
+    }
+
+    pub fn delete_group_conversation(
+        &self,
+        conversation_id: &str,
+    ) -> Result<Vec<ProjectionInvalidation>, NodeError> {
+        let mut connection = self.connect()?;
+        let transaction = connection
+            .transaction()
+            .map_err(|_| NodeError::IoError {})?;
+        for sql in [
+            "DELETE FROM group_deliveries WHERE message_id_hex IN (
+                SELECT message_id_hex FROM messages WHERE conversation_id = ?1
+             )",
+            "DELETE FROM messages WHERE conversation_id = ?1",
+            "DELETE FROM conversation_reads WHERE conversation_id = ?1",
+            "DELETE FROM group_conversations WHERE conversation_id = ?1",
+        ] {
+            transaction
+                .execute(sql, params![conversation_id])
+                .map_err(|_| NodeError::IoError {})?;
+        }
+        let messages = self.bump_projection_revision_tx(
+            &transaction,
+            ProjectionScope::Messages {},
+            Some(conversation_id.to_string()),
+            Some("group-conversation-deleted".to_string()),
+        )?;
+        let conversations = self.bump_projection_revision_tx(
+            &transaction,
+            ProjectionScope::Conversations {},
+            None,
+            Some("group-conversation-deleted".to_string()),
+        )?;
+        transaction.commit().map_err(|_| NodeError::IoError {})?;
+        Ok(vec![messages, conversations])
+    }
+
+    pub fn upsert_group_delivery(
+        &self,
+        delivery: &GroupMemberDeliveryRecord,
+    ) -> Result<ProjectionInvalidation, NodeError> {
+        let mut connection = self.connect()?;
+        let transaction = connection
+            .transaction()
+            .map_err(|_| NodeError::IoError {})?;
+        self.write_group_delivery_tx(&transaction, delivery)?;
+        let invalidation = self.bump_projection_revision_tx(
+            &transaction,
+            ProjectionScope::Messages {},
+            None,
+            Some("group-delivery-updated".to_string()),
+        )?;
+        transaction.commit().map_err(|_| NodeError::IoError {})?;
+        Ok(invalidation)
+    }
+
+    pub fn list_group_deliveries(
+        &self,
+        message_id_hex: &str,
+    ) -> Result<Vec<GroupMemberDeliveryRecord>, NodeError> {
+        let connection = self.connect()?;
+        let mut statement = connection
+            .prepare(
+                "SELECT json FROM group_deliveries WHERE message_id_hex = ?1 ORDER BY member_hex ASC",
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let rows = statement
+            .query_map(params![message_id_hex], |row| row.get::<_, String>(0))
+            .map_err(|_| NodeError::IoError {})?;
+        let mut records = Vec::new();
+        for row in rows {
+            let raw: String = row.map_err(|_| NodeError::IoError {})?;
+            records.push(deserialize_json(&raw)?);
+        }
+        Ok(records)
+    }
+
+    pub fn get_group_delivery_by_lxmf_id(
+        &self,
+        lxmf_message_id_hex: &str,
+    ) -> Result<Option<GroupMemberDeliveryRecord>, NodeError> {
+        let connection = self.connect()?;
+        let raw = connection
+            .query_row(
+                "SELECT json FROM group_deliveries WHERE lxmf_message_id_hex = ?1",
+                params![lxmf_message_id_hex],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?;
+        raw.map(|raw| deserialize_json(&raw)).transpose()
+    }
+
+    // Receipts only ever move a member's copy forward; a read copy stays read.
+    pub fn mark_group_delivery_state(
+        &self,
+        lxmf_message_id_hex: &str,
+        state: MessageState,
+        updated_at_ms: u64,
+    ) -> Result<Option<(GroupMemberDeliveryRecord, ProjectionInvalidation)>, NodeError> {
+        let mut connection = self.connect()?;
+        let transaction = connection
+            .transaction()
+            .map_err(|_| NodeError::IoError {})?;
+        let raw: Option<String> = transaction
+            .query_row(
+                "SELECT json FROM group_deliveries WHERE lxmf_message_id_hex = ?1",
+                params![lxmf_message_id_hex],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?;
+        let Some(raw) = raw else {
+            return Ok(None);
+        };
+        let mut delivery: GroupMemberDeliveryRecord = deserialize_json(&raw)?;
+        if matches!(delivery.state, MessageState::Read {}) || delivery.state == state {
+            return Ok(None);
+        }
+        delivery.state = state;
+        delivery.detail = None;
+        delivery.updated_at_ms = updated_at_ms;
+        self.write_group_delivery_tx(&transaction, &delivery)?;
+        let invalidation = self.bump_projection_revision_tx(
+            &transaction,
+            ProjectionScope::Messages {},
+            None,
+            Some("group-delivery-updated".to_string()),
+        )?;
+        transaction.commit().map_err(|_| NodeError::IoError {})?;
+        Ok(Some((delivery, invalidation)))
+    }
+
+    fn write_group_delivery_tx(
+        &self,
+        transaction: &Transaction<'_>,
+        delivery: &GroupMemberDeliveryRecord,
+    ) -> Result<(), NodeError> {
+        let json = serialize_json(delivery)?;
+        transaction
+            .execute(
+                "INSERT INTO group_deliveries (message_id_hex, member_hex, lxmf_message_id_hex, updated_at_ms, json)
+                 VALUES (?1, ?2, ?3, ?4, ?5)
+                 ON CONFLICT(message_id_hex, member_hex) DO UPDATE SET
+                    lxmf_message_id_hex = excluded.lxmf_message_id_hex,
+                    updated_at_ms = excluded.updated_at_ms,
+                    json = excluded.json",
+                params![
+                    delivery.message_id_hex,
+                    delivery.member_hex,
+                    delivery.lxmf_message_id_hex,
+                    delivery.updated_at_ms as i64,
+                    json
+                ],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        Ok(())
+    }
+
+    pub(crate) fn enqueue_outbound(&self, record: &OutboundQueueRecord) -> Result<(), NodeError> {
+        let connection = self.connect()?;
+        write_outbound_queue_record(&connection, record)
+    }
+
+    pub(crate) fn list_outbound_queue(&self) -> Result<Vec<OutboundQueueRecord>, NodeError> {
+        let connection = self.connect()?;
+        query_json_records(
+            &connection,
+            "SELECT json FROM outbound_queue ORDER BY enqueued_at_ms ASC, queue_id ASC",
+        )
+    }
+
+    // Counted before the attempt starts, so an attempt the process did not
+    // survive still counts against the queue entry.
+    pub(crate) fn record_outbound_attempt(
+        &self,
+        queue_id: &str,
+        attempted_at_ms: u64,
+    ) -> Result<Option<OutboundQueueRecord>, NodeError> {
+        let connection = self.connect()?;
+        let raw = connection
+            .query_row(
+                "SELECT json FROM outbound_queue WHERE queue_id = ?1",
+                params![queue_id],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?;
+        let Some(raw) = raw else {
+            return Ok(None);
+        };
+        let mut record: OutboundQueueRecord = deserialize_json(&raw)?;
+        record.attempts = record.attempts.saturating_add(1);
+        record.updated_at_ms = attempted_at_ms;
+        write_outbound_queue_record(&connection, &record)?;
+        Ok(Some(record))
+    }
+
+    // Pushes the next attempt out by the backoff for the attempts already
+    // spent. Returns `None` when the entry was removed in the meantime.
+    pub(crate) fn schedule_outbound_retry(
+        &self,
+        queue_id: &str,
+        failed_at_ms: u64,
+    ) -> Result<Option<OutboundQueueRecord>, NodeError> {
+        let connection = self.connect()?;
+        let raw = connection
+            .query_row(
+                "SELECT json FROM outbound_queue WHERE queue_id = ?1",
+                params![queue_id],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?;
+        let Some(raw) = raw else {
+            return Ok(None);
+        };
+        let mut record: OutboundQueueRecord = deserialize_json(&raw)?;
+        record.updated_at_ms = failed_at_ms;
+        record.next_attempt_at_ms =
+            failed_at_ms.saturating_add(outbound_retry_delay_ms(record.attempts));
+        write_outbound_queue_record(&connection, &record)?;
+        Ok(Some(record))
+    }
+
+    pub(crate) fn remove_outbound(&self, queue_id: &str) -> Result<(), NodeError> {
+        let connection = self.connect()?;
+        connection
+            .execute(
+                "DELETE FROM outbound_queue WHERE queue_id = ?1",
+                params![queue_id],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        Ok(())
+    }
+
+    pub(crate) fn upsert_propagation_node_health(
+        &self,
+        health: &PropagationNodeHealth,
+    ) -> Result<(), NodeError> {
+        let connection = self.connect()?;
+        let json = serialize_json(health)?;
+        connection
+            .execute(
+                "INSERT INTO propagation_node_health (destination_hex, json)
+                 VALUES (?1, ?2)
+                 ON CONFLICT(destination_hex) DO UPDATE SET json = excluded.json",
+                params![health.destination_hex, json],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        Ok(())
+    }
+
+    pub(crate) fn list_propagation_node_health(
+        &self,
+    ) -> Result<Vec<PropagationNodeHealth>, NodeError> {
+        let connection = self.connect()?;
+        query_json_records(
+            &connection,
+            "SELECT json FROM propagation_node_health ORDER BY destination_hex ASC",
+        )
+    }
+
+    pub(crate) fn upsert_pending_lxmf_delivery<T: Serialize>(
+        &self,
+        tracking_key: &str,
+        sent_at_ms: u64,
+        pending: &T,
+    ) -> Result<(), NodeError> {
+        let connection = self.connect()?;
+        let json = serialize_json(pending)?;
+        connection
+            .execute(
+                "INSERT INTO pending_lxmf_deliveries (tracking_key, sent_at_ms, json)
+                 VALUES (?1, ?2, ?3)
+                 ON CONFLICT(tracking_key) DO UPDATE SET
+                    sent_at_ms = excluded.sent_at_ms,
+                    json = excluded.json",
+                params![tracking_key, sent_at_ms as i64, json],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        Ok(())
+    }
+
+    pub(crate) fn list_pending_lxmf_deliveries<T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> Result<Vec<(String, T)>, NodeError> {
+        let connection = self.connect()?;
+        let mut statement = connection
+            .prepare(
+                "SELECT tracking_key, json FROM pending_lxmf_deliveries ORDER BY sent_at_ms ASC",
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let rows = statement
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(|_| NodeError::IoError {})?;
+        let mut records = Vec::new();
+        for row in rows {
+            let (tracking_key, raw) = row.map_err(|_| NodeError::IoError {})?;
+            records.push((tracking_key, deserialize_json(&raw)?));
+        }
+        Ok(records)
+    }
+
+    pub(crate) fn delete_pending_lxmf_delivery(&self, tracking_key: &str) -> Result<(), NodeError> {
+        let connection = self.connect()?;
+        connection
+            .execute(
+                "DELETE FROM pending_lxmf_deliveries WHERE tracking_key = ?1",
+                params![tracking_key],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        Ok(())
+    }
+
+    // Kept next to the message rather than in its JSON so a retry goes out at
+    // the priority the original send was given.
+    pub(crate) fn set_message_priority(
+        &self,
+        message_id_hex: &str,
+        priority: MessagePriority,
+    ) -> Result<(), NodeError> {
+        let connection = self.connect()?;
+        connection
+            .execute(
+                "UPDATE messages SET priority = ?2 WHERE message_id_hex = ?1",
+                params![message_id_hex, serialize_json(&priority)?],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        Ok(())
+    }
+
+    pub(crate) fn get_message_priority(
+        &self,
+        message_id_hex: &str,
+    ) -> Result<Option<MessagePriority>, NodeError> {
+        let connection = self.connect()?;
+        let raw = connection
+            .query_row(
+                "SELECT priority FROM messages WHERE message_id_hex = ?1",
+                params![message_id_hex],
+                |row| row.get::<_, Option<String>>(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?
+            .flatten();
+        raw.as_deref().map(deserialize_json).transpose()
+    }
+
+    pub(crate) fn delete_message(
+        &self,
+        message_id_hex: &str,
+        reason: &str,
+    ) -> Result<Vec<ProjectionInvalidation>, NodeError> {
+        let mut connection = self.connect()?;
+        let transaction = connection
+            .transaction()
+            .map_err(|_| NodeError::IoError {})?;
+        let conversation_id: Option<String> = transaction
+            .query_row(
+                "SELECT conversation_id FROM messages WHERE message_id_hex = ?1",
+                params![message_id_hex],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?;
+        let Some(conversation_id) = conversation_id else {
+            return Ok(Vec::new());
+        };
+        transaction
+            .execute(
+                "DELETE FROM messages WHERE message_id_hex = ?1",
+                params![message_id_hex],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let messages = self.bump_projection_revision_tx(
+            &transaction,
+            ProjectionScope::Messages {},
+            Some(conversation_id),
+            Some(reason.to_string()),
+        )?;
+        let conversations = self.bump_projection_revision_tx(
+            &transaction,
+            ProjectionScope::Conversations {},
+            None,
+            Some(reason.to_string()),
+        )?;
+        transaction.commit().map_err(|_| NodeError::IoError {})?;
+        Ok(vec![messages, conversations])
+    }
+
+    pub fn get_message(&self, message_id_hex: &str) -> Result<Option<MessageRecord>, NodeError> {
+        let connection = self.connect()?;
+        let raw = connection
+            .query_row(
+                "SELECT json FROM messages WHERE message_id_hex = ?1",
+                params![message_id_hex],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?;
+        raw.map(|raw| deserialize_json(&raw)).transpose()
+    }
+
+    // Moves the conversation's read mark forward and returns the inbound
+    // messages it newly covers, so the caller can acknowledge them.
+    pub(crate) fn mark_conversation_read_resolved(
+        &self,
+        conversation_id: &str,
+        read_at_ms: u64,
+        resolver: &ConversationPeerResolver,
+    ) -> Result<(Vec<MessageRecord>, Vec<ProjectionInvalidation>), NodeError> {
+        let canonical_id = resolver.canonical_for(conversation_id);
+        if canonical_id.is_empty() {
+            return Err(NodeError::InvalidConfig {});
+        }
+        let previous = self
+            .conversation_read_marks_resolved(resolver)?
+            .get(&canonical_id)
+            .copied();
+        let newly_read = self
+            .list_messages_resolved(Some(canonical_id.as_str()), resolver)?
+            .into_iter()
+            .filter(|message| is_unread_message(message, previous))
+            .filter(|message| inbound_message_at_ms(message) <= read_at_ms)
+            .collect::<Vec<_>>();
+
+        let mut connection = self.connect()?;
+        let transaction = connection
+            .transaction()
+            .map_err(|_| NodeError::IoError {})?;
+        transaction
+            .execute(
+                "INSERT INTO conversation_reads (conversation_id, read_at_ms)
+                 VALUES (?1, ?2)
+                 ON CONFLICT(conversation_id) DO UPDATE SET
+                    read_at_ms = max(read_at_ms, excluded.read_at_ms)",
+                params![canonical_id, read_at_ms as i64],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let conversations = self.bump_projection_revision_tx(
+            &transaction,
+            ProjectionScope::Conversations {},
+            None,
+            Some("conversation-read".to_string()),
+        )?;
+        transaction.commit().map_err(|_| NodeError::IoError {})?;
+        Ok((newly_read, vec![conversations]))
+    }
+
+    // Unread counts keyed by the peer each message was exchanged with, for
+    // callers that group conversations by raw destination rather than by the
+    // resolver's canonical id. Read marks are still matched canonically.
+    pub(crate) fn unread_counts_by_peer(
+        &self,
+        resolver: &ConversationPeerResolver,
+    ) -> Result<HashMap<String, u32>, NodeError> {
+        let read_marks = self.conversation_read_marks_resolved(resolver)?;
+        let connection = self.connect()?;
+        let records = query_json_records::<MessageRecord>(
+            &connection,
+            "SELECT json FROM messages ORDER BY updated_at_ms ASC",
+        )?;
+        let mut counts = HashMap::<String, u32>::new();
+        for message in records {
+            let read_at_ms = read_marks
+                .get(&resolver.canonical_for(message.conversation_id.as_str()))
+                .copied();
+            if is_unread_message(&message, read_at_ms) {
+                let count = counts
+                    .entry(canonical_message_peer_key(&message))
+                    .or_default();
+                *count = count.saturating_add(1);
+            }
+        }
+        Ok(counts)
+    }
+
+    // Marks stored under an alias, or under a canonical id the resolver has
+    // since folded into another thread, count for the thread they resolve to.
+    fn conversation_read_marks_resolved(
+        &self,
+        resolver: &ConversationPeerResolver,
+    ) -> Result<HashMap<String, u64>, NodeError> {
+        let mut marks = HashMap::<String, u64>::new();
+        for (conversation_id, read_at_ms) in self.conversation_read_marks()? {
+            let mark = marks
+                .entry(resolver.canonical_for(conversation_id.as_str()))
+                .or_default();
+            *mark = (*mark).max(read_at_ms);
+        }
+        Ok(marks)
+    }
+
+    fn conversation_read_marks(&self) -> Result<HashMap<String, u64>, NodeError> {
+        let connection = self.connect()?;
+        let mut statement = connection
+            .prepare("SELECT conversation_id, read_at_ms FROM conversation_reads")
+            .map_err(|_| NodeError::IoError {})?;
+        let rows = statement
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i64>(1)?.max(0) as u64,
+                ))
+            })
+            .map_err(|_| NodeError::IoError {})?;
+        let mut marks = HashMap::new();
+        for row in rows {
+            let (conversation_id, read_at_ms) = row.map_err(|_| NodeError::IoError {})?;
+            marks.insert(conversation_id, read_at_ms);
+        }
+        Ok(marks)
+    }
+
     pub fn upsert_message(
         &self,
         message: &MessageRecord,
@@ -1375,6 +2619,12 @@ impl AppStateStore {
                     params![id],
                 )
                 .map_err(|_| NodeError::IoError {})?;
+            transaction
+                .execute(
+                    "DELETE FROM conversation_reads WHERE conversation_id = ?1",
+                    params![id],
+                )
+                .map_err(|_| NodeError::IoError {})?;
         }
         let messages = self.bump_projection_revision_tx(
             &transaction,
@@ -1408,10 +2658,11 @@ impl AppStateStore {
             .transaction()
             .map_err(|_| NodeError::IoError {})?;
         self.write_telemetry_tx(&transaction, position)?;
+        self.append_telemetry_track_tx(&transaction, position)?;
         let invalidation = self.bump_projection_revision_tx(
             &transaction,
             ProjectionScope::Telemetry {},
-            Some(position.callsign.to_ascii_lowercase()),
+            Some(callsign_key(&position.callsign)),
             Some("telemetry-upserted".to_string()),
         )?;
         transaction.commit().map_err(|_| NodeError::IoError {})?;
@@ -1429,17 +2680,121 @@ impl AppStateStore {
         transaction
             .execute(
                 "DELETE FROM telemetry_positions WHERE callsign_key = ?1",
-                params![callsign.trim().to_ascii_lowercase()],
+                params![callsign_key(callsign)],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        transaction
+            .execute(
+                "DELETE FROM telemetry_tracks WHERE callsign_key = ?1",
+                params![callsign_key(callsign)],
             )
             .map_err(|_| NodeError::IoError {})?;
         let invalidation = self.bump_projection_revision_tx(
             &transaction,
             ProjectionScope::Telemetry {},
-            Some(callsign.trim().to_ascii_lowercase()),
+            Some(callsign_key(callsign)),
             Some("telemetry-deleted".to_string()),
         )?;
         transaction.commit().map_err(|_| NodeError::IoError {})?;
-        Ok(invalidation)
+        Ok(invalidation)
+    }
+
+    // Returns the track oldest-first; `limit` keeps the most recent points.
+    pub fn get_telemetry_track(
+        &self,
+        callsign: &str,
+        since_ms: Option<u64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<TelemetryPositionRecord>, NodeError> {
+        let connection = self.connect()?;
+        let mut statement = connection
+            .prepare(
+                "SELECT json FROM telemetry_tracks
+                 WHERE callsign_key = ?1 AND updated_at_ms >= ?2
+                 ORDER BY updated_at_ms DESC
+                 LIMIT ?3",
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let rows = statement
+            .query_map(
+                params![
+                    callsign_key(callsign),
+                    since_ms.unwrap_or(0) as i64,
+                    limit.map_or(-1, i64::from)
+                ],
+                |row| row.get::<_, String>(0),
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let mut track = Vec::new();
+        for row in rows {
+            track.push(deserialize_json::<TelemetryPositionRecord>(
+                &row.map_err(|_| NodeError::IoError {})?,
+            )?);
+        }
+        track.reverse();
+        Ok(track)
+    }
+
+    // Marks positions older than the stale window and purges those older than
+    // the expiry window; a zero window disables that step.
+    pub fn sweep_telemetry_positions(
+        &self,
+        stale_after_ms: u64,
+        expire_after_ms: u64,
+        now_ms: u64,
+    ) -> Result<Vec<ProjectionInvalidation>, NodeError> {
+        let mut connection = self.connect()?;
+        let transaction = connection
+            .transaction()
+            .map_err(|_| NodeError::IoError {})?;
+        let mut invalidations = Vec::new();
+
+        if expire_after_ms > 0 {
+            let expired_before_ms = now_ms.saturating_sub(expire_after_ms);
+            let purged = transaction
+                .execute(
+                    "DELETE FROM telemetry_positions WHERE updated_at_ms < ?1",
+                    params![expired_before_ms as i64],
+                )
+                .map_err(|_| NodeError::IoError {})?;
+            if purged > 0 {
+                invalidations.push(self.bump_projection_revision_tx(
+                    &transaction,
+                    ProjectionScope::Telemetry {},
+                    None,
+                    Some("telemetry-expired".to_string()),
+                )?);
+            }
+        }
+
+        if stale_after_ms > 0 {
+            let stale_before_ms = now_ms.saturating_sub(stale_after_ms);
+            let newly_stale = query_json_records::<TelemetryPositionRecord>(
+                &transaction,
+                format!(
+                    "SELECT json FROM telemetry_positions WHERE updated_at_ms < {stale_before_ms}"
+                )
+                .as_str(),
+            )?
+            .into_iter()
+            .filter(|position| !position.stale)
+            .collect::<Vec<_>>();
+            for mut position in newly_stale.iter().cloned() {
+                position.stale = true;
+                self.write_telemetry_tx(&transaction, &position)?;
+            }
+            if !newly_stale.is_empty() {
+                invalidations.push(self.bump_projection_revision_tx(
+                    &transaction,
+                    ProjectionScope::Telemetry {},
+                    None,
+                    Some("telemetry-stale".to_string()),
+                )?);
+            }
+        }
+
+        transaction.commit().map_err(|_| NodeError::IoError {})?;
+        Ok(invalidations)
     }
 
     pub fn get_sos_settings(&self) -> Result<Option<SosSettingsRecord>, NodeError> {
@@ -1589,6 +2944,51 @@ impl AppStateStore {
         )
     }
 
+    // Received clips live next to the database so they share its lifetime and
+    // the host app's storage permissions.
+    pub fn write_sos_audio_file(&self, file_name: &str, bytes: &[u8]) -> Result<String, NodeError> {
+        self.write_storage_file(Path::new(SOS_AUDIO_DIR_NAME), file_name, bytes)
+    }
+
+    // Attachments are grouped per message so two messages carrying the same
+    // file name never overwrite each other.
+    pub fn write_message_attachment(
+        &self,
+        message_id_hex: &str,
+        file_name: &str,
+        bytes: &[u8],
+    ) -> Result<String, NodeError> {
+        let message_dir = message_id_hex
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect::<String>();
+        if message_dir.is_empty() {
+            return Err(NodeError::InvalidConfig {});
+        }
+        self.write_storage_file(
+            &Path::new(MESSAGE_ATTACHMENTS_DIR_NAME).join(message_dir),
+            file_name,
+            bytes,
+        )
+    }
+
+    fn write_storage_file(
+        &self,
+        subdirectory: &Path,
+        file_name: &str,
+        bytes: &[u8],
+    ) -> Result<String, NodeError> {
+        let directory = self
+            .db_path
+            .parent()
+            .map(|parent| parent.join(subdirectory))
+            .ok_or(NodeError::IoError {})?;
+        fs::create_dir_all(&directory).map_err(|_| NodeError::IoError {})?;
+        let path = directory.join(file_name);
+        fs::write(&path, bytes).map_err(|_| NodeError::IoError {})?;
+        Ok(path.to_string_lossy().to_string())
+    }
+
     pub fn upsert_sos_audio(
         &self,
         audio: &SosAudioRecord,
@@ -1626,6 +3026,161 @@ impl AppStateStore {
         Ok(invalidation)
     }
 
+    pub fn list_sos_deliveries(
+        &self,
+        incident_id: &str,
+    ) -> Result<Vec<SosDeliveryRecord>, NodeError> {
+        let connection = self.connect()?;
+        let mut statement = connection
+            .prepare(
+                "SELECT json FROM sos_deliveries WHERE incident_id = ?1 ORDER BY destination_hex ASC, kind ASC",
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let rows = statement
+            .query_map(params![incident_id], |row| row.get::<_, String>(0))
+            .map_err(|_| NodeError::IoError {})?;
+        let mut records = Vec::new();
+        for row in rows {
+            records.push(deserialize_json(&row.map_err(|_| NodeError::IoError {})?)?);
+        }
+        Ok(records)
+    }
+
+    pub fn get_sos_delivery(
+        &self,
+        incident_id: &str,
+        destination_hex: &str,
+        kind: SosMessageKind,
+    ) -> Result<Option<SosDeliveryRecord>, NodeError> {
+        let connection = self.connect()?;
+        let raw: Option<String> = connection
+            .query_row(
+                "SELECT json FROM sos_deliveries
+                 WHERE incident_id = ?1 AND destination_hex = ?2 AND kind = ?3",
+                params![incident_id, destination_hex, sos_kind_label(kind)],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?;
+        raw.map(|value| deserialize_json(&value)).transpose()
+    }
+
+    pub fn upsert_sos_delivery(
+        &self,
+        delivery: &SosDeliveryRecord,
+    ) -> Result<ProjectionInvalidation, NodeError> {
+        let mut connection = self.connect()?;
+        let transaction = connection
+            .transaction()
+            .map_err(|_| NodeError::IoError {})?;
+        self.write_sos_delivery_tx(&transaction, delivery)?;
+        let invalidation = self.bump_projection_revision_tx(
+            &transaction,
+            ProjectionScope::Sos {},
+            Some(delivery.incident_id.clone()),
+            Some("sos-delivery-updated".to_string()),
+        )?;
+        transaction.commit().map_err(|_| NodeError::IoError {})?;
+        Ok(invalidation)
+    }
+
+    // Transport receipts only carry the LXMF message id, so delivery is matched
+    // against the id recorded for the recipient's latest attempt.
+    pub fn mark_sos_delivery_delivered(
+        &self,
+        lxmf_message_id_hex: &str,
+        delivered_at_ms: u64,
+    ) -> Result<Option<(SosDeliveryRecord, ProjectionInvalidation)>, NodeError> {
+        let mut connection = self.connect()?;
+        let transaction = connection
+            .transaction()
+            .map_err(|_| NodeError::IoError {})?;
+        let raw: Option<String> = transaction
+            .query_row(
+                "SELECT json FROM sos_deliveries WHERE lxmf_message_id_hex = ?1",
+                params![lxmf_message_id_hex],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?;
+        let Some(raw) = raw else {
+            return Ok(None);
+        };
+        let mut delivery: SosDeliveryRecord = deserialize_json(&raw)?;
+        if matches!(delivery.state, SosDeliveryState::Delivered {}) {
+            return Ok(None);
+        }
+        delivery.state = SosDeliveryState::Delivered {};
+        delivery.detail = None;
+        delivery.updated_at_ms = delivered_at_ms;
+        self.write_sos_delivery_tx(&transaction, &delivery)?;
+        let invalidation = self.bump_projection_revision_tx(
+            &transaction,
+            ProjectionScope::Sos {},
+            Some(delivery.incident_id.clone()),
+            Some("sos-delivery-delivered".to_string()),
+        )?;
+        transaction.commit().map_err(|_| NodeError::IoError {})?;
+        Ok(Some((delivery, invalidation)))
+    }
+
+    pub fn list_sos_responders(
+        &self,
+        incident_id: &str,
+    ) -> Result<Vec<SosResponderRecord>, NodeError> {
+        let connection = self.connect()?;
+        let mut statement = connection
+            .prepare(
+                "SELECT json FROM sos_responders WHERE incident_id = ?1 ORDER BY updated_at_ms DESC",
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let rows = statement
+            .query_map(params![incident_id], |row| row.get::<_, String>(0))
+            .map_err(|_| NodeError::IoError {})?;
+        let mut records = Vec::new();
+        for row in rows {
+            records.push(deserialize_json(&row.map_err(|_| NodeError::IoError {})?)?);
+        }
+        Ok(records)
+    }
+
+    // A responder keeps one row per incident; older acknowledgements that arrive
+    // late over propagation never overwrite a newer status.
+    pub fn upsert_sos_responder(
+        &self,
+        responder: &SosResponderRecord,
+    ) -> Result<ProjectionInvalidation, NodeError> {
+        let mut connection = self.connect()?;
+        let transaction = connection
+            .transaction()
+            .map_err(|_| NodeError::IoError {})?;
+        let json = serialize_json(responder)?;
+        transaction
+            .execute(
+                "INSERT INTO sos_responders (incident_id, responder_hex, updated_at_ms, json)
+                 VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT(incident_id, responder_hex) DO UPDATE SET
+                    updated_at_ms = excluded.updated_at_ms,
+                    json = excluded.json
+                 WHERE excluded.updated_at_ms >= sos_responders.updated_at_ms",
+                params![
+                    responder.incident_id,
+                    responder.responder_hex,
+                    responder.updated_at_ms as i64,
+                    json
+                ],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        let invalidation = self.bump_projection_revision_tx(
+            &transaction,
+            ProjectionScope::Sos {},
+            Some(responder.incident_id.clone()),
+            Some("sos-responder-updated".to_string()),
+        )?;
+        transaction.commit().map_err(|_| NodeError::IoError {})?;
+        Ok(invalidation)
+    }
+
     pub fn bump_projection_revision(
         &self,
         scope: ProjectionScope,
@@ -1689,7 +3244,7 @@ impl AppStateStore {
                     deleted_at_ms = excluded.deleted_at_ms,
                     json = excluded.json",
                 params![
-                    record.callsign.to_ascii_lowercase(),
+                    callsign_key(&record.callsign),
                     record.team_uid,
                     record.overall_status,
                     record.updated_at_ms as i64,
@@ -1701,6 +3256,41 @@ impl AppStateStore {
         Ok(())
     }
 
+    // Appends a revision unless nothing an after-action review would look at
+    // has changed since the last one, e.g. when only the sync state moved.
+    fn record_eam_revision_tx(
+        &self,
+        transaction: &Transaction<'_>,
+        record: &EamProjectionRecord,
+    ) -> Result<(), NodeError> {
+        let callsign_key = callsign_key(&record.callsign);
+        let previous = transaction
+            .query_row(
+                "SELECT json FROM eam_revisions WHERE callsign_key = ?1
+                 ORDER BY revision DESC LIMIT 1",
+                params![callsign_key],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?
+            .map(|raw| deserialize_json::<EamProjectionRecord>(&raw))
+            .transpose()?;
+        if previous
+            .as_ref()
+            .is_some_and(|previous| eam_field_changes(Some(previous), record).is_empty())
+        {
+            return Ok(());
+        }
+        transaction
+            .execute(
+                "INSERT INTO eam_revisions (callsign_key, recorded_at_ms, json)
+                 VALUES (?1, ?2, ?3)",
+                params![callsign_key, now_ms() as i64, serialize_json(record)?],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        Ok(())
+    }
+
     fn write_event_tx(
         &self,
         transaction: &Transaction<'_>,
@@ -1803,6 +3393,23 @@ impl AppStateStore {
         Ok(())
     }
 
+    // Colour teams keep `updated_at_ms = 0` so any edit replaces the seed, and
+    // a deleted colour team is not brought back.
+    fn seed_color_teams(&self) -> Result<(), NodeError> {
+        let connection = self.connect()?;
+        for team in color_team_records() {
+            connection
+                .execute(
+                    "INSERT OR IGNORE INTO teams
+                        (team_uid, parent_team_uid, updated_at_ms, deleted_at_ms, json)
+                     VALUES (?1, NULL, 0, NULL, ?2)",
+                    params![team.team_uid, serialize_json(&team)?],
+                )
+                .map_err(|_| NodeError::IoError {})?;
+        }
+        Ok(())
+    }
+
     fn load_checklist_tx(
         &self,
         transaction: &Transaction<'_>,
@@ -1827,12 +3434,42 @@ impl AppStateStore {
         transaction: &Transaction<'_>,
         message: &MessageRecord,
     ) -> Result<(), NodeError> {
-        let canonical_message = canonicalize_chat_message(message);
+        let mut canonical_message = canonicalize_chat_message(message);
+        let stored = transaction
+            .query_row(
+                "SELECT json FROM messages WHERE message_id_hex = ?1",
+                params![canonical_message.message_id_hex],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|_| NodeError::IoError {})?
+            .map(|raw| deserialize_json::<MessageRecord>(&raw))
+            .transpose()?;
+        if let Some(stored) = stored {
+            carry_message_actions(&mut canonical_message, &stored);
+            // A read receipt is final; a late transport receipt or retry must
+            // not move a stored message back out of Read.
+            if matches!(stored.state, MessageState::Read {})
+                && !matches!(canonical_message.state, MessageState::Read {})
+            {
+                canonical_message.state = stored.state;
+                canonical_message.detail = stored.detail;
+            }
+        }
         let json = serialize_json(&canonical_message)?;
+        // The creation time and search row id are fixed by the first write.
+        let created_at_ms = canonical_message
+            .received_at_ms
+            .or(canonical_message.sent_at_ms)
+            .unwrap_or(canonical_message.updated_at_ms);
         transaction
             .execute(
-                "INSERT INTO messages (message_id_hex, conversation_id, updated_at_ms, json)
-                 VALUES (?1, ?2, ?3, ?4)
+                "INSERT INTO messages
+                    (message_id_hex, conversation_id, updated_at_ms, json, created_at_ms, search_rowid)
+                 VALUES (
+                    ?1, ?2, ?3, ?4, ?5,
+                    (SELECT coalesce(max(search_rowid), 0) + 1 FROM messages)
+                 )
                  ON CONFLICT(message_id_hex) DO UPDATE SET
                     conversation_id = excluded.conversation_id,
                     updated_at_ms = excluded.updated_at_ms,
@@ -1841,7 +3478,8 @@ impl AppStateStore {
                     canonical_message.message_id_hex,
                     canonical_message.conversation_id,
                     canonical_message.updated_at_ms as i64,
-                    json
+                    json,
+                    created_at_ms as i64
                 ],
             )
             .map_err(|_| NodeError::IoError {})?;
@@ -1909,7 +3547,7 @@ impl AppStateStore {
                     updated_at_ms = excluded.updated_at_ms,
                     json = excluded.json",
                 params![
-                    position.callsign.to_ascii_lowercase(),
+                    callsign_key(&position.callsign),
                     position.updated_at_ms as i64,
                     json
                 ],
@@ -1918,6 +3556,66 @@ impl AppStateStore {
         Ok(())
     }
 
+    fn append_telemetry_track_tx(
+        &self,
+        transaction: &Transaction<'_>,
+        position: &TelemetryPositionRecord,
+    ) -> Result<(), NodeError> {
+        let callsign_key = callsign_key(&position.callsign);
+        let json = serialize_json(position)?;
+        transaction
+            .execute(
+                "INSERT INTO telemetry_tracks (callsign_key, updated_at_ms, json)
+                 VALUES (?1, ?2, ?3)
+                 ON CONFLICT(callsign_key, updated_at_ms) DO UPDATE SET
+                    json = excluded.json",
+                params![callsign_key, position.updated_at_ms as i64, json],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        transaction
+            .execute(
+                "DELETE FROM telemetry_tracks
+                 WHERE callsign_key = ?1 AND updated_at_ms < (
+                    SELECT MIN(updated_at_ms) FROM (
+                        SELECT updated_at_ms FROM telemetry_tracks
+                        WHERE callsign_key = ?1
+                        ORDER BY updated_at_ms DESC
+                        LIMIT ?2
+                    )
+                 )",
+                params![callsign_key, TELEMETRY_TRACK_MAX_POINTS as i64],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        Ok(())
+    }
+
+    fn write_sos_delivery_tx(
+        &self,
+        transaction: &Transaction<'_>,
+        delivery: &SosDeliveryRecord,
+    ) -> Result<(), NodeError> {
+        let json = serialize_json(delivery)?;
+        transaction
+            .execute(
+                "INSERT INTO sos_deliveries (incident_id, destination_hex, kind, lxmf_message_id_hex, updated_at_ms, json)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                 ON CONFLICT(incident_id, destination_hex, kind) DO UPDATE SET
+                    lxmf_message_id_hex = excluded.lxmf_message_id_hex,
+                    updated_at_ms = excluded.updated_at_ms,
+                    json = excluded.json",
+                params![
+                    delivery.incident_id,
+                    delivery.destination_hex,
+                    sos_kind_label(delivery.kind),
+                    delivery.lxmf_message_id_hex,
+                    delivery.updated_at_ms as i64,
+                    json
+                ],
+            )
+            .map_err(|_| NodeError::IoError {})?;
+        Ok(())
+    }
+
     fn write_sos_alert_tx(
         &self,
         transaction: &Transaction<'_>,
@@ -2005,6 +3703,30 @@ fn serialize_json<T: Serialize>(value: &T) -> Result<String, NodeError> {
     serde_json::to_string(value).map_err(|_| NodeError::InternalError {})
 }
 
+fn write_outbound_queue_record(
+    connection: &Connection,
+    record: &OutboundQueueRecord,
+) -> Result<(), NodeError> {
+    let json = serialize_json(record)?;
+    connection
+        .execute(
+            "INSERT INTO outbound_queue (queue_id, destination_hex, attempts, enqueued_at_ms, json)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(queue_id) DO UPDATE SET
+                attempts = excluded.attempts,
+                json = excluded.json",
+            params![
+                record.queue_id,
+                record.payload.destination_hex(),
+                record.attempts as i64,
+                record.enqueued_at_ms as i64,
+                json
+            ],
+        )
+        .map_err(|_| NodeError::IoError {})?;
+    Ok(())
+}
+
 fn deserialize_json<T: serde::de::DeserializeOwned>(value: &str) -> Result<T, NodeError> {
     serde_json::from_str(value).map_err(|_| NodeError::InternalError {})
 }
@@ -2031,6 +3753,9 @@ fn canonicalize_chat_message_with_resolver(
 }
 
 fn canonical_message_peer_key(message: &MessageRecord) -> String {
+    if is_group_conversation_id(message.conversation_id.as_str()) {
+        return normalize_message_peer_key(message.conversation_id.as_str());
+    }
     let preferred = match message.direction {
         MessageDirection::Inbound {} => message
             .source_hex
@@ -2043,19 +3768,231 @@ fn canonical_message_peer_key(message: &MessageRecord) -> String {
     if !normalized.is_empty() {
         return normalized;
     }
-    normalize_message_peer_key(message.conversation_id.as_str())
+    normalize_message_peer_key(message.conversation_id.as_str())
+}
+
+fn inbound_message_at_ms(message: &MessageRecord) -> u64 {
+    message.received_at_ms.unwrap_or(message.updated_at_ms)
+}
+
+fn is_unread_message(message: &MessageRecord, read_at_ms: Option<u64>) -> bool {
+    matches!(message.direction, MessageDirection::Inbound {})
+        && read_at_ms.is_none_or(|read_at_ms| inbound_message_at_ms(message) > read_at_ms)
+}
+
+// A conversation's canonical id and every alias folded into it, as a JSON
+// array for `json_each`. Pages and searches match rows still filed under an
+// alias instead of rewriting the whole table before each query.
+fn conversation_ids_json(
+    resolver: &ConversationPeerResolver,
+    conversation_id: &str,
+) -> Result<String, NodeError> {
+    let canonical_id = resolver.canonical_for(conversation_id);
+    let mut ids = resolver.aliases_for_canonical(canonical_id.as_str());
+    ids.push(canonical_id);
+    serialize_json(&ids)
+}
+
+fn normalize_message_peer_key(value: &str) -> String {
+    value.trim().to_ascii_lowercase()
+}
+
+// The key EAMs and telemetry are stored under, so a callsign typed with stray
+// whitespace or different case still lands on the same row.
+fn callsign_key(callsign: &str) -> String {
+    callsign.trim().to_ascii_lowercase()
+}
+
+fn truncate_preview(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    Some(trimmed.chars().take(120).collect())
+}
+
+// Databases created before messages carried a creation time and a stable
+// search row id gain both columns once. Existing rows keep the row id the
+// search index was built on, and the triggers are recreated against the new
+// column.
+fn upgrade_messages_table(connection: &Connection) -> Result<(), NodeError> {
+    let upgraded = connection
+        .query_row(
+            "SELECT count(*) FROM pragma_table_info('messages') WHERE name = 'search_rowid'",
+            [],
+            |row| row.get::<_, i64>(0),
+        )
+        .map_err(|_| NodeError::IoError {})?
+        > 0;
+    if !upgraded {
+        connection
+            .execute_batch(
+                "
+            ALTER TABLE messages ADD COLUMN created_at_ms INTEGER NOT NULL DEFAULT 0;
+            ALTER TABLE messages ADD COLUMN search_rowid INTEGER;
+            UPDATE messages SET
+                created_at_ms = coalesce(
+                    json_extract(json, '$.received_at_ms'),
+                    json_extract(json, '$.sent_at_ms'),
+                    updated_at_ms
+                ),
+                search_rowid = rowid;
+            DROP TRIGGER IF EXISTS messages_fts_insert;
+            DROP TRIGGER IF EXISTS messages_fts_update;
+            DROP TRIGGER IF EXISTS messages_fts_delete;
+            ",
+            )
+            .map_err(|_| NodeError::IoError {})?;
+    }
+    let has_priority = connection
+        .query_row(
+            "SELECT count(*) FROM pragma_table_info('messages') WHERE name = 'priority'",
+            [],
+            |row| row.get::<_, i64>(0),
+        )
+        .map_err(|_| NodeError::IoError {})?
+        > 0;
+    if has_priority {
+        return Ok(());
+    }
+    connection
+        .execute("ALTER TABLE messages ADD COLUMN priority TEXT", [])
+        .map(|_| ())
+        .map_err(|_| NodeError::IoError {})
+}
+
+fn message_page_limit(limit: u32) -> i64 {
+    match limit {
+        0 => i64::from(MESSAGE_PAGE_DEFAULT_LIMIT),
+        value => i64::from(value.min(MESSAGE_PAGE_MAX_LIMIT)),
+    }
+}
+
+// User input is never passed to FTS5 verbatim: each term is quoted so
+// operators are matched literally, then prefix-matched. Terms without any
+// letters or digits would tokenize to nothing and are dropped.
+fn fts_match_expression(query: &str) -> Option<String> {
+    let terms = query
+        .split_whitespace()
+        .filter(|term| term.chars().any(char::is_alphanumeric))
+        .map(|term| format!("\"{}\"*", term.replace('"', "\"\"")))
+        .collect::<Vec<_>>();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+fn summarize_eams(
+    team_uid: &str,
+    records: Vec<EamProjectionRecord>,
+    schema: &EamSchemaRecord,
+    now_ms: u64,
+) -> Option<EamTeamSummaryRecord> {
+    if records.is_empty() {
+        return None;
+    }
+    let mut summary = EamTeamSummaryRecord {
+        team_uid: team_uid.to_string(),
+        total: records.len() as u32,
+        active_total: 0,
+        deleted_total: 0,
+        overall_status: None,
+        green_total: 0,
+        yellow_total: 0,
+        red_total: 0,
+        expired_total: 0,
+        not_reporting_total: 0,
+        low_confidence_total: 0,
+        status_totals: Vec::new(),
+        updated_at_ms: 0,
+    };
+    let mut current_statuses = Vec::new();
+    for record in records {
+        summary.updated_at_ms = summary.updated_at_ms.max(record.updated_at_ms);
+        if record.deleted_at_ms.is_some() {
+            summary.deleted_total += 1;
+            continue;
+        }
+        if !is_eam_reporting(&record, now_ms) {
+            summary.not_reporting_total += 1;
+        }
+        // Expired reports no longer say anything about the member's
+        // current state, so they stay out of the status roll-up.
+        if record.expired || is_eam_expired(&record, now_ms) {
+            summary.expired_total += 1;
+            continue;
+        }
+        summary.active_total += 1;
+        if is_low_confidence(&record) {
+            summary.low_confidence_total += 1;
+        }
+        match record.overall_status.as_deref() {
+            Some("Green") => summary.green_total += 1,
+            Some("Yellow") => summary.yellow_total += 1,
+            Some("Red") => summary.red_total += 1,
+            _ => {}
+        }
+        if let Some(status) = record.overall_status {
+            current_statuses.push(status);
+        }
+    }
+    let mut ranked_values = schema.values.iter().collect::<Vec<_>>();
+    ranked_values.sort_by_key(|value| value.rank);
+    summary.status_totals = ranked_values
+        .into_iter()
+        .map(|value| EamStatusTotal {
+            status: value.value.clone(),
+            total: current_statuses
+                .iter()
+                .filter(|status| **status == value.value)
+                .count() as u32,
+        })
+        .collect();
+    // The team roll-up follows the schema's rule, worst-of by default.
+    summary.overall_status =
+        aggregate_eam_status(schema, current_statuses.iter().map(String::as_str));
+    Some(summary)
 }
 
-fn normalize_message_peer_key(value: &str) -> String {
-    value.trim().to_ascii_lowercase()
+fn write_team_tx(transaction: &Transaction<'_>, team: &TeamRecord) -> Result<(), NodeError> {
+    transaction
+        .execute(
+            "INSERT INTO teams (team_uid, parent_team_uid, updated_at_ms, deleted_at_ms, json)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(team_uid) DO UPDATE SET
+                parent_team_uid = excluded.parent_team_uid,
+                updated_at_ms = excluded.updated_at_ms,
+                deleted_at_ms = excluded.deleted_at_ms,
+                json = excluded.json",
+            params![
+                team.team_uid,
+                team.parent_team_uid,
+                team.updated_at_ms as i64,
+                team.deleted_at_ms.map(|value| value as i64),
+                serialize_json(team)?
+            ],
+        )
+        .map_err(|_| NodeError::IoError {})?;
+    Ok(())
 }
 
-fn truncate_preview(value: &str) -> Option<String> {
-    let trimmed = value.trim();
-    if trimmed.is_empty() {
-        return None;
-    }
-    Some(trimmed.chars().take(120).collect())
+fn write_eam_schema_tx(
+    transaction: &Transaction<'_>,
+    schema: &EamSchemaRecord,
+) -> Result<(), NodeError> {
+    transaction
+        .execute(
+            "INSERT INTO eam_schemas (schema_uid, updated_at_ms, json)
+             VALUES (?1, ?2, ?3)
+             ON CONFLICT(schema_uid) DO UPDATE SET
+                updated_at_ms = excluded.updated_at_ms,
+                json = excluded.json",
+            params![
+                schema.schema_uid,
+                schema.updated_at_ms as i64,
+                serialize_json(schema)?
+            ],
+        )
+        .map_err(|_| NodeError::IoError {})?;
+    Ok(())
 }
 
 fn query_json_records<T: serde::de::DeserializeOwned>(
@@ -2609,6 +4546,7 @@ fn projection_scope_name(scope: ProjectionScope) -> &'static str {
         ProjectionScope::Messages {} => "Messages",
         ProjectionScope::Telemetry {} => "Telemetry",
         ProjectionScope::Sos {} => "Sos",
+        ProjectionScope::Teams {} => "Teams",
     }
 }
 
@@ -2820,7 +4758,7 @@ fn unix_seconds_now() -> i64 {
         .unwrap_or_default()
 }
 
-fn parse_rfc3339_epoch_seconds(timestamp: &str) -> Option<i64> {
+pub(crate) fn parse_rfc3339_epoch_seconds(timestamp: &str) -> Option<i64> {
     let trimmed = timestamp.trim();
     let suffix = trimmed.strip_suffix('Z')?;
     let (date, time) = suffix.split_once('T')?;
@@ -2856,7 +4794,7 @@ fn parse_rfc3339_epoch_seconds(timestamp: &str) -> Option<i64> {
     Some(days_from_civil(year, month, day) * 86_400 + hour * 3_600 + minute * 60 + second)
 }
 
-fn format_rfc3339_from_epoch_seconds(epoch_seconds: i64) -> String {
+pub(crate) fn format_rfc3339_from_epoch_seconds(epoch_seconds: i64) -> String {
     let days_since_epoch = epoch_seconds.div_euclid(86_400);
     let seconds_of_day = epoch_seconds.rem_euclid(86_400);
     let (year, month, day) = civil_from_days(days_since_epoch);
@@ -2898,14 +4836,15 @@ mod tests {
 
     use super::*;
     use crate::types::{
-        AppSettingsRecord, ChecklistCellRecord, ChecklistColumnRecord, ChecklistColumnType,
-        ChecklistCreateFromTemplateRequest, ChecklistMode, ChecklistOriginType, ChecklistRecord,
-        ChecklistSettingsRecord, ChecklistStatusCounts, ChecklistSystemColumnKey,
-        ChecklistTaskCellSetRequest, ChecklistTaskRecord, ChecklistTaskRowAddRequest,
-        ChecklistTaskRowDeleteRequest, ChecklistTaskRowStyleSetRequest, ChecklistTaskStatus,
-        ChecklistTaskStatusSetRequest, ChecklistTemplateImportCsvRequest, ChecklistUpdatePatch,
-        ChecklistUpdateRequest, ChecklistUserTaskStatus, HubMode, HubSettingsRecord,
-        MessageDirection, MessageMethod, MessageState, ProjectionScope, TelemetrySettingsRecord,
+        sample_eam, AppSettingsRecord, ChecklistCellRecord, ChecklistColumnRecord,
+        ChecklistColumnType, ChecklistCreateFromTemplateRequest, ChecklistMode,
+        ChecklistOriginType, ChecklistRecord, ChecklistSettingsRecord, ChecklistStatusCounts,
+        ChecklistSystemColumnKey, ChecklistTaskCellSetRequest, ChecklistTaskRecord,
+        ChecklistTaskRowAddRequest, ChecklistTaskRowDeleteRequest, ChecklistTaskRowStyleSetRequest,
+        ChecklistTaskStatus, ChecklistTaskStatusSetRequest, ChecklistTemplateImportCsvRequest,
+        ChecklistUpdatePatch, ChecklistUpdateRequest, ChecklistUserTaskStatus, HubMode,
+        HubSettingsRecord, MessageDirection, MessageMethod, MessageState, ProjectionScope,
+        SosResponseKind, TeamLevel, TelemetrySettingsRecord,
     };
 
     static TEST_COUNTER: AtomicU64 = AtomicU64::new(0);
@@ -2945,6 +4884,7 @@ mod tests {
             checklists: ChecklistSettingsRecord {
                 default_task_due_step_minutes,
             },
+            propagation_sync: crate::types::PropagationSyncSettingsRecord::default(),
         }
     }
 
@@ -2970,6 +4910,10 @@ mod tests {
             sent_at_ms: Some(updated_at_ms),
             received_at_ms: None,
             updated_at_ms,
+            attachments: Vec::new(),
+            edited_at_ms: None,
+            deleted_at_ms: None,
+            reactions: Vec::new(),
         }
     }
 
@@ -3097,6 +5041,212 @@ mod tests {
         assert_eq!(messages[1].conversation_id, "abcdef");
     }
 
+    #[test]
+    fn telemetry_sweep_marks_stale_and_purges_expired_positions() {
+        let storage_dir = test_storage_dir("telemetry-sweep");
+        let store =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("create store");
+        for (callsign, updated_at_ms) in [("fresh", 95_000), ("stale", 40_000), ("gone", 1_000)] {
+            store
+                .record_local_telemetry_fix(&TelemetryPositionRecord {
+                    callsign: callsign.to_string(),
+                    lat: 44.0,
+                    lon: -63.0,
+                    alt: None,
+                    course: None,
+                    speed: None,
+                    accuracy: None,
+                    updated_at_ms,
+                    stale: false,
+                })
+                .expect("record telemetry");
+        }
+
+        let invalidations = store
+            .sweep_telemetry_positions(30_000, 90_000, 100_000)
+            .expect("sweep telemetry");
+        assert_eq!(invalidations.len(), 2);
+        assert!(invalidations
+            .iter()
+            .all(|invalidation| matches!(invalidation.scope, ProjectionScope::Telemetry {})));
+
+        let positions = store.get_telemetry_positions().expect("list telemetry");
+        assert_eq!(positions.len(), 2);
+        assert!(!positions[0].stale);
+        assert_eq!(positions[1].callsign, "stale");
+        assert!(positions[1].stale);
+
+        let repeated = store
+            .sweep_telemetry_positions(30_000, 90_000, 100_000)
+            .expect("repeat sweep");
+        assert!(repeated.is_empty());
+    }
+
+    #[test]
+    fn telemetry_track_keeps_history_behind_latest_position() {
+        let storage_dir = test_storage_dir("telemetry-track");
+        let store =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("create store");
+        for (index, updated_at_ms) in [10_000u64, 20_000, 30_000, 40_000].into_iter().enumerate() {
+            store
+                .record_local_telemetry_fix(&TelemetryPositionRecord {
+                    callsign: "Medic-1".to_string(),
+                    lat: 44.0 + index as f64 * 0.001,
+                    lon: -63.0,
+                    alt: None,
+                    course: None,
+                    speed: None,
+                    accuracy: None,
+                    updated_at_ms,
+                    stale: false,
+                })
+                .expect("record telemetry");
+        }
+
+        assert_eq!(store.get_telemetry_positions().expect("latest").len(), 1);
+        let track = store
+            .get_telemetry_track("medic-1", None, None)
+            .expect("full track");
+        assert_eq!(
+            track
+                .iter()
+                .map(|position| position.updated_at_ms)
+                .collect::<Vec<_>>(),
+            vec![10_000, 20_000, 30_000, 40_000]
+        );
+
+        let recent = store
+            .get_telemetry_track("MEDIC-1", Some(20_000), Some(2))
+            .expect("recent track");
+        assert_eq!(
+            recent
+                .iter()
+                .map(|position| position.updated_at_ms)
+                .collect::<Vec<_>>(),
+            vec![30_000, 40_000]
+        );
+
+        store
+            .delete_local_telemetry("Medic-1")
+            .expect("delete telemetry");
+        assert!(store
+            .get_telemetry_track("Medic-1", None, None)
+            .expect("deleted track")
+            .is_empty());
+    }
+
+    #[test]
+    fn sos_delivery_is_marked_delivered_by_lxmf_message_id() {
+        let storage_dir = test_storage_dir("sos-delivery");
+        let store =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("create store");
+        let mut delivery = SosDeliveryRecord {
+            incident_id: "sos-local-1".to_string(),
+            destination_hex: "a".repeat(32),
+            kind: SosMessageKind::Active {},
+            state: SosDeliveryState::Queued {},
+            attempts: 1,
+            lxmf_message_id_hex: None,
+            via_propagation: false,
+            detail: None,
+            last_attempt_at_ms: 1_000,
+            updated_at_ms: 1_000,
+        };
+        store
+            .upsert_sos_delivery(&delivery)
+            .expect("queue delivery");
+        assert!(store
+            .mark_sos_delivery_delivered("beef", 2_000)
+            .expect("unknown receipt")
+            .is_none());
+
+        delivery.state = SosDeliveryState::Sent {};
+        delivery.lxmf_message_id_hex = Some("beef".to_string());
+        delivery.updated_at_ms = 1_500;
+        store.upsert_sos_delivery(&delivery).expect("sent delivery");
+
+        let (delivered, invalidation) = store
+            .mark_sos_delivery_delivered("beef", 2_000)
+            .expect("mark delivered")
+            .expect("delivery matched");
+        assert!(matches!(delivered.state, SosDeliveryState::Delivered {}));
+        assert_eq!(delivered.updated_at_ms, 2_000);
+        assert!(matches!(invalidation.scope, ProjectionScope::Sos {}));
+        assert!(store
+            .mark_sos_delivery_delivered("beef", 3_000)
+            .expect("repeat receipt")
+            .is_none());
+
+        let update = SosDeliveryRecord {
+            kind: SosMessageKind::Update {},
+            state: SosDeliveryState::Queued {},
+            lxmf_message_id_hex: None,
+            updated_at_ms: 4_000,
+            ..delivery.clone()
+        };
+        store.upsert_sos_delivery(&update).expect("queue update");
+        let active = store
+            .get_sos_delivery(
+                "sos-local-1",
+                "a".repeat(32).as_str(),
+                SosMessageKind::Active {},
+            )
+            .expect("load active")
+            .expect("active delivery kept");
+        assert!(matches!(active.state, SosDeliveryState::Delivered {}));
+
+        let deliveries = store
+            .list_sos_deliveries("sos-local-1")
+            .expect("list deliveries");
+        assert_eq!(deliveries.len(), 2);
+        assert!(store
+            .list_sos_deliveries("sos-local-2")
+            .expect("other incident")
+            .is_empty());
+    }
+
+    #[test]
+    fn sos_responders_keep_latest_status_per_responder() {
+        let storage_dir = test_storage_dir("sos-responders");
+        let store =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("create store");
+        let responder =
+            |responder_hex: &str, kind: SosResponseKind, updated_at_ms: u64| SosResponderRecord {
+                incident_id: "sos-local-1".to_string(),
+                responder_hex: responder_hex.to_string(),
+                kind,
+                eta_seconds: None,
+                lat: None,
+                lon: None,
+                accuracy: None,
+                battery_percent: None,
+                message_id_hex: None,
+                responded_at_ms: updated_at_ms,
+                updated_at_ms,
+            };
+
+        store
+            .upsert_sos_responder(&responder("aa", SosResponseKind::EnRoute {}, 2_000))
+            .expect("en route");
+        store
+            .upsert_sos_responder(&responder("aa", SosResponseKind::Acknowledged {}, 1_000))
+            .expect("late acknowledgement");
+        store
+            .upsert_sos_responder(&responder("bb", SosResponseKind::OnScene {}, 3_000))
+            .expect("on scene");
+
+        let responders = store
+            .list_sos_responders("sos-local-1")
+            .expect("list responders");
+        assert_eq!(responders.len(), 2);
+        assert_eq!(responders[0].responder_hex, "bb");
+        assert!(matches!(responders[1].kind, SosResponseKind::EnRoute {}));
+        assert!(store
+            .list_sos_responders("sos-local-2")
+            .expect("other incident")
+            .is_empty());
+    }
+
     #[test]
     fn startup_history_lists_persisted_messages_without_runtime() {
         let storage_dir = test_storage_dir("startup-history");
@@ -3128,6 +5278,408 @@ mod tests {
         assert_eq!(messages[0].conversation_id, "peer-1");
     }
 
+    #[test]
+    fn message_history_pages_backwards_and_searches_full_text() {
+        let storage_dir = test_storage_dir("message-page-search");
+        let store =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("create store");
+        let resolver = ConversationPeerResolver::default();
+        for index in 1..=5u64 {
+            store
+                .upsert_message(&message(
+                    format!("msg-{index}").as_str(),
+                    "peer-1",
+                    MessageDirection::Inbound {},
+                    "PEER-1",
+                    Some("PEER-1"),
+                    index * 10,
+                ))
+                .expect("persist message");
+        }
+
+        let newest = store
+            .list_messages_page_resolved("peer-1", None, None, 2, &resolver)
+            .expect("newest page");
+        let ids = newest
+            .iter()
+            .map(|message| message.message_id_hex.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["msg-4", "msg-5"]);
+
+        // A state change on an older message must not move it across pages.
+        let mut read = store
+            .get_message("msg-2")
+            .expect("load message")
+            .expect("message exists");
+        read.updated_at_ms = 1_000;
+        store.upsert_message(&read).expect("update message");
+        let older = store
+            .list_messages_page_resolved(
+                "peer-1",
+                None,
+                Some(newest[0].message_id_hex.as_str()),
+                2,
+                &resolver,
+            )
+            .expect("older page");
+        let ids = older
+            .iter()
+            .map(|message| message.message_id_hex.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["msg-2", "msg-3"]);
+
+        for id in ["tie-a", "tie-b"] {
+            store
+                .upsert_message(&message(
+                    id,
+                    "peer-2",
+                    MessageDirection::Inbound {},
+                    "PEER-2",
+                    Some("PEER-2"),
+                    50,
+                ))
+                .expect("persist tied message");
+        }
+        let first = store
+            .list_messages_page_resolved("peer-2", None, None, 1, &resolver)
+            .expect("first tied page");
+        assert_eq!(first[0].message_id_hex, "tie-b");
+        let second = store
+            .list_messages_page_resolved("peer-2", None, Some("tie-b"), 1, &resolver)
+            .expect("second tied page");
+        assert_eq!(second[0].message_id_hex, "tie-a");
+
+        let mut edited = message(
+            "msg-3",
+            "peer-1",
+            MessageDirection::Inbound {},
+            "PEER-1",
+            Some("PEER-1"),
+            30,
+        );
+        edited.body_utf8 = "Water supply low at the shelter (north)".to_string();
+        store.upsert_message(&edited).expect("update message");
+
+        let hits = store
+            .search_messages_resolved("shel NORTH", None, 10, &resolver)
+            .expect("search");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].message_id_hex, "msg-3");
+        assert!(store
+            .search_messages_resolved("body msg-3", None, 10, &resolver)
+            .expect("stale body")
+            .is_empty());
+        assert!(store
+            .search_messages_resolved("water", Some("peer-2"), 10, &resolver)
+            .expect("other conversation")
+            .is_empty());
+        assert!(store
+            .search_messages_resolved("\"(", None, 10, &resolver)
+            .expect("punctuation is literal")
+            .is_empty());
+
+        store
+            .delete_conversation_resolved("peer-1", &resolver)
+            .expect("delete conversation");
+        assert!(store
+            .search_messages_resolved("water", None, 10, &resolver)
+            .expect("search after delete")
+            .is_empty());
+    }
+
+    #[test]
+    fn read_marks_persist_unread_counts_per_conversation() {
+        let storage_dir = test_storage_dir("conversation-read-marks");
+        let store =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("create store");
+        let resolver = ConversationPeerResolver::default();
+        for (id, direction, updated_at_ms) in [
+            ("in-1", MessageDirection::Inbound {}, 10),
+            ("out-1", MessageDirection::Outbound {}, 20),
+            ("in-2", MessageDirection::Inbound {}, 30),
+        ] {
+            store
+                .upsert_message(&message(
+                    id,
+                    "peer-1",
+                    direction,
+                    "PEER-1",
+                    Some("PEER-1"),
+                    updated_at_ms,
+                ))
+                .expect("persist message");
+        }
+        assert_eq!(
+            store.list_conversations().expect("conversations")[0].unread_count,
+            2
+        );
+
+        let (newly_read, _) = store
+            .mark_conversation_read_resolved("PEER-1", 30, &resolver)
+            .expect("mark read");
+        let ids = newly_read
+            .iter()
+            .map(|message| message.message_id_hex.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["in-1", "in-2"]);
+
+        store
+            .upsert_message(&message(
+                "in-3",
+                "peer-1",
+                MessageDirection::Inbound {},
+                "PEER-1",
+                Some("PEER-1"),
+                40,
+            ))
+            .expect("persist later message");
+        let reopened =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("reopen");
+        assert_eq!(
+            reopened.list_conversations().expect("conversations")[0].unread_count,
+            1
+        );
+        assert_eq!(
+            reopened
+                .unread_counts_by_peer(&resolver)
+                .expect("counts")
+                .get("peer-1"),
+            Some(&1)
+        );
+        let (newly_read, _) = reopened
+            .mark_conversation_read_resolved("peer-1", 40, &resolver)
+            .expect("mark read again");
+        assert_eq!(newly_read.len(), 1);
+        assert_eq!(
+            reopened.list_conversations().expect("conversations")[0].unread_count,
+            0
+        );
+    }
+
+    #[test]
+    fn stale_writes_do_not_roll_back_edits_or_deletions() {
+        let storage_dir = test_storage_dir("message-actions");
+        let store =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("create store");
+        let original = message(
+            "out-1",
+            "peer-a",
+            MessageDirection::Outbound {},
+            "peer-a",
+            None,
+            10,
+        );
+        let mut edited = original.clone();
+        edited.body_utf8 = "corrected".to_string();
+        edited.edited_at_ms = Some(20);
+        store.upsert_message(&edited).expect("persist edit");
+
+        let mut delivered = original.clone();
+        delivered.state = MessageState::Delivered {};
+        delivered.updated_at_ms = 30;
+        store
+            .upsert_message(&delivered)
+            .expect("stale delivery update");
+        let stored = store.get_message("out-1").expect("get").expect("message");
+        assert_eq!(stored.body_utf8, "corrected");
+        assert_eq!(stored.edited_at_ms, Some(20));
+        assert!(matches!(stored.state, MessageState::Delivered {}));
+
+        let mut deleted = stored.clone();
+        deleted.body_utf8 = String::new();
+        deleted.deleted_at_ms = Some(40);
+        store.upsert_message(&deleted).expect("persist delete");
+        store.upsert_message(&delivered).expect("stale write");
+        let stored = store.get_message("out-1").expect("get").expect("message");
+        assert_eq!(stored.deleted_at_ms, Some(40));
+        assert!(stored.body_utf8.is_empty());
+    }
+
+    #[test]
+    fn message_priority_survives_state_updates() {
+        let storage_dir = test_storage_dir("message-priority");
+        let store =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("create store");
+        let mut outbound = message(
+            "out-1",
+            "peer-a",
+            MessageDirection::Outbound {},
+            "peer-a",
+            None,
+            10,
+        );
+        store.upsert_message(&outbound).expect("persist message");
+        assert_eq!(store.get_message_priority("out-1").expect("priority"), None);
+
+        store
+            .set_message_priority("out-1", MessagePriority::Flash {})
+            .expect("set priority");
+        outbound.state = MessageState::Failed {};
+        outbound.updated_at_ms = 20;
+        store.upsert_message(&outbound).expect("update message");
+
+        assert_eq!(
+            store.get_message_priority("out-1").expect("priority"),
+            Some(MessagePriority::Flash {})
+        );
+    }
+
+    #[test]
+    fn read_marks_follow_folded_threads_and_read_state_is_final() {
+        let storage_dir = test_storage_dir("conversation-read-folded");
+        let store =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("create store");
+        let mut inbound = message(
+            "in-1",
+            "app-dest-1",
+            MessageDirection::Inbound {},
+            "APP-DEST-1",
+            Some("APP-DEST-1"),
+            10,
+        );
+        store.upsert_message(&inbound).expect("persist message");
+        store
+            .mark_conversation_read_resolved("app-dest-1", 20, &ConversationPeerResolver::default())
+            .expect("mark read before the peer is known");
+
+        let mut resolver = ConversationPeerResolver::default();
+        resolver.insert(
+            vec!["app-dest-1".to_string(), "lxmf-1".to_string()],
+            "identity-1".to_string(),
+            "lxmf-1".to_string(),
+            None,
+        );
+        let conversations = store
+            .list_conversations_resolved(&resolver)
+            .expect("conversations");
+        assert_eq!(conversations[0].conversation_id, "identity-1");
+        assert_eq!(conversations[0].unread_count, 0);
+        assert!(store
+            .unread_counts_by_peer(&resolver)
+            .expect("counts")
+            .is_empty());
+
+        inbound.state = MessageState::Read {};
+        store.upsert_message(&inbound).expect("mark message read");
+        inbound.state = MessageState::Received {};
+        inbound.updated_at_ms = 30;
+        store.upsert_message(&inbound).expect("late receipt");
+        assert!(matches!(
+            store
+                .get_message("in-1")
+                .expect("load message")
+                .expect("message exists")
+                .state,
+            MessageState::Read {}
+        ));
+    }
+
+    #[test]
+    fn group_rooms_keep_their_thread_and_member_deliveries() {
+        let storage_dir = test_storage_dir("group-conversations");
+        let store =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("create store");
+        let group = GroupConversationRecord {
+            conversation_id: "group:team:red".to_string(),
+            title: "Red Team".to_string(),
+            team_uid: Some("red".to_string()),
+            mission_uid: None,
+            member_hexes: vec!["a".repeat(32), "b".repeat(32)],
+            created_at_ms: 1,
+            updated_at_ms: 1,
+        };
+        store
+            .upsert_group_conversation(&group)
+            .expect("persist group");
+        let empty = store.list_conversations().expect("conversations");
+        assert_eq!(empty.len(), 1);
+        assert_eq!(empty[0].peer_display_name.as_deref(), Some("Red Team"));
+
+        store
+            .upsert_message(&message(
+                "group-in",
+                "group:team:red",
+                MessageDirection::Inbound {},
+                "group:team:red",
+                Some(&"a".repeat(32)),
+                10,
+            ))
+            .expect("persist group message");
+        let conversations = store.list_conversations().expect("conversations");
+        assert_eq!(conversations.len(), 1);
+        assert_eq!(conversations[0].conversation_id, "group:team:red");
+        assert_eq!(conversations[0].unread_count, 1);
+
+        store
+            .upsert_group_delivery(&GroupMemberDeliveryRecord {
+                message_id_hex: "group-in".to_string(),
+                member_hex: "b".repeat(32),
+                lxmf_message_id_hex: Some("c".repeat(64)),
+                state: MessageState::Read {},
+                detail: None,
+                updated_at_ms: 11,
+            })
+            .expect("persist delivery");
+        assert!(store
+            .mark_group_delivery_state(&"c".repeat(64), MessageState::Delivered {}, 12)
+            .expect("receipt")
+            .is_none());
+
+        store
+            .delete_group_conversation("group:team:red")
+            .expect("delete group");
+        assert!(store
+            .list_conversations()
+            .expect("conversations")
+            .is_empty());
+        assert!(store
+            .list_group_deliveries("group-in")
+            .expect("deliveries")
+            .is_empty());
+    }
+
+    #[test]
+    fn outbound_queue_survives_reopen_and_counts_attempts() {
+        let storage_dir = test_storage_dir("outbound-queue");
+        let store =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("create store");
+        let record = OutboundQueueRecord::new(
+            crate::outbound_queue::OutboundPayload::Bytes {
+                job: crate::outbound_queue::QueuedBytes {
+                    destination_hex: "a".repeat(32),
+                    bytes: b"checklist".to_vec(),
+                    fields_bytes: Some(vec![0x80]),
+                    send_mode: crate::types::SendMode::Auto {},
+                },
+            },
+            10,
+        );
+        store.enqueue_outbound(&record).expect("enqueue");
+        store
+            .record_outbound_attempt(record.queue_id.as_str(), 20)
+            .expect("attempt");
+
+        let reopened =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("reopen");
+        let queued = reopened.list_outbound_queue().expect("queue");
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].queue_id, record.queue_id);
+        assert_eq!(queued[0].attempts, 1);
+        assert_eq!(queued[0].updated_at_ms, 20);
+
+        let retry = reopened
+            .schedule_outbound_retry(record.queue_id.as_str(), 30)
+            .expect("retry")
+            .expect("still queued");
+        assert_eq!(retry.attempts, 1);
+        assert_eq!(retry.next_attempt_at_ms, 30 + 15_000);
+
+        reopened
+            .remove_outbound(record.queue_id.as_str())
+            .expect("remove");
+        assert!(reopened.list_outbound_queue().expect("queue").is_empty());
+    }
+
     #[test]
     fn peer_identity_aliases_fold_existing_split_threads() {
         let storage_dir = test_storage_dir("identity-alias-thread");
@@ -3168,6 +5720,18 @@ mod tests {
             Some("Poco".to_string()),
         );
 
+        let page = store
+            .list_messages_page_resolved("APPDEST", None, None, 10, &resolver)
+            .expect("page across aliases");
+        assert_eq!(page.len(), 2);
+        assert!(page
+            .iter()
+            .all(|message| message.conversation_id == "identity"));
+        let stored = store.list_messages(None).expect("stored messages");
+        assert!(stored
+            .iter()
+            .all(|message| message.conversation_id != "identity"));
+
         let conversations = store
             .list_conversations_resolved(&resolver)
             .expect("list after aliases");
@@ -3603,4 +6167,204 @@ mod tests {
 
         assert!(matches!(result, Err(NodeError::InvalidConfig {})));
     }
+
+    fn team_eam(callsign: &str, team_uid: &str, overall_status: &str) -> EamProjectionRecord {
+        EamProjectionRecord {
+            callsign: callsign.to_string(),
+            group_name: "BLUE".to_string(),
+            security_status: overall_status.to_string(),
+            capability_status: overall_status.to_string(),
+            preparedness_status: overall_status.to_string(),
+            medical_status: overall_status.to_string(),
+            mobility_status: overall_status.to_string(),
+            comms_status: overall_status.to_string(),
+            updated_at_ms: now_ms(),
+            eam_uid: None,
+            team_member_uid: None,
+            team_uid: Some(team_uid.to_string()),
+            overall_status: Some(overall_status.to_string()),
+            ttl_seconds: None,
+            ..sample_eam()
+        }
+    }
+
+    #[test]
+    fn team_rollups_cover_the_whole_subtree() {
+        let storage_dir = test_storage_dir("team-rollups");
+        let store =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("create store");
+        let teams = store.list_teams().expect("teams");
+        assert_eq!(teams.len(), 13);
+
+        let section = TeamRecord {
+            team_uid: "section-1".to_string(),
+            name: "North".to_string(),
+            level: TeamLevel::Section {},
+            parent_team_uid: None,
+            color: None,
+            members: Vec::new(),
+            updated_at_ms: 10,
+            deleted_at_ms: None,
+        };
+        let mut blue = teams
+            .into_iter()
+            .find(|team| team.color.as_deref() == Some("BLUE"))
+            .expect("blue team");
+        blue.parent_team_uid = Some(section.team_uid.clone());
+        blue.updated_at_ms = 20;
+        let squad = TeamRecord {
+            team_uid: "squad-1".to_string(),
+            name: "Blue 1".to_string(),
+            level: TeamLevel::Squad {},
+            parent_team_uid: Some(blue.team_uid.clone()),
+            ..section.clone()
+        };
+        for team in [&section, &blue, &squad] {
+            assert!(store.upsert_team(team).expect("upsert team").is_some());
+        }
+        let stale = TeamRecord {
+            name: "Renamed".to_string(),
+            updated_at_ms: 5,
+            ..section.clone()
+        };
+        assert!(store.upsert_team(&stale).expect("stale team").is_none());
+
+        store
+            .upsert_eam(&team_eam("POCO", squad.team_uid.as_str(), "Red"))
+            .expect("squad eam");
+        store
+            .upsert_eam(&team_eam("ROMEO", blue.team_uid.as_str(), "Green"))
+            .expect("team eam");
+
+        let rollups = store.get_team_rollups().expect("rollups");
+        let rollup = |team_uid: &str| {
+            rollups
+                .iter()
+                .find(|rollup| rollup.team.team_uid == team_uid)
+                .expect("rollup")
+        };
+        let section_rollup = rollup("section-1");
+        assert_eq!(section_rollup.team.name, "North");
+        assert_eq!(section_rollup.depth, 0);
+        assert_eq!(section_rollup.child_team_uids, vec![blue.team_uid.clone()]);
+        let summary = section_rollup.summary.as_ref().expect("section summary");
+        assert_eq!(summary.total, 2);
+        assert_eq!(summary.overall_status.as_deref(), Some("Red"));
+        assert_eq!(rollup(blue.team_uid.as_str()).depth, 1);
+        let squad_rollup = rollup("squad-1");
+        assert_eq!(squad_rollup.depth, 2);
+        assert_eq!(
+            squad_rollup.summary.as_ref().map(|summary| summary.total),
+            Some(1)
+        );
+        assert!(rollup(crate::team_registry::TEAM_UID_RED).summary.is_none());
+    }
+
+    #[test]
+    fn eam_sweep_flags_expired_reports_and_summary_counts_them() {
+        let storage_dir = test_storage_dir("eam-sweep");
+        let store =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("create store");
+        let now = now_ms();
+
+        let mut expired = team_eam("POCO", "team-1", "Red");
+        expired.updated_at_ms = now - 120_000;
+        expired.ttl_seconds = Some(60);
+        let mut quiet = team_eam("ROMEO", "team-1", "Green");
+        quiet.updated_at_ms = now - 2 * 60 * 60 * 1000;
+        let mut unsure = team_eam("SIERRA", "team-1", "Yellow");
+        unsure.confidence = Some(0.2);
+        for record in [&expired, &quiet, &unsure] {
+            store.upsert_eam(record).expect("upsert eam");
+        }
+
+        let invalidation = store
+            .sweep_expired_eams(now)
+            .expect("sweep")
+            .expect("sweep invalidation");
+        assert!(matches!(invalidation.scope, ProjectionScope::Eams {}));
+        assert_eq!(invalidation.key, None);
+        assert_eq!(invalidation.reason.as_deref(), Some("eam-expired"));
+        let flagged = store
+            .get_eams()
+            .expect("eams")
+            .into_iter()
+            .filter(|record| record.expired)
+            .map(|record| record.callsign)
+            .collect::<Vec<_>>();
+        assert_eq!(flagged, vec!["POCO".to_string()]);
+        assert!(store
+            .sweep_expired_eams(now)
+            .expect("second sweep")
+            .is_none());
+
+        let summary = store
+            .get_eam_team_summary("team-1")
+            .expect("summary")
+            .expect("team summary");
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.active_total, 2);
+        assert_eq!(summary.expired_total, 1);
+        assert_eq!(summary.not_reporting_total, 2);
+        assert_eq!(summary.low_confidence_total, 1);
+        assert_eq!(summary.red_total, 0);
+    }
+
+    #[test]
+    fn received_schema_versions_never_replace_the_active_schema() {
+        let storage_dir = test_storage_dir("eam-schema-received");
+        let store =
+            AppStateStore::new(Some(storage_dir.to_string_lossy().as_ref())).expect("create store");
+        let schema = |schema_uid: &str, version: u32| EamSchemaRecord {
+            schema_uid: schema_uid.to_string(),
+            name: "Field kit".to_string(),
+            version,
+            ..built_in_eam_schema()
+        };
+        store
+            .upsert_eam_schema(&schema("field-kit", 1))
+            .expect("store schema");
+        store
+            .set_active_eam_schema("field-kit")
+            .expect("activate schema");
+
+        assert!(store
+            .store_received_eam_schema(&schema("field-kit", 2))
+            .expect("receive newer version")
+            .is_some());
+        let active = store
+            .get_active_eam_schema()
+            .expect("active schema")
+            .expect("stored active schema");
+        assert_eq!(
+            (active.schema_uid.as_str(), active.version),
+            ("field-kit", 1)
+        );
+        let copy = store
+            .list_eam_schemas()
+            .expect("schemas")
+            .into_iter()
+            .find(|stored| stored.schema_uid == "field-kit-v2")
+            .expect("inactive copy");
+        assert!(!copy.active);
+        assert!(store
+            .store_received_eam_schema(&schema("field-kit", 2))
+            .expect("receive again")
+            .is_none());
+
+        store
+            .upsert_eam_schema(&schema("spare", 1))
+            .expect("store spare");
+        store
+            .store_received_eam_schema(&schema("spare", 3))
+            .expect("receive spare");
+        let versions = store
+            .list_eam_schemas()
+            .expect("schemas")
+            .into_iter()
+            .filter(|stored| stored.schema_uid.starts_with("spare"))
+            .map(|stored| (stored.schema_uid, stored.version))
+            .collect::<Vec<_>>();
+        assert_eq!(versions, vec![("spare".to_string(), 3)]);
+    }
 }
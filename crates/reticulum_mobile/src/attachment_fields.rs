@@ -0,0 +1,286 @@
+use std::path::Path;
+
+use rmpv::Value as MsgPackValue;
+
+use crate::lxmf_fields::{
+    attachment_file_name, msgpack_get_indexed, msgpack_string, FIELD_FILE_ATTACHMENTS, FIELD_IMAGE,
+};
+use crate::types::{
+    LxmfAttachmentRequest, MessageAttachmentKind, MessageAttachmentRecord, NodeError,
+};
+
+// LXMF propagation nodes refuse messages above 256 KB by default. Keeping the
+// attachment bytes under that leaves room for the envelope, so a message that
+// falls back to a propagation node is still accepted there.
+pub(crate) const ATTACHMENT_MAX_TOTAL_BYTES: usize = 250_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct AttachmentPayload {
+    pub(crate) kind: MessageAttachmentKind,
+    pub(crate) file_name: String,
+    pub(crate) mime_type: Option<String>,
+    pub(crate) bytes: Vec<u8>,
+}
+
+impl AttachmentPayload {
+    pub(crate) fn record(&self, path: Option<String>) -> MessageAttachmentRecord {
+        MessageAttachmentRecord {
+            kind: self.kind,
+            file_name: self.file_name.clone(),
+            mime_type: self.mime_type.clone(),
+            size_bytes: self.bytes.len() as u64,
+            path,
+        }
+    }
+}
+
+pub(crate) fn load_outbound_attachments(
+    requests: &[LxmfAttachmentRequest],
+) -> Result<Vec<AttachmentPayload>, NodeError> {
+    let mut payloads = Vec::with_capacity(requests.len());
+    let mut total_bytes = 0usize;
+    for request in requests {
+        let path = Path::new(request.path.as_str());
+        let file_name = request
+            .file_name
+            .as_deref()
+            .filter(|value| !value.trim().is_empty())
+            .or_else(|| path.file_name().and_then(|value| value.to_str()))
+            .map(attachment_file_name)
+            .ok_or(NodeError::InvalidConfig {})?;
+        let mime_type = request
+            .mime_type
+            .clone()
+            .filter(|value| !value.trim().is_empty())
+            .or_else(|| mime_type_for_file_name(file_name.as_str()).map(str::to_string));
+        if matches!(request.kind, MessageAttachmentKind::Image {})
+            && mime_type.as_deref().and_then(image_type_for_mime).is_none()
+        {
+            return Err(NodeError::InvalidConfig {});
+        }
+        let bytes = fs_err::read(path).map_err(|_| NodeError::IoError {})?;
+        total_bytes = total_bytes.saturating_add(bytes.len());
+        if total_bytes > ATTACHMENT_MAX_TOTAL_BYTES {
+            return Err(NodeError::LxmfPacketTooLarge {});
+        }
+        payloads.push(AttachmentPayload {
+            kind: request.kind,
+            file_name,
+            mime_type,
+            bytes,
+        });
+    }
+    // The image field holds a single picture; anything more goes as a file.
+    if payloads
+        .iter()
+        .filter(|payload| matches!(payload.kind, MessageAttachmentKind::Image {}))
+        .count()
+        > 1
+    {
+        return Err(NodeError::InvalidConfig {});
+    }
+    Ok(payloads)
+}
+
+pub(crate) fn build_attachment_fields(
+    attachments: &[AttachmentPayload],
+) -> Result<Option<Vec<u8>>, NodeError> {
+    if attachments.is_empty() {
+        return Ok(None);
+    }
+    let mut files = Vec::new();
+    let mut entries = Vec::new();
+    for attachment in attachments {
+        match attachment.kind {
+            MessageAttachmentKind::File {} => files.push(MsgPackValue::Array(vec![
+                MsgPackValue::from(attachment.file_name.as_str()),
+                MsgPackValue::Binary(attachment.bytes.clone()),
+            ])),
+            MessageAttachmentKind::Image {} => {
+                let image_type = attachment
+                    .mime_type
+                    .as_deref()
+                    .and_then(image_type_for_mime)
+                    .ok_or(NodeError::InvalidConfig {})?;
+                entries.push((
+                    MsgPackValue::from(FIELD_IMAGE),
+                    MsgPackValue::Array(vec![
+                        MsgPackValue::from(image_type),
+                        MsgPackValue::Binary(attachment.bytes.clone()),
+                    ]),
+                ));
+            }
+        }
+    }
+    if !files.is_empty() {
+        entries.push((
+            MsgPackValue::from(FIELD_FILE_ATTACHMENTS),
+            MsgPackValue::Array(files),
+        ));
+    }
+    rmp_serde::to_vec(&MsgPackValue::Map(entries))
+        .map(Some)
+        .map_err(|_| NodeError::InternalError {})
+}
+
+pub(crate) fn parse_attachment_fields(fields_bytes: &[u8]) -> Vec<AttachmentPayload> {
+    let Ok(MsgPackValue::Map(entries)) = rmp_serde::from_slice::<MsgPackValue>(fields_bytes) else {
+        return Vec::new();
+    };
+    let mut attachments = Vec::new();
+    if let Some(MsgPackValue::Array(files)) = msgpack_get_indexed(&entries, FIELD_FILE_ATTACHMENTS)
+    {
+        for (index, file) in files.iter().enumerate() {
+            let MsgPackValue::Array(parts) = file else {
+                continue;
+            };
+            let Some(MsgPackValue::Binary(bytes)) = parts.get(1) else {
+                continue;
+            };
+            let file_name = parts
+                .first()
+                .and_then(msgpack_string)
+                .map(|value| attachment_file_name(value.as_str()))
+                .unwrap_or_else(|| format!("attachment-{}", index + 1));
+            attachments.push(AttachmentPayload {
+                kind: MessageAttachmentKind::File {},
+                mime_type: mime_type_for_file_name(file_name.as_str()).map(str::to_string),
+                file_name,
+                bytes: bytes.clone(),
+            });
+        }
+    }
+    if let Some(MsgPackValue::Array(parts)) = msgpack_get_indexed(&entries, FIELD_IMAGE) {
+        if let Some(MsgPackValue::Binary(bytes)) = parts.get(1) {
+            let image_type = parts
+                .first()
+                .and_then(msgpack_string)
+                .map(|value| value.trim().to_ascii_lowercase())
+                .filter(|value| {
+                    !value.is_empty() && value.chars().all(|c| c.is_ascii_alphanumeric())
+                })
+                .unwrap_or_else(|| "bin".to_string());
+            let file_name = format!("image.{image_type}");
+            attachments.push(AttachmentPayload {
+                kind: MessageAttachmentKind::Image {},
+                mime_type: mime_type_for_file_name(file_name.as_str()).map(str::to_string),
+                file_name,
+                bytes: bytes.clone(),
+            });
+        }
+    }
+    attachments
+}
+
+fn mime_type_for_file_name(file_name: &str) -> Option<&'static str> {
+    let (_, extension) = file_name.rsplit_once('.')?;
+    match extension.to_ascii_lowercase().as_str() {
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "png" => Some("image/png"),
+        "webp" => Some("image/webp"),
+        "gif" => Some("image/gif"),
+        "pdf" => Some("application/pdf"),
+        "txt" => Some("text/plain"),
+        "csv" => Some("text/csv"),
+        "json" => Some("application/json"),
+        "zip" => Some("application/zip"),
+        "ogg" => Some("audio/ogg"),
+        "opus" => Some("audio/opus"),
+        _ => None,
+    }
+}
+
+fn image_type_for_mime(mime_type: &str) -> Option<&'static str> {
+    match mime_type.trim().to_ascii_lowercase().as_str() {
+        "image/jpeg" | "image/jpg" => Some("jpg"),
+        "image/png" => Some("png"),
+        "image/webp" => Some("webp"),
+        "image/gif" => Some("gif"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload(kind: MessageAttachmentKind, file_name: &str, mime_type: &str) -> AttachmentPayload {
+        AttachmentPayload {
+            kind,
+            file_name: file_name.to_string(),
+            mime_type: Some(mime_type.to_string()),
+            bytes: vec![0xAB; 64],
+        }
+    }
+
+    #[test]
+    fn attachments_round_trip_through_lxmf_file_and_image_fields() {
+        let outbound = vec![
+            payload(
+                MessageAttachmentKind::File {},
+                "report.pdf",
+                "application/pdf",
+            ),
+            payload(MessageAttachmentKind::Image {}, "photo.jpeg", "image/jpeg"),
+        ];
+
+        let fields = build_attachment_fields(&outbound)
+            .expect("fields")
+            .expect("non-empty fields");
+        let inbound = parse_attachment_fields(fields.as_slice());
+
+        assert_eq!(inbound.len(), 2);
+        assert_eq!(inbound[0].kind, MessageAttachmentKind::File {});
+        assert_eq!(inbound[0].file_name, "report.pdf");
+        assert_eq!(inbound[0].mime_type.as_deref(), Some("application/pdf"));
+        assert_eq!(inbound[1].kind, MessageAttachmentKind::Image {});
+        assert_eq!(inbound[1].file_name, "image.jpg");
+        assert_eq!(inbound[1].mime_type.as_deref(), Some("image/jpeg"));
+        assert_eq!(inbound[1].record(None).size_bytes, 64);
+        assert!(build_attachment_fields(&[]).expect("empty").is_none());
+    }
+
+    #[test]
+    fn peer_supplied_file_names_cannot_escape_the_attachment_directory() {
+        assert_eq!(attachment_file_name("../../etc/passwd"), "passwd");
+        assert_eq!(attachment_file_name("C:\\temp\\map v2.png"), "map_v2.png");
+        assert_eq!(attachment_file_name(".."), "attachment");
+        assert_eq!(attachment_file_name(""), "attachment");
+    }
+
+    #[test]
+    fn outbound_attachments_respect_the_propagation_size_cap() {
+        let dir = std::env::temp_dir().join(format!(
+            "reticulum-mobile-attachments-{}",
+            std::process::id()
+        ));
+        fs_err::create_dir_all(&dir).expect("temp dir");
+        let small = dir.join("notes.txt");
+        let large = dir.join("large.bin");
+        fs_err::write(&small, b"hello").expect("small file");
+        fs_err::write(&large, vec![0u8; ATTACHMENT_MAX_TOTAL_BYTES]).expect("large file");
+        let request = |path: &Path, kind| LxmfAttachmentRequest {
+            kind,
+            path: path.to_string_lossy().to_string(),
+            file_name: None,
+            mime_type: None,
+        };
+
+        let loaded = load_outbound_attachments(&[request(&small, MessageAttachmentKind::File {})])
+            .expect("small attachment");
+        assert_eq!(loaded[0].file_name, "notes.txt");
+        assert_eq!(loaded[0].mime_type.as_deref(), Some("text/plain"));
+        assert!(matches!(
+            load_outbound_attachments(&[
+                request(&small, MessageAttachmentKind::File {}),
+                request(&large, MessageAttachmentKind::File {}),
+            ]),
+            Err(NodeError::LxmfPacketTooLarge {})
+        ));
+        assert!(matches!(
+            load_outbound_attachments(&[request(&small, MessageAttachmentKind::Image {})]),
+            Err(NodeError::InvalidConfig {})
+        ));
+        let _ = fs_err::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use crate::app_state::parse_rfc3339_epoch_seconds;
+use crate::types::EamProjectionRecord;
+
+pub(crate) const EAM_SWEEP_INTERVAL: Duration = Duration::from_secs(30);
+
+// Reports sent without a TTL still count as "not reporting" once they are this
+// old, so a member who went quiet shows up in the team summary either way.
+pub(crate) const EAM_DEFAULT_REPORTING_WINDOW_MS: u64 = 60 * 60 * 1000;
+
+pub(crate) const EAM_LOW_CONFIDENCE_THRESHOLD: f64 = 0.5;
+
+// `reported_at` is the reporter's clock; the local receive time stands in when
+// it is missing or unparseable.
+pub(crate) fn eam_reported_at_ms(record: &EamProjectionRecord) -> u64 {
+    record
+        .reported_at
+        .as_deref()
+        .and_then(parse_rfc3339_epoch_seconds)
+        .and_then(|seconds| u64::try_from(seconds).ok())
+        .map_or(record.updated_at_ms, |seconds| seconds.saturating_mul(1000))
+}
+
+pub(crate) fn eam_expires_at_ms(record: &EamProjectionRecord) -> Option<u64> {
+    let ttl_seconds = record.ttl_seconds.filter(|value| *value > 0)?;
+    Some(eam_reported_at_ms(record).saturating_add(ttl_seconds.saturating_mul(1000)))
+}
+
+pub(crate) fn is_eam_expired(record: &EamProjectionRecord, now_ms: u64) -> bool {
+    record.deleted_at_ms.is_none()
+        && eam_expires_at_ms(record).is_some_and(|expires_at_ms| expires_at_ms <= now_ms)
+}
+
+pub(crate) fn is_eam_reporting(record: &EamProjectionRecord, now_ms: u64) -> bool {
+    let stale_at_ms = eam_expires_at_ms(record).unwrap_or_else(|| {
+        eam_reported_at_ms(record).saturating_add(EAM_DEFAULT_REPORTING_WINDOW_MS)
+    });
+    stale_at_ms > now_ms
+}
+
+pub(crate) fn is_low_confidence(record: &EamProjectionRecord) -> bool {
+    record.confidence.is_some_and(|confidence| {
+        confidence.is_finite() && confidence < EAM_LOW_CONFIDENCE_THRESHOLD
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::sample_eam;
+
+    fn eam(reported_at: Option<&str>, ttl_seconds: Option<u64>) -> EamProjectionRecord {
+        EamProjectionRecord {
+            updated_at_ms: 1_774_396_800_000,
+            reported_at: reported_at.map(str::to_string),
+            confidence: Some(0.4),
+            ttl_seconds,
+            ..sample_eam()
+        }
+    }
+
+    #[test]
+    fn reports_expire_after_their_ttl_from_reported_at() {
+        // 2026-03-25T00:00:00Z
+        let reported_at_ms = 1_774_396_800_000;
+        let record = eam(Some("2026-03-25T00:00:00Z"), Some(600));
+
+        assert_eq!(eam_reported_at_ms(&record), reported_at_ms);
+        assert!(!is_eam_expired(&record, reported_at_ms + 599_999));
+        assert!(is_eam_expired(&record, reported_at_ms + 600_000));
+        assert!(!is_eam_reporting(&record, reported_at_ms + 600_000));
+        assert!(is_low_confidence(&record));
+
+        let untimed = eam(None, None);
+        assert!(!is_eam_expired(&untimed, u64::MAX));
+        assert!(is_eam_reporting(&untimed, reported_at_ms + 1));
+        assert!(!is_eam_reporting(
+            &untimed,
+            reported_at_ms + EAM_DEFAULT_REPORTING_WINDOW_MS
+        ));
+    }
+}
@@ -0,0 +1,172 @@
+use crate::types::{EamFieldChange, EamProjectionRecord, EamRevisionRecord};
+
+// A stored revision before it is diffed against the one preceding it.
+pub(crate) struct StoredEamRevision {
+    pub(crate) revision: u64,
+    pub(crate) recorded_at_ms: u64,
+    pub(crate) record: EamProjectionRecord,
+}
+
+// The fields an after-action review cares about. Sync bookkeeping
+// (`sync_state`, `last_synced_at_ms`, ...) changes on every round trip and is
+// left out so it does not produce empty revisions. Custom schema dimensions
+// follow as `status.<dimension>`.
+fn tracked_fields(record: &EamProjectionRecord) -> Vec<(String, Option<String>)> {
+    let fixed: [(&str, Option<String>); 16] = [
+        ("group_name", Some(record.group_name.clone())),
+        ("team_uid", record.team_uid.clone()),
+        ("team_member_uid", record.team_member_uid.clone()),
+        ("security_status", Some(record.security_status.clone())),
+        ("capability_status", Some(record.capability_status.clone())),
+        (
+            "preparedness_status",
+            Some(record.preparedness_status.clone()),
+        ),
+        ("medical_status", Some(record.medical_status.clone())),
+        ("mobility_status", Some(record.mobility_status.clone())),
+        ("comms_status", Some(record.comms_status.clone())),
+        ("overall_status", record.overall_status.clone()),
+        ("notes", record.notes.clone()),
+        (
+            "confidence",
+            record.confidence.map(|value| value.to_string()),
+        ),
+        (
+            "ttl_seconds",
+            record.ttl_seconds.map(|value| value.to_string()),
+        ),
+        ("reported_at", record.reported_at.clone()),
+        ("reported_by", record.reported_by.clone()),
+        (
+            "deleted_at_ms",
+            record.deleted_at_ms.map(|value| value.to_string()),
+        ),
+    ];
+    fixed
+        .into_iter()
+        .map(|(field, value)| (field.to_string(), value))
+        .chain(record.custom_statuses.iter().map(|entry| {
+            (
+                format!("status.{}", entry.dimension),
+                Some(entry.value.clone()),
+            )
+        }))
+        .collect()
+}
+
+pub(crate) fn eam_field_changes(
+    previous: Option<&EamProjectionRecord>,
+    current: &EamProjectionRecord,
+) -> Vec<EamFieldChange> {
+    let previous_fields = previous.map(tracked_fields).unwrap_or_default();
+    let current_fields = tracked_fields(current);
+    let value_of = |fields: &[(String, Option<String>)], field: &str| {
+        fields
+            .iter()
+            .find(|(name, _)| name == field)
+            .and_then(|(_, value)| value.clone())
+    };
+    // Dimensions dropped since the previous revision show up as cleared.
+    let removed = previous_fields
+        .iter()
+        .filter(|(field, _)| !current_fields.iter().any(|(name, _)| name == field))
+        .map(|(field, _)| (field.clone(), None));
+    current_fields
+        .iter()
+        .cloned()
+        .chain(removed)
+        .filter_map(|(field, current_value)| {
+            let previous_value = value_of(&previous_fields, field.as_str());
+            (previous_value != current_value).then_some(EamFieldChange {
+                field,
+                previous_value,
+                current_value,
+            })
+        })
+        .collect()
+}
+
+// Revisions come in oldest first; each is diffed against the one before it.
+pub(crate) fn build_eam_history(revisions: Vec<StoredEamRevision>) -> Vec<EamRevisionRecord> {
+    let mut previous: Option<EamProjectionRecord> = None;
+    revisions
+        .into_iter()
+        .map(|stored| {
+            let changes = eam_field_changes(previous.as_ref(), &stored.record);
+            let revision = EamRevisionRecord {
+                revision: stored.revision,
+                callsign: stored.record.callsign.clone(),
+                recorded_at_ms: stored.recorded_at_ms,
+                reported_at: stored.record.reported_at.clone(),
+                reported_by: stored.record.reported_by.clone(),
+                source_identity: stored
+                    .record
+                    .source
+                    .as_ref()
+                    .map(|source| source.rns_identity.clone()),
+                deleted: stored.record.deleted_at_ms.is_some(),
+                changes,
+                record: stored.record.clone(),
+            };
+            previous = Some(stored.record);
+            revision
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{sample_eam, EamSourceRecord};
+
+    fn eam(medical_status: &str, reported_by: &str) -> EamProjectionRecord {
+        EamProjectionRecord {
+            medical_status: medical_status.to_string(),
+            reported_by: Some(reported_by.to_string()),
+            source: Some(EamSourceRecord {
+                rns_identity: "identity-1".to_string(),
+                display_name: None,
+            }),
+            ..sample_eam()
+        }
+    }
+
+    #[test]
+    fn history_diffs_each_revision_against_the_previous_one() {
+        let first = eam("Green", "Atlas-1");
+        let mut synced = first.clone();
+        synced.sync_state = Some("synced".to_string());
+        synced.last_synced_at_ms = Some(20);
+        assert!(eam_field_changes(Some(&first), &synced).is_empty());
+
+        let history = build_eam_history(vec![
+            StoredEamRevision {
+                revision: 1,
+                recorded_at_ms: 10,
+                record: first,
+            },
+            StoredEamRevision {
+                revision: 2,
+                recorded_at_ms: 30,
+                record: eam("Red", "Medic-2"),
+            },
+        ]);
+
+        assert_eq!(history.len(), 2);
+        assert!(history[0]
+            .changes
+            .iter()
+            .any(|change| change.field == "medical_status" && change.previous_value.is_none()));
+        assert_eq!(
+            history[1]
+                .changes
+                .iter()
+                .map(|change| change.field.as_str())
+                .collect::<Vec<_>>(),
+            vec!["medical_status", "reported_by"]
+        );
+        assert_eq!(history[1].changes[0].current_value.as_deref(), Some("Red"));
+        assert_eq!(history[1].reported_by.as_deref(), Some("Medic-2"));
+        assert_eq!(history[1].source_identity.as_deref(), Some("identity-1"));
+    }
+}
@@ -0,0 +1,242 @@
+use rmpv::Value as MsgPackValue;
+
+use crate::lxmf_fields::{
+    msgpack_get_indexed, msgpack_get_named, msgpack_map_entries, msgpack_string, msgpack_u64,
+    FIELD_COMMANDS,
+};
+use crate::types::{
+    EamRollCallDirection, EamRollCallMemberRecord, EamRollCallMemberState, EamRollCallRecord,
+};
+
+pub(crate) const EAM_ROLL_CALL_COMMAND_TYPE: &str = "mission.registry.eam.request";
+const EAM_UPSERT_COMMAND_TYPE: &str = "mission.registry.eam.upsert";
+pub(crate) const DEFAULT_EAM_ROLL_CALL_DUE_SECONDS: u32 = 10 * 60;
+
+// A leader's request for status reports. It goes out like any other mission
+// command with its own correlation id per recipient; responders echo the
+// roll-call uid on the EAM they send back so the leader can tick them off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct EamRollCallCommand {
+    pub(crate) roll_call_uid: String,
+    pub(crate) team_uid: Option<String>,
+    pub(crate) team_member_uids: Vec<String>,
+    pub(crate) requested_by: Option<String>,
+    pub(crate) requested_at_ms: u64,
+    pub(crate) due_at_ms: u64,
+}
+
+impl EamRollCallCommand {
+    pub(crate) fn from_record(record: &EamRollCallRecord) -> Self {
+        Self {
+            roll_call_uid: record.roll_call_uid.clone(),
+            team_uid: record.team_uid.clone(),
+            team_member_uids: record
+                .members
+                .iter()
+                .map(|member| member.team_member_uid.clone())
+                .collect(),
+            requested_by: record.requested_by.clone(),
+            requested_at_ms: record.requested_at_ms,
+            due_at_ms: record.due_at_ms,
+        }
+    }
+
+    // Recipients pick themselves out: by member uid when the leader listed
+    // members, otherwise by team.
+    pub(crate) fn addresses(&self, own_member_uid: &str, own_team_uid: Option<&str>) -> bool {
+        if self
+            .team_member_uids
+            .iter()
+            .any(|member_uid| member_uid.eq_ignore_ascii_case(own_member_uid))
+        {
+            return true;
+        }
+        self.team_member_uids.is_empty()
+            && self
+                .team_uid
+                .as_deref()
+                .is_some_and(|team_uid| Some(team_uid) == own_team_uid)
+    }
+}
+
+pub(crate) fn parse_eam_roll_call_fields(fields_bytes: &[u8]) -> Option<EamRollCallCommand> {
+    let fields = rmp_serde::from_slice::<MsgPackValue>(fields_bytes).ok()?;
+    let args = mission_command_args(&fields, EAM_ROLL_CALL_COMMAND_TYPE)?;
+    let text = |key: &str| {
+        msgpack_get_named(args, &[key])
+            .and_then(msgpack_string)
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    let team_member_uids = match msgpack_get_named(args, &["team_member_uids"]) {
+        Some(MsgPackValue::Array(values)) => values.iter().filter_map(msgpack_string).collect(),
+        _ => Vec::new(),
+    };
+    let command = EamRollCallCommand {
+        roll_call_uid: text("roll_call_uid")?,
+        team_uid: text("team_uid"),
+        team_member_uids,
+        requested_by: text("requested_by"),
+        requested_at_ms: msgpack_get_named(args, &["requested_at_ms"])
+            .and_then(msgpack_u64)
+            .unwrap_or(0),
+        due_at_ms: msgpack_get_named(args, &["due_at_ms"])
+            .and_then(msgpack_u64)
+            .unwrap_or(0),
+    };
+    (command.team_uid.is_some() || !command.team_member_uids.is_empty()).then_some(command)
+}
+
+// The roll call an EAM report answers, when the reporter sent it in reply to
+// one.
+pub(crate) fn parse_eam_roll_call_uid(fields_bytes: &[u8]) -> Option<String> {
+    let fields = rmp_serde::from_slice::<MsgPackValue>(fields_bytes).ok()?;
+    let args = mission_command_args(&fields, EAM_UPSERT_COMMAND_TYPE)?;
+    msgpack_get_named(args, &["roll_call_uid"])
+        .and_then(msgpack_string)
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+// Ticks off the member that sent an EAM. A report sent in reply to the roll
+// call always counts; any other report only counts when it arrived after the
+// request went out, so one already in flight does not answer it.
+pub(crate) fn record_roll_call_response(
+    record: &mut EamRollCallRecord,
+    team_member_uid: Option<&str>,
+    callsign: &str,
+    received_at_ms: u64,
+    answers_roll_call: bool,
+) -> bool {
+    if !answers_roll_call && received_at_ms < record.requested_at_ms {
+        return false;
+    }
+    let Some(member) = record.members.iter_mut().find(|member| {
+        team_member_uid
+            .is_some_and(|member_uid| member.team_member_uid.eq_ignore_ascii_case(member_uid))
+            || member
+                .callsign
+                .as_deref()
+                .is_some_and(|value| value.eq_ignore_ascii_case(callsign.trim()))
+    }) else {
+        return false;
+    };
+    if matches!(member.state, EamRollCallMemberState::Responded {}) {
+        return false;
+    }
+    member.state = EamRollCallMemberState::Responded {};
+    member.responded_at_ms = Some(received_at_ms);
+    record.updated_at_ms = record.updated_at_ms.max(received_at_ms);
+    true
+}
+
+// Moves members still pending past the due time to overdue. Inbound requests
+// are left alone; only the leader tracks who is late.
+pub(crate) fn mark_roll_call_overdue(record: &mut EamRollCallRecord, now_ms: u64) -> bool {
+    if !matches!(record.direction, EamRollCallDirection::Outbound {}) || record.due_at_ms > now_ms {
+        return false;
+    }
+    let mut changed = false;
+    for member in &mut record.members {
+        if matches!(member.state, EamRollCallMemberState::Pending {}) {
+            member.state = EamRollCallMemberState::Overdue {};
+            changed = true;
+        }
+    }
+    if changed {
+        record.updated_at_ms = now_ms;
+    }
+    changed
+}
+
+pub(crate) fn pending_member(
+    team_member_uid: &str,
+    callsign: Option<&str>,
+) -> EamRollCallMemberRecord {
+    EamRollCallMemberRecord {
+        team_member_uid: team_member_uid.trim().to_string(),
+        callsign: callsign.map(str::to_string),
+        state: EamRollCallMemberState::Pending {},
+        responded_at_ms: None,
+    }
+}
+
+fn mission_command_args<'a>(
+    fields: &'a MsgPackValue,
+    command_type: &str,
+) -> Option<&'a [(MsgPackValue, MsgPackValue)]> {
+    let MsgPackValue::Array(commands) =
+        msgpack_get_indexed(msgpack_map_entries(fields)?, FIELD_COMMANDS)?
+    else {
+        return None;
+    };
+    commands
+        .iter()
+        .filter_map(msgpack_map_entries)
+        .find(|entries| {
+            msgpack_get_named(entries, &["command_type"])
+                .and_then(msgpack_string)
+                .as_deref()
+                == Some(command_type)
+        })
+        .and_then(|entries| msgpack_get_named(entries, &["args"]))
+        .and_then(msgpack_map_entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roll_call() -> EamRollCallRecord {
+        EamRollCallRecord {
+            roll_call_uid: "rollcall-1".to_string(),
+            direction: EamRollCallDirection::Outbound {},
+            team_uid: Some("team-1".to_string()),
+            requested_by: Some("Atlas-1".to_string()),
+            requester_hex: None,
+            requested_at_ms: 1_000,
+            due_at_ms: 5_000,
+            members: vec![
+                pending_member("member-1", Some("POCO")),
+                pending_member("member-2", Some("ROMEO")),
+            ],
+            updated_at_ms: 1_000,
+        }
+    }
+
+    #[test]
+    fn members_respond_or_go_overdue() {
+        let mut record = roll_call();
+
+        assert!(!record_roll_call_response(
+            &mut record,
+            Some("member-1"),
+            "POCO",
+            999,
+            false
+        ));
+        assert!(record_roll_call_response(
+            &mut record,
+            Some("member-1"),
+            "POCO",
+            999,
+            true
+        ));
+        assert!(!mark_roll_call_overdue(&mut record, 4_999));
+        assert!(mark_roll_call_overdue(&mut record, 5_000));
+        assert_eq!(
+            record.members[0].state,
+            EamRollCallMemberState::Responded {}
+        );
+        assert_eq!(record.members[1].state, EamRollCallMemberState::Overdue {});
+
+        assert!(record_roll_call_response(
+            &mut record,
+            None,
+            "romeo",
+            6_000,
+            false
+        ));
+        assert_eq!(record.members[1].responded_at_ms, Some(6_000));
+    }
+}
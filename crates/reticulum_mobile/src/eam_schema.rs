@@ -0,0 +1,249 @@
+use std::collections::HashSet;
+
+use crate::types::{
+    EamOverallStatusRule, EamProjectionRecord, EamSchemaRecord, EamStatusDimensionRecord,
+    EamStatusValueRecord, NodeError,
+};
+
+pub(crate) const BUILT_IN_EAM_SCHEMA_UID: &str = "eam-schema-default";
+pub(crate) const EAM_SCHEMA_COMMAND_TYPE: &str = "mission.registry.eam.schema.upsert";
+
+// Dimension keys that map onto the fixed EAM status fields. Any other key in a
+// schema is carried in `custom_statuses`.
+const BUILT_IN_DIMENSIONS: [(&str, &str); 6] = [
+    ("security", "Security"),
+    ("capability", "Capability"),
+    ("preparedness", "Preparedness"),
+    ("medical", "Medical"),
+    ("mobility", "Mobility"),
+    ("comms", "Comms"),
+];
+
+// The Green/Yellow/Red taxonomy the app shipped with. It ranks reports
+// whenever no other schema has been activated; reports are only validated
+// against a schema that was stored explicitly.
+pub(crate) fn built_in_eam_schema() -> EamSchemaRecord {
+    EamSchemaRecord {
+        schema_uid: BUILT_IN_EAM_SCHEMA_UID.to_string(),
+        name: "Default".to_string(),
+        version: 1,
+        dimensions: BUILT_IN_DIMENSIONS
+            .iter()
+            .map(|(key, label)| EamStatusDimensionRecord {
+                key: (*key).to_string(),
+                label: (*label).to_string(),
+                required: false,
+            })
+            .collect(),
+        values: [("Green", 1), ("Yellow", 2), ("Red", 3)]
+            .into_iter()
+            .map(|(value, rank)| EamStatusValueRecord {
+                value: value.to_string(),
+                rank,
+            })
+            .collect(),
+        unknown_value: "Unknown".to_string(),
+        overall_rule: EamOverallStatusRule::WorstOf {},
+        active: true,
+        updated_at_ms: 0,
+    }
+}
+
+pub(crate) fn eam_status_value<'a>(
+    record: &'a EamProjectionRecord,
+    dimension: &str,
+) -> Option<&'a str> {
+    let value = match dimension {
+        "security" => record.security_status.as_str(),
+        "capability" => record.capability_status.as_str(),
+        "preparedness" => record.preparedness_status.as_str(),
+        "medical" => record.medical_status.as_str(),
+        "mobility" => record.mobility_status.as_str(),
+        "comms" => record.comms_status.as_str(),
+        _ => record
+            .custom_statuses
+            .iter()
+            .find(|entry| entry.dimension == dimension)
+            .map(|entry| entry.value.as_str())?,
+    };
+    Some(value.trim()).filter(|value| !value.is_empty())
+}
+
+// Higher ranks are worse. Values outside the schema, including the unknown
+// value, have no rank.
+pub(crate) fn eam_status_rank(schema: &EamSchemaRecord, value: &str) -> Option<u32> {
+    schema
+        .values
+        .iter()
+        .find(|candidate| candidate.value == value)
+        .map(|candidate| candidate.rank)
+}
+
+pub(crate) fn aggregate_eam_status<'a>(
+    schema: &EamSchemaRecord,
+    values: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    let ranked = values
+        .into_iter()
+        .filter_map(|value| eam_status_rank(schema, value).map(|rank| (rank, value)));
+    let selected = match schema.overall_rule {
+        EamOverallStatusRule::WorstOf {} => ranked.max_by_key(|(rank, _)| *rank),
+        EamOverallStatusRule::BestOf {} => ranked.min_by_key(|(rank, _)| *rank),
+    };
+    selected.map(|(_, value)| value.to_string())
+}
+
+pub(crate) fn derive_eam_overall_status(
+    schema: &EamSchemaRecord,
+    record: &EamProjectionRecord,
+) -> Option<String> {
+    aggregate_eam_status(
+        schema,
+        schema
+            .dimensions
+            .iter()
+            .filter_map(|dimension| eam_status_value(record, dimension.key.as_str())),
+    )
+}
+
+pub(crate) fn validate_eam_schema(schema: &EamSchemaRecord) -> Result<(), NodeError> {
+    let mut keys = HashSet::new();
+    let mut values = HashSet::new();
+    let valid = !schema.schema_uid.trim().is_empty()
+        && !schema.name.trim().is_empty()
+        && !schema.dimensions.is_empty()
+        && !schema.values.is_empty()
+        && schema
+            .dimensions
+            .iter()
+            .all(|dimension| !dimension.key.trim().is_empty() && keys.insert(dimension.key.trim()))
+        && schema
+            .values
+            .iter()
+            .all(|value| !value.value.trim().is_empty() && values.insert(value.value.trim()))
+        && !values.contains(schema.unknown_value.trim());
+    if valid {
+        Ok(())
+    } else {
+        Err(NodeError::InvalidConfig {})
+    }
+}
+
+// Rejects reports that use values the schema does not allow, leave a required
+// dimension empty, or carry custom dimensions the schema does not define.
+pub(crate) fn validate_eam_record(
+    schema: &EamSchemaRecord,
+    record: &EamProjectionRecord,
+) -> Result<(), NodeError> {
+    let unknown_dimension = record.custom_statuses.iter().any(|entry| {
+        !schema
+            .dimensions
+            .iter()
+            .any(|dimension| dimension.key == entry.dimension)
+    });
+    if unknown_dimension {
+        return Err(NodeError::InvalidConfig {});
+    }
+    for dimension in &schema.dimensions {
+        match eam_status_value(record, dimension.key.as_str()) {
+            Some(value) if value == schema.unknown_value => {
+                if dimension.required {
+                    return Err(NodeError::InvalidConfig {});
+                }
+            }
+            Some(value) => {
+                if eam_status_rank(schema, value).is_none() {
+                    return Err(NodeError::InvalidConfig {});
+                }
+            }
+            None if dimension.required => return Err(NodeError::InvalidConfig {}),
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{sample_eam, EamStatusEntry};
+
+    fn eam(medical_status: &str) -> EamProjectionRecord {
+        EamProjectionRecord {
+            capability_status: "Yellow".to_string(),
+            preparedness_status: "Green".to_string(),
+            medical_status: medical_status.to_string(),
+            mobility_status: "Green".to_string(),
+            comms_status: "Unknown".to_string(),
+            ..sample_eam()
+        }
+    }
+
+    #[test]
+    fn built_in_schema_keeps_the_worst_of_ranking() {
+        let schema = built_in_eam_schema();
+
+        assert_eq!(
+            derive_eam_overall_status(&schema, &eam("Green")).as_deref(),
+            Some("Yellow")
+        );
+        assert_eq!(
+            derive_eam_overall_status(&schema, &eam("Red")).as_deref(),
+            Some("Red")
+        );
+        assert!(validate_eam_record(&schema, &eam("Green")).is_ok());
+        assert!(validate_eam_record(&schema, &eam("Ready")).is_err());
+        assert!(validate_eam_schema(&schema).is_ok());
+    }
+
+    #[test]
+    fn custom_schema_validates_and_ranks_its_own_dimensions() {
+        let schema = EamSchemaRecord {
+            schema_uid: "fire-service".to_string(),
+            name: "Fire service".to_string(),
+            version: 2,
+            dimensions: vec![
+                EamStatusDimensionRecord {
+                    key: "water".to_string(),
+                    label: "Water supply".to_string(),
+                    required: true,
+                },
+                EamStatusDimensionRecord {
+                    key: "medical".to_string(),
+                    label: "Medical".to_string(),
+                    required: false,
+                },
+            ],
+            values: [("Ready", 1), ("Limited", 5), ("Out", 9)]
+                .into_iter()
+                .map(|(value, rank)| EamStatusValueRecord {
+                    value: value.to_string(),
+                    rank,
+                })
+                .collect(),
+            unknown_value: "Unknown".to_string(),
+            overall_rule: EamOverallStatusRule::BestOf {},
+            active: false,
+            updated_at_ms: 0,
+        };
+        let mut record = eam("Unknown");
+        assert!(validate_eam_record(&schema, &record).is_err());
+
+        record.custom_statuses = vec![EamStatusEntry {
+            dimension: "water".to_string(),
+            value: "Limited".to_string(),
+        }];
+        assert!(validate_eam_record(&schema, &record).is_ok());
+        record.medical_status = "Out".to_string();
+        assert_eq!(
+            derive_eam_overall_status(&schema, &record).as_deref(),
+            Some("Limited")
+        );
+
+        record.custom_statuses.push(EamStatusEntry {
+            dimension: "power".to_string(),
+            value: "Ready".to_string(),
+        });
+        assert!(validate_eam_record(&schema, &record).is_err());
+    }
+}
@@ -0,0 +1,326 @@
+use rand_core::{OsRng, RngCore};
+use rmpv::Value as MsgPackValue;
+
+use crate::lxmf_fields::{build_command_envelope, msgpack_get_named, parse_command_envelope};
+use crate::types::{GroupConversationRecord, GroupMemberDeliveryRecord, MessageState, NodeError};
+
+// Group conversation ids share the `conversation_id` space with 1:1 threads,
+// so the prefix is what keeps them from being folded onto a single peer.
+pub(crate) const GROUP_CONVERSATION_PREFIX: &str = "group:";
+
+// Tagged `group_chat` on every per-member copy of a group message, so a
+// recipient can file the copy into the same room and learn the room when it
+// has not seen it before.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GroupEnvelope {
+    pub(crate) conversation_id: String,
+    pub(crate) group_message_id: String,
+    pub(crate) title: String,
+    pub(crate) team_uid: Option<String>,
+    pub(crate) mission_uid: Option<String>,
+    pub(crate) member_hexes: Vec<String>,
+}
+
+impl GroupEnvelope {
+    pub(crate) fn for_group(group: &GroupConversationRecord, group_message_id: &str) -> Self {
+        Self {
+            conversation_id: group.conversation_id.clone(),
+            group_message_id: group_message_id.to_string(),
+            title: group.title.clone(),
+            team_uid: group.team_uid.clone(),
+            mission_uid: group.mission_uid.clone(),
+            member_hexes: group.member_hexes.clone(),
+        }
+    }
+
+    pub(crate) fn to_group(&self, now_ms: u64) -> GroupConversationRecord {
+        GroupConversationRecord {
+            conversation_id: self.conversation_id.clone(),
+            title: self.title.clone(),
+            team_uid: self.team_uid.clone(),
+            mission_uid: self.mission_uid.clone(),
+            member_hexes: self.member_hexes.clone(),
+            created_at_ms: now_ms,
+            updated_at_ms: now_ms,
+        }
+    }
+}
+
+// Random rather than time based: two sends landing in the same millisecond
+// must still get their own delivery ledgers.
+pub(crate) fn new_group_message_id() -> String {
+    let mut bytes = [0u8; 16];
+    OsRng.fill_bytes(&mut bytes);
+    format!("grp-{}", hex::encode(bytes))
+}
+
+pub(crate) fn is_group_conversation_id(conversation_id: &str) -> bool {
+    conversation_id
+        .trim()
+        .to_ascii_lowercase()
+        .starts_with(GROUP_CONVERSATION_PREFIX)
+}
+
+// Rooms tied to an EAM team or a mission get a stable id, so every member
+// that creates the room locally lands on the same conversation.
+pub(crate) fn normalize_group_conversation(
+    mut group: GroupConversationRecord,
+    now_ms: u64,
+) -> Result<GroupConversationRecord, NodeError> {
+    group.team_uid = trimmed(group.team_uid);
+    group.mission_uid = trimmed(group.mission_uid);
+    let conversation_id = group.conversation_id.trim().to_ascii_lowercase();
+    group.conversation_id = if conversation_id.is_empty() {
+        match (group.team_uid.as_deref(), group.mission_uid.as_deref()) {
+            (Some(team_uid), _) => format!("{GROUP_CONVERSATION_PREFIX}team:{team_uid}"),
+            (None, Some(mission_uid)) => {
+                format!("{GROUP_CONVERSATION_PREFIX}mission:{mission_uid}")
+            }
+            (None, None) => return Err(NodeError::InvalidConfig {}),
+        }
+        .to_ascii_lowercase()
+    } else if conversation_id.starts_with(GROUP_CONVERSATION_PREFIX) {
+        conversation_id
+    } else {
+        format!("{GROUP_CONVERSATION_PREFIX}{conversation_id}")
+    };
+    let mut members = group
+        .member_hexes
+        .iter()
+        .filter_map(|value| normalize_member_hex(value))
+        .collect::<Vec<_>>();
+    members.sort();
+    members.dedup();
+    if members.is_empty() {
+        return Err(NodeError::InvalidConfig {});
+    }
+    group.member_hexes = members;
+    group.title = group.title.trim().to_string();
+    if group.title.is_empty() {
+        group.title = group
+            .team_uid
+            .clone()
+            .or_else(|| group.mission_uid.clone())
+            .unwrap_or_else(|| group.conversation_id.clone());
+    }
+    if group.created_at_ms == 0 {
+        group.created_at_ms = now_ms;
+    }
+    group.updated_at_ms = now_ms;
+    Ok(group)
+}
+
+pub(crate) fn is_group_member(group: &GroupConversationRecord, destination_hex: &str) -> bool {
+    normalize_member_hex(destination_hex)
+        .is_some_and(|member| group.member_hexes.iter().any(|value| *value == member))
+}
+
+pub(crate) fn normalize_member_hex(value: &str) -> Option<String> {
+    let normalized = value.trim().to_ascii_lowercase();
+    (normalized.len() == 32 && normalized.chars().all(|ch| ch.is_ascii_hexdigit()))
+        .then_some(normalized)
+}
+
+// The group message reads as delivered only once every member has it; a
+// single successful copy is enough for it to count as sent.
+pub(crate) fn group_message_state(deliveries: &[GroupMemberDeliveryRecord]) -> MessageState {
+    if deliveries.is_empty() {
+        return MessageState::Sending {};
+    }
+    let all = |check: fn(&MessageState) -> bool| deliveries.iter().all(|d| check(&d.state));
+    let any = |check: fn(&MessageState) -> bool| deliveries.iter().any(|d| check(&d.state));
+    if all(|state| matches!(state, MessageState::Read {})) {
+        MessageState::Read {}
+    } else if all(|state| matches!(state, MessageState::Delivered {} | MessageState::Read {})) {
+        MessageState::Delivered {}
+    } else if any(|state| {
+        matches!(
+            state,
+            MessageState::SentDirect {} | MessageState::Delivered {} | MessageState::Read {}
+        )
+    }) {
+        MessageState::SentDirect {}
+    } else if any(|state| matches!(state, MessageState::SentToPropagation {})) {
+        MessageState::SentToPropagation {}
+    } else if all(|state| matches!(state, MessageState::Failed {})) {
+        MessageState::Failed {}
+    } else {
+        MessageState::Sending {}
+    }
+}
+
+pub(crate) fn build_group_fields(envelope: &GroupEnvelope) -> Result<Vec<u8>, NodeError> {
+    let mut entries = vec![
+        (
+            MsgPackValue::from("group_chat"),
+            MsgPackValue::from(envelope.conversation_id.as_str()),
+        ),
+        (
+            MsgPackValue::from("group_message_id"),
+            MsgPackValue::from(envelope.group_message_id.as_str()),
+        ),
+        (
+            MsgPackValue::from("title"),
+            MsgPackValue::from(envelope.title.as_str()),
+        ),
+        (
+            MsgPackValue::from("members"),
+            MsgPackValue::Array(
+                envelope
+                    .member_hexes
+                    .iter()
+                    .map(|member| MsgPackValue::from(member.as_str()))
+                    .collect(),
+            ),
+        ),
+    ];
+    if let Some(team_uid) = envelope.team_uid.as_deref() {
+        entries.push((MsgPackValue::from("team_uid"), MsgPackValue::from(team_uid)));
+    }
+    if let Some(mission_uid) = envelope.mission_uid.as_deref() {
+        entries.push((
+            MsgPackValue::from("mission_uid"),
+            MsgPackValue::from(mission_uid),
+        ));
+    }
+    build_command_envelope(entries)
+}
+
+pub(crate) fn parse_group_fields(fields_bytes: &[u8]) -> Option<GroupEnvelope> {
+    let envelope = parse_command_envelope(fields_bytes, "group_chat")?;
+    let envelope = envelope.as_slice();
+    let conversation_id = named_string(envelope, "group_chat")?.to_ascii_lowercase();
+    if !is_group_conversation_id(conversation_id.as_str()) {
+        return None;
+    }
+    let member_hexes = match msgpack_get_named(envelope, &["members"]) {
+        Some(MsgPackValue::Array(items)) => items
+            .iter()
+            .filter_map(|item| item.as_str().and_then(normalize_member_hex))
+            .collect(),
+        _ => Vec::new(),
+    };
+    Some(GroupEnvelope {
+        title: named_string(envelope, "title").unwrap_or_else(|| conversation_id.clone()),
+        conversation_id,
+        group_message_id: named_string(envelope, "group_message_id").unwrap_or_default(),
+        team_uid: named_string(envelope, "team_uid"),
+        mission_uid: named_string(envelope, "mission_uid"),
+        member_hexes,
+    })
+}
+
+fn named_string(entries: &[(MsgPackValue, MsgPackValue)], key: &str) -> Option<String> {
+    msgpack_get_named(entries, &[key])
+        .and_then(MsgPackValue::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn trimmed(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(conversation_id: &str, members: &[&str]) -> GroupConversationRecord {
+        GroupConversationRecord {
+            conversation_id: conversation_id.to_string(),
+            title: String::new(),
+            team_uid: Some(" Team-Red ".to_string()),
+            mission_uid: None,
+            member_hexes: members.iter().map(|member| member.to_string()).collect(),
+            created_at_ms: 0,
+            updated_at_ms: 0,
+        }
+    }
+
+    fn delivery(state: MessageState) -> GroupMemberDeliveryRecord {
+        GroupMemberDeliveryRecord {
+            message_id_hex: "group-msg".to_string(),
+            member_hex: "a".repeat(32),
+            lxmf_message_id_hex: None,
+            state,
+            detail: None,
+            updated_at_ms: 1,
+        }
+    }
+
+    #[test]
+    fn team_rooms_get_stable_ids_and_clean_member_lists() {
+        let normalized = normalize_group_conversation(
+            group(
+                "",
+                &[&"B".repeat(32), &"b".repeat(32), "not-hex", &"a".repeat(32)],
+            ),
+            1_000,
+        )
+        .expect("group");
+
+        assert_eq!(normalized.conversation_id, "group:team:team-red");
+        assert_eq!(normalized.title, "Team-Red");
+        assert_eq!(
+            normalized.member_hexes,
+            vec!["a".repeat(32), "b".repeat(32)]
+        );
+        assert_eq!(normalized.created_at_ms, 1_000);
+        assert!(normalize_group_conversation(group("", &["not-hex"]), 1).is_err());
+        assert_eq!(
+            normalize_group_conversation(group("Ops", &[&"a".repeat(32)]), 1)
+                .expect("explicit id")
+                .conversation_id,
+            "group:ops"
+        );
+    }
+
+    #[test]
+    fn group_envelope_round_trips_through_command_field() {
+        let group = normalize_group_conversation(group("", &[&"a".repeat(32)]), 1).expect("group");
+        let envelope = GroupEnvelope::for_group(&group, "grp-1");
+
+        let fields = build_group_fields(&envelope).expect("fields");
+
+        assert_eq!(parse_group_fields(fields.as_slice()), Some(envelope));
+        assert!(crate::mission_sync::parse_mission_sync_metadata(fields.as_slice()).is_none());
+    }
+
+    #[test]
+    fn group_message_ids_do_not_repeat() {
+        let first = new_group_message_id();
+        let second = new_group_message_id();
+
+        assert!(first.starts_with("grp-"));
+        assert_eq!(first.len(), 36);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn group_state_waits_for_every_member() {
+        assert!(matches!(
+            group_message_state(&[
+                delivery(MessageState::Delivered {}),
+                delivery(MessageState::Failed {}),
+            ]),
+            MessageState::SentDirect {}
+        ));
+        assert!(matches!(
+            group_message_state(&[
+                delivery(MessageState::Delivered {}),
+                delivery(MessageState::Read {}),
+            ]),
+            MessageState::Delivered {}
+        ));
+        assert!(matches!(
+            group_message_state(&[
+                delivery(MessageState::Failed {}),
+                delivery(MessageState::Failed {}),
+            ]),
+            MessageState::Failed {}
+        ));
+    }
+}
@@ -0,0 +1,673 @@
+use std::time::Duration;
+
+use log::{debug, info};
+use reqwest::{Method, StatusCode, Url};
+use serde_json::{json, Map as JsonMap, Value as JsonValue};
+use sha2::{Digest, Sha256};
+
+use crate::runtime::now_ms;
+use crate::types::{
+    EamProjectionRecord, EventProjectionRecord, HubDirectoryPeerRecord, HubDirectorySnapshot,
+    HubMode, NodeConfig, NodeError, TelemetryPositionRecord,
+};
+
+const HUB_HTTP_TIMEOUT: Duration = Duration::from_secs(15);
+const HUB_API_KEY_HEADER: &str = "X-API-Key";
+const HUB_CLIENT_TYPE: &str = "reticulum_mobile";
+const HUB_REGISTERED_MODE: &str = "connected";
+const EAM_COLLECTION_PATH: &str = "EmergencyActionMessage";
+const EVENT_COLLECTION_PATH: &str = "Event";
+const CLIENT_COLLECTION_PATH: &str = "Client";
+const JOIN_PATH: &str = "RCH";
+const TELEMETRY_EVENT_TYPE: &str = "a-f-G-U-C";
+const TELEMETRY_EVENT_HOW: &str = "m-g";
+const MISSION_EVENT_HOW: &str = "h-e";
+// Event UIDs are integers in the REST contract; keep them inside the range a
+// JSON number can carry without precision loss on the hub side.
+const JSON_SAFE_INTEGER_MASK: u64 = (1 << 53) - 1;
+
+#[derive(Debug, Clone)]
+pub(crate) struct HubRegistration {
+    pub(crate) identity: String,
+    pub(crate) destination_hash: String,
+    pub(crate) display_name: String,
+    pub(crate) announce_capabilities: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum HubPush {
+    UpsertEam(EamProjectionRecord),
+    DeleteEam { callsign: String },
+    UpsertEvent(EventProjectionRecord),
+    Telemetry(TelemetryPositionRecord),
+}
+
+impl HubPush {
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            Self::UpsertEam(_) => "eam-upsert",
+            Self::DeleteEam { .. } => "eam-delete",
+            Self::UpsertEvent(_) => "event-upsert",
+            Self::Telemetry(_) => "telemetry-upsert",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct HubHttpClient {
+    base_url: Url,
+    api_key: Option<String>,
+    http: reqwest::Client,
+}
+
+impl HubHttpClient {
+    pub(crate) fn new(base_url: &str, api_key: Option<&str>) -> Result<Self, NodeError> {
+        let base_url = Url::parse(base_url.trim()).map_err(|_| NodeError::InvalidConfig {})?;
+        if !matches!(base_url.scheme(), "http" | "https") || base_url.cannot_be_a_base() {
+            return Err(NodeError::InvalidConfig {});
+        }
+        let http = reqwest::Client::builder()
+            .timeout(HUB_HTTP_TIMEOUT)
+            .build()
+            .map_err(|_| NodeError::InternalError {})?;
+        Ok(Self {
+            base_url,
+            api_key: api_key
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string),
+            http,
+        })
+    }
+
+    pub(crate) fn from_config(config: &NodeConfig) -> Option<Self> {
+        if !matches!(config.hub_mode, HubMode::Connected {}) {
+            return None;
+        }
+        let base_url = config
+            .hub_api_base_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())?;
+        match Self::new(base_url, config.hub_api_key.as_deref()) {
+            Ok(client) => Some(client),
+            Err(err) => {
+                info!("[hub][http] ignoring hub api base url {base_url}: {err}");
+                None
+            }
+        }
+    }
+
+    pub(crate) async fn fetch_directory(&self) -> Result<HubDirectorySnapshot, NodeError> {
+        let value = self
+            .request(Method::GET, &[CLIENT_COLLECTION_PATH], None)
+            .await?;
+        parse_hub_directory_json(&value, now_ms()).ok_or(NodeError::InternalError {})
+    }
+
+    pub(crate) async fn register(&self, registration: &HubRegistration) -> Result<(), NodeError> {
+        let body = json!({
+            "identity": registration.identity,
+            "destination_hash": registration.destination_hash,
+            "display_name": registration.display_name,
+            "announce_capabilities": registration.announce_capabilities,
+            "client_type": HUB_CLIENT_TYPE,
+            "registered_mode": HUB_REGISTERED_MODE,
+        });
+        self.request(Method::POST, &[JOIN_PATH], Some(&body))
+            .await
+            .map(|_| ())
+    }
+
+    pub(crate) async fn push(&self, push: &HubPush) -> Result<(), NodeError> {
+        match push {
+            HubPush::UpsertEam(record) => self.upsert_eam(record).await,
+            HubPush::DeleteEam { callsign } => self.delete_eam(callsign).await,
+            HubPush::UpsertEvent(record) => self.upsert_event(record).await,
+            HubPush::Telemetry(position) => self.push_telemetry(position).await,
+        }
+    }
+
+    pub(crate) async fn upsert_eam(&self, record: &EamProjectionRecord) -> Result<(), NodeError> {
+        let body = eam_rest_body(record)?;
+        self.put_or_create(EAM_COLLECTION_PATH, &body).await
+    }
+
+    pub(crate) async fn delete_eam(&self, callsign: &str) -> Result<(), NodeError> {
+        let callsign = callsign.trim();
+        if callsign.is_empty() {
+            return Err(NodeError::InvalidConfig {});
+        }
+        self.request(Method::DELETE, &[EAM_COLLECTION_PATH, callsign], None)
+            .await
+            .map(|_| ())
+    }
+
+    pub(crate) async fn upsert_event(
+        &self,
+        record: &EventProjectionRecord,
+    ) -> Result<(), NodeError> {
+        let body = event_rest_body(record)?;
+        self.put_or_create(EVENT_COLLECTION_PATH, &body).await
+    }
+
+    pub(crate) async fn push_telemetry(
+        &self,
+        position: &TelemetryPositionRecord,
+    ) -> Result<(), NodeError> {
+        let body = telemetry_event_rest_body(position)?;
+        self.put_or_create(EVENT_COLLECTION_PATH, &body).await
+    }
+
+    // PUT answers `null` for unknown records, in which case the record is
+    // created with POST.
+    async fn put_or_create(&self, collection: &str, body: &JsonValue) -> Result<(), NodeError> {
+        let updated = self.request(Method::PUT, &[collection], Some(body)).await?;
+        if !updated.is_null() {
+            return Ok(());
+        }
+        debug!("[hub][http] {collection} not found on hub, creating");
+        self.request(Method::POST, &[collection], Some(body))
+            .await
+            .map(|_| ())
+    }
+
+    fn endpoint(&self, segments: &[&str]) -> Result<Url, NodeError> {
+        let mut url = self.base_url.clone();
+        url.path_segments_mut()
+            .map_err(|_| NodeError::InvalidConfig {})?
+            .pop_if_empty()
+            .extend(segments);
+        Ok(url)
+    }
+
+    async fn request(
+        &self,
+        method: Method,
+        segments: &[&str],
+        body: Option<&JsonValue>,
+    ) -> Result<JsonValue, NodeError> {
+        let url = self.endpoint(segments)?;
+        let mut request = self
+            .http
+            .request(method.clone(), url.clone())
+            .header(reqwest::header::ACCEPT, "application/json");
+        if let Some(api_key) = self.api_key.as_deref() {
+            request = request.header(HUB_API_KEY_HEADER, api_key);
+        }
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+
+        let response = request.send().await.map_err(map_transport_error)?;
+        let status = response.status();
+        if !status.is_success() {
+            info!("[hub][http] {method} {url} failed status={status}");
+            return Err(map_status_error(status));
+        }
+        let text = response.text().await.map_err(map_transport_error)?;
+        if text.trim().is_empty() {
+            return Ok(JsonValue::Null);
+        }
+        serde_json::from_str(text.as_str()).map_err(|_| NodeError::InternalError {})
+    }
+}
+
+fn map_transport_error(err: reqwest::Error) -> NodeError {
+    if err.is_timeout() {
+        NodeError::Timeout {}
+    } else {
+        NodeError::NetworkError {}
+    }
+}
+
+fn map_status_error(status: StatusCode) -> NodeError {
+    match status {
+        StatusCode::BAD_REQUEST
+        | StatusCode::UNAUTHORIZED
+        | StatusCode::FORBIDDEN
+        | StatusCode::NOT_FOUND
+        | StatusCode::METHOD_NOT_ALLOWED => NodeError::InvalidConfig {},
+        StatusCode::REQUEST_TIMEOUT | StatusCode::GATEWAY_TIMEOUT => NodeError::Timeout {},
+        _ => NodeError::NetworkError {},
+    }
+}
+
+pub(crate) fn registration_capabilities(capability_string: &str) -> Vec<String> {
+    capability_string
+        .split([';', ','])
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .filter(|token| !token.to_ascii_lowercase().starts_with("name="))
+        .map(str::to_string)
+        .collect()
+}
+
+fn eam_status_value(value: &str) -> Option<&'static str> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "red" => Some("Red"),
+        "yellow" => Some("Yellow"),
+        "green" => Some("Green"),
+        _ => None,
+    }
+}
+
+fn eam_rest_body(record: &EamProjectionRecord) -> Result<JsonValue, NodeError> {
+    let callsign = record.callsign.trim();
+    if callsign.is_empty() {
+        return Err(NodeError::InvalidConfig {});
+    }
+    let mut body = JsonMap::new();
+    body.insert("callsign".to_string(), JsonValue::from(callsign));
+    if !record.group_name.trim().is_empty() {
+        body.insert(
+            "groupName".to_string(),
+            JsonValue::from(record.group_name.trim()),
+        );
+    }
+    for (key, value) in [
+        ("medicalStatus", record.medical_status.as_str()),
+        ("commsStatus", record.comms_status.as_str()),
+        ("preparednessStatus", record.preparedness_status.as_str()),
+        ("mobilityStatus", record.mobility_status.as_str()),
+        ("securityCapability", record.capability_status.as_str()),
+        ("securityStatus", record.security_status.as_str()),
+    ] {
+        if let Some(status) = eam_status_value(value) {
+            body.insert(key.to_string(), JsonValue::from(status));
+        }
+    }
+    Ok(JsonValue::Object(body))
+}
+
+fn rest_event_uid(uid: &str) -> i64 {
+    if let Ok(value) = uid.trim().parse::<i64>() {
+        return value;
+    }
+    let digest = Sha256::digest(uid.trim().as_bytes());
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    (u64::from_be_bytes(bytes) & JSON_SAFE_INTEGER_MASK) as i64
+}
+
+fn event_rest_body(record: &EventProjectionRecord) -> Result<JsonValue, NodeError> {
+    let uid = record.uid.trim();
+    let command_type = record.command_type.trim();
+    if uid.is_empty() || command_type.is_empty() {
+        return Err(NodeError::InvalidConfig {});
+    }
+    let mut body = JsonMap::new();
+    body.insert("uid".to_string(), JsonValue::from(rest_event_uid(uid)));
+    body.insert("how".to_string(), JsonValue::from(MISSION_EVENT_HOW));
+    body.insert("type".to_string(), JsonValue::from(command_type));
+    body.insert(
+        "time".to_string(),
+        JsonValue::from(record.updated_at_ms / 1000),
+    );
+    if !record.timestamp.trim().is_empty() {
+        body.insert(
+            "start".to_string(),
+            JsonValue::from(record.timestamp.trim()),
+        );
+    }
+    Ok(JsonValue::Object(body))
+}
+
+fn telemetry_event_rest_body(position: &TelemetryPositionRecord) -> Result<JsonValue, NodeError> {
+    let callsign = position.callsign.trim();
+    if callsign.is_empty() || !position.lat.is_finite() || !position.lon.is_finite() {
+        return Err(NodeError::InvalidConfig {});
+    }
+    let mut point = JsonMap::new();
+    point.insert("lat".to_string(), JsonValue::from(position.lat));
+    point.insert("lon".to_string(), JsonValue::from(position.lon));
+    if let Some(alt) = position.alt.filter(|value| value.is_finite()) {
+        point.insert("hae".to_string(), JsonValue::from(alt));
+    }
+    if let Some(accuracy) = position.accuracy.filter(|value| value.is_finite()) {
+        point.insert("ce".to_string(), JsonValue::from(accuracy));
+    }
+    Ok(json!({
+        "uid": rest_event_uid(format!("telemetry-{}", callsign.to_ascii_lowercase()).as_str()),
+        "how": TELEMETRY_EVENT_HOW,
+        "type": TELEMETRY_EVENT_TYPE,
+        "time": position.updated_at_ms / 1000,
+        "point": JsonValue::Object(point),
+    }))
+}
+
+fn json_get<'a>(entries: &'a JsonMap<String, JsonValue>, keys: &[&str]) -> Option<&'a JsonValue> {
+    keys.iter().find_map(|key| entries.get(*key))
+}
+
+fn json_string(value: &JsonValue) -> Option<String> {
+    value
+        .as_str()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn json_string_vec(value: &JsonValue) -> Option<Vec<String>> {
+    match value {
+        JsonValue::Array(items) => Some(items.iter().filter_map(json_string).collect()),
+        JsonValue::String(raw) => Some(registration_capabilities(raw)),
+        _ => None,
+    }
+}
+
+fn parse_hub_directory_peer_json(value: &JsonValue) -> Option<HubDirectoryPeerRecord> {
+    let entries = value.as_object()?;
+    Some(HubDirectoryPeerRecord {
+        identity: json_get(entries, &["identity", "identity_hash", "rns_identity"])
+            .and_then(json_string)?,
+        destination_hash: json_get(
+            entries,
+            &["destination_hash", "destination", "destinationHash"],
+        )
+        .and_then(json_string)?,
+        display_name: json_get(entries, &["display_name", "displayName", "name"])
+            .and_then(json_string),
+        announce_capabilities: json_get(entries, &["announce_capabilities", "capabilities"])
+            .and_then(json_string_vec)
+            .unwrap_or_default(),
+        client_type: json_get(entries, &["client_type", "clientType"]).and_then(json_string),
+        registered_mode: json_get(entries, &["registered_mode", "registeredMode"])
+            .and_then(json_string),
+        last_seen: json_get(entries, &["last_seen", "lastSeen"]).and_then(json_string),
+        status: json_get(entries, &["status"]).and_then(json_string),
+    })
+}
+
+pub(crate) fn parse_hub_directory_json(
+    value: &JsonValue,
+    received_at_ms: u64,
+) -> Option<HubDirectorySnapshot> {
+    let (effective_connected_mode, items) = match value {
+        JsonValue::Array(items) => (false, items),
+        JsonValue::Object(entries) => {
+            let effective_connected_mode = entries
+                .get("effective_connected_mode")
+                .and_then(JsonValue::as_bool)
+                .unwrap_or(false);
+            let items = json_get(entries, &["items", "clients", "data"])?.as_array()?;
+            (effective_connected_mode, items)
+        }
+        _ => return None,
+    };
+    Some(HubDirectorySnapshot {
+        effective_connected_mode,
+        items: items
+            .iter()
+            .filter_map(parse_hub_directory_peer_json)
+            .collect(),
+        received_at_ms,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::sample_eam;
+    use std::collections::HashMap;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::task::JoinHandle;
+
+    #[derive(Debug)]
+    struct MockRequest {
+        method: String,
+        path: String,
+        headers: HashMap<String, String>,
+        body: String,
+    }
+
+    async fn read_mock_request(stream: &mut tokio::net::TcpStream) -> MockRequest {
+        let mut buffer = Vec::new();
+        let mut chunk = [0u8; 4096];
+        let header_end = loop {
+            let read = stream.read(&mut chunk).await.expect("read request");
+            assert!(read > 0, "connection closed before request headers");
+            buffer.extend_from_slice(&chunk[..read]);
+            if let Some(index) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+                break index + 4;
+            }
+        };
+        let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+        let mut lines = head.split("\r\n");
+        let mut request_line = lines.next().unwrap_or_default().split(' ');
+        let method = request_line.next().unwrap_or_default().to_string();
+        let path = request_line.next().unwrap_or_default().to_string();
+        let headers = lines
+            .filter_map(|line| line.split_once(':'))
+            .map(|(key, value)| (key.trim().to_ascii_lowercase(), value.trim().to_string()))
+            .collect::<HashMap<_, _>>();
+        let content_length = headers
+            .get("content-length")
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(0);
+        while buffer.len() < header_end + content_length {
+            let read = stream.read(&mut chunk).await.expect("read body");
+            assert!(read > 0, "connection closed before request body");
+            buffer.extend_from_slice(&chunk[..read]);
+        }
+        MockRequest {
+            method,
+            path,
+            headers,
+            body: String::from_utf8_lossy(&buffer[header_end..header_end + content_length])
+                .to_string(),
+        }
+    }
+
+    async fn spawn_mock_hub(
+        responses: Vec<(u16, &'static str)>,
+    ) -> (String, JoinHandle<Vec<MockRequest>>) {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock hub");
+        let base_url = format!("http://{}/api", listener.local_addr().expect("addr"));
+        let handle = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().await.expect("accept");
+                requests.push(read_mock_request(&mut stream).await);
+                let response = format!(
+                    "HTTP/1.1 {status} Mock\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream
+                    .write_all(response.as_bytes())
+                    .await
+                    .expect("write response");
+                let _ = stream.shutdown().await;
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    fn eam(callsign: &str) -> EamProjectionRecord {
+        EamProjectionRecord {
+            callsign: callsign.to_string(),
+            group_name: "YELLOW".to_string(),
+            security_status: "green".to_string(),
+            capability_status: "Yellow".to_string(),
+            preparedness_status: "Green".to_string(),
+            medical_status: "Red".to_string(),
+            mobility_status: "Unknown".to_string(),
+            comms_status: "Green".to_string(),
+            ..sample_eam()
+        }
+    }
+
+    #[tokio::test]
+    async fn fetch_directory_reads_client_list_with_api_key() {
+        let (base_url, server) = spawn_mock_hub(vec![(
+            200,
+            r#"{"effective_connected_mode":true,"items":[{"identity":"aa11","destination_hash":"bb22","display_name":"Hub Peer","announce_capabilities":["R3AKT","telemetry"],"status":"active"},{"identity":"missing-destination"}]}"#,
+        )])
+        .await;
+        let client = HubHttpClient::new(base_url.as_str(), Some(" secret ")).expect("client");
+
+        let snapshot = client.fetch_directory().await.expect("directory");
+        let requests = server.await.expect("mock hub");
+
+        assert!(snapshot.effective_connected_mode);
+        assert_eq!(snapshot.items.len(), 1);
+        assert_eq!(snapshot.items[0].destination_hash, "bb22");
+        assert_eq!(
+            snapshot.items[0].announce_capabilities,
+            vec!["R3AKT".to_string(), "telemetry".to_string()]
+        );
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/api/Client");
+        assert_eq!(
+            requests[0].headers.get("x-api-key").map(String::as_str),
+            Some("secret")
+        );
+    }
+
+    #[tokio::test]
+    async fn register_posts_join_request() {
+        let (base_url, server) = spawn_mock_hub(vec![(200, r#"{"status":"joined"}"#)]).await;
+        let client = HubHttpClient::new(base_url.as_str(), None).expect("client");
+
+        client
+            .register(&HubRegistration {
+                identity: "aa11".to_string(),
+                destination_hash: "bb22".to_string(),
+                display_name: "Mobile".to_string(),
+                announce_capabilities: registration_capabilities("name=Mobile;R3AKT,telemetry"),
+            })
+            .await
+            .expect("register");
+        let requests = server.await.expect("mock hub");
+
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/api/RCH");
+        assert!(!requests[0].headers.contains_key("x-api-key"));
+        let body: JsonValue = serde_json::from_str(requests[0].body.as_str()).expect("json");
+        assert_eq!(body["destination_hash"], "bb22");
+        assert_eq!(body["registered_mode"], "connected");
+        assert_eq!(body["announce_capabilities"], json!(["R3AKT", "telemetry"]));
+    }
+
+    #[tokio::test]
+    async fn eam_upsert_creates_record_when_put_returns_null() {
+        let (base_url, server) =
+            spawn_mock_hub(vec![(200, "null"), (200, r#"{"callsign":"ALPHA-1"}"#)]).await;
+        let client = HubHttpClient::new(base_url.as_str(), Some("secret")).expect("client");
+
+        client
+            .push(&HubPush::UpsertEam(eam("ALPHA-1")))
+            .await
+            .expect("upsert");
+        let requests = server.await.expect("mock hub");
+
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "PUT");
+        assert_eq!(requests[1].method, "POST");
+        assert_eq!(requests[1].path, "/api/EmergencyActionMessage");
+        let body: JsonValue = serde_json::from_str(requests[1].body.as_str()).expect("json");
+        assert_eq!(body["callsign"], "ALPHA-1");
+        assert_eq!(body["groupName"], "YELLOW");
+        assert_eq!(body["securityStatus"], "Green");
+        assert_eq!(body["securityCapability"], "Yellow");
+        assert_eq!(body["medicalStatus"], "Red");
+        assert!(body.get("mobilityStatus").is_none());
+    }
+
+    #[tokio::test]
+    async fn eam_delete_escapes_callsign_path_segment() {
+        let (base_url, server) =
+            spawn_mock_hub(vec![(200, r#"{"status":"deleted","callsign":"ALPHA 1"}"#)]).await;
+        let client = HubHttpClient::new(base_url.as_str(), None).expect("client");
+
+        client.delete_eam("ALPHA 1").await.expect("delete");
+        let requests = server.await.expect("mock hub");
+
+        assert_eq!(requests[0].method, "DELETE");
+        assert_eq!(requests[0].path, "/api/EmergencyActionMessage/ALPHA%201");
+    }
+
+    #[tokio::test]
+    async fn telemetry_is_pushed_as_position_event() {
+        let (base_url, server) = spawn_mock_hub(vec![(200, r#"{"uid":1}"#)]).await;
+        let client = HubHttpClient::new(base_url.as_str(), None).expect("client");
+
+        client
+            .push(&HubPush::Telemetry(TelemetryPositionRecord {
+                callsign: "ALPHA-1".to_string(),
+                lat: 44.5,
+                lon: -63.5,
+                alt: Some(12.0),
+                course: None,
+                speed: None,
+                accuracy: Some(4.0),
+                updated_at_ms: 1_700_000_000_000,
+                stale: false,
+            }))
+            .await
+            .expect("telemetry");
+        let requests = server.await.expect("mock hub");
+
+        assert_eq!(requests[0].method, "PUT");
+        assert_eq!(requests[0].path, "/api/Event");
+        let body: JsonValue = serde_json::from_str(requests[0].body.as_str()).expect("json");
+        assert_eq!(body["type"], TELEMETRY_EVENT_TYPE);
+        assert_eq!(body["point"]["lat"], 44.5);
+        assert_eq!(body["point"]["hae"], 12.0);
+        assert_eq!(body["point"]["ce"], 4.0);
+        assert_eq!(body["time"], 1_700_000_000);
+        assert!(body["uid"].as_i64().is_some());
+    }
+
+    #[tokio::test]
+    async fn rejected_requests_surface_as_errors() {
+        let (base_url, server) =
+            spawn_mock_hub(vec![(401, r#"{"error":"unauthorized","code":401}"#)]).await;
+        let client = HubHttpClient::new(base_url.as_str(), Some("wrong")).expect("client");
+
+        let result = client.fetch_directory().await;
+        server.await.expect("mock hub");
+
+        assert!(matches!(result, Err(NodeError::InvalidConfig {})));
+    }
+
+    #[test]
+    fn rest_event_uid_is_stable_and_json_safe() {
+        assert_eq!(rest_event_uid("42"), 42);
+        let hashed = rest_event_uid("event-abc");
+        assert_eq!(hashed, rest_event_uid(" event-abc "));
+        assert!(hashed >= 0 && (hashed as u64) <= JSON_SAFE_INTEGER_MASK);
+    }
+
+    #[test]
+    fn from_config_requires_connected_mode_and_valid_url() {
+        let mut config = NodeConfig {
+            name: "node".to_string(),
+            storage_dir: None,
+            tcp_clients: Vec::new(),
+            broadcast: false,
+            announce_interval_seconds: 60,
+            stale_after_minutes: 30,
+            announce_capabilities: String::new(),
+            hub_mode: HubMode::SemiAutonomous {},
+            hub_identity_hash: None,
+            hub_api_base_url: Some("https://hub.example/api".to_string()),
+            hub_api_key: None,
+            hub_refresh_interval_seconds: 0,
+        };
+        assert!(HubHttpClient::from_config(&config).is_none());
+
+        config.hub_mode = HubMode::Connected {};
+        assert!(HubHttpClient::from_config(&config).is_some());
+
+        config.hub_api_base_url = Some("ftp://hub.example".to_string());
+        assert!(HubHttpClient::from_config(&config).is_none());
+
+        config.hub_api_base_url = Some("   ".to_string());
+        assert!(HubHttpClient::from_config(&config).is_none());
+    }
+}
@@ -17,14 +17,18 @@ use crate::types::{
     ChecklistTaskRowStyleSetRequest, ChecklistTaskStatusSetRequest,
     ChecklistTemplateImportCsvRequest, ChecklistTemplateListRequest, ChecklistTemplateRecord,
     ChecklistUpdatePatch, ChecklistUpdateRequest, ConversationRecord, EamProjectionRecord,
-    EventProjectionRecord, HubDirectoryPeerRecord, HubDirectorySnapshot, HubMode,
-    HubSettingsRecord, LegacyImportPayload, LogLevel, LxmfDeliveryMethod,
-    LxmfDeliveryRepresentation, LxmfDeliveryStatus, LxmfFallbackStage, MessageDirection,
-    MessageMethod, MessageRecord, MessageState, NodeConfig, NodeError, NodeEvent, NodeStatus,
-    PeerChange, PeerRecord, PeerState, ProjectionScope, SavedPeerRecord, SendLxmfRequest, SendMode,
-    SendOutcome, SosAlertRecord, SosAudioRecord, SosDeviceTelemetryRecord, SosLocationRecord,
-    SosMessageKind, SosSettingsRecord, SosState, SosStatusRecord, SosTriggerSource, SyncPhase,
-    TelemetryPositionRecord, TelemetrySettingsRecord,
+    EventProjectionRecord, GroupConversationRecord, GroupMemberDeliveryRecord,
+    HubDirectoryPeerRecord, HubDirectorySnapshot, HubMode, HubSettingsRecord, LegacyImportPayload,
+    LogLevel, LxmfAttachmentRequest, LxmfDeliveryMethod, LxmfDeliveryRepresentation,
+    LxmfDeliveryStatus, LxmfFallbackStage, MessageAttachmentKind, MessageAttachmentRecord,
+    MessageDirection, MessageMethod, MessagePriority, MessageReaction, MessageRecord, MessageState,
+    NodeConfig, NodeError, NodeEvent, NodeStatus, PeerChange, PeerRecord, PeerState,
+    ProjectionScope, PropagationSyncSettingsRecord, SavedPeerRecord, SendLxmfRequest, SendMode,
+    SendOutcome, SosAlertRecord, SosAudioRecord, SosDeliveryRecord, SosDeliveryState,
+    SosDeviceTelemetryRecord, SosLocationRecord, SosMessageKind, SosRecipientPolicyRecord,
+    SosResponderRecord, SosResponseKind, SosSettingsRecord, SosState, SosStatusRecord,
+    SosTriggerSource, SyncPhase, TelemetryPositionRecord, TelemetrySettingsRecord,
+    TelemetryTrackFormat,
 };
 
 const RESULT_OK: jint = 0;
@@ -90,6 +94,20 @@ struct SendLxmfInput {
     send_mode: Option<String>,
     #[serde(default)]
     use_propagation_node: bool,
+    #[serde(default)]
+    attachments: Vec<LxmfAttachmentInput>,
+    send_at_ms: Option<u64>,
+    expires_at_ms: Option<u64>,
+    priority: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LxmfAttachmentInput {
+    kind: Option<String>,
+    path: String,
+    file_name: Option<String>,
+    mime_type: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -98,6 +116,14 @@ struct MessageIdInput {
     message_id_hex: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MessageActionInput {
+    message_id_hex: String,
+    body_utf8: Option<String>,
+    reaction: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct OptionalDestinationInput {
@@ -110,18 +136,73 @@ struct SyncRequestInput {
     limit: Option<u32>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NetworkAvailabilityInput {
+    available: bool,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct MessageListInput {
     conversation_id: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MessagePageInput {
+    conversation_id: String,
+    before_ms: Option<u64>,
+    before_message_id_hex: Option<String>,
+    #[serde(default)]
+    limit: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MessageSearchInput {
+    query: String,
+    conversation_id: Option<String>,
+    #[serde(default)]
+    limit: u32,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct ConversationDeleteInput {
     conversation_id: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConversationReadInput {
+    conversation_id: String,
+    #[serde(default)]
+    send_read_receipts: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GroupConversationInput {
+    #[serde(default)]
+    conversation_id: String,
+    #[serde(default)]
+    title: String,
+    team_uid: Option<String>,
+    mission_uid: Option<String>,
+    #[serde(default)]
+    member_hexes: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GroupMessageInput {
+    conversation_id: String,
+    body_utf8: String,
+    title: Option<String>,
+    priority: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct LegacyImportInput {
@@ -146,6 +227,8 @@ struct AppSettingsInput {
     hub: HubSettingsInput,
     #[serde(default)]
     checklists: ChecklistSettingsInput,
+    #[serde(default)]
+    propagation_sync: PropagationSyncSettingsInput,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -154,6 +237,14 @@ struct ChecklistSettingsInput {
     default_task_due_step_minutes: Option<u32>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PropagationSyncSettingsInput {
+    enabled: Option<bool>,
+    interval_seconds: Option<u32>,
+    sync_on_reconnect: Option<bool>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct HubSettingsInput {
@@ -216,6 +307,50 @@ struct EamProjectionInput {
     sync_error: Option<String>,
     draft_created_at: Option<u64>,
     last_synced_at: Option<u64>,
+    #[serde(default)]
+    custom_statuses: Vec<EamStatusEntryInput>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EamStatusEntryInput {
+    dimension: String,
+    value: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EamStatusDimensionInput {
+    key: String,
+    label: String,
+    #[serde(default)]
+    required: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EamStatusValueInput {
+    value: String,
+    rank: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EamSchemaInput {
+    schema_uid: String,
+    name: String,
+    #[serde(default)]
+    version: u32,
+    dimensions: Vec<EamStatusDimensionInput>,
+    values: Vec<EamStatusValueInput>,
+    unknown_value: Option<String>,
+    overall_rule: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SchemaUidInput {
+    schema_uid: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -258,6 +393,15 @@ struct MessageRecordInput {
     updated_at: u64,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TelemetryTrackInput {
+    callsign: String,
+    since_ms: Option<u64>,
+    limit: Option<u32>,
+    format: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct TelemetryPositionInput {
@@ -278,6 +422,34 @@ struct DeleteEamInput {
     deleted_at_ms: Option<u64>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TeamMemberInput {
+    rns_identity: String,
+    team_member_uid: Option<String>,
+    callsign: Option<String>,
+    role: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TeamInput {
+    team_uid: String,
+    name: String,
+    level: Option<String>,
+    parent_team_uid: Option<String>,
+    color: Option<String>,
+    #[serde(default)]
+    members: Vec<TeamMemberInput>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DeleteTeamInput {
+    team_uid: String,
+    deleted_at_ms: Option<u64>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct DeleteEventInput {
@@ -411,6 +583,28 @@ struct CallsignInput {
     callsign: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EamRollCallInput {
+    team_uid: Option<String>,
+    #[serde(default)]
+    team_member_uids: Vec<String>,
+    due_in_seconds: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RollCallUidInput {
+    roll_call_uid: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EamRollCallResponseInput {
+    roll_call_uid: String,
+    record: EamProjectionInput,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct SosSettingsInput {
@@ -436,6 +630,23 @@ struct SosSettingsInput {
     floating_button_y: f64,
     active_pill_x: f64,
     active_pill_y: f64,
+    #[serde(default)]
+    recipients: SosRecipientPolicyInput,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SosRecipientPolicyInput {
+    saved_peers: Option<bool>,
+    #[serde(default)]
+    contacts: Vec<String>,
+    #[serde(default)]
+    same_team: bool,
+    team_uid: Option<String>,
+    #[serde(default)]
+    hub: bool,
+    #[serde(default)]
+    r3akt_peers: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -450,6 +661,20 @@ struct SosTriggerInput {
     source: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SosIncidentInput {
+    incident_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SosRespondInput {
+    incident_id: String,
+    kind: String,
+    eta_seconds: Option<u32>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct SosDeactivateInput {
@@ -715,6 +940,9 @@ fn parse_message_state(value: &str) -> Result<MessageState, NodeError> {
         "TimedOut" => Ok(MessageState::TimedOut {}),
         "Cancelled" => Ok(MessageState::Cancelled {}),
         "Received" => Ok(MessageState::Received {}),
+        "Read" => Ok(MessageState::Read {}),
+        "Scheduled" => Ok(MessageState::Scheduled {}),
+        "Expired" => Ok(MessageState::Expired {}),
         _ => Err(NodeError::InvalidConfig {}),
     }
 }
@@ -738,6 +966,16 @@ fn parse_sos_trigger_source(value: Option<&str>) -> SosTriggerSource {
     }
 }
 
+fn parse_sos_response_kind(value: &str) -> Option<SosResponseKind> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "acknowledged" | "ack" => Some(SosResponseKind::Acknowledged {}),
+        "enroute" | "en_route" | "en-route" => Some(SosResponseKind::EnRoute {}),
+        "onscene" | "on_scene" | "on-scene" => Some(SosResponseKind::OnScene {}),
+        "autoanswer" | "auto_answer" | "auto-answer" => Some(SosResponseKind::AutoAnswer {}),
+        _ => None,
+    }
+}
+
 fn to_saved_peer_record(input: SavedPeerInput) -> SavedPeerRecord {
     SavedPeerRecord {
         destination_hex: input.destination.trim().to_ascii_lowercase(),
@@ -776,6 +1014,14 @@ fn to_sos_settings_record(input: SosSettingsInput) -> SosSettingsRecord {
         floating_button_y: input.floating_button_y,
         active_pill_x: input.active_pill_x,
         active_pill_y: input.active_pill_y,
+        recipients: SosRecipientPolicyRecord {
+            saved_peers: input.recipients.saved_peers.unwrap_or(true),
+            contacts: input.recipients.contacts,
+            same_team: input.recipients.same_team,
+            team_uid: input.recipients.team_uid,
+            hub: input.recipients.hub,
+            r3akt_peers: input.recipients.r3akt_peers,
+        },
     }
 }
 
@@ -822,6 +1068,14 @@ fn to_app_settings_record(input: AppSettingsInput) -> AppSettingsRecord {
                 .unwrap_or(crate::types::DEFAULT_CHECKLIST_TASK_DUE_STEP_MINUTES)
                 .max(1),
         },
+        propagation_sync: PropagationSyncSettingsRecord {
+            enabled: input.propagation_sync.enabled.unwrap_or(true),
+            interval_seconds: input
+                .propagation_sync
+                .interval_seconds
+                .unwrap_or(crate::types::DEFAULT_PROPAGATION_SYNC_INTERVAL_SECONDS),
+            sync_on_reconnect: input.propagation_sync.sync_on_reconnect.unwrap_or(true),
+        },
     }
 }
 
@@ -854,6 +1108,77 @@ fn to_eam_projection_record(input: EamProjectionInput) -> EamProjectionRecord {
         sync_error: input.sync_error,
         draft_created_at_ms: input.draft_created_at,
         last_synced_at_ms: input.last_synced_at,
+        expired: false,
+        custom_statuses: input
+            .custom_statuses
+            .into_iter()
+            .map(|entry| crate::types::EamStatusEntry {
+                dimension: entry.dimension,
+                value: entry.value,
+            })
+            .collect(),
+    }
+}
+
+fn to_eam_schema_record(input: EamSchemaInput) -> crate::types::EamSchemaRecord {
+    crate::types::EamSchemaRecord {
+        schema_uid: input.schema_uid,
+        name: input.name,
+        version: input.version.max(1),
+        dimensions: input
+            .dimensions
+            .into_iter()
+            .map(|dimension| crate::types::EamStatusDimensionRecord {
+                key: dimension.key,
+                label: dimension.label,
+                required: dimension.required,
+            })
+            .collect(),
+        values: input
+            .values
+            .into_iter()
+            .map(|value| crate::types::EamStatusValueRecord {
+                value: value.value,
+                rank: value.rank,
+            })
+            .collect(),
+        unknown_value: input.unknown_value.unwrap_or_else(|| "Unknown".to_string()),
+        overall_rule: match input.overall_rule.as_deref().map(str::trim) {
+            Some("BestOf") | Some("bestOf") => crate::types::EamOverallStatusRule::BestOf {},
+            _ => crate::types::EamOverallStatusRule::WorstOf {},
+        },
+        active: false,
+        updated_at_ms: 0,
+    }
+}
+
+fn to_team_record(input: TeamInput) -> crate::types::TeamRecord {
+    crate::types::TeamRecord {
+        team_uid: input.team_uid,
+        name: input.name,
+        level: match input.level.as_deref().map(str::trim) {
+            Some("Section") | Some("section") => crate::types::TeamLevel::Section {},
+            Some("Squad") | Some("squad") => crate::types::TeamLevel::Squad {},
+            _ => crate::types::TeamLevel::Team {},
+        },
+        parent_team_uid: input.parent_team_uid,
+        color: input.color,
+        members: input
+            .members
+            .into_iter()
+            .map(|member| crate::types::TeamMemberRecord {
+                rns_identity: member.rns_identity,
+                team_member_uid: member.team_member_uid,
+                callsign: member.callsign,
+                role: match member.role.as_deref().map(str::trim) {
+                    Some("Leader") | Some("leader") => crate::types::TeamMemberRole::Leader {},
+                    Some("Deputy") | Some("deputy") => crate::types::TeamMemberRole::Deputy {},
+                    _ => crate::types::TeamMemberRole::Member {},
+                },
+            })
+            .collect(),
+        updated_at_ms: 0,
+        deleted_at_ms: None,
     }
 }
 
@@ -894,6 +1219,10 @@ fn to_message_record(input: MessageRecordInput) -> Result<MessageRecord, NodeErr
         sent_at_ms: input.sent_at,
         received_at_ms: input.received_at,
         updated_at_ms: input.updated_at,
+        attachments: Vec::new(),
+        edited_at_ms: None,
+        deleted_at_ms: None,
+        reactions: Vec::new(),
     })
 }
 
@@ -907,6 +1236,7 @@ fn to_telemetry_position_record(input: TelemetryPositionInput) -> TelemetryPosit
         speed: input.speed,
         accuracy: input.accuracy,
         updated_at_ms: input.updated_at,
+        stale: false,
     }
 }
 
@@ -1144,6 +1474,11 @@ fn app_settings_json(settings: &AppSettingsRecord) -> serde_json::Value {
         "hub": hub_settings_json(&settings.hub),
         "checklists": {
             "defaultTaskDueStepMinutes": settings.checklists.default_task_due_step_minutes
+        },
+        "propagationSync": {
+            "enabled": settings.propagation_sync.enabled,
+            "intervalSeconds": settings.propagation_sync.interval_seconds,
+            "syncOnReconnect": settings.propagation_sync.sync_on_reconnect
         }
     })
 }
@@ -1160,6 +1495,14 @@ fn sos_kind_to_str(kind: SosMessageKind) -> &'static str {
     crate::sos::sos_kind_label(kind)
 }
 
+fn sos_delivery_state_to_str(state: SosDeliveryState) -> &'static str {
+    crate::sos::sos_delivery_label(state)
+}
+
+fn sos_response_kind_to_str(kind: SosResponseKind) -> &'static str {
+    crate::sos_fields::sos_response_kind_to_str(kind)
+}
+
 fn sos_settings_json(settings: &SosSettingsRecord) -> serde_json::Value {
     json!({
         "enabled": settings.enabled,
@@ -1182,7 +1525,15 @@ fn sos_settings_json(settings: &SosSettingsRecord) -> serde_json::Value {
         "floatingButtonX": settings.floating_button_x,
         "floatingButtonY": settings.floating_button_y,
         "activePillX": settings.active_pill_x,
-        "activePillY": settings.active_pill_y
+        "activePillY": settings.active_pill_y,
+        "recipients": {
+            "savedPeers": settings.recipients.saved_peers,
+            "contacts": settings.recipients.contacts,
+            "sameTeam": settings.recipients.same_team,
+            "teamUid": settings.recipients.team_uid,
+            "hub": settings.recipients.hub,
+            "r3aktPeers": settings.recipients.r3akt_peers
+        }
     })
 }
 
@@ -1199,6 +1550,60 @@ fn sos_status_json(status: &SosStatusRecord) -> serde_json::Value {
     })
 }
 
+fn sos_delivery_json(delivery: &SosDeliveryRecord) -> serde_json::Value {
+    json!({
+        "incidentId": delivery.incident_id,
+        "destinationHex": delivery.destination_hex,
+        "kind": sos_kind_to_str(delivery.kind),
+        "state": sos_delivery_state_to_str(delivery.state),
+        "attempts": delivery.attempts,
+        "lxmfMessageIdHex": delivery.lxmf_message_id_hex,
+        "viaPropagation": delivery.via_propagation,
+        "detail": delivery.detail,
+        "lastAttemptAtMs": delivery.last_attempt_at_ms,
+        "updatedAtMs": delivery.updated_at_ms
+    })
+}
+
+fn group_conversation_json(group: &GroupConversationRecord) -> Value {
+    json!({
+        "conversationId": group.conversation_id,
+        "title": group.title,
+        "teamUid": group.team_uid,
+        "missionUid": group.mission_uid,
+        "memberHexes": group.member_hexes,
+        "createdAtMs": group.created_at_ms,
+        "updatedAtMs": group.updated_at_ms
+    })
+}
+
+fn group_delivery_json(delivery: &GroupMemberDeliveryRecord) -> Value {
+    json!({
+        "messageIdHex": delivery.message_id_hex,
+        "memberHex": delivery.member_hex,
+        "lxmfMessageIdHex": delivery.lxmf_message_id_hex,
+        "state": message_state_to_str(delivery.state),
+        "detail": delivery.detail,
+        "updatedAtMs": delivery.updated_at_ms
+    })
+}
+
+fn sos_responder_json(responder: &SosResponderRecord) -> serde_json::Value {
+    json!({
+        "incidentId": responder.incident_id,
+        "responderHex": responder.responder_hex,
+        "kind": sos_response_kind_to_str(responder.kind),
+        "etaSeconds": responder.eta_seconds,
+        "lat": responder.lat,
+        "lon": responder.lon,
+        "accuracy": responder.accuracy,
+        "batteryPercent": responder.battery_percent,
+        "messageIdHex": responder.message_id_hex,
+        "respondedAtMs": responder.responded_at_ms,
+        "updatedAtMs": responder.updated_at_ms
+    })
+}
+
 fn sos_alert_json(alert: &SosAlertRecord) -> serde_json::Value {
     json!({
         "incidentId": alert.incident_id,
@@ -1290,7 +1695,87 @@ fn eam_projection_json(record: &EamProjectionRecord) -> serde_json::Value {
         "syncState": record.sync_state,
         "syncError": record.sync_error,
         "draftCreatedAt": record.draft_created_at_ms,
-        "lastSyncedAt": record.last_synced_at_ms
+        "lastSyncedAt": record.last_synced_at_ms,
+        "expired": record.expired,
+        "customStatuses": record
+            .custom_statuses
+            .iter()
+            .map(|entry| json!({
+                "dimension": entry.dimension,
+                "value": entry.value
+            }))
+            .collect::<Vec<_>>()
+    })
+}
+
+fn eam_schema_json(schema: &crate::types::EamSchemaRecord) -> serde_json::Value {
+    json!({
+        "schemaUid": schema.schema_uid,
+        "name": schema.name,
+        "version": schema.version,
+        "dimensions": schema
+            .dimensions
+            .iter()
+            .map(|dimension| json!({
+                "key": dimension.key,
+                "label": dimension.label,
+                "required": dimension.required
+            }))
+            .collect::<Vec<_>>(),
+        "values": schema
+            .values
+            .iter()
+            .map(|value| json!({
+                "value": value.value,
+                "rank": value.rank
+            }))
+            .collect::<Vec<_>>(),
+        "unknownValue": schema.unknown_value,
+        "overallRule": match schema.overall_rule {
+            crate::types::EamOverallStatusRule::WorstOf {} => "WorstOf",
+            crate::types::EamOverallStatusRule::BestOf {} => "BestOf",
+        },
+        "active": schema.active,
+        "updatedAt": schema.updated_at_ms
+    })
+}
+
+fn team_json(team: &crate::types::TeamRecord) -> serde_json::Value {
+    json!({
+        "teamUid": team.team_uid,
+        "name": team.name,
+        "level": match team.level {
+            crate::types::TeamLevel::Section {} => "Section",
+            crate::types::TeamLevel::Team {} => "Team",
+            crate::types::TeamLevel::Squad {} => "Squad",
+        },
+        "parentTeamUid": team.parent_team_uid,
+        "color": team.color,
+        "members": team
+            .members
+            .iter()
+            .map(|member| json!({
+                "rnsIdentity": member.rns_identity,
+                "teamMemberUid": member.team_member_uid,
+                "callsign": member.callsign,
+                "role": match member.role {
+                    crate::types::TeamMemberRole::Leader {} => "Leader",
+                    crate::types::TeamMemberRole::Deputy {} => "Deputy",
+                    crate::types::TeamMemberRole::Member {} => "Member",
+                }
+            }))
+            .collect::<Vec<_>>(),
+        "updatedAt": team.updated_at_ms,
+        "deletedAt": team.deleted_at_ms
+    })
+}
+
+fn team_rollup_json(rollup: &crate::types::TeamRollupRecord) -> serde_json::Value {
+    json!({
+        "team": team_json(&rollup.team),
+        "childTeamUids": rollup.child_team_uids,
+        "depth": rollup.depth,
+        "summary": rollup.summary.as_ref().map(eam_team_summary_json)
     })
 }
 
@@ -1444,7 +1929,62 @@ fn telemetry_position_json(record: &TelemetryPositionRecord) -> serde_json::Valu
         "course": record.course,
         "speed": record.speed,
         "accuracy": record.accuracy,
-        "updatedAt": record.updated_at_ms
+        "updatedAt": record.updated_at_ms,
+        "stale": record.stale
+    })
+}
+
+fn eam_revision_json(revision: &crate::types::EamRevisionRecord) -> serde_json::Value {
+    json!({
+        "revision": revision.revision,
+        "callsign": revision.callsign,
+        "recordedAt": revision.recorded_at_ms,
+        "reportedAt": revision.reported_at,
+        "reportedBy": revision.reported_by,
+        "sourceIdentity": revision.source_identity,
+        "deleted": revision.deleted,
+        "changes": revision
+            .changes
+            .iter()
+            .map(|change| json!({
+                "field": change.field,
+                "previousValue": change.previous_value,
+                "currentValue": change.current_value
+            }))
+            .collect::<Vec<_>>(),
+        "record": eam_projection_json(&revision.record)
+    })
+}
+
+fn eam_roll_call_json(roll_call: &crate::types::EamRollCallRecord) -> serde_json::Value {
+    use crate::types::{EamRollCallDirection, EamRollCallMemberState};
+
+    json!({
+        "rollCallUid": roll_call.roll_call_uid,
+        "direction": match roll_call.direction {
+            EamRollCallDirection::Outbound {} => "Outbound",
+            EamRollCallDirection::Inbound {} => "Inbound",
+        },
+        "teamUid": roll_call.team_uid,
+        "requestedBy": roll_call.requested_by,
+        "requesterHex": roll_call.requester_hex,
+        "requestedAt": roll_call.requested_at_ms,
+        "dueAt": roll_call.due_at_ms,
+        "members": roll_call
+            .members
+            .iter()
+            .map(|member| json!({
+                "teamMemberUid": member.team_member_uid,
+                "callsign": member.callsign,
+                "state": match member.state {
+                    EamRollCallMemberState::Pending {} => "Pending",
+                    EamRollCallMemberState::Responded {} => "Responded",
+                    EamRollCallMemberState::Overdue {} => "Overdue",
+                },
+                "respondedAt": member.responded_at_ms
+            }))
+            .collect::<Vec<_>>(),
+        "updatedAt": roll_call.updated_at_ms
     })
 }
 
@@ -1458,6 +1998,17 @@ fn eam_team_summary_json(summary: &crate::types::EamTeamSummaryRecord) -> serde_
         "greenTotal": summary.green_total,
         "yellowTotal": summary.yellow_total,
         "redTotal": summary.red_total,
+        "expiredTotal": summary.expired_total,
+        "notReportingTotal": summary.not_reporting_total,
+        "lowConfidenceTotal": summary.low_confidence_total,
+        "statusTotals": summary
+            .status_totals
+            .iter()
+            .map(|total| json!({
+                "status": total.status,
+                "total": total.total
+            }))
+            .collect::<Vec<_>>(),
         "updatedAt": summary.updated_at_ms
     })
 }
@@ -1496,6 +2047,14 @@ fn lxmf_delivery_status_to_str(status: LxmfDeliveryStatus) -> &'static str {
         LxmfDeliveryStatus::Acknowledged {} => "Acknowledged",
         LxmfDeliveryStatus::Failed {} => "Failed",
         LxmfDeliveryStatus::TimedOut {} => "TimedOut",
+        LxmfDeliveryStatus::Expired {} => "Expired",
+    }
+}
+
+fn telemetry_track_format_from_input(format: Option<&str>) -> TelemetryTrackFormat {
+    match format.unwrap_or("").trim() {
+        "GeoJson" | "geojson" => TelemetryTrackFormat::GeoJson {},
+        _ => TelemetryTrackFormat::Gpx {},
     }
 }
 
@@ -1510,6 +2069,48 @@ fn send_mode_from_input(send_mode: Option<&str>, use_propagation_node: bool) ->
     }
 }
 
+fn parse_message_priority(priority: Option<&str>) -> Option<MessagePriority> {
+    match priority?.trim() {
+        "Sos" => Some(MessagePriority::Sos {}),
+        "Flash" => Some(MessagePriority::Flash {}),
+        "Mission" => Some(MessagePriority::Mission {}),
+        "Routine" => Some(MessagePriority::Routine {}),
+        "Telemetry" => Some(MessagePriority::Telemetry {}),
+        _ => None,
+    }
+}
+
+fn parse_message_attachment_kind(kind: Option<&str>) -> MessageAttachmentKind {
+    match kind.unwrap_or("").trim() {
+        "Image" => MessageAttachmentKind::Image {},
+        _ => MessageAttachmentKind::File {},
+    }
+}
+
+fn parse_message_reaction(reaction: Option<&str>) -> Result<MessageReaction, NodeError> {
+    match reaction.unwrap_or("").trim() {
+        "ThumbsUp" => Ok(MessageReaction::ThumbsUp {}),
+        "Seen" => Ok(MessageReaction::Seen {}),
+        "Copy" => Ok(MessageReaction::Copy {}),
+        _ => Err(NodeError::InvalidConfig {}),
+    }
+}
+
+fn message_reaction_to_str(reaction: MessageReaction) -> &'static str {
+    match reaction {
+        MessageReaction::ThumbsUp {} => "ThumbsUp",
+        MessageReaction::Seen {} => "Seen",
+        MessageReaction::Copy {} => "Copy",
+    }
+}
+
+fn message_attachment_kind_to_str(kind: MessageAttachmentKind) -> &'static str {
+    match kind {
+        MessageAttachmentKind::File {} => "File",
+        MessageAttachmentKind::Image {} => "Image",
+    }
+}
+
 fn lxmf_delivery_method_to_str(method: LxmfDeliveryMethod) -> &'static str {
     match method {
         LxmfDeliveryMethod::Direct {} => "Direct",
@@ -1553,6 +2154,9 @@ fn message_state_to_str(state: MessageState) -> &'static str {
         MessageState::TimedOut {} => "TimedOut",
         MessageState::Cancelled {} => "Cancelled",
         MessageState::Received {} => "Received",
+        MessageState::Read {} => "Read",
+        MessageState::Scheduled {} => "Scheduled",
+        MessageState::Expired {} => "Expired",
     }
 }
 
@@ -1601,6 +2205,7 @@ fn projection_scope_to_str(scope: ProjectionScope) -> &'static str {
         ProjectionScope::Messages {} => "Messages",
         ProjectionScope::Telemetry {} => "Telemetry",
         ProjectionScope::Sos {} => "Sos",
+        ProjectionScope::Teams {} => "Teams",
     }
 }
 
@@ -1618,7 +2223,33 @@ fn message_record_json(message: &MessageRecord) -> Value {
         "detail": message.detail,
         "sentAtMs": message.sent_at_ms,
         "receivedAtMs": message.received_at_ms,
-        "updatedAtMs": message.updated_at_ms
+        "updatedAtMs": message.updated_at_ms,
+        "attachments": message
+            .attachments
+            .iter()
+            .map(message_attachment_json)
+            .collect::<Vec<_>>(),
+        "editedAtMs": message.edited_at_ms,
+        "deletedAtMs": message.deleted_at_ms,
+        "reactions": message
+            .reactions
+            .iter()
+            .map(|reaction| json!({
+                "reactorHex": reaction.reactor_hex,
+                "reaction": message_reaction_to_str(reaction.reaction),
+                "reactedAtMs": reaction.reacted_at_ms
+            }))
+            .collect::<Vec<_>>()
+    })
+}
+
+fn message_attachment_json(attachment: &MessageAttachmentRecord) -> Value {
+    json!({
+        "kind": message_attachment_kind_to_str(attachment.kind),
+        "fileName": attachment.file_name,
+        "mimeType": attachment.mime_type,
+        "sizeBytes": attachment.size_bytes,
+        "path": attachment.path
     })
 }
 
@@ -1730,6 +2361,9 @@ fn event_to_wire_json(event: NodeEvent) -> String {
             ("messageReceived", message_record_json(&message))
         }
         NodeEvent::MessageUpdated { message } => ("messageUpdated", message_record_json(&message)),
+        NodeEvent::GroupDeliveryUpdated { delivery } => {
+            ("groupDeliveryUpdated", group_delivery_json(&delivery))
+        }
         NodeEvent::SyncUpdated { status } => (
             "syncUpdated",
             json!({
@@ -1738,7 +2372,10 @@ fn event_to_wire_json(event: NodeEvent) -> String {
                 "requestedAtMs": status.requested_at_ms,
                 "completedAtMs": status.completed_at_ms,
                 "messagesReceived": status.messages_received,
-                "detail": status.detail
+                "detail": status.detail,
+                "lastTrigger": status.last_trigger,
+                "consecutiveFailures": status.consecutive_failures,
+                "nextSyncAtMs": status.next_sync_at_ms
             }),
         ),
         NodeEvent::HubDirectoryUpdated { snapshot } => (
@@ -1760,7 +2397,13 @@ fn event_to_wire_json(event: NodeEvent) -> String {
         ),
         NodeEvent::SosStatusChanged { status } => ("sosStatusChanged", sos_status_json(&status)),
         NodeEvent::SosAlertChanged { alert } => ("sosAlertChanged", sos_alert_json(&alert)),
-        NodeEvent::SosTelemetryRequested {} => ("sosTelemetryRequested", json!({})),
+        NodeEvent::SosDeliveryUpdated { delivery } => {
+            ("sosDeliveryUpdated", sos_delivery_json(&delivery))
+        }
+        NodeEvent::SosResponderUpdated { responder } => {
+            ("sosResponderUpdated", sos_responder_json(&responder))
+        }
+        NodeEvent::SosTelemetryRequested {} => ("sosTelemetryRequested", json!({})),
         NodeEvent::SosAudioRecordingRequested {
             incident_id,
             duration_seconds,
@@ -1771,6 +2414,12 @@ fn event_to_wire_json(event: NodeEvent) -> String {
                 "durationSeconds": duration_seconds
             }),
         ),
+        NodeEvent::EamReportRequested { roll_call } => {
+            ("eamReportRequested", eam_roll_call_json(&roll_call))
+        }
+        NodeEvent::EamRollCallUpdated { roll_call } => {
+            ("eamRollCallUpdated", eam_roll_call_json(&roll_call))
+        }
         NodeEvent::Log { level, message } => (
             "log",
             json!({
@@ -2143,6 +2792,19 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_sendLxmf
         body_utf8: payload.body_utf8,
         title: payload.title,
         send_mode: send_mode_from_input(payload.send_mode.as_deref(), payload.use_propagation_node),
+        attachments: payload
+            .attachments
+            .into_iter()
+            .map(|attachment| LxmfAttachmentRequest {
+                kind: parse_message_attachment_kind(attachment.kind.as_deref()),
+                path: attachment.path,
+                file_name: attachment.file_name,
+                mime_type: attachment.mime_type,
+            })
+            .collect(),
+        send_at_ms: payload.send_at_ms,
+        expires_at_ms: payload.expires_at_ms,
+        priority: parse_message_priority(payload.priority.as_deref()),
     }) {
         Ok(message_id_hex) => ok_json_result(&mut env, &json!({ "messageIdHex": message_id_hex })),
         Err(err) => {
@@ -2216,6 +2878,119 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_cancelLx
     }
 }
 
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_editMessageJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: MessageActionInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid edit message payload: {e}"),
+            )
+        }
+    };
+
+    let guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = match guard.node.as_ref() {
+        Some(v) => v,
+        None => return err_result("NotRunning", "node not initialized"),
+    };
+    match node.edit_message(
+        payload.message_id_hex,
+        payload.body_utf8.unwrap_or_default(),
+    ) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteMessageJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: MessageActionInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid delete message payload: {e}"),
+            )
+        }
+    };
+
+    let guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = match guard.node.as_ref() {
+        Some(v) => v,
+        None => return err_result("NotRunning", "node not initialized"),
+    };
+    match node.delete_message(payload.message_id_hex) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_reactToMessageJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: MessageActionInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", format!("invalid reaction payload: {e}")),
+    };
+
+    let guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = match guard.node.as_ref() {
+        Some(v) => v,
+        None => return err_result("NotRunning", "node not initialized"),
+    };
+    let reaction = match parse_message_reaction(payload.reaction.as_deref()) {
+        Ok(v) => v,
+        Err(_) => return err_result("InvalidConfig", "unknown reaction"),
+    };
+    match node.react_to_message(payload.message_id_hex, reaction) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
 #[no_mangle]
 pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_setActivePropagationNodeJson(
     mut env: JNIEnv,
@@ -2285,6 +3060,66 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_requestL
     }
 }
 
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_setNetworkAvailableJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: NetworkAvailabilityInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", format!("invalid network payload: {e}")),
+    };
+
+    let guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = match guard.node.as_ref() {
+        Some(v) => v,
+        None => return err_result("NotRunning", "node not initialized"),
+    };
+    match node.set_network_available(payload.available) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_listPropagationNodesJson(
+    mut env: JNIEnv,
+    _class: JClass,
+) -> jstring {
+    let guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
+    };
+    let node = match guard.node.as_ref() {
+        Some(v) => v,
+        None => {
+            set_last_error("NotRunning", "node not initialized");
+            return ptr::null_mut();
+        }
+    };
+    match node.list_propagation_nodes() {
+        Ok(nodes) => ok_json_result(&mut env, &nodes),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
 #[no_mangle]
 pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_broadcastBase64(
     mut env: JNIEnv,
@@ -2523,26 +3358,26 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_listMess
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteConversationJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_listMessagesPageJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
-) -> jint {
+) -> jstring {
     let raw = match jstring_to_rust(&mut env, request_json) {
         Ok(v) => v,
         Err(e) => {
             set_last_error("InvalidConfig", e);
-            return 1;
+            return ptr::null_mut();
         }
     };
-    let payload: ConversationDeleteInput = match serde_json::from_str(&raw) {
+    let payload: MessagePageInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
         Err(e) => {
             set_last_error(
                 "InvalidConfig",
-                format!("invalid conversation delete payload: {e}"),
+                format!("invalid message page payload: {e}"),
             );
-            return 1;
+            return ptr::null_mut();
         }
     };
 
@@ -2550,71 +3385,66 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteCo
         Ok(v) => v,
         Err(_) => {
             set_last_error("InternalError", "bridge lock poisoned");
-            return 1;
+            return ptr::null_mut();
         }
     };
     let node = ensure_node(&mut guard);
-    match node.delete_conversation(payload.conversation_id) {
-        Ok(()) => 0,
+    match node.list_messages_page(
+        payload.conversation_id,
+        payload.before_ms,
+        payload.before_message_id_hex,
+        payload.limit,
+    ) {
+        Ok(items) => ok_json_result(
+            &mut env,
+            &json!({
+                "items": items.iter().map(message_record_json).collect::<Vec<_>>()
+            }),
+        ),
         Err(err) => {
             set_last_node_error(err);
-            1
+            ptr::null_mut()
         }
     }
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getLxmfSyncStatusJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_searchMessagesJson(
     mut env: JNIEnv,
     _class: JClass,
+    request_json: JString,
 ) -> jstring {
-    let guard = match bridge_state().lock() {
+    let raw = match jstring_to_rust(&mut env, request_json) {
         Ok(v) => v,
-        Err(_) => {
-            set_last_error("InternalError", "bridge lock poisoned");
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
             return ptr::null_mut();
         }
     };
-    let node = match guard.node.as_ref() {
-        Some(v) => v,
-        None => {
-            set_last_error("NotRunning", "node not initialized");
+    let payload: MessageSearchInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error(
+                "InvalidConfig",
+                format!("invalid message search payload: {e}"),
+            );
             return ptr::null_mut();
         }
     };
-    match node.get_lxmf_sync_status() {
-        Ok(status) => ok_json_result(&mut env, &status),
-        Err(err) => {
-            set_last_node_error(err);
-            ptr::null_mut()
-        }
-    }
-}
 
-#[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_listTelemetryDestinationsJson(
-    mut env: JNIEnv,
-    _class: JClass,
-) -> jstring {
-    let guard = match bridge_state().lock() {
+    let mut guard = match bridge_state().lock() {
         Ok(v) => v,
         Err(_) => {
             set_last_error("InternalError", "bridge lock poisoned");
             return ptr::null_mut();
         }
     };
-    let node = match guard.node.as_ref() {
-        Some(v) => v,
-        None => {
-            set_last_error("NotRunning", "node not initialized");
-            return ptr::null_mut();
-        }
-    };
-    match node.list_telemetry_destinations() {
+    let node = ensure_node(&mut guard);
+    match node.search_messages(payload.query, payload.conversation_id, payload.limit) {
         Ok(items) => ok_json_result(
             &mut env,
             &json!({
-                "items": items
+                "items": items.iter().map(message_record_json).collect::<Vec<_>>()
             }),
         ),
         Err(err) => {
@@ -2625,154 +3455,138 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_listTele
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_legacyImportCompletedJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteConversationJson(
     mut env: JNIEnv,
     _class: JClass,
-) -> jstring {
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return 1;
+        }
+    };
+    let payload: ConversationDeleteInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error(
+                "InvalidConfig",
+                format!("invalid conversation delete payload: {e}"),
+            );
+            return 1;
+        }
+    };
+
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
         Err(_) => {
             set_last_error("InternalError", "bridge lock poisoned");
-            return ptr::null_mut();
+            return 1;
         }
     };
     let node = ensure_node(&mut guard);
-    match node.legacy_import_completed() {
-        Ok(completed) => ok_json_result(&mut env, &json!({ "completed": completed })),
+    match node.delete_conversation(payload.conversation_id) {
+        Ok(()) => 0,
         Err(err) => {
             set_last_node_error(err);
-            ptr::null_mut()
+            1
         }
     }
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_importLegacyStateJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_markConversationReadJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
 ) -> jint {
     let raw = match jstring_to_rust(&mut env, request_json) {
-        Ok(v) => v,
-        Err(e) => return err_result("InvalidConfig", e),
-    };
-    let payload: LegacyImportInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
         Err(e) => {
-            return err_result(
-                "InvalidConfig",
-                format!("invalid legacy import payload: {e}"),
-            )
+            set_last_error("InvalidConfig", e);
+            return 1;
         }
     };
-    let mut guard = match bridge_state().lock() {
-        Ok(v) => v,
-        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
-    };
-    let node = ensure_node(&mut guard);
-    let messages = match payload
-        .messages
-        .unwrap_or_default()
-        .into_iter()
-        .map(to_message_record)
-        .collect::<Result<Vec<_>, _>>()
-    {
+    let payload: ConversationReadInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
-        Err(err) => {
-            set_last_node_error(err);
-            return RESULT_ERR;
+        Err(e) => {
+            set_last_error(
+                "InvalidConfig",
+                format!("invalid conversation read payload: {e}"),
+            );
+            return 1;
         }
     };
-    let legacy = LegacyImportPayload {
-        settings: payload.settings.map(to_app_settings_record),
-        saved_peers: payload
-            .saved_peers
-            .unwrap_or_default()
-            .into_iter()
-            .map(to_saved_peer_record)
-            .collect(),
-        eams: payload
-            .eams
-            .unwrap_or_default()
-            .into_iter()
-            .map(to_eam_projection_record)
-            .collect(),
-        events: payload
-            .events
-            .unwrap_or_default()
-            .into_iter()
-            .map(to_event_projection_record)
-            .collect(),
-        messages,
-        telemetry_positions: payload
-            .telemetry_positions
-            .unwrap_or_default()
-            .into_iter()
-            .map(to_telemetry_position_record)
-            .collect(),
-    };
-    match node.import_legacy_state(legacy) {
-        Ok(_) => ok_result(),
-        Err(err) => {
-            set_last_node_error(err);
-            RESULT_ERR
-        }
-    }
-}
 
-#[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getAppSettingsJson(
-    mut env: JNIEnv,
-    _class: JClass,
-) -> jstring {
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
         Err(_) => {
             set_last_error("InternalError", "bridge lock poisoned");
-            return ptr::null_mut();
+            return 1;
         }
     };
     let node = ensure_node(&mut guard);
-    match node.get_app_settings() {
-        Ok(Some(settings)) => ok_json_result(&mut env, &app_settings_json(&settings)),
-        Ok(None) => ok_json_result(&mut env, &json!({ "settings": null })),
+    match node.mark_conversation_read(payload.conversation_id, payload.send_read_receipts) {
+        Ok(()) => 0,
         Err(err) => {
             set_last_node_error(err);
-            ptr::null_mut()
+            1
         }
     }
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_setAppSettingsJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_upsertGroupConversationJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
-) -> jint {
+) -> jstring {
     let raw = match jstring_to_rust(&mut env, request_json) {
         Ok(v) => v,
-        Err(e) => return err_result("InvalidConfig", e),
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return ptr::null_mut();
+        }
     };
-    let payload: AppSettingsInput = match serde_json::from_str(&raw) {
+    let payload: GroupConversationInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
-        Err(e) => return err_result("InvalidConfig", format!("invalid settings payload: {e}")),
+        Err(e) => {
+            set_last_error(
+                "InvalidConfig",
+                format!("invalid group conversation payload: {e}"),
+            );
+            return ptr::null_mut();
+        }
     };
+
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
-        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
     };
     let node = ensure_node(&mut guard);
-    match node.set_app_settings(to_app_settings_record(payload)) {
-        Ok(_) => ok_result(),
+    match node.upsert_group_conversation(GroupConversationRecord {
+        conversation_id: payload.conversation_id,
+        title: payload.title,
+        team_uid: payload.team_uid,
+        mission_uid: payload.mission_uid,
+        member_hexes: payload.member_hexes,
+        created_at_ms: 0,
+        updated_at_ms: 0,
+    }) {
+        Ok(group) => ok_json_result(&mut env, &group_conversation_json(&group)),
         Err(err) => {
             set_last_node_error(err);
-            RESULT_ERR
+            ptr::null_mut()
         }
     }
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getSavedPeersJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_listGroupConversationsJson(
     mut env: JNIEnv,
     _class: JClass,
 ) -> jstring {
@@ -2784,10 +3598,12 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getSaved
         }
     };
     let node = ensure_node(&mut guard);
-    match node.get_saved_peers() {
+    match node.list_group_conversations() {
         Ok(items) => ok_json_result(
             &mut env,
-            &json!({ "items": items.iter().map(saved_peer_json).collect::<Vec<_>>() }),
+            &json!({
+                "items": items.iter().map(group_conversation_json).collect::<Vec<_>>()
+            }),
         ),
         Err(err) => {
             set_last_node_error(err);
@@ -2797,53 +3613,91 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getSaved
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_setSavedPeersJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteGroupConversationJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
 ) -> jint {
     let raw = match jstring_to_rust(&mut env, request_json) {
         Ok(v) => v,
-        Err(e) => return err_result("InvalidConfig", e),
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return 1;
+        }
     };
-    let payload: SavedPeersPayload = match serde_json::from_str(&raw) {
+    let payload: ConversationDeleteInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
-        Err(e) => return err_result("InvalidConfig", format!("invalid saved peers payload: {e}")),
+        Err(e) => {
+            set_last_error(
+                "InvalidConfig",
+                format!("invalid group conversation delete payload: {e}"),
+            );
+            return 1;
+        }
     };
+
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
-        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return 1;
+        }
     };
     let node = ensure_node(&mut guard);
-    let peers = payload
-        .saved_peers
-        .into_iter()
-        .map(to_saved_peer_record)
-        .collect();
-    match node.set_saved_peers(peers) {
-        Ok(_) => ok_result(),
+    match node.delete_group_conversation(payload.conversation_id) {
+        Ok(()) => 0,
         Err(err) => {
             set_last_node_error(err);
-            RESULT_ERR
+            1
         }
     }
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getOperationalSummaryJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_sendGroupMessageJson(
     mut env: JNIEnv,
     _class: JClass,
+    request_json: JString,
 ) -> jstring {
-    let mut guard = match bridge_state().lock() {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return ptr::null_mut();
+        }
+    };
+    let payload: GroupMessageInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error(
+                "InvalidConfig",
+                format!("invalid group message payload: {e}"),
+            );
+            return ptr::null_mut();
+        }
+    };
+
+    let guard = match bridge_state().lock() {
         Ok(v) => v,
         Err(_) => {
             set_last_error("InternalError", "bridge lock poisoned");
             return ptr::null_mut();
         }
     };
-    let node = ensure_node(&mut guard);
-    match node.get_operational_summary() {
-        Ok(summary) => ok_json_result(&mut env, &operational_summary_json(&summary)),
+    let node = match guard.node.as_ref() {
+        Some(v) => v,
+        None => {
+            set_last_error("NotRunning", "node not initialized");
+            return ptr::null_mut();
+        }
+    };
+    match node.send_group_message(
+        payload.conversation_id,
+        payload.body_utf8,
+        payload.title,
+        parse_message_priority(payload.priority.as_deref()),
+    ) {
+        Ok(message_id_hex) => ok_json_result(&mut env, &json!({ "messageIdHex": message_id_hex })),
         Err(err) => {
             set_last_node_error(err);
             ptr::null_mut()
@@ -2852,7 +3706,7 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getOpera
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getChecklistsJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_listGroupDeliveriesJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
@@ -2864,16 +3718,17 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getCheck
             return ptr::null_mut();
         }
     };
-    let payload: ChecklistListInput = match serde_json::from_str(&raw) {
+    let payload: MessageIdInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
         Err(e) => {
             set_last_error(
                 "InvalidConfig",
-                format!("invalid checklist list payload: {e}"),
+                format!("invalid group delivery list payload: {e}"),
             );
             return ptr::null_mut();
         }
     };
+
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
         Err(_) => {
@@ -2882,13 +3737,12 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getCheck
         }
     };
     let node = ensure_node(&mut guard);
-    match node.list_active_checklists(Some(ChecklistListActiveRequest {
-        search: payload.search,
-        sort_by: payload.sort_by,
-    })) {
+    match node.list_group_deliveries(payload.message_id_hex) {
         Ok(items) => ok_json_result(
             &mut env,
-            &json!({ "items": items.iter().map(checklist_record_json).collect::<Vec<_>>() }),
+            &json!({
+                "items": items.iter().map(group_delivery_json).collect::<Vec<_>>()
+            }),
         ),
         Err(err) => {
             set_last_node_error(err);
@@ -2898,39 +3752,54 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getCheck
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getChecklistJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getLxmfSyncStatusJson(
     mut env: JNIEnv,
     _class: JClass,
-    request_json: JString,
 ) -> jstring {
-    let raw = match jstring_to_rust(&mut env, request_json) {
+    let guard = match bridge_state().lock() {
         Ok(v) => v,
-        Err(e) => {
-            set_last_error("InvalidConfig", e);
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
             return ptr::null_mut();
         }
     };
-    let payload: ChecklistDeleteInput = match serde_json::from_str(&raw) {
-        Ok(v) => v,
-        Err(e) => {
-            set_last_error(
-                "InvalidConfig",
-                format!("invalid checklist get payload: {e}"),
-            );
+    let node = match guard.node.as_ref() {
+        Some(v) => v,
+        None => {
+            set_last_error("NotRunning", "node not initialized");
             return ptr::null_mut();
         }
     };
-    let mut guard = match bridge_state().lock() {
+    match node.get_lxmf_sync_status() {
+        Ok(status) => ok_json_result(&mut env, &status),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getSendQueueMetricsJson(
+    mut env: JNIEnv,
+    _class: JClass,
+) -> jstring {
+    let guard = match bridge_state().lock() {
         Ok(v) => v,
         Err(_) => {
             set_last_error("InternalError", "bridge lock poisoned");
             return ptr::null_mut();
         }
     };
-    let node = ensure_node(&mut guard);
-    match node.get_checklist(payload.checklist_uid) {
-        Ok(Some(record)) => ok_json_result(&mut env, &checklist_record_json(&record)),
-        Ok(None) => ok_json_result(&mut env, &json!({ "checklist": null })),
+    let node = match guard.node.as_ref() {
+        Some(v) => v,
+        None => {
+            set_last_error("NotRunning", "node not initialized");
+            return ptr::null_mut();
+        }
+    };
+    match node.get_send_queue_metrics() {
+        Ok(metrics) => ok_json_result(&mut env, &metrics),
         Err(err) => {
             set_last_node_error(err);
             ptr::null_mut()
@@ -2939,28 +3808,823 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getCheck
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getChecklistTemplatesJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_listTelemetryDestinationsJson(
     mut env: JNIEnv,
     _class: JClass,
-    request_json: JString,
 ) -> jstring {
-    let raw = match jstring_to_rust(&mut env, request_json) {
+    let guard = match bridge_state().lock() {
         Ok(v) => v,
-        Err(e) => {
-            set_last_error("InvalidConfig", e);
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
             return ptr::null_mut();
         }
     };
-    let payload: ChecklistListInput = match serde_json::from_str(&raw) {
-        Ok(v) => v,
-        Err(e) => {
-            set_last_error(
-                "InvalidConfig",
-                format!("invalid checklist template list payload: {e}"),
-            );
+    let node = match guard.node.as_ref() {
+        Some(v) => v,
+        None => {
+            set_last_error("NotRunning", "node not initialized");
             return ptr::null_mut();
         }
-    };
+    };
+    match node.list_telemetry_destinations() {
+        Ok(items) => ok_json_result(
+            &mut env,
+            &json!({
+                "items": items
+            }),
+        ),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_legacyImportCompletedJson(
+    mut env: JNIEnv,
+    _class: JClass,
+) -> jstring {
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
+    };
+    let node = ensure_node(&mut guard);
+    match node.legacy_import_completed() {
+        Ok(completed) => ok_json_result(&mut env, &json!({ "completed": completed })),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_importLegacyStateJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: LegacyImportInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid legacy import payload: {e}"),
+            )
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = ensure_node(&mut guard);
+    let messages = match payload
+        .messages
+        .unwrap_or_default()
+        .into_iter()
+        .map(to_message_record)
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(v) => v,
+        Err(err) => {
+            set_last_node_error(err);
+            return RESULT_ERR;
+        }
+    };
+    let legacy = LegacyImportPayload {
+        settings: payload.settings.map(to_app_settings_record),
+        saved_peers: payload
+            .saved_peers
+            .unwrap_or_default()
+            .into_iter()
+            .map(to_saved_peer_record)
+            .collect(),
+        eams: payload
+            .eams
+            .unwrap_or_default()
+            .into_iter()
+            .map(to_eam_projection_record)
+            .collect(),
+        events: payload
+            .events
+            .unwrap_or_default()
+            .into_iter()
+            .map(to_event_projection_record)
+            .collect(),
+        messages,
+        telemetry_positions: payload
+            .telemetry_positions
+            .unwrap_or_default()
+            .into_iter()
+            .map(to_telemetry_position_record)
+            .collect(),
+    };
+    match node.import_legacy_state(legacy) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getAppSettingsJson(
+    mut env: JNIEnv,
+    _class: JClass,
+) -> jstring {
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
+    };
+    let node = ensure_node(&mut guard);
+    match node.get_app_settings() {
+        Ok(Some(settings)) => ok_json_result(&mut env, &app_settings_json(&settings)),
+        Ok(None) => ok_json_result(&mut env, &json!({ "settings": null })),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_setAppSettingsJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: AppSettingsInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", format!("invalid settings payload: {e}")),
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = ensure_node(&mut guard);
+    match node.set_app_settings(to_app_settings_record(payload)) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getSavedPeersJson(
+    mut env: JNIEnv,
+    _class: JClass,
+) -> jstring {
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
+    };
+    let node = ensure_node(&mut guard);
+    match node.get_saved_peers() {
+        Ok(items) => ok_json_result(
+            &mut env,
+            &json!({ "items": items.iter().map(saved_peer_json).collect::<Vec<_>>() }),
+        ),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_setSavedPeersJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: SavedPeersPayload = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", format!("invalid saved peers payload: {e}")),
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = ensure_node(&mut guard);
+    let peers = payload
+        .saved_peers
+        .into_iter()
+        .map(to_saved_peer_record)
+        .collect();
+    match node.set_saved_peers(peers) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getOperationalSummaryJson(
+    mut env: JNIEnv,
+    _class: JClass,
+) -> jstring {
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
+    };
+    let node = ensure_node(&mut guard);
+    match node.get_operational_summary() {
+        Ok(summary) => ok_json_result(&mut env, &operational_summary_json(&summary)),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getChecklistsJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jstring {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return ptr::null_mut();
+        }
+    };
+    let payload: ChecklistListInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error(
+                "InvalidConfig",
+                format!("invalid checklist list payload: {e}"),
+            );
+            return ptr::null_mut();
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
+    };
+    let node = ensure_node(&mut guard);
+    match node.list_active_checklists(Some(ChecklistListActiveRequest {
+        search: payload.search,
+        sort_by: payload.sort_by,
+    })) {
+        Ok(items) => ok_json_result(
+            &mut env,
+            &json!({ "items": items.iter().map(checklist_record_json).collect::<Vec<_>>() }),
+        ),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getChecklistJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jstring {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return ptr::null_mut();
+        }
+    };
+    let payload: ChecklistDeleteInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error(
+                "InvalidConfig",
+                format!("invalid checklist get payload: {e}"),
+            );
+            return ptr::null_mut();
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
+    };
+    let node = ensure_node(&mut guard);
+    match node.get_checklist(payload.checklist_uid) {
+        Ok(Some(record)) => ok_json_result(&mut env, &checklist_record_json(&record)),
+        Ok(None) => ok_json_result(&mut env, &json!({ "checklist": null })),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getChecklistTemplatesJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jstring {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return ptr::null_mut();
+        }
+    };
+    let payload: ChecklistListInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error(
+                "InvalidConfig",
+                format!("invalid checklist template list payload: {e}"),
+            );
+            return ptr::null_mut();
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
+    };
+    let node = ensure_node(&mut guard);
+    match node.list_checklist_templates(Some(ChecklistTemplateListRequest {
+        search: payload.search,
+        sort_by: payload.sort_by,
+    })) {
+        Ok(items) => ok_json_result(
+            &mut env,
+            &json!({ "items": items.iter().map(checklist_template_json).collect::<Vec<_>>() }),
+        ),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_importChecklistTemplateCsvJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jstring {
+    let err_result = |code: &str, message: String| {
+        set_last_error(code, message);
+        ptr::null_mut()
+    };
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: ChecklistTemplateImportInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid checklist template import payload: {e}"),
+            )
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned".to_string()),
+    };
+    let node = ensure_node(&mut guard);
+    match node.import_checklist_template_csv(to_checklist_template_import_request(payload)) {
+        Ok(template) => ok_json_result(&mut env, &checklist_template_json(&template)),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_createChecklistFromTemplateJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let err_result = |code: &str, message: String| {
+        set_last_error(code, message);
+        RESULT_ERR
+    };
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: ChecklistCreateInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid checklist create-from-template payload: {e}"),
+            )
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned".to_string()),
+    };
+    let node = ensure_node(&mut guard);
+    match node.create_checklist_from_template(ChecklistCreateFromTemplateRequest {
+        checklist_uid: payload.checklist_uid,
+        mission_uid: payload.mission_uid,
+        template_uid: payload.template_uid,
+        name: payload.name,
+        description: payload.description,
+        start_time: payload.start_time,
+        created_by_team_member_rns_identity: payload.created_by_team_member_rns_identity,
+        created_by_team_member_display_name: payload.created_by_team_member_display_name,
+    }) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_createOnlineChecklistJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: ChecklistCreateInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid checklist create payload: {e}"),
+            )
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = ensure_node(&mut guard);
+    match node.create_online_checklist(to_checklist_create_request(payload)) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_updateChecklistJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: ChecklistUpdateInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid checklist update payload: {e}"),
+            )
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = ensure_node(&mut guard);
+    match node.update_checklist(to_checklist_update_request(payload)) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteChecklistJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: ChecklistDeleteInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid checklist delete payload: {e}"),
+            )
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = ensure_node(&mut guard);
+    match node.delete_checklist(ChecklistDeleteRequest {
+        checklist_uid: payload.checklist_uid,
+        delete_remote: payload.delete_remote,
+    }) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_joinChecklistJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: ChecklistUidInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid checklist join payload: {e}"),
+            )
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = ensure_node(&mut guard);
+    match node.join_checklist(payload.checklist_uid) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_uploadChecklistJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: ChecklistUidInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid checklist upload payload: {e}"),
+            )
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = ensure_node(&mut guard);
+    match node.upload_checklist(payload.checklist_uid) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_setChecklistTaskStatusJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: ChecklistTaskStatusInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid checklist task status payload: {e}"),
+            )
+        }
+    };
+    let request = match to_checklist_task_status_request(payload) {
+        Ok(v) => v,
+        Err(err) => return err_result("InvalidConfig", err.to_string()),
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = ensure_node(&mut guard);
+    match node.set_checklist_task_status(request) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_addChecklistTaskRowJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: ChecklistTaskRowAddInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid checklist task row add payload: {e}"),
+            )
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = ensure_node(&mut guard);
+    match node.add_checklist_task_row(to_checklist_task_row_add_request(payload)) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteChecklistTaskRowJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: ChecklistTaskRowDeleteInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid checklist task row delete payload: {e}"),
+            )
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = ensure_node(&mut guard);
+    match node.delete_checklist_task_row(to_checklist_task_row_delete_request(payload)) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_setChecklistTaskRowStyleJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: ChecklistTaskRowStyleInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid checklist task row style payload: {e}"),
+            )
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = ensure_node(&mut guard);
+    match node.set_checklist_task_row_style(to_checklist_task_row_style_request(payload)) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_setChecklistTaskCellJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: ChecklistTaskCellInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid checklist task cell payload: {e}"),
+            )
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = ensure_node(&mut guard);
+    match node.set_checklist_task_cell(to_checklist_task_cell_request(payload)) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getEamsJson(
+    mut env: JNIEnv,
+    _class: JClass,
+) -> jstring {
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
         Err(_) => {
@@ -2969,13 +4633,10 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getCheck
         }
     };
     let node = ensure_node(&mut guard);
-    match node.list_checklist_templates(Some(ChecklistTemplateListRequest {
-        search: payload.search,
-        sort_by: payload.sort_by,
-    })) {
+    match node.get_eams() {
         Ok(items) => ok_json_result(
             &mut env,
-            &json!({ "items": items.iter().map(checklist_template_json).collect::<Vec<_>>() }),
+            &json!({ "items": items.iter().map(eam_projection_json).collect::<Vec<_>>() }),
         ),
         Err(err) => {
             set_last_node_error(err);
@@ -2985,80 +4646,56 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getCheck
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_importChecklistTemplateCsvJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_upsertEamJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
-) -> jstring {
-    let err_result = |code: &str, message: String| {
-        set_last_error(code, message);
-        ptr::null_mut()
-    };
+) -> jint {
     let raw = match jstring_to_rust(&mut env, request_json) {
         Ok(v) => v,
         Err(e) => return err_result("InvalidConfig", e),
     };
-    let payload: ChecklistTemplateImportInput = match serde_json::from_str(&raw) {
+    let payload: EamProjectionInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
-        Err(e) => {
-            return err_result(
-                "InvalidConfig",
-                format!("invalid checklist template import payload: {e}"),
-            )
-        }
+        Err(e) => return err_result("InvalidConfig", format!("invalid eam payload: {e}")),
     };
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
-        Err(_) => return err_result("InternalError", "bridge lock poisoned".to_string()),
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
     };
     let node = ensure_node(&mut guard);
-    match node.import_checklist_template_csv(to_checklist_template_import_request(payload)) {
-        Ok(template) => ok_json_result(&mut env, &checklist_template_json(&template)),
+    match node.upsert_eam(to_eam_projection_record(payload)) {
+        Ok(_) => ok_result(),
         Err(err) => {
             set_last_node_error(err);
-            ptr::null_mut()
+            RESULT_ERR
         }
     }
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_createChecklistFromTemplateJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteEamJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
 ) -> jint {
-    let err_result = |code: &str, message: String| {
-        set_last_error(code, message);
-        RESULT_ERR
-    };
     let raw = match jstring_to_rust(&mut env, request_json) {
         Ok(v) => v,
         Err(e) => return err_result("InvalidConfig", e),
     };
-    let payload: ChecklistCreateInput = match serde_json::from_str(&raw) {
+    let payload: DeleteEamInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
-        Err(e) => {
-            return err_result(
-                "InvalidConfig",
-                format!("invalid checklist create-from-template payload: {e}"),
-            )
-        }
+        Err(e) => return err_result("InvalidConfig", format!("invalid eam delete payload: {e}")),
     };
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
-        Err(_) => return err_result("InternalError", "bridge lock poisoned".to_string()),
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
     };
     let node = ensure_node(&mut guard);
-    match node.create_checklist_from_template(ChecklistCreateFromTemplateRequest {
-        checklist_uid: payload.checklist_uid,
-        mission_uid: payload.mission_uid,
-        template_uid: payload.template_uid,
-        name: payload.name,
-        description: payload.description,
-        start_time: payload.start_time,
-        created_by_team_member_rns_identity: payload.created_by_team_member_rns_identity,
-        created_by_team_member_display_name: payload.created_by_team_member_display_name,
-    }) {
+    match node.delete_eam(
+        payload.callsign,
+        payload.deleted_at_ms.unwrap_or_else(crate::runtime::now_ms),
+    ) {
         Ok(_) => ok_result(),
         Err(err) => {
             set_last_node_error(err);
@@ -3068,73 +4705,94 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_createCh
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_createOnlineChecklistJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getEamHistoryJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
-) -> jint {
+) -> jstring {
     let raw = match jstring_to_rust(&mut env, request_json) {
         Ok(v) => v,
-        Err(e) => return err_result("InvalidConfig", e),
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return ptr::null_mut();
+        }
     };
-    let payload: ChecklistCreateInput = match serde_json::from_str(&raw) {
+    let payload: CallsignInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
         Err(e) => {
-            return err_result(
-                "InvalidConfig",
-                format!("invalid checklist create payload: {e}"),
-            )
+            set_last_error("InvalidConfig", format!("invalid eam history payload: {e}"));
+            return ptr::null_mut();
         }
     };
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
-        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
     };
     let node = ensure_node(&mut guard);
-    match node.create_online_checklist(to_checklist_create_request(payload)) {
-        Ok(_) => ok_result(),
+    match node.get_eam_history(payload.callsign) {
+        Ok(history) => ok_json_result(
+            &mut env,
+            &history.iter().map(eam_revision_json).collect::<Vec<_>>(),
+        ),
         Err(err) => {
             set_last_node_error(err);
-            RESULT_ERR
+            ptr::null_mut()
         }
     }
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_updateChecklistJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_listEamSchemasJson(
     mut env: JNIEnv,
     _class: JClass,
-    request_json: JString,
-) -> jint {
-    let raw = match jstring_to_rust(&mut env, request_json) {
-        Ok(v) => v,
-        Err(e) => return err_result("InvalidConfig", e),
-    };
-    let payload: ChecklistUpdateInput = match serde_json::from_str(&raw) {
+) -> jstring {
+    let mut guard = match bridge_state().lock() {
         Ok(v) => v,
-        Err(e) => {
-            return err_result(
-                "InvalidConfig",
-                format!("invalid checklist update payload: {e}"),
-            )
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
         }
     };
+    let node = ensure_node(&mut guard);
+    match node.list_eam_schemas() {
+        Ok(items) => ok_json_result(
+            &mut env,
+            &json!({ "items": items.iter().map(eam_schema_json).collect::<Vec<_>>() }),
+        ),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getActiveEamSchemaJson(
+    mut env: JNIEnv,
+    _class: JClass,
+) -> jstring {
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
-        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
     };
     let node = ensure_node(&mut guard);
-    match node.update_checklist(to_checklist_update_request(payload)) {
-        Ok(_) => ok_result(),
+    match node.get_active_eam_schema() {
+        Ok(schema) => ok_json_result(&mut env, &eam_schema_json(&schema)),
         Err(err) => {
             set_last_node_error(err);
-            RESULT_ERR
+            ptr::null_mut()
         }
     }
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteChecklistJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_upsertEamSchemaJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
@@ -3143,24 +4801,16 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteCh
         Ok(v) => v,
         Err(e) => return err_result("InvalidConfig", e),
     };
-    let payload: ChecklistDeleteInput = match serde_json::from_str(&raw) {
+    let payload: EamSchemaInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
-        Err(e) => {
-            return err_result(
-                "InvalidConfig",
-                format!("invalid checklist delete payload: {e}"),
-            )
-        }
+        Err(e) => return err_result("InvalidConfig", format!("invalid eam schema payload: {e}")),
     };
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
         Err(_) => return err_result("InternalError", "bridge lock poisoned"),
     };
     let node = ensure_node(&mut guard);
-    match node.delete_checklist(ChecklistDeleteRequest {
-        checklist_uid: payload.checklist_uid,
-        delete_remote: payload.delete_remote,
-    }) {
+    match node.upsert_eam_schema(to_eam_schema_record(payload)) {
         Ok(_) => ok_result(),
         Err(err) => {
             set_last_node_error(err);
@@ -3170,7 +4820,7 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteCh
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_joinChecklistJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_setActiveEamSchemaJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
@@ -3179,21 +4829,16 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_joinChec
         Ok(v) => v,
         Err(e) => return err_result("InvalidConfig", e),
     };
-    let payload: ChecklistUidInput = match serde_json::from_str(&raw) {
+    let payload: SchemaUidInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
-        Err(e) => {
-            return err_result(
-                "InvalidConfig",
-                format!("invalid checklist join payload: {e}"),
-            )
-        }
+        Err(e) => return err_result("InvalidConfig", format!("invalid eam schema payload: {e}")),
     };
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
         Err(_) => return err_result("InternalError", "bridge lock poisoned"),
     };
     let node = ensure_node(&mut guard);
-    match node.join_checklist(payload.checklist_uid) {
+    match node.set_active_eam_schema(payload.schema_uid) {
         Ok(_) => ok_result(),
         Err(err) => {
             set_last_node_error(err);
@@ -3203,7 +4848,7 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_joinChec
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_uploadChecklistJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_publishEamSchemaJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
@@ -3212,21 +4857,16 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_uploadCh
         Ok(v) => v,
         Err(e) => return err_result("InvalidConfig", e),
     };
-    let payload: ChecklistUidInput = match serde_json::from_str(&raw) {
+    let payload: SchemaUidInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
-        Err(e) => {
-            return err_result(
-                "InvalidConfig",
-                format!("invalid checklist upload payload: {e}"),
-            )
-        }
+        Err(e) => return err_result("InvalidConfig", format!("invalid eam schema payload: {e}")),
     };
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
         Err(_) => return err_result("InternalError", "bridge lock poisoned"),
     };
     let node = ensure_node(&mut guard);
-    match node.upload_checklist(payload.checklist_uid) {
+    match node.publish_eam_schema(payload.schema_uid) {
         Ok(_) => ok_result(),
         Err(err) => {
             set_last_node_error(err);
@@ -3236,44 +4876,51 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_uploadCh
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_setChecklistTaskStatusJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_requestEamRollCallJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
-) -> jint {
+) -> jstring {
     let raw = match jstring_to_rust(&mut env, request_json) {
         Ok(v) => v,
-        Err(e) => return err_result("InvalidConfig", e),
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return ptr::null_mut();
+        }
     };
-    let payload: ChecklistTaskStatusInput = match serde_json::from_str(&raw) {
+    let payload: EamRollCallInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
         Err(e) => {
-            return err_result(
+            set_last_error(
                 "InvalidConfig",
-                format!("invalid checklist task status payload: {e}"),
-            )
+                format!("invalid eam roll call payload: {e}"),
+            );
+            return ptr::null_mut();
         }
     };
-    let request = match to_checklist_task_status_request(payload) {
-        Ok(v) => v,
-        Err(err) => return err_result("InvalidConfig", err.to_string()),
-    };
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
-        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
     };
     let node = ensure_node(&mut guard);
-    match node.set_checklist_task_status(request) {
-        Ok(_) => ok_result(),
+    match node.request_eam_roll_call(crate::types::EamRollCallRequest {
+        team_uid: payload.team_uid,
+        team_member_uids: payload.team_member_uids,
+        due_in_seconds: payload.due_in_seconds,
+    }) {
+        Ok(roll_call) => ok_json_result(&mut env, &eam_roll_call_json(&roll_call)),
         Err(err) => {
             set_last_node_error(err);
-            RESULT_ERR
+            ptr::null_mut()
         }
     }
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_addChecklistTaskRowJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_respondToEamRollCallJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
@@ -3282,12 +4929,12 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_addCheck
         Ok(v) => v,
         Err(e) => return err_result("InvalidConfig", e),
     };
-    let payload: ChecklistTaskRowAddInput = match serde_json::from_str(&raw) {
+    let payload: EamRollCallResponseInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
         Err(e) => {
             return err_result(
                 "InvalidConfig",
-                format!("invalid checklist task row add payload: {e}"),
+                format!("invalid eam roll call response payload: {e}"),
             )
         }
     };
@@ -3296,7 +4943,10 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_addCheck
         Err(_) => return err_result("InternalError", "bridge lock poisoned"),
     };
     let node = ensure_node(&mut guard);
-    match node.add_checklist_task_row(to_checklist_task_row_add_request(payload)) {
+    match node.respond_to_eam_roll_call(
+        payload.roll_call_uid,
+        to_eam_projection_record(payload.record),
+    ) {
         Ok(_) => ok_result(),
         Err(err) => {
             set_last_node_error(err);
@@ -3306,106 +4956,113 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_addCheck
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteChecklistTaskRowJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getEamRollCallJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
-) -> jint {
+) -> jstring {
     let raw = match jstring_to_rust(&mut env, request_json) {
         Ok(v) => v,
-        Err(e) => return err_result("InvalidConfig", e),
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return ptr::null_mut();
+        }
     };
-    let payload: ChecklistTaskRowDeleteInput = match serde_json::from_str(&raw) {
+    let payload: RollCallUidInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
         Err(e) => {
-            return err_result(
+            set_last_error(
                 "InvalidConfig",
-                format!("invalid checklist task row delete payload: {e}"),
-            )
+                format!("invalid eam roll call payload: {e}"),
+            );
+            return ptr::null_mut();
         }
     };
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
-        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
     };
     let node = ensure_node(&mut guard);
-    match node.delete_checklist_task_row(to_checklist_task_row_delete_request(payload)) {
-        Ok(_) => ok_result(),
+    match node.get_eam_roll_call(payload.roll_call_uid) {
+        Ok(roll_call) => ok_json_result(&mut env, &roll_call.as_ref().map(eam_roll_call_json)),
         Err(err) => {
             set_last_node_error(err);
-            RESULT_ERR
+            ptr::null_mut()
         }
     }
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_setChecklistTaskRowStyleJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_listEamRollCallsJson(
     mut env: JNIEnv,
     _class: JClass,
-    request_json: JString,
-) -> jint {
-    let raw = match jstring_to_rust(&mut env, request_json) {
-        Ok(v) => v,
-        Err(e) => return err_result("InvalidConfig", e),
-    };
-    let payload: ChecklistTaskRowStyleInput = match serde_json::from_str(&raw) {
-        Ok(v) => v,
-        Err(e) => {
-            return err_result(
-                "InvalidConfig",
-                format!("invalid checklist task row style payload: {e}"),
-            )
-        }
-    };
+) -> jstring {
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
-        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
     };
     let node = ensure_node(&mut guard);
-    match node.set_checklist_task_row_style(to_checklist_task_row_style_request(payload)) {
-        Ok(_) => ok_result(),
+    match node.list_eam_roll_calls() {
+        Ok(items) => ok_json_result(
+            &mut env,
+            &json!({ "items": items.iter().map(eam_roll_call_json).collect::<Vec<_>>() }),
+        ),
         Err(err) => {
             set_last_node_error(err);
-            RESULT_ERR
+            ptr::null_mut()
         }
     }
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_setChecklistTaskCellJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getEamTeamSummaryJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
-) -> jint {
+) -> jstring {
     let raw = match jstring_to_rust(&mut env, request_json) {
         Ok(v) => v,
-        Err(e) => return err_result("InvalidConfig", e),
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return ptr::null_mut();
+        }
     };
-    let payload: ChecklistTaskCellInput = match serde_json::from_str(&raw) {
+    let payload: TeamUidInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
         Err(e) => {
-            return err_result(
+            set_last_error(
                 "InvalidConfig",
-                format!("invalid checklist task cell payload: {e}"),
-            )
+                format!("invalid eam team summary payload: {e}"),
+            );
+            return ptr::null_mut();
         }
     };
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
-        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
     };
     let node = ensure_node(&mut guard);
-    match node.set_checklist_task_cell(to_checklist_task_cell_request(payload)) {
-        Ok(_) => ok_result(),
+    match node.get_eam_team_summary(payload.team_uid) {
+        Ok(Some(summary)) => ok_json_result(&mut env, &eam_team_summary_json(&summary)),
+        Ok(None) => ok_json_result(&mut env, &json!({ "summary": null })),
         Err(err) => {
             set_last_node_error(err);
-            RESULT_ERR
+            ptr::null_mut()
         }
     }
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getEamsJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_listTeamsJson(
     mut env: JNIEnv,
     _class: JClass,
 ) -> jstring {
@@ -3417,10 +5074,10 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getEamsJ
         }
     };
     let node = ensure_node(&mut guard);
-    match node.get_eams() {
+    match node.list_teams() {
         Ok(items) => ok_json_result(
             &mut env,
-            &json!({ "items": items.iter().map(eam_projection_json).collect::<Vec<_>>() }),
+            &json!({ "items": items.iter().map(team_json).collect::<Vec<_>>() }),
         ),
         Err(err) => {
             set_last_node_error(err);
@@ -3430,7 +5087,7 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getEamsJ
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_upsertEamJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_upsertTeamJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
@@ -3439,16 +5096,16 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_upsertEa
         Ok(v) => v,
         Err(e) => return err_result("InvalidConfig", e),
     };
-    let payload: EamProjectionInput = match serde_json::from_str(&raw) {
+    let payload: TeamInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
-        Err(e) => return err_result("InvalidConfig", format!("invalid eam payload: {e}")),
+        Err(e) => return err_result("InvalidConfig", format!("invalid team payload: {e}")),
     };
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
         Err(_) => return err_result("InternalError", "bridge lock poisoned"),
     };
     let node = ensure_node(&mut guard);
-    match node.upsert_eam(to_eam_projection_record(payload)) {
+    match node.upsert_team(to_team_record(payload)) {
         Ok(_) => ok_result(),
         Err(err) => {
             set_last_node_error(err);
@@ -3458,7 +5115,7 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_upsertEa
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteEamJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteTeamJson(
     mut env: JNIEnv,
     _class: JClass,
     request_json: JString,
@@ -3467,17 +5124,17 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteEa
         Ok(v) => v,
         Err(e) => return err_result("InvalidConfig", e),
     };
-    let payload: DeleteEamInput = match serde_json::from_str(&raw) {
+    let payload: DeleteTeamInput = match serde_json::from_str(&raw) {
         Ok(v) => v,
-        Err(e) => return err_result("InvalidConfig", format!("invalid eam delete payload: {e}")),
+        Err(e) => return err_result("InvalidConfig", format!("invalid team delete payload: {e}")),
     };
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
         Err(_) => return err_result("InternalError", "bridge lock poisoned"),
     };
     let node = ensure_node(&mut guard);
-    match node.delete_eam(
-        payload.callsign,
+    match node.delete_team(
+        payload.team_uid,
         payload.deleted_at_ms.unwrap_or_else(crate::runtime::now_ms),
     ) {
         Ok(_) => ok_result(),
@@ -3489,28 +5146,10 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_deleteEa
 }
 
 #[no_mangle]
-pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getEamTeamSummaryJson(
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getTeamRollupsJson(
     mut env: JNIEnv,
     _class: JClass,
-    request_json: JString,
 ) -> jstring {
-    let raw = match jstring_to_rust(&mut env, request_json) {
-        Ok(v) => v,
-        Err(e) => {
-            set_last_error("InvalidConfig", e);
-            return ptr::null_mut();
-        }
-    };
-    let payload: TeamUidInput = match serde_json::from_str(&raw) {
-        Ok(v) => v,
-        Err(e) => {
-            set_last_error(
-                "InvalidConfig",
-                format!("invalid eam team summary payload: {e}"),
-            );
-            return ptr::null_mut();
-        }
-    };
     let mut guard = match bridge_state().lock() {
         Ok(v) => v,
         Err(_) => {
@@ -3519,9 +5158,11 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getEamTe
         }
     };
     let node = ensure_node(&mut guard);
-    match node.get_eam_team_summary(payload.team_uid) {
-        Ok(Some(summary)) => ok_json_result(&mut env, &eam_team_summary_json(&summary)),
-        Ok(None) => ok_json_result(&mut env, &json!({ "summary": null })),
+    match node.get_team_rollups() {
+        Ok(items) => ok_json_result(
+            &mut env,
+            &json!({ "items": items.iter().map(team_rollup_json).collect::<Vec<_>>() }),
+        ),
         Err(err) => {
             set_last_node_error(err);
             ptr::null_mut()
@@ -3643,6 +5284,99 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getTelem
     }
 }
 
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getTelemetryTrackJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jstring {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return ptr::null_mut();
+        }
+    };
+    let payload: TelemetryTrackInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error(
+                "InvalidConfig",
+                format!("invalid telemetry track payload: {e}"),
+            );
+            return ptr::null_mut();
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
+    };
+    let node = ensure_node(&mut guard);
+    match node.get_telemetry_track(payload.callsign, payload.since_ms, payload.limit) {
+        Ok(items) => ok_json_result(
+            &mut env,
+            &json!({ "items": items.iter().map(telemetry_position_json).collect::<Vec<_>>() }),
+        ),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_exportTelemetryTrackJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jstring {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return ptr::null_mut();
+        }
+    };
+    let payload: TelemetryTrackInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error(
+                "InvalidConfig",
+                format!("invalid telemetry track export payload: {e}"),
+            );
+            return ptr::null_mut();
+        }
+    };
+    let format = telemetry_track_format_from_input(payload.format.as_deref());
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
+    };
+    let node = ensure_node(&mut guard);
+    match node.export_telemetry_track(payload.callsign, payload.since_ms, payload.limit, format) {
+        Ok(content) => ok_json_result(
+            &mut env,
+            &json!({
+                "format": match format {
+                    TelemetryTrackFormat::Gpx {} => "Gpx",
+                    TelemetryTrackFormat::GeoJson {} => "GeoJson",
+                },
+                "content": content,
+            }),
+        ),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
 #[no_mangle]
 pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_recordLocalTelemetryFixJson(
     mut env: JNIEnv,
@@ -4006,6 +5740,140 @@ pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_submitSo
     }
 }
 
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_getSosDeliveryReportJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jstring {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return ptr::null_mut();
+        }
+    };
+    let payload: SosIncidentInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error(
+                "InvalidConfig",
+                format!("invalid sos delivery report payload: {e}"),
+            );
+            return ptr::null_mut();
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
+    };
+    let node = ensure_node(&mut guard);
+    match node.get_sos_delivery_report(payload.incident_id) {
+        Ok(report) => ok_json_result(
+            &mut env,
+            &json!({
+                "incidentId": report.incident_id,
+                "queued": report.queued,
+                "sent": report.sent,
+                "delivered": report.delivered,
+                "failed": report.failed,
+                "recipients": report.recipients.iter().map(sos_delivery_json).collect::<Vec<_>>()
+            }),
+        ),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_respondToSosJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jint {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => return err_result("InvalidConfig", e),
+    };
+    let payload: SosRespondInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            return err_result(
+                "InvalidConfig",
+                format!("invalid SOS response payload: {e}"),
+            )
+        }
+    };
+    let Some(kind) = parse_sos_response_kind(payload.kind.as_str()) else {
+        return err_result(
+            "InvalidConfig",
+            format!("unsupported SOS response kind: {}", payload.kind),
+        );
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => return err_result("InternalError", "bridge lock poisoned"),
+    };
+    let node = ensure_node(&mut guard);
+    match node.respond_to_sos(payload.incident_id, kind, payload.eta_seconds) {
+        Ok(_) => ok_result(),
+        Err(err) => {
+            set_last_node_error(err);
+            RESULT_ERR
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_listSosRespondersJson(
+    mut env: JNIEnv,
+    _class: JClass,
+    request_json: JString,
+) -> jstring {
+    let raw = match jstring_to_rust(&mut env, request_json) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error("InvalidConfig", e);
+            return ptr::null_mut();
+        }
+    };
+    let payload: SosIncidentInput = match serde_json::from_str(&raw) {
+        Ok(v) => v,
+        Err(e) => {
+            set_last_error(
+                "InvalidConfig",
+                format!("invalid sos responders payload: {e}"),
+            );
+            return ptr::null_mut();
+        }
+    };
+    let mut guard = match bridge_state().lock() {
+        Ok(v) => v,
+        Err(_) => {
+            set_last_error("InternalError", "bridge lock poisoned");
+            return ptr::null_mut();
+        }
+    };
+    let node = ensure_node(&mut guard);
+    match node.list_sos_responders(payload.incident_id) {
+        Ok(responders) => ok_json_result(
+            &mut env,
+            &json!({
+                "items": responders.iter().map(sos_responder_json).collect::<Vec<_>>()
+            }),
+        ),
+        Err(err) => {
+            set_last_node_error(err);
+            ptr::null_mut()
+        }
+    }
+}
+
 #[no_mangle]
 pub extern "system" fn Java_network_reticulum_emergency_ReticulumBridge_listSosAlertsJson(
     mut env: JNIEnv,
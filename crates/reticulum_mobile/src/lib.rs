@@ -1,17 +1,34 @@
 mod announce_compat;
 mod app_state;
+mod attachment_fields;
+mod eam_freshness;
+mod eam_history;
+mod eam_roll_call;
+mod eam_schema;
 mod event_bus;
+mod group_chat;
+mod hub_client;
 mod jni_bridge;
 mod logger;
 mod lxmf_fields;
+mod message_action_fields;
+mod message_expiry;
 mod messaging_compat;
 mod mission_sync;
 mod node;
+mod outbound_queue;
+mod propagation_health;
+mod propagation_sync;
+mod read_receipt_fields;
 mod runtime;
 mod sdk_bridge;
+mod send_priority;
 mod sos;
 mod sos_detector;
 mod sos_fields;
+mod team_registry;
+mod telemetry_publisher;
+mod telemetry_track;
 mod types;
 
 pub use node::{EventSubscription, Node};
@@ -25,16 +42,25 @@ pub use types::{
     ChecklistTaskRowDeleteRequest, ChecklistTaskRowStyleSetRequest, ChecklistTaskStatus,
     ChecklistTaskStatusSetRequest, ChecklistTemplateImportCsvRequest, ChecklistTemplateListRequest,
     ChecklistTemplateRecord, ChecklistUpdatePatch, ChecklistUpdateRequest, ChecklistUserTaskStatus,
-    ConversationRecord, EamProjectionRecord, EamSourceRecord, EamTeamSummaryRecord,
-    EventProjectionRecord, HubDirectoryPeerRecord, HubDirectorySnapshot, HubMode,
-    HubSettingsRecord, LegacyImportPayload, LogLevel, LxmfDeliveryMethod,
+    ConversationRecord, EamFieldChange, EamOverallStatusRule, EamProjectionRecord,
+    EamRevisionRecord, EamRollCallDirection, EamRollCallMemberRecord, EamRollCallMemberState,
+    EamRollCallRecord, EamRollCallRequest, EamSchemaRecord, EamSourceRecord,
+    EamStatusDimensionRecord, EamStatusEntry, EamStatusTotal, EamStatusValueRecord,
+    EamTeamSummaryRecord, EventProjectionRecord, GroupConversationRecord,
+    GroupMemberDeliveryRecord, HubDirectoryPeerRecord, HubDirectorySnapshot, HubMode,
+    HubSettingsRecord, LegacyImportPayload, LogLevel, LxmfAttachmentRequest, LxmfDeliveryMethod,
     LxmfDeliveryRepresentation, LxmfDeliveryStatus, LxmfDeliveryUpdate, LxmfFallbackStage,
-    MessageDirection, MessageMethod, MessageRecord, MessageState, NodeConfig, NodeError, NodeEvent,
-    NodeStatus, OperationalNotice, OperationalSummary, PeerChange, PeerRecord, PeerState,
-    ProjectionInvalidation, ProjectionScope, SavedPeerRecord, SendLxmfRequest, SendMode,
-    SendOutcome, SosAlertRecord, SosAudioRecord, SosDeviceTelemetryRecord, SosLocationRecord,
-    SosMessageKind, SosSettingsRecord, SosState, SosStatusRecord, SosTriggerSource, SyncPhase,
-    SyncStatus, TelemetryPositionRecord, TelemetrySettingsRecord,
+    MessageAttachmentKind, MessageAttachmentRecord, MessageDirection, MessageMethod,
+    MessagePriority, MessageReaction, MessageReactionRecord, MessageRecord, MessageState,
+    NodeConfig, NodeError, NodeEvent, NodeStatus, OperationalNotice, OperationalSummary,
+    PeerChange, PeerRecord, PeerState, ProjectionInvalidation, ProjectionScope,
+    PropagationNodeRecord, PropagationSyncSettingsRecord, SavedPeerRecord, SendLxmfRequest,
+    SendMode, SendOutcome, SendQueueClassMetrics, SosAlertRecord, SosAudioRecord,
+    SosDeliveryRecord, SosDeliveryReport, SosDeliveryState, SosDeviceTelemetryRecord,
+    SosLocationRecord, SosMessageKind, SosRecipientPolicyRecord, SosResponderRecord,
+    SosResponseKind, SosSettingsRecord, SosState, SosStatusRecord, SosTriggerSource, SyncPhase,
+    SyncStatus, TeamLevel, TeamMemberRecord, TeamMemberRole, TeamRecord, TeamRollupRecord,
+    TelemetryPositionRecord, TelemetrySettingsRecord, TelemetryTrackFormat,
 };
 
 pub fn healthcheck() -> String {
@@ -1,3 +1,7 @@
+use rmpv::Value as MsgPackValue;
+
+use crate::types::NodeError;
+
 // Shared LXMF field IDs used by REM mobile.
 //
 // `FIELD_COMMANDS` remains `0x09` in this workspace for:
@@ -7,6 +11,146 @@
 //
 // The parser boundary between those payload families is defined by their inner
 // envelope keys, not by different numeric field IDs.
+pub(crate) const FIELD_FILE_ATTACHMENTS: i64 = 0x05;
+pub(crate) const FIELD_IMAGE: i64 = 0x06;
 pub(crate) const FIELD_COMMANDS: i64 = 0x09;
 pub(crate) const FIELD_RESULTS: i64 = 0x0A;
 pub(crate) const FIELD_EVENT: i64 = 0x0D;
+
+// Keeps only the final path component and characters that are safe on every
+// host filesystem, so a peer cannot steer where the file lands.
+pub(crate) fn attachment_file_name(raw: &str) -> String {
+    let base = raw.rsplit(['/', '\\']).next().unwrap_or_default();
+    let sanitized = base
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    let trimmed = sanitized.trim_start_matches('.');
+    if trimmed.is_empty() {
+        "attachment".to_string()
+    } else {
+        trimmed.chars().take(128).collect()
+    }
+}
+
+// Chat envelopes (read receipts, message actions, group copies) ride in the
+// command field as a map told apart by its tag key. Clients that do not know
+// the tag ignore the field and show only the body, which is empty for the
+// envelopes that are pure signals.
+pub(crate) fn build_command_envelope(
+    entries: Vec<(MsgPackValue, MsgPackValue)>,
+) -> Result<Vec<u8>, NodeError> {
+    let fields = MsgPackValue::Map(vec![(
+        MsgPackValue::from(FIELD_COMMANDS),
+        MsgPackValue::Array(vec![MsgPackValue::Map(entries)]),
+    )]);
+    rmp_serde::to_vec(&fields).map_err(|_| NodeError::InternalError {})
+}
+
+// The entries of the first command envelope that carries `tag`.
+pub(crate) fn parse_command_envelope(
+    fields_bytes: &[u8],
+    tag: &str,
+) -> Option<Vec<(MsgPackValue, MsgPackValue)>> {
+    let fields = rmp_serde::from_slice::<MsgPackValue>(fields_bytes).ok()?;
+    let commands = msgpack_get_indexed(msgpack_map_entries(&fields)?, FIELD_COMMANDS)?;
+    let tagged = |value: &MsgPackValue| {
+        msgpack_map_entries(value)
+            .filter(|entries| msgpack_get_named(entries, &[tag]).is_some())
+            .map(<[_]>::to_vec)
+    };
+    match commands {
+        MsgPackValue::Array(items) => items.iter().find_map(tagged),
+        other => tagged(other),
+    }
+}
+
+// LXMF message ids are 32-byte hashes. Only those are shared with peers;
+// locally generated ids never leave the device.
+pub(crate) fn normalize_lxmf_message_id(value: &str) -> Option<String> {
+    let normalized = value.trim().to_ascii_lowercase();
+    (normalized.len() == 64 && normalized.chars().all(|ch| ch.is_ascii_hexdigit()))
+        .then_some(normalized)
+}
+
+pub(crate) fn msgpack_map_entries(value: &MsgPackValue) -> Option<&[(MsgPackValue, MsgPackValue)]> {
+    match value {
+        MsgPackValue::Map(entries) => Some(entries.as_slice()),
+        _ => None,
+    }
+}
+
+pub(crate) fn msgpack_get_indexed<'a>(
+    entries: &'a [(MsgPackValue, MsgPackValue)],
+    key: i64,
+) -> Option<&'a MsgPackValue> {
+    let key_string = key.to_string();
+    for (entry_key, entry_value) in entries {
+        match entry_key {
+            MsgPackValue::Integer(value) if value.as_i64() == Some(key) => {
+                return Some(entry_value)
+            }
+            MsgPackValue::String(value) if value.as_str() == Some(key_string.as_str()) => {
+                return Some(entry_value)
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+pub(crate) fn msgpack_get_named<'a>(
+    entries: &'a [(MsgPackValue, MsgPackValue)],
+    keys: &[&str],
+) -> Option<&'a MsgPackValue> {
+    for wanted in keys {
+        for (entry_key, entry_value) in entries {
+            if matches!(entry_key, MsgPackValue::String(actual) if actual.as_str() == Some(*wanted))
+            {
+                return Some(entry_value);
+            }
+        }
+    }
+    None
+}
+
+pub(crate) fn msgpack_string(value: &MsgPackValue) -> Option<String> {
+    match value {
+        MsgPackValue::String(value) => value.as_str().map(str::to_string),
+        MsgPackValue::Binary(value) => String::from_utf8(value.clone()).ok(),
+        _ => None,
+    }
+}
+
+pub(crate) fn msgpack_bool(value: &MsgPackValue) -> Option<bool> {
+    match value {
+        MsgPackValue::Boolean(value) => Some(*value),
+        _ => None,
+    }
+}
+
+pub(crate) fn msgpack_f64(value: &MsgPackValue) -> Option<f64> {
+    match value {
+        MsgPackValue::F32(value) => Some(f64::from(*value)),
+        MsgPackValue::F64(value) => Some(*value),
+        MsgPackValue::Integer(value) => value.as_i64().map(|entry| entry as f64),
+        _ => None,
+    }
+}
+
+pub(crate) fn msgpack_u64(value: &MsgPackValue) -> Option<u64> {
+    match value {
+        MsgPackValue::Integer(value) => value.as_u64().or_else(|| {
+            value
+                .as_i64()
+                .and_then(|entry| (entry >= 0).then_some(entry as u64))
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,322 @@
+use rmpv::Value as MsgPackValue;
+
+use crate::lxmf_fields::{
+    build_command_envelope, msgpack_get_named, msgpack_u64, normalize_lxmf_message_id,
+    parse_command_envelope,
+};
+use crate::types::{MessageReaction, MessageReactionRecord, MessageRecord, NodeError};
+
+// Edits carry the full replacement text, so a correction stays bounded the
+// same way an ordinary chat body is.
+const MESSAGE_ACTION_MAX_BODY_BYTES: usize = 16 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum MessageActionKind {
+    Edit { body_utf8: String },
+    Delete,
+    React { reaction: MessageReaction },
+}
+
+// Edits, deletions and reactions point at an earlier message by its LXMF id
+// and are tagged `message_action`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MessageAction {
+    pub(crate) target_message_id: String,
+    pub(crate) kind: MessageActionKind,
+    pub(crate) acted_at_ms: u64,
+}
+
+pub(crate) fn build_message_action_fields(action: &MessageAction) -> Result<Vec<u8>, NodeError> {
+    let target_message_id = normalize_lxmf_message_id(action.target_message_id.as_str())
+        .ok_or(NodeError::InvalidConfig {})?;
+    let mut envelope = vec![
+        (
+            MsgPackValue::from("message_action"),
+            MsgPackValue::from(action_name(&action.kind)),
+        ),
+        (
+            MsgPackValue::from("target_message_id"),
+            MsgPackValue::from(target_message_id),
+        ),
+        (
+            MsgPackValue::from("acted_at_ms"),
+            MsgPackValue::from(action.acted_at_ms),
+        ),
+    ];
+    match &action.kind {
+        MessageActionKind::Edit { body_utf8 } => {
+            if body_utf8.trim().is_empty() || body_utf8.len() > MESSAGE_ACTION_MAX_BODY_BYTES {
+                return Err(NodeError::InvalidConfig {});
+            }
+            envelope.push((
+                MsgPackValue::from("body"),
+                MsgPackValue::from(body_utf8.as_str()),
+            ));
+        }
+        MessageActionKind::Delete => {}
+        MessageActionKind::React { reaction } => envelope.push((
+            MsgPackValue::from("reaction"),
+            MsgPackValue::from(reaction_name(*reaction)),
+        )),
+    }
+    build_command_envelope(envelope)
+}
+
+pub(crate) fn parse_message_action_fields(fields_bytes: &[u8]) -> Option<MessageAction> {
+    let envelope = parse_command_envelope(fields_bytes, "message_action")?;
+    let named = |key: &str| msgpack_get_named(&envelope, &[key]);
+    let kind = match named("message_action")?.as_str()? {
+        "edit" => {
+            let body_utf8 = named("body")?.as_str()?;
+            if body_utf8.trim().is_empty() || body_utf8.len() > MESSAGE_ACTION_MAX_BODY_BYTES {
+                return None;
+            }
+            MessageActionKind::Edit {
+                body_utf8: body_utf8.to_string(),
+            }
+        }
+        "delete" => MessageActionKind::Delete,
+        "react" => MessageActionKind::React {
+            reaction: parse_reaction(named("reaction")?.as_str()?)?,
+        },
+        _ => return None,
+    };
+    Some(MessageAction {
+        target_message_id: normalize_lxmf_message_id(named("target_message_id")?.as_str()?)?,
+        kind,
+        acted_at_ms: named("acted_at_ms").and_then(msgpack_u64).unwrap_or(0),
+    })
+}
+
+// Applies an action from `actor_hex` to the stored record. Only the author may
+// edit or delete, a deleted message stays a tombstone, and an edit older than
+// the one already applied is ignored. Returns whether the record changed.
+pub(crate) fn apply_message_action(
+    record: &mut MessageRecord,
+    action: &MessageAction,
+    actor_hex: &str,
+) -> bool {
+    if record.deleted_at_ms.is_some() {
+        return false;
+    }
+    let is_author = record
+        .source_hex
+        .as_deref()
+        .is_some_and(|source_hex| source_hex.eq_ignore_ascii_case(actor_hex));
+    match &action.kind {
+        MessageActionKind::Edit { body_utf8 } => {
+            if !is_author
+                || record
+                    .edited_at_ms
+                    .is_some_and(|edited_at_ms| edited_at_ms >= action.acted_at_ms)
+                || record.body_utf8 == *body_utf8
+            {
+                return false;
+            }
+            record.body_utf8 = body_utf8.clone();
+            record.edited_at_ms = Some(action.acted_at_ms);
+        }
+        MessageActionKind::Delete => {
+            if !is_author {
+                return false;
+            }
+            record.title = None;
+            record.body_utf8 = String::new();
+            record.attachments.clear();
+            record.reactions.clear();
+            record.deleted_at_ms = Some(action.acted_at_ms);
+        }
+        MessageActionKind::React { reaction } => {
+            if record.reactions.iter().any(|existing| {
+                existing.reaction == *reaction
+                    && existing.reactor_hex.eq_ignore_ascii_case(actor_hex)
+            }) {
+                return false;
+            }
+            record.reactions.push(MessageReactionRecord {
+                reactor_hex: actor_hex.to_ascii_lowercase(),
+                reaction: *reaction,
+                reacted_at_ms: action.acted_at_ms,
+            });
+        }
+    }
+    record.updated_at_ms = record.updated_at_ms.max(action.acted_at_ms);
+    true
+}
+
+// Folds the edits, deletion and reactions already on the stored copy into a
+// new write of the same message, so a write built from an older copy (a
+// delivery update, a retry) cannot roll them back.
+pub(crate) fn carry_message_actions(record: &mut MessageRecord, stored: &MessageRecord) {
+    if let Some(deleted_at_ms) = stored.deleted_at_ms {
+        record.title = None;
+        record.body_utf8 = String::new();
+        record.attachments.clear();
+        record.reactions.clear();
+        record.deleted_at_ms = Some(deleted_at_ms);
+        return;
+    }
+    if record.deleted_at_ms.is_some() {
+        return;
+    }
+    if stored.edited_at_ms > record.edited_at_ms {
+        record.body_utf8 = stored.body_utf8.clone();
+        record.edited_at_ms = stored.edited_at_ms;
+    }
+    for reaction in &stored.reactions {
+        if !record.reactions.iter().any(|existing| {
+            existing.reaction == reaction.reaction
+                && existing
+                    .reactor_hex
+                    .eq_ignore_ascii_case(reaction.reactor_hex.as_str())
+        }) {
+            record.reactions.push(reaction.clone());
+        }
+    }
+}
+
+fn action_name(kind: &MessageActionKind) -> &'static str {
+    match kind {
+        MessageActionKind::Edit { .. } => "edit",
+        MessageActionKind::Delete => "delete",
+        MessageActionKind::React { .. } => "react",
+    }
+}
+
+fn reaction_name(reaction: MessageReaction) -> &'static str {
+    match reaction {
+        MessageReaction::ThumbsUp {} => "thumbs_up",
+        MessageReaction::Seen {} => "seen",
+        MessageReaction::Copy {} => "copy",
+    }
+}
+
+fn parse_reaction(value: &str) -> Option<MessageReaction> {
+    match value.trim() {
+        "thumbs_up" => Some(MessageReaction::ThumbsUp {}),
+        "seen" => Some(MessageReaction::Seen {}),
+        "copy" => Some(MessageReaction::Copy {}),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MessageDirection, MessageMethod, MessageState};
+
+    fn received(source_hex: &str) -> MessageRecord {
+        MessageRecord {
+            message_id_hex: "ab".repeat(32),
+            conversation_id: source_hex.to_string(),
+            direction: MessageDirection::Inbound {},
+            destination_hex: source_hex.to_string(),
+            source_hex: Some(source_hex.to_string()),
+            title: None,
+            body_utf8: "grid 38SMB 4488 1234".to_string(),
+            method: MessageMethod::Direct {},
+            state: MessageState::Received {},
+            detail: None,
+            sent_at_ms: None,
+            received_at_ms: Some(10),
+            updated_at_ms: 10,
+            attachments: Vec::new(),
+            edited_at_ms: None,
+            deleted_at_ms: None,
+            reactions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn actions_round_trip_through_the_command_field() {
+        let action = MessageAction {
+            target_message_id: "AB".repeat(32),
+            kind: MessageActionKind::Edit {
+                body_utf8: "grid 38SMB 4488 1243".to_string(),
+            },
+            acted_at_ms: 20,
+        };
+
+        let fields = build_message_action_fields(&action).expect("fields");
+        let parsed = parse_message_action_fields(fields.as_slice()).expect("action");
+
+        assert_eq!(parsed.target_message_id, "ab".repeat(32));
+        assert_eq!(parsed.kind, action.kind);
+        assert!(crate::read_receipt_fields::parse_read_receipt_fields(fields.as_slice()).is_none());
+        assert!(build_message_action_fields(&MessageAction {
+            target_message_id: "grp-1".to_string(),
+            kind: MessageActionKind::Delete,
+            acted_at_ms: 20,
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn only_the_author_edits_and_deletes_leave_a_tombstone() {
+        let author = "cd".repeat(16);
+        let mut record = received(author.as_str());
+        let edit = |body: &str, acted_at_ms| MessageAction {
+            target_message_id: record_id(),
+            kind: MessageActionKind::Edit {
+                body_utf8: body.to_string(),
+            },
+            acted_at_ms,
+        };
+
+        assert!(!apply_message_action(
+            &mut record,
+            &edit("spoofed", 20),
+            "ef".repeat(16).as_str()
+        ));
+        assert!(apply_message_action(
+            &mut record,
+            &edit("grid 38SMB 4488 1243", 20),
+            author.as_str()
+        ));
+        assert!(!apply_message_action(
+            &mut record,
+            &edit("stale", 15),
+            author.as_str()
+        ));
+        assert_eq!(record.body_utf8, "grid 38SMB 4488 1243");
+        assert_eq!(record.edited_at_ms, Some(20));
+
+        let thumbs_up = MessageAction {
+            target_message_id: record_id(),
+            kind: MessageActionKind::React {
+                reaction: MessageReaction::ThumbsUp {},
+            },
+            acted_at_ms: 25,
+        };
+        assert!(apply_message_action(
+            &mut record,
+            &thumbs_up,
+            "ef".repeat(16).as_str()
+        ));
+        assert!(!apply_message_action(
+            &mut record,
+            &thumbs_up,
+            "EF".repeat(16).as_str()
+        ));
+        assert_eq!(record.reactions.len(), 1);
+
+        let delete = MessageAction {
+            target_message_id: record_id(),
+            kind: MessageActionKind::Delete,
+            acted_at_ms: 30,
+        };
+        assert!(apply_message_action(&mut record, &delete, author.as_str()));
+        assert_eq!(record.deleted_at_ms, Some(30));
+        assert!(record.body_utf8.is_empty());
+        assert!(record.reactions.is_empty());
+        assert!(!apply_message_action(
+            &mut record,
+            &edit("revived", 40),
+            author.as_str()
+        ));
+    }
+
+    fn record_id() -> String {
+        "ab".repeat(32)
+    }
+}
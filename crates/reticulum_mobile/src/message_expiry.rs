@@ -0,0 +1,94 @@
+use rmpv::Value as MsgPackValue;
+
+use crate::lxmf_fields::{msgpack_get_named, msgpack_u64, parse_command_envelope, FIELD_COMMANDS};
+use crate::types::NodeError;
+
+// Expiring messages carry their deadline under `expires_at_ms` in the command
+// field, so a copy that sat on a propagation node is dropped on arrival rather
+// than shown hours late.
+pub(crate) fn with_expiry_field(
+    fields_bytes: Option<Vec<u8>>,
+    expires_at_ms: Option<u64>,
+) -> Result<Option<Vec<u8>>, NodeError> {
+    let Some(expires_at_ms) = expires_at_ms else {
+        return Ok(fields_bytes);
+    };
+    let mut entries = match fields_bytes {
+        Some(bytes) => match rmp_serde::from_slice::<MsgPackValue>(bytes.as_slice()) {
+            Ok(MsgPackValue::Map(entries)) => entries,
+            _ => return Err(NodeError::InvalidConfig {}),
+        },
+        None => Vec::new(),
+    };
+    let envelope = MsgPackValue::Map(vec![(
+        MsgPackValue::from("expires_at_ms"),
+        MsgPackValue::from(expires_at_ms),
+    )]);
+    match entries.iter_mut().find(|(key, _)| is_commands_key(key)) {
+        Some((_, MsgPackValue::Array(items))) => items.push(envelope),
+        Some((_, other)) => {
+            let existing = std::mem::replace(other, MsgPackValue::Nil);
+            *other = MsgPackValue::Array(vec![existing, envelope]);
+        }
+        None => entries.push((
+            MsgPackValue::from(FIELD_COMMANDS),
+            MsgPackValue::Array(vec![envelope]),
+        )),
+    }
+    rmp_serde::to_vec(&MsgPackValue::Map(entries))
+        .map(Some)
+        .map_err(|_| NodeError::InternalError {})
+}
+
+pub(crate) fn parse_expiry_field(fields_bytes: &[u8]) -> Option<u64> {
+    let envelope = parse_command_envelope(fields_bytes, "expires_at_ms")?;
+    msgpack_get_named(&envelope, &["expires_at_ms"]).and_then(msgpack_u64)
+}
+
+pub(crate) fn is_expired(expires_at_ms: Option<u64>, now_ms: u64) -> bool {
+    expires_at_ms.is_some_and(|expires_at_ms| now_ms >= expires_at_ms)
+}
+
+fn is_commands_key(key: &MsgPackValue) -> bool {
+    matches!(key, MsgPackValue::Integer(key) if key.as_i64() == Some(FIELD_COMMANDS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attachment_fields::{build_attachment_fields, AttachmentPayload};
+    use crate::types::MessageAttachmentKind;
+
+    #[test]
+    fn expiry_rides_alongside_existing_fields() {
+        let attachments = build_attachment_fields(&[AttachmentPayload {
+            kind: MessageAttachmentKind::File {},
+            file_name: "orders.txt".to_string(),
+            mime_type: Some("text/plain".to_string()),
+            bytes: b"hold position".to_vec(),
+        }])
+        .expect("attachment fields");
+
+        let fields = with_expiry_field(attachments, Some(1_700_000_060_000))
+            .expect("fields")
+            .expect("expiry fields");
+
+        assert_eq!(
+            parse_expiry_field(fields.as_slice()),
+            Some(1_700_000_060_000)
+        );
+        assert_eq!(
+            crate::attachment_fields::parse_attachment_fields(fields.as_slice()).len(),
+            1
+        );
+        assert!(crate::mission_sync::parse_mission_sync_metadata(fields.as_slice()).is_none());
+        assert_eq!(with_expiry_field(None, None).expect("no expiry"), None);
+    }
+
+    #[test]
+    fn deadline_is_inclusive() {
+        assert!(is_expired(Some(10), 10));
+        assert!(!is_expired(Some(10), 9));
+        assert!(!is_expired(None, u64::MAX));
+    }
+}
@@ -38,6 +38,9 @@ pub enum MessageState {
     TimedOut,
     Cancelled,
     Received,
+    Read,
+    Scheduled,
+    Expired,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -140,6 +143,43 @@ pub struct MessageRecord {
     pub sent_at_ms: Option<u64>,
     pub received_at_ms: Option<u64>,
     pub updated_at_ms: u64,
+    #[serde(default)]
+    pub attachments: Vec<MessageAttachment>,
+    #[serde(default)]
+    pub edited_at_ms: Option<u64>,
+    #[serde(default)]
+    pub deleted_at_ms: Option<u64>,
+    #[serde(default)]
+    pub reactions: Vec<MessageReaction>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReactionKind {
+    ThumbsUp,
+    Seen,
+    Copy,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageReaction {
+    pub reactor_hex: String,
+    pub reaction: ReactionKind,
+    pub reacted_at_ms: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AttachmentKind {
+    File,
+    Image,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageAttachment {
+    pub kind: AttachmentKind,
+    pub file_name: String,
+    pub mime_type: Option<String>,
+    pub size_bytes: u64,
+    pub path: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -150,6 +190,12 @@ pub struct SyncStatus {
     pub completed_at_ms: Option<u64>,
     pub messages_received: u32,
     pub detail: Option<String>,
+    #[serde(default)]
+    pub last_trigger: Option<String>,
+    #[serde(default)]
+    pub consecutive_failures: u32,
+    #[serde(default)]
+    pub next_sync_at_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -161,6 +207,10 @@ pub struct SendMessageRequest {
     pub send_mode: SendMode,
     #[serde(default)]
     pub use_propagation_node: bool,
+    #[serde(default)]
+    pub attachments: Vec<MessageAttachment>,
+    #[serde(default)]
+    pub expires_at_ms: Option<u64>,
 }
 
 impl SendMessageRequest {
@@ -208,6 +258,9 @@ impl Default for SyncStatus {
             completed_at_ms: None,
             messages_received: 0,
             detail: None,
+            last_trigger: None,
+            consecutive_failures: 0,
+            next_sync_at_ms: None,
         }
     }
 }
@@ -609,6 +662,13 @@ impl MessagingStore {
         is_new
     }
 
+    pub fn remove_message(&mut self, message_id_hex: &str) -> Option<MessageRecord> {
+        let removed = self.message_records.remove(message_id_hex)?;
+        self.message_order.retain(|id| id != message_id_hex);
+        self.outbound_messages.remove(message_id_hex);
+        Some(removed)
+    }
+
     pub fn update_message(
         &mut self,
         message_id_hex: &str,
@@ -617,6 +677,10 @@ impl MessagingStore {
         updated_at_ms: u64,
     ) -> Option<MessageRecord> {
         let record = self.message_records.get_mut(message_id_hex)?;
+        // A read receipt is final; a late transport receipt must not undo it.
+        if record.state == MessageState::Read && state != MessageState::Read {
+            return Some(record.clone());
+        }
         record.state = state;
         record.detail = detail;
         record.updated_at_ms = updated_at_ms;
@@ -1064,6 +1128,10 @@ mod tests {
             sent_at_ms: Some(30),
             received_at_ms: None,
             updated_at_ms: now,
+            attachments: Vec::new(),
+            edited_at_ms: None,
+            deleted_at_ms: None,
+            reactions: Vec::new(),
         });
 
         let conversations = store.list_conversations();
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -11,37 +11,74 @@ use tokio::runtime::{Builder as RuntimeBuilder, Runtime};
 use tokio::sync::mpsc;
 
 use crate::app_state::{canonicalize_chat_message, AppStateStore, ConversationPeerResolver};
+use crate::eam_roll_call::{
+    pending_member, EamRollCallCommand, DEFAULT_EAM_ROLL_CALL_DUE_SECONDS,
+    EAM_ROLL_CALL_COMMAND_TYPE,
+};
+use crate::eam_schema::{
+    built_in_eam_schema, derive_eam_overall_status, validate_eam_record, EAM_SCHEMA_COMMAND_TYPE,
+};
 use crate::event_bus::EventBus;
+use crate::group_chat::{
+    is_group_conversation_id, new_group_message_id, normalize_group_conversation,
+};
+use crate::hub_client::{HubHttpClient, HubPush};
 use crate::logger::NodeLogger;
 use crate::lxmf_fields::FIELD_COMMANDS;
+use crate::message_action_fields::MessageActionKind;
 use crate::messaging_compat as sdkmsg;
-use crate::runtime::{load_or_create_identity, now_ms, run_node, Command};
+use crate::read_receipt_fields::{build_read_receipt_fields, ReadReceipt};
+use crate::runtime::{
+    load_or_create_identity, node_error_code, now_ms, run_node, Command, SendBytesReceipt,
+};
 use crate::sos::{
-    active_status, compose_sos_body, countdown_status, default_sos_settings, idle_status,
-    new_incident_id, normalize_sos_settings, set_pin, verify_pin,
+    active_status, compose_sos_body, compose_sos_response_body, countdown_status,
+    default_sos_settings, idle_status, new_incident_id, normalize_sos_settings,
+    responder_from_response, set_pin, sos_audio_file_name, sos_auto_answer_due,
+    sos_delivery_report, sos_delivery_retry_due, sos_retry_send_mode, sos_update_due,
+    updated_status, validate_sos_audio, verify_pin,
 };
 use crate::sos_detector::SosTriggerDetector;
-use crate::sos_fields::{build_sos_fields, SosCommand};
+use crate::sos_fields::{
+    build_sos_audio_fields, build_sos_fields, build_sos_response_fields, SosAudioClip, SosCommand,
+    SosResponse,
+};
+use crate::team_registry::{
+    team_for_member, team_subtree_uids, validate_team, TEAM_UID_BLUE, TEAM_UID_BROWN,
+    TEAM_UID_CYAN, TEAM_UID_DARK_BLUE, TEAM_UID_DARK_GREEN, TEAM_UID_GREEN, TEAM_UID_MAGENTA,
+    TEAM_UID_MAROON, TEAM_UID_ORANGE, TEAM_UID_PURPLE, TEAM_UID_RED, TEAM_UID_TEAL,
+    TEAM_UID_YELLOW, TEAM_UPSERT_COMMAND_TYPE,
+};
+use crate::telemetry_publisher::{TelemetryPublishPolicy, TelemetryPublisherState};
+use crate::telemetry_track::export_telemetry_track;
 use crate::types::{
     AnnounceRecord, AppSettingsRecord, ChecklistCreateFromTemplateRequest,
     ChecklistCreateOnlineRequest, ChecklistDeleteRequest, ChecklistListActiveRequest,
     ChecklistRecord, ChecklistTaskCellSetRequest, ChecklistTaskRowAddRequest,
     ChecklistTaskRowDeleteRequest, ChecklistTaskRowStyleSetRequest, ChecklistTaskStatusSetRequest,
     ChecklistTemplateImportCsvRequest, ChecklistTemplateListRequest, ChecklistTemplateRecord,
-    ChecklistUpdateRequest, ConversationRecord, EamProjectionRecord, EamSourceRecord,
-    EamTeamSummaryRecord, EventProjectionRecord, HubDirectorySnapshot, HubMode,
-    LegacyImportPayload, LogLevel, MessageDirection, MessageMethod, MessageRecord, MessageState,
-    NodeConfig, NodeError, NodeEvent, NodeStatus, OperationalSummary, PeerRecord, PeerState,
-    ProjectionInvalidation, ProjectionScope, SavedPeerRecord, SendLxmfRequest, SendMode,
-    SosAlertRecord, SosAudioRecord, SosDeviceTelemetryRecord, SosLocationRecord, SosMessageKind,
-    SosSettingsRecord, SosState, SosStatusRecord, SosTriggerSource, SyncStatus,
-    TelemetryPositionRecord,
+    ChecklistUpdateRequest, ConversationRecord, EamProjectionRecord, EamRevisionRecord,
+    EamRollCallDirection, EamRollCallMemberState, EamRollCallRecord, EamRollCallRequest,
+    EamSchemaRecord, EamSourceRecord, EamTeamSummaryRecord, EventProjectionRecord,
+    GroupConversationRecord, GroupMemberDeliveryRecord, HubDirectorySnapshot, HubMode,
+    LegacyImportPayload, LogLevel, MessageDirection, MessageMethod, MessagePriority,
+    MessageReaction, MessageRecord, MessageState, NodeConfig, NodeError, NodeEvent, NodeStatus,
+    OperationalNotice, OperationalSummary, PeerRecord, PeerState, ProjectionInvalidation,
+    ProjectionScope, PropagationNodeRecord, SavedPeerRecord, SendLxmfRequest, SendMode,
+    SendQueueClassMetrics, SosAlertRecord, SosAudioRecord, SosDeliveryRecord, SosDeliveryReport,
+    SosDeliveryState, SosDeviceTelemetryRecord, SosLocationRecord, SosMessageKind,
+    SosRecipientPolicyRecord, SosResponderRecord, SosResponseKind, SosSettingsRecord, SosState,
+    SosStatusRecord, SosTriggerSource, SyncStatus, TeamRecord, TeamRollupRecord,
+    TelemetryPositionRecord, TelemetryTrackFormat,
 };
 
 const APP_DESTINATION_NAME: (&str, &str) = ("r3akt", "emergency");
 const LXMF_DELIVERY_NAME: (&str, &str) = ("lxmf", "delivery");
 const SEND_COMMAND_TIMEOUT: Duration = Duration::from_secs(120);
 const COMMAND_QUEUE_CAPACITY: usize = 256;
+const TELEMETRY_PUBLISHER_TICK: Duration = Duration::from_secs(1);
+const SOS_SCHEDULER_TICK: Duration = Duration::from_secs(5);
+const COMMAND_REPLY_POLL_INTERVAL: Duration = Duration::from_millis(100);
 fn dispatch_command(tx: &mpsc::Sender<Command>, command: Command) -> Result<(), NodeError> {
     if tokio::runtime::Handle::try_current().is_ok() {
         return tx.try_send(command).map_err(|error| match error {
@@ -54,6 +91,24 @@ fn dispatch_command(tx: &mpsc::Sender<Command>, command: Command) -> Result<(),
         .map_err(|_| NodeError::NotRunning {})
 }
 
+// Command replies arrive on a blocking channel. Polling it from a task keeps a
+// send that is slow to resolve from holding a thread for the whole timeout.
+async fn await_command_reply<T>(
+    resp_rx: cb::Receiver<Result<T, NodeError>>,
+    timeout: Duration,
+) -> Result<T, NodeError> {
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        match resp_rx.try_recv() {
+            Ok(result) => return result,
+            Err(cb::TryRecvError::Empty) if tokio::time::Instant::now() < deadline => {
+                tokio::time::sleep(COMMAND_REPLY_POLL_INTERVAL).await;
+            }
+            Err(_) => return Err(NodeError::Timeout {}),
+        }
+    }
+}
+
 fn build_node_runtime() -> Result<Runtime, NodeError> {
     RuntimeBuilder::new_multi_thread()
         .enable_io()
@@ -82,7 +137,9 @@ struct NodeInner {
     hub_directory_snapshot: Arc<Mutex<Option<HubDirectorySnapshot>>>,
     sos_device_telemetry: Arc<Mutex<Option<SosDeviceTelemetryRecord>>>,
     sos_detector: Arc<Mutex<SosTriggerDetector>>,
+    telemetry_publisher: Arc<Mutex<TelemetryPublisherState>>,
     active_config: Option<NodeConfigFingerprint>,
+    hub_client: Option<HubHttpClient>,
     runtime: Option<Runtime>,
     cmd_tx: Option<mpsc::Sender<Command>>,
 }
@@ -147,60 +204,7 @@ fn emit_projection_invalidation(bus: &EventBus, invalidation: ProjectionInvalida
     bus.emit(NodeEvent::ProjectionInvalidated { invalidation });
 }
 
-fn trimmed_non_empty(value: Option<&str>) -> Option<String> {
-    value
-        .map(str::trim)
-        .filter(|value| !value.is_empty())
-        .map(str::to_string)
-}
-
-fn conversation_peer_resolver(peers: &[PeerRecord]) -> ConversationPeerResolver {
-    let mut resolver = ConversationPeerResolver::default();
-    for peer in peers {
-        let destination_hex = match trimmed_non_empty(Some(peer.destination_hex.as_str())) {
-            Some(value) => value,
-            None => continue,
-        };
-        let lxmf_destination_hex = trimmed_non_empty(peer.lxmf_destination_hex.as_deref());
-        let identity_hex = trimmed_non_empty(peer.identity_hex.as_deref());
-        let canonical_id = identity_hex
-            .clone()
-            .or_else(|| lxmf_destination_hex.clone())
-            .unwrap_or_else(|| destination_hex.clone());
-        let peer_destination_hex = lxmf_destination_hex
-            .clone()
-            .unwrap_or_else(|| destination_hex.clone());
-        let mut aliases = vec![destination_hex];
-        if let Some(lxmf_destination_hex) = lxmf_destination_hex {
-            aliases.push(lxmf_destination_hex);
-        }
-        if let Some(identity_hex) = identity_hex {
-            aliases.push(identity_hex);
-        }
-        resolver.insert(
-            aliases,
-            canonical_id,
-            peer_destination_hex,
-            peer.display_name.clone(),
-        );
-    }
-    resolver
-}
-
 const DEFAULT_R3AKT_TEAM_COLOR: &str = "YELLOW";
-const TEAM_UID_YELLOW: &str = "d6b6e188b910d6bdd24d04b7a7ec5444";
-const TEAM_UID_RED: &str = "65ce79a3a3e4b51ec0ec52d1d3d2b0b9";
-const TEAM_UID_BLUE: &str = "43341e5c822d99857fa6e8641f2ca9c0";
-const TEAM_UID_ORANGE: &str = "a83eb640e4c4884be14831e3d7ef5ae0";
-const TEAM_UID_MAGENTA: &str = "7ac50a910f42b06cd9cb68dad3def681";
-const TEAM_UID_MAROON: &str = "372824ef4f15881291455562f7570233";
-const TEAM_UID_PURPLE: &str = "4bf2a1d2217c8668942658137f2a6824";
-const TEAM_UID_DARK_BLUE: &str = "cbb35fc9a8f5a91d7bd2b5e5b644edcd";
-const TEAM_UID_CYAN: &str = "d4cd5030b68df059ec6beabe416dd6a6";
-const TEAM_UID_TEAL: &str = "4d7a7a974beec395bf83491604768499";
-const TEAM_UID_GREEN: &str = "612a32262163b73a80eca944c2158546";
-const TEAM_UID_DARK_GREEN: &str = "341653613d4c76d56bee99c1f38177b1";
-const TEAM_UID_BROWN: &str = "4efe72ac30f5b85142fdcab6d96c7631";
 
 #[derive(Debug, Clone)]
 struct MissionReplicationTarget {
@@ -267,9 +271,10 @@ fn telemetry_destinations_from_peers(
     destinations
 }
 
-fn telemetry_destinations_from_hub_snapshot(
+fn capability_destinations_from_hub_snapshot(
     snapshot: &HubDirectorySnapshot,
     self_destination_hex: Option<&str>,
+    capability: &str,
 ) -> Vec<String> {
     let mut destinations = Vec::new();
     let mut seen = HashSet::<String>::new();
@@ -283,7 +288,7 @@ fn telemetry_destinations_from_hub_snapshot(
         if !item
             .announce_capabilities
             .iter()
-            .any(|capability| capability.eq_ignore_ascii_case("telemetry"))
+            .any(|announced| announced.eq_ignore_ascii_case(capability))
         {
             continue;
         }
@@ -294,6 +299,40 @@ fn telemetry_destinations_from_hub_snapshot(
     destinations
 }
 
+// How traffic for a group of peers is routed under the effective hub mode:
+// through the hub when connected, to the hub's directory when semi-autonomous
+// with a selected hub, and to directly announced peers otherwise.
+enum PeerGroupRoute<'a> {
+    Hub(String),
+    Directory(&'a HubDirectorySnapshot),
+    Peers,
+}
+
+fn resolve_peer_group_route<'a>(
+    active_config: Option<&NodeConfigFingerprint>,
+    hub_directory_snapshot: Option<&'a HubDirectorySnapshot>,
+) -> Result<PeerGroupRoute<'a>, NodeError> {
+    let Some(config) = active_config else {
+        return Ok(PeerGroupRoute::Peers);
+    };
+    match effective_hub_mode(config.hub_mode, hub_directory_snapshot) {
+        HubMode::Autonomous {} => Ok(PeerGroupRoute::Peers),
+        HubMode::Connected {} => configured_hub_destination(config).map(PeerGroupRoute::Hub),
+        HubMode::SemiAutonomous {} => match hub_directory_snapshot {
+            Some(snapshot)
+                if config
+                    .hub_identity_hash
+                    .as_deref()
+                    .and_then(normalize_hex_32)
+                    .is_some() =>
+            {
+                Ok(PeerGroupRoute::Directory(snapshot))
+            }
+            _ => Ok(PeerGroupRoute::Peers),
+        },
+    }
+}
+
 fn build_runtime_telemetry_destinations(
     status: &NodeStatus,
     peers: &[PeerRecord],
@@ -301,39 +340,191 @@ fn build_runtime_telemetry_destinations(
     hub_directory_snapshot: Option<&HubDirectorySnapshot>,
 ) -> Result<Vec<String>, NodeError> {
     let self_destination_hex = normalize_hex_32(status.app_destination_hex.as_str());
-    let Some(config) = active_config else {
-        return Ok(telemetry_destinations_from_peers(
-            peers,
-            self_destination_hex.as_deref(),
-        ));
+    Ok(
+        match resolve_peer_group_route(active_config, hub_directory_snapshot)? {
+            PeerGroupRoute::Hub(hub_destination_hex) => vec![hub_destination_hex],
+            PeerGroupRoute::Directory(snapshot) => capability_destinations_from_hub_snapshot(
+                snapshot,
+                self_destination_hex.as_deref(),
+                "telemetry",
+            ),
+            PeerGroupRoute::Peers => {
+                telemetry_destinations_from_peers(peers, self_destination_hex.as_deref())
+            }
+        },
+    )
+}
+
+struct SosRecipientSources<'a> {
+    saved_peers: &'a [SavedPeerRecord],
+    peers: &'a [PeerRecord],
+    eams: &'a [EamProjectionRecord],
+    active_config: Option<&'a NodeConfigFingerprint>,
+    hub_directory_snapshot: Option<&'a HubDirectorySnapshot>,
+}
+
+fn build_sos_recipient_destinations(
+    status: &NodeStatus,
+    policy: &SosRecipientPolicyRecord,
+    sources: &SosRecipientSources<'_>,
+) -> Vec<String> {
+    let own_destinations = [
+        normalize_hex_32(status.app_destination_hex.as_str()),
+        normalize_hex_32(status.lxmf_destination_hex.as_str()),
+    ];
+    let mut destinations = Vec::new();
+    let mut seen = HashSet::<String>::new();
+    let mut push = |value: &str| {
+        let Some(destination_hex) = normalize_hex_32(value) else {
+            return;
+        };
+        if own_destinations
+            .iter()
+            .flatten()
+            .any(|own| own == &destination_hex)
+        {
+            return;
+        }
+        if seen.insert(destination_hex.clone()) {
+            destinations.push(destination_hex);
+        }
     };
 
-    match effective_hub_mode(config.hub_mode, hub_directory_snapshot) {
-        HubMode::Autonomous {} => Ok(telemetry_destinations_from_peers(
-            peers,
-            self_destination_hex.as_deref(),
-        )),
-        HubMode::Connected {} => Ok(vec![configured_hub_destination(config)?]),
-        HubMode::SemiAutonomous {} => {
-            if config
-                .hub_identity_hash
-                .as_deref()
-                .and_then(normalize_hex_32)
-                .is_some()
-            {
-                if let Some(snapshot) = hub_directory_snapshot {
-                    return Ok(telemetry_destinations_from_hub_snapshot(
-                        snapshot,
-                        self_destination_hex.as_deref(),
-                    ));
+    // Team and R3AKT groups follow the same routing as telemetry. A connected
+    // node without a usable hub still alerts the peers it can reach.
+    let route = resolve_peer_group_route(sources.active_config, sources.hub_directory_snapshot)
+        .unwrap_or(PeerGroupRoute::Peers);
+
+    if policy.saved_peers {
+        for peer in sources.saved_peers {
+            push(peer.destination_hex.as_str());
+        }
+    }
+    for contact in &policy.contacts {
+        push(contact.as_str());
+    }
+    if policy.same_team {
+        if let Some(team_uid) = sos_recipient_team_uid(status, policy, sources.eams) {
+            let member_identities = sources
+                .eams
+                .iter()
+                .filter(|eam| eam.deleted_at_ms.is_none())
+                .filter(|eam| {
+                    eam.team_uid
+                        .as_deref()
+                        .is_some_and(|value| value.trim().eq_ignore_ascii_case(team_uid))
+                })
+                .filter_map(|eam| eam.source.as_ref())
+                .map(|source| source.rns_identity.trim().to_ascii_lowercase())
+                .collect::<HashSet<_>>();
+            match &route {
+                PeerGroupRoute::Hub(hub_destination_hex) => push(hub_destination_hex.as_str()),
+                PeerGroupRoute::Directory(snapshot) => {
+                    for item in &snapshot.items {
+                        if member_identities.contains(&item.identity.trim().to_ascii_lowercase()) {
+                            push(item.destination_hash.as_str());
+                        }
+                    }
+                }
+                PeerGroupRoute::Peers => {
+                    for peer in sources.peers {
+                        if peer.identity_hex.as_deref().is_some_and(|identity| {
+                            member_identities.contains(&identity.trim().to_ascii_lowercase())
+                        }) {
+                            push(peer.destination_hex.as_str());
+                        }
+                    }
+                }
+            }
+        }
+    }
+    if policy.hub {
+        if let Some(hub_destination_hex) = sources
+            .active_config
+            .and_then(|config| configured_hub_destination(config).ok())
+        {
+            push(hub_destination_hex.as_str());
+        }
+    }
+    if policy.r3akt_peers {
+        match &route {
+            PeerGroupRoute::Hub(hub_destination_hex) => push(hub_destination_hex.as_str()),
+            PeerGroupRoute::Directory(snapshot) => {
+                for destination_hex in
+                    capability_destinations_from_hub_snapshot(snapshot, None, "r3akt")
+                {
+                    push(destination_hex.as_str());
+                }
+            }
+            PeerGroupRoute::Peers => {
+                for peer in sources.peers {
+                    if !peer.stale && has_capability_token(peer.app_data.as_deref(), "r3akt") {
+                        push(peer.destination_hex.as_str());
+                    }
                 }
             }
-            Ok(telemetry_destinations_from_peers(
-                peers,
-                self_destination_hex.as_deref(),
-            ))
         }
     }
+    destinations
+}
+
+// An explicit team in the policy wins; otherwise the team is taken from the
+// local member's own EAM.
+fn sos_recipient_team_uid<'a>(
+    status: &NodeStatus,
+    policy: &'a SosRecipientPolicyRecord,
+    eams: &'a [EamProjectionRecord],
+) -> Option<&'a str> {
+    policy.team_uid.as_deref().or_else(|| {
+        eams.iter()
+            .filter(|eam| eam.deleted_at_ms.is_none())
+            .find(|eam| {
+                eam.source.as_ref().is_some_and(|source| {
+                    source
+                        .rns_identity
+                        .trim()
+                        .eq_ignore_ascii_case(status.identity_hex.trim())
+                })
+            })
+            .and_then(|eam| eam.team_uid.as_deref())
+            .map(str::trim)
+            .filter(|team_uid| !team_uid.is_empty())
+    })
+}
+
+fn resolve_sos_recipients(
+    app_state: &AppStateStore,
+    settings: &SosSettingsRecord,
+    status: &NodeStatus,
+    peers_snapshot: &Arc<Mutex<Vec<PeerRecord>>>,
+    active_config: Option<&NodeConfigFingerprint>,
+    hub_directory_snapshot: &Arc<Mutex<Option<HubDirectorySnapshot>>>,
+) -> Result<Vec<String>, NodeError> {
+    let saved_peers = app_state.get_saved_peers()?;
+    let eams = if settings.recipients.same_team {
+        app_state.get_eams()?
+    } else {
+        Vec::new()
+    };
+    let peers = peers_snapshot
+        .lock()
+        .map(|peers| peers.clone())
+        .unwrap_or_default();
+    let hub_directory_snapshot = hub_directory_snapshot
+        .lock()
+        .ok()
+        .and_then(|snapshot| snapshot.clone());
+    Ok(build_sos_recipient_destinations(
+        status,
+        &settings.recipients,
+        &SosRecipientSources {
+            saved_peers: saved_peers.as_slice(),
+            peers: peers.as_slice(),
+            eams: eams.as_slice(),
+            active_config,
+            hub_directory_snapshot: hub_directory_snapshot.as_ref(),
+        },
+    ))
 }
 
 fn configured_hub_destination(config: &NodeConfigFingerprint) -> Result<String, NodeError> {
@@ -408,6 +599,14 @@ fn team_uid_for_color(color: &str) -> &'static str {
 }
 
 fn populate_eam_defaults(status: &NodeStatus, record: &EamProjectionRecord) -> EamProjectionRecord {
+    populate_eam_defaults_with_schema(status, record, &built_in_eam_schema())
+}
+
+fn populate_eam_defaults_with_schema(
+    status: &NodeStatus,
+    record: &EamProjectionRecord,
+    schema: &EamSchemaRecord,
+) -> EamProjectionRecord {
     let mut normalized = record.clone();
     let team_color = normalize_team_color(normalized.group_name.as_str());
     normalized.group_name = team_color.to_string();
@@ -430,7 +629,7 @@ fn populate_eam_defaults(status: &NodeStatus, record: &EamProjectionRecord) -> E
         });
     }
     if normalized.overall_status.is_none() {
-        normalized.overall_status = derive_eam_overall_status(&normalized);
+        normalized.overall_status = derive_eam_overall_status(schema, &normalized);
     }
     normalized
 }
@@ -777,34 +976,6 @@ fn build_runtime_event_replication_targets(
     }
 }
 
-fn eam_status_rank(value: &str) -> u8 {
-    match value {
-        "Green" => 1,
-        "Yellow" => 2,
-        "Red" => 3,
-        _ => 0,
-    }
-}
-
-fn derive_eam_overall_status(record: &EamProjectionRecord) -> Option<String> {
-    let mut best_status: Option<&str> = None;
-    for value in [
-        record.security_status.as_str(),
-        record.capability_status.as_str(),
-        record.preparedness_status.as_str(),
-        record.medical_status.as_str(),
-        record.mobility_status.as_str(),
-        record.comms_status.as_str(),
-    ] {
-        if eam_status_rank(value) >= eam_status_rank(best_status.unwrap_or_default()) {
-            best_status = Some(value);
-        }
-    }
-    best_status
-        .filter(|value| !value.is_empty() && *value != "Unknown")
-        .map(str::to_string)
-}
-
 fn msgpack_map(entries: Vec<(&str, MsgPackValue)>) -> MsgPackValue {
     MsgPackValue::Map(
         entries
@@ -1483,10 +1654,126 @@ fn checklist_task_cell_args_json(
     args
 }
 
+// Shares an EAM schema as a template. The schema travels as JSON so peers on
+// older builds can ignore the command without parsing it.
+fn build_eam_schema_payload(
+    schema: &EamSchemaRecord,
+    target: &MissionReplicationTarget,
+) -> Result<(Vec<u8>, Vec<u8>), NodeError> {
+    let correlation_id = format!(
+        "eam-schema-{}-{}-{}",
+        sanitize_correlation_token(schema.schema_uid.as_str()),
+        &target.app_destination_hex[..target.app_destination_hex.len().min(8)],
+        now_ms(),
+    );
+    let command_id = format!("cmd-{correlation_id}");
+    let mut shared = schema.clone();
+    shared.active = false;
+    let schema_json = serde_json::to_string(&shared).map_err(|_| NodeError::InternalError {})?;
+    let fields = build_mission_command_fields(
+        command_id.as_str(),
+        correlation_id.as_str(),
+        EAM_SCHEMA_COMMAND_TYPE,
+        vec![
+            ("schema_uid", MsgPackValue::from(schema.schema_uid.as_str())),
+            ("version", MsgPackValue::from(schema.version)),
+            ("schema_json", MsgPackValue::from(schema_json)),
+        ],
+    )?;
+    let body = format!("EAM schema {} v{}", schema.name.trim(), schema.version).into_bytes();
+    Ok((body, fields))
+}
+
+// Deletions travel as the same command carrying the tombstone; receivers keep
+// whichever copy has the later `updated_at_ms`.
+fn build_team_payload(
+    team: &TeamRecord,
+    target: &MissionReplicationTarget,
+) -> Result<(Vec<u8>, Vec<u8>), NodeError> {
+    let correlation_id = format!(
+        "team-{}-{}-{}",
+        sanitize_correlation_token(team.team_uid.as_str()),
+        &target.app_destination_hex[..target.app_destination_hex.len().min(8)],
+        team.updated_at_ms,
+    );
+    let command_id = format!("cmd-{correlation_id}");
+    let team_json = serde_json::to_string(team).map_err(|_| NodeError::InternalError {})?;
+    let fields = build_mission_command_fields(
+        command_id.as_str(),
+        correlation_id.as_str(),
+        TEAM_UPSERT_COMMAND_TYPE,
+        vec![
+            ("team_uid", MsgPackValue::from(team.team_uid.as_str())),
+            ("updated_at_ms", MsgPackValue::from(team.updated_at_ms)),
+            ("team_json", MsgPackValue::from(team_json)),
+        ],
+    )?;
+    let body = if team.deleted_at_ms.is_some() {
+        format!("Team {} removed", team.name.trim())
+    } else {
+        format!("Team {}", team.name.trim())
+    };
+    Ok((body.into_bytes(), fields))
+}
+
+// Each recipient gets its own correlation id so the deliveries are tracked
+// separately; the roll-call uid travels in the args.
+fn build_eam_roll_call_payload(
+    command: &EamRollCallCommand,
+    target: &MissionReplicationTarget,
+) -> Result<(Vec<u8>, Vec<u8>), NodeError> {
+    if command.roll_call_uid.trim().is_empty() {
+        return Err(NodeError::InvalidConfig {});
+    }
+    let correlation_id = format!(
+        "eam-roll-call-{}-{}-{}",
+        sanitize_correlation_token(command.roll_call_uid.as_str()),
+        &target.app_destination_hex[..target.app_destination_hex.len().min(8)],
+        command.requested_at_ms,
+    );
+    let command_id = format!("cmd-{correlation_id}");
+    let mut args = vec![
+        (
+            "roll_call_uid",
+            MsgPackValue::from(command.roll_call_uid.as_str()),
+        ),
+        (
+            "team_member_uids",
+            msgpack_string_array(&command.team_member_uids),
+        ),
+        (
+            "requested_at_ms",
+            MsgPackValue::from(command.requested_at_ms),
+        ),
+        ("due_at_ms", MsgPackValue::from(command.due_at_ms)),
+    ];
+    if let Some(team_uid) = command.team_uid.as_deref() {
+        args.push(("team_uid", MsgPackValue::from(team_uid)));
+    }
+    if let Some(requested_by) = command.requested_by.as_deref() {
+        args.push(("requested_by", MsgPackValue::from(requested_by)));
+    }
+    let fields = build_mission_command_fields(
+        command_id.as_str(),
+        correlation_id.as_str(),
+        EAM_ROLL_CALL_COMMAND_TYPE,
+        args,
+    )?;
+    let body = match command.requested_by.as_deref() {
+        Some(requested_by) => format!("EAM roll call from {requested_by}"),
+        None => "EAM roll call".to_string(),
+    };
+    Ok((body.into_bytes(), fields))
+}
+
+// A report sent in reply to a roll call carries its uid so the requesting
+// leader can tick the reporter off.
 fn build_eam_replication_payload(
     status: &NodeStatus,
     record: &EamProjectionRecord,
+    schema: &EamSchemaRecord,
     target: &MissionReplicationTarget,
+    roll_call_uid: Option<&str>,
 ) -> Result<(Vec<u8>, Vec<u8>), NodeError> {
     let team_member_uid = record
         .team_member_uid
@@ -1503,7 +1790,7 @@ fn build_eam_replication_payload(
     }
 
     let send_ts_ms = now_ms();
-    let correlation_id = format!(
+    let upsert_id = format!(
         "eam-upsert-{}-{}-{send_ts_ms}",
         record
             .eam_uid
@@ -1514,7 +1801,7 @@ fn build_eam_replication_payload(
             .to_ascii_lowercase(),
         &target.app_destination_hex[..8],
     );
-    let command_id = format!("cmd-{correlation_id}");
+    let command_id = format!("cmd-{upsert_id}");
     let display_name = status.name.trim();
     let source_identity = status.identity_hex.as_str();
     let reported_by = record
@@ -1526,13 +1813,13 @@ fn build_eam_replication_payload(
     let overall_status = record
         .overall_status
         .clone()
-        .or_else(|| derive_eam_overall_status(record))
+        .or_else(|| derive_eam_overall_status(schema, record))
         .unwrap_or_else(|| "Unknown".to_string());
     let body = format!("EAM {} {}", record.callsign.trim(), overall_status).into_bytes();
 
     let fields = build_mission_command_fields(
         command_id.as_str(),
-        correlation_id.as_str(),
+        upsert_id.as_str(),
         "mission.registry.eam.upsert",
         vec![
             ("callsign", MsgPackValue::from(record.callsign.as_str())),
@@ -1614,6 +1901,32 @@ fn build_eam_replication_payload(
                 .map(|value| ("ttl_seconds", MsgPackValue::from(value)))
                 .into_iter(),
         )
+        .chain(
+            roll_call_uid
+                .map(|value| ("roll_call_uid", MsgPackValue::from(value)))
+                .into_iter(),
+        )
+        .chain(
+            (!record.custom_statuses.is_empty())
+                .then(|| {
+                    (
+                        "custom_statuses",
+                        MsgPackValue::Map(
+                            record
+                                .custom_statuses
+                                .iter()
+                                .map(|entry| {
+                                    (
+                                        MsgPackValue::from(entry.dimension.as_str()),
+                                        MsgPackValue::from(entry.value.as_str()),
+                                    )
+                                })
+                                .collect(),
+                        ),
+                    )
+                })
+                .into_iter(),
+        )
         .collect(),
     )?;
 
@@ -1841,47 +2154,453 @@ fn build_telemetry_replication_payload(
     Ok((body, fields))
 }
 
-fn emit_sos_status(
-    app_state: &AppStateStore,
+fn build_telemetry_scheduled_sends(
+    status: &NodeStatus,
+    peers: &[PeerRecord],
+    active_config: Option<&NodeConfigFingerprint>,
+    hub_directory_snapshot: Option<&HubDirectorySnapshot>,
+    position: &TelemetryPositionRecord,
     bus: &EventBus,
-    status: &SosStatusRecord,
-    reason: &str,
-) -> Result<(), NodeError> {
-    let invalidation = app_state.set_sos_status(status, reason)?;
-    emit_projection_invalidation(bus, invalidation);
-    bus.emit(NodeEvent::SosStatusChanged {
-        status: status.clone(),
-    });
-    Ok(())
+) -> Result<Vec<ScheduledMissionSend>, NodeError> {
+    let mut scheduled_sends = Vec::new();
+    let telemetry_destinations =
+        build_runtime_telemetry_destinations(status, peers, active_config, hub_directory_snapshot)?;
+    for destination_hex in telemetry_destinations {
+        let target = MissionReplicationTarget {
+            app_destination_hex: destination_hex,
+            send_mode: SendMode::Auto {},
+        };
+        match build_telemetry_replication_payload(position, &target) {
+            Ok((body, fields)) => {
+                scheduled_sends.push((target.app_destination_hex, body, fields, target.send_mode))
+            }
+            Err(err) => bus.emit(NodeEvent::Error {
+                code: "InvalidConfig".to_string(),
+                message: format!(
+                    "telemetry replication skipped destination={} callsign={} reason={}",
+                    target.app_destination_hex, position.callsign, err
+                ),
+            }),
+        }
+    }
+    Ok(scheduled_sends)
 }
 
-fn is_pending_sos_countdown_for_incident(status: &SosStatusRecord, incident_id: &str) -> bool {
-    matches!(status.state, SosState::Countdown {})
-        && status.incident_id.as_deref() == Some(incident_id)
+struct MissionSendRoute {
+    hub_client: Option<HubHttpClient>,
+    cmd_tx: mpsc::Sender<Command>,
+    active_config: Option<NodeConfigFingerprint>,
+    hub_directory_snapshot: Option<HubDirectorySnapshot>,
+    bus: EventBus,
 }
 
-fn app_state_has_pending_sos_countdown(app_state: &AppStateStore, incident_id: &str) -> bool {
-    matches!(
-        app_state.get_sos_status(),
-        Ok(Some(status)) if is_pending_sos_countdown_for_incident(&status, incident_id)
-    )
+async fn deliver_mission_push(
+    route: MissionSendRoute,
+    push: HubPush,
+    scheduled_sends: Vec<ScheduledMissionSend>,
+) {
+    if let Some(hub_client) = route.hub_client.as_ref() {
+        match hub_client.push(&push).await {
+            Ok(()) => return,
+            Err(err) => emit_hub_push_fallback(&route.bus, &push, err),
+        }
+    }
+
+    for (destination_hex, bytes, fields_bytes, send_mode) in scheduled_sends {
+        let routed = routed_destination_hex(
+            destination_hex.clone(),
+            route.active_config.as_ref(),
+            route.hub_directory_snapshot.as_ref(),
+        );
+        let sent = match routed {
+            Ok(destination_hex) => {
+                let (resp_tx, resp_rx) = cb::bounded(1);
+                match route
+                    .cmd_tx
+                    .send(Command::SendBytes {
+                        destination_hex,
+                        bytes,
+                        fields_bytes: Some(fields_bytes),
+                        send_mode,
+                        resp: resp_tx,
+                    })
+                    .await
+                {
+                    Ok(()) => await_command_reply(resp_rx, SEND_COMMAND_TIMEOUT).await,
+                    Err(_) => Err(NodeError::NotRunning {}),
+                }
+            }
+            Err(err) => Err(err),
+        };
+        if let Err(err) = sent {
+            route.bus.emit(NodeEvent::Error {
+                code: node_error_code(&err).to_string(),
+                message: format!(
+                    "{} replication failed destination={} reason={}",
+                    push.label(),
+                    destination_hex,
+                    err
+                ),
+            });
+        }
+    }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn run_sos_fanout(
+fn emit_hub_push_fallback(bus: &EventBus, push: &HubPush, err: NodeError) {
+    bus.emit(NodeEvent::OperationalNotice {
+        notice: OperationalNotice {
+            level: LogLevel::Warn {},
+            message: format!(
+                "Hub HTTP {} failed ({err}); falling back to LXMF.",
+                push.label()
+            ),
+            at_ms: now_ms(),
+        },
+    });
+}
+
+struct TelemetryPublisherTask {
     app_state: AppStateStore,
+    status: Arc<Mutex<NodeStatus>>,
+    peers_snapshot: Arc<Mutex<Vec<PeerRecord>>>,
+    hub_directory_snapshot: Arc<Mutex<Option<HubDirectorySnapshot>>>,
+    publisher: Arc<Mutex<TelemetryPublisherState>>,
+    active_config: NodeConfigFingerprint,
+    hub_client: Option<HubHttpClient>,
+    cmd_tx: mpsc::Sender<Command>,
     bus: EventBus,
-    tx: mpsc::Sender<Command>,
-    status: NodeStatus,
-    settings: SosSettingsRecord,
-    saved_peers: Vec<SavedPeerRecord>,
-    telemetry: Option<SosDeviceTelemetryRecord>,
-    incident_id: String,
-    trigger_source: SosTriggerSource,
-    kind: SosMessageKind,
-) -> Option<SosStatusRecord> {
-    let now = now_ms();
-    let sending = SosStatusRecord {
+}
+
+// Re-publishes the latest accepted local fix so position keeps flowing when
+// the host stops calling `record_local_telemetry_fix` (e.g. backgrounded app).
+async fn run_telemetry_publisher(task: TelemetryPublisherTask) {
+    let mut interval = tokio::time::interval(TELEMETRY_PUBLISHER_TICK);
+    loop {
+        interval.tick().await;
+        let Ok(settings) = task.app_state.get_app_settings() else {
+            continue;
+        };
+        let policy =
+            TelemetryPublishPolicy::from_settings(settings.as_ref().map(|value| &value.telemetry));
+        let Some(position) = task
+            .publisher
+            .lock()
+            .ok()
+            .and_then(|mut publisher| publisher.due_republish(&policy, now_ms()))
+        else {
+            continue;
+        };
+
+        let Some(status) = task.status.lock().ok().map(|status| status.clone()) else {
+            continue;
+        };
+        let peers = task
+            .peers_snapshot
+            .lock()
+            .map(|peers| peers.clone())
+            .unwrap_or_default();
+        let hub_directory_snapshot = task
+            .hub_directory_snapshot
+            .lock()
+            .ok()
+            .and_then(|snapshot| snapshot.clone());
+        let scheduled_sends = match build_telemetry_scheduled_sends(
+            &status,
+            peers.as_slice(),
+            Some(&task.active_config),
+            hub_directory_snapshot.as_ref(),
+            &position,
+            &task.bus,
+        ) {
+            Ok(scheduled_sends) => scheduled_sends,
+            Err(err) => {
+                task.bus.emit(NodeEvent::Error {
+                    code: "InvalidConfig".to_string(),
+                    message: format!(
+                        "telemetry republish skipped callsign={} reason={}",
+                        position.callsign, err
+                    ),
+                });
+                continue;
+            }
+        };
+
+        deliver_mission_push(
+            MissionSendRoute {
+                hub_client: task.hub_client.clone(),
+                cmd_tx: task.cmd_tx.clone(),
+                active_config: Some(task.active_config.clone()),
+                hub_directory_snapshot,
+                bus: task.bus.clone(),
+            },
+            HubPush::Telemetry(position),
+            scheduled_sends,
+        )
+        .await;
+    }
+}
+
+fn emit_sos_status(
+    app_state: &AppStateStore,
+    bus: &EventBus,
+    status: &SosStatusRecord,
+    reason: &str,
+) -> Result<(), NodeError> {
+    let invalidation = app_state.set_sos_status(status, reason)?;
+    emit_projection_invalidation(bus, invalidation);
+    bus.emit(NodeEvent::SosStatusChanged {
+        status: status.clone(),
+    });
+    Ok(())
+}
+
+fn is_pending_sos_countdown_for_incident(status: &SosStatusRecord, incident_id: &str) -> bool {
+    matches!(status.state, SosState::Countdown {})
+        && status.incident_id.as_deref() == Some(incident_id)
+}
+
+fn app_state_has_pending_sos_countdown(app_state: &AppStateStore, incident_id: &str) -> bool {
+    matches!(
+        app_state.get_sos_status(),
+        Ok(Some(status)) if is_pending_sos_countdown_for_incident(&status, incident_id)
+    )
+}
+
+#[derive(Clone, Copy)]
+struct SosDispatch<'a> {
+    runtime: &'a tokio::runtime::Handle,
+    app_state: &'a AppStateStore,
+    bus: &'a EventBus,
+    tx: &'a mpsc::Sender<Command>,
+    status: &'a NodeStatus,
+    body: &'a str,
+    telemetry: Option<&'a SosDeviceTelemetryRecord>,
+    incident_id: &'a str,
+    trigger_source: SosTriggerSource,
+    kind: SosMessageKind,
+    now: u64,
+}
+
+fn dispatch_sos_messages(dispatch: &SosDispatch<'_>, recipients: Vec<String>) {
+    for destination_hex in recipients {
+        send_sos_message(dispatch, destination_hex, 1, SendMode::Auto {});
+    }
+}
+
+fn send_sos_message(
+    dispatch: &SosDispatch<'_>,
+    destination_hex: String,
+    attempt: u32,
+    send_mode: SendMode,
+) {
+    let command = SosCommand {
+        state: dispatch.kind,
+        incident_id: dispatch.incident_id.to_string(),
+        trigger_source: dispatch.trigger_source,
+        sent_at_ms: dispatch.now,
+        audio_id: None,
+    };
+    let fields = match build_sos_fields(&command, dispatch.telemetry) {
+        Ok(fields) => fields,
+        Err(err) => {
+            dispatch.bus.emit(NodeEvent::Error {
+                code: "InternalError".to_string(),
+                message: format!(
+                    "sos field encode failed destination={destination_hex} reason={err}"
+                ),
+            });
+            return;
+        }
+    };
+    // Retries re-send an alert the conversation already shows.
+    if attempt == 1 {
+        let message_id_hex = format!(
+            "{}-{}-{}",
+            dispatch.incident_id,
+            destination_hex.chars().take(8).collect::<String>(),
+            dispatch.now
+        );
+        let record = canonicalize_chat_message(&MessageRecord {
+            message_id_hex: message_id_hex.clone(),
+            conversation_id: sdkmsg::MessagingStore::conversation_id_for(destination_hex.as_str()),
+            direction: MessageDirection::Outbound {},
+            destination_hex: destination_hex.clone(),
+            source_hex: Some(dispatch.status.lxmf_destination_hex.clone()),
+            title: Some("SOS Emergency".to_string()),
+            body_utf8: dispatch.body.to_string(),
+            method: MessageMethod::Direct {},
+            state: MessageState::Queued {},
+            detail: Some(format!("sos:{}", crate::sos::sos_kind_label(dispatch.kind))),
+            sent_at_ms: Some(dispatch.now),
+            received_at_ms: None,
+            updated_at_ms: dispatch.now,
+            attachments: Vec::new(),
+            edited_at_ms: None,
+            deleted_at_ms: None,
+            reactions: Vec::new(),
+        });
+        if let Ok(invalidations) = dispatch.app_state.upsert_message(&record) {
+            for invalidation in invalidations {
+                emit_projection_invalidation(dispatch.bus, invalidation);
+            }
+            dispatch
+                .bus
+                .emit(NodeEvent::MessageUpdated { message: record });
+        }
+    }
+    emit_sos_delivery(
+        dispatch.app_state,
+        dispatch.bus,
+        SosDeliveryRecord {
+            incident_id: dispatch.incident_id.to_string(),
+            destination_hex: destination_hex.clone(),
+            kind: dispatch.kind,
+            state: SosDeliveryState::Queued {},
+            attempts: attempt,
+            lxmf_message_id_hex: None,
+            via_propagation: matches!(send_mode, SendMode::PropagationOnly {}),
+            detail: None,
+            last_attempt_at_ms: dispatch.now,
+            updated_at_ms: dispatch.now,
+        },
+    );
+
+    let (resp_tx, resp_rx) = cb::bounded(1);
+    if let Err(err) = dispatch_command(
+        dispatch.tx,
+        Command::SendBytes {
+            destination_hex: destination_hex.clone(),
+            bytes: dispatch.body.as_bytes().to_vec(),
+            fields_bytes: Some(fields),
+            send_mode,
+            resp: resp_tx,
+        },
+    ) {
+        dispatch.bus.emit(NodeEvent::Error {
+            code: "NotRunning".to_string(),
+            message: format!("sos send enqueue failed destination={destination_hex} reason={err}"),
+        });
+        record_sos_send_result(
+            dispatch.app_state,
+            dispatch.bus,
+            dispatch.incident_id,
+            destination_hex.as_str(),
+            dispatch.kind,
+            attempt,
+            Err(err),
+        );
+        return;
+    }
+
+    let app_state = dispatch.app_state.clone();
+    let bus = dispatch.bus.clone();
+    let incident_id = dispatch.incident_id.to_string();
+    let kind = dispatch.kind;
+    dispatch.runtime.spawn(async move {
+        let result = await_command_reply(resp_rx, SEND_COMMAND_TIMEOUT).await;
+        record_sos_send_result(
+            &app_state,
+            &bus,
+            incident_id.as_str(),
+            destination_hex.as_str(),
+            kind,
+            attempt,
+            result,
+        );
+    });
+}
+
+// Only the outcome of the recipient's latest attempt for this message kind is
+// recorded; a result for an attempt that has since been superseded is dropped.
+fn record_sos_send_result(
+    app_state: &AppStateStore,
+    bus: &EventBus,
+    incident_id: &str,
+    destination_hex: &str,
+    kind: SosMessageKind,
+    attempt: u32,
+    result: Result<SendBytesReceipt, NodeError>,
+) {
+    let Ok(Some(mut delivery)) = app_state.get_sos_delivery(incident_id, destination_hex, kind)
+    else {
+        return;
+    };
+    if delivery.attempts != attempt || !matches!(delivery.state, SosDeliveryState::Queued {}) {
+        return;
+    }
+    match result {
+        Ok(receipt) => {
+            delivery.state = SosDeliveryState::Sent {};
+            delivery.lxmf_message_id_hex = receipt.message_id_hex;
+            delivery.via_propagation = receipt.used_propagation_node;
+            delivery.detail = None;
+        }
+        Err(err) => {
+            delivery.state = SosDeliveryState::Failed {};
+            delivery.detail = Some(err.to_string());
+        }
+    }
+    delivery.updated_at_ms = now_ms();
+    emit_sos_delivery(app_state, bus, delivery);
+}
+
+fn emit_sos_delivery(app_state: &AppStateStore, bus: &EventBus, delivery: SosDeliveryRecord) {
+    match app_state.upsert_sos_delivery(&delivery) {
+        Ok(invalidation) => {
+            emit_projection_invalidation(bus, invalidation);
+            bus.emit(NodeEvent::SosDeliveryUpdated { delivery });
+        }
+        Err(err) => bus.emit(NodeEvent::Error {
+            code: "IoError".to_string(),
+            message: format!(
+                "sos delivery update failed destination={} reason={err}",
+                delivery.destination_hex
+            ),
+        }),
+    }
+}
+
+// Each retry keeps the kind of the delivery it repeats; everything else comes
+// from the incident's `Update` dispatch.
+fn retry_failed_sos_deliveries(dispatch: &SosDispatch<'_>, settings: &SosSettingsRecord) {
+    let Ok(deliveries) = dispatch.app_state.list_sos_deliveries(dispatch.incident_id) else {
+        return;
+    };
+    for delivery in deliveries
+        .into_iter()
+        .filter(|delivery| sos_delivery_retry_due(delivery, dispatch.now))
+    {
+        let body = compose_sos_body(settings, delivery.kind, dispatch.telemetry);
+        let dispatch = SosDispatch {
+            body: body.as_str(),
+            kind: delivery.kind,
+            ..*dispatch
+        };
+        send_sos_message(
+            &dispatch,
+            delivery.destination_hex,
+            delivery.attempts.saturating_add(1),
+            sos_retry_send_mode(delivery.attempts),
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_sos_fanout(
+    runtime: &tokio::runtime::Handle,
+    app_state: AppStateStore,
+    bus: EventBus,
+    tx: mpsc::Sender<Command>,
+    status: NodeStatus,
+    settings: SosSettingsRecord,
+    recipients: Vec<String>,
+    telemetry: Option<SosDeviceTelemetryRecord>,
+    incident_id: String,
+    trigger_source: SosTriggerSource,
+    kind: SosMessageKind,
+) -> Option<SosStatusRecord> {
+    let now = now_ms();
+    let sending = SosStatusRecord {
         state: SosState::Sending {},
         incident_id: Some(incident_id.clone()),
         trigger_source: Some(trigger_source),
@@ -1907,87 +2626,221 @@ fn run_sos_fanout(
     }
 
     let body = compose_sos_body(&settings, kind, telemetry.as_ref());
-    for peer in saved_peers {
-        let destination_hex = peer.destination_hex.trim().to_ascii_lowercase();
-        if destination_hex.is_empty() {
+    dispatch_sos_messages(
+        &SosDispatch {
+            runtime,
+            app_state: &app_state,
+            bus: &bus,
+            tx: &tx,
+            status: &status,
+            body: body.as_str(),
+            telemetry: telemetry.as_ref(),
+            incident_id: incident_id.as_str(),
+            trigger_source,
+            kind,
+            now,
+        },
+        recipients,
+    );
+
+    let next = if matches!(kind, SosMessageKind::Cancelled {}) {
+        idle_status()
+    } else {
+        active_status(incident_id, trigger_source, now)
+    };
+    if emit_sos_status(&app_state, &bus, &next, "sos-fanout-complete").is_err() {
+        return None;
+    }
+    Some(next)
+}
+
+struct SosSchedulerTask {
+    app_state: AppStateStore,
+    status: Arc<Mutex<NodeStatus>>,
+    peers_snapshot: Arc<Mutex<Vec<PeerRecord>>>,
+    hub_directory_snapshot: Arc<Mutex<Option<HubDirectorySnapshot>>>,
+    active_config: NodeConfigFingerprint,
+    sos_device_telemetry: Arc<Mutex<Option<SosDeviceTelemetryRecord>>>,
+    cmd_tx: mpsc::Sender<Command>,
+    bus: EventBus,
+}
+
+// Answers saved peers' alerts silently and, while an incident of our own is
+// active, keeps sending `Update` fanouts and retrying failed recipients.
+// Schedule state is read from the persisted SOS status each tick so it resumes
+// after a restart.
+async fn run_sos_scheduler(task: SosSchedulerTask) {
+    let runtime = tokio::runtime::Handle::current();
+    let mut interval = tokio::time::interval(SOS_SCHEDULER_TICK);
+    loop {
+        interval.tick().await;
+        let settings = match task.app_state.get_sos_settings() {
+            Ok(settings) => settings
+                .map(normalize_sos_settings)
+                .unwrap_or_else(default_sos_settings),
+            Err(_) => continue,
+        };
+        let Some(status) = task.status.lock().ok().map(|status| status.clone()) else {
             continue;
+        };
+        let telemetry = latest_sos_telemetry(&task.sos_device_telemetry);
+        let now = now_ms();
+        if settings.silent_auto_answer {
+            send_sos_auto_answers(&task, &status, &settings, telemetry.as_ref(), now);
         }
-        let command = SosCommand {
-            state: kind,
-            incident_id: incident_id.clone(),
-            trigger_source,
-            sent_at_ms: now,
-            audio_id: None,
+        let Ok(Some(current)) = task.app_state.get_sos_status() else {
+            continue;
         };
-        let fields = match build_sos_fields(&command, telemetry.as_ref()) {
-            Ok(fields) => fields,
-            Err(err) => {
-                bus.emit(NodeEvent::Error {
-                    code: "InternalError".to_string(),
-                    message: format!(
-                        "sos field encode failed destination={destination_hex} reason={err}"
-                    ),
-                });
-                continue;
-            }
+        if !matches!(current.state, SosState::Active {}) {
+            continue;
+        }
+        let Some(incident_id) = current.incident_id.as_deref() else {
+            continue;
         };
-        let message_id_hex = format!(
-            "{}-{}-{}",
+        let body = compose_sos_body(&settings, SosMessageKind::Update {}, telemetry.as_ref());
+        let dispatch = SosDispatch {
+            runtime: &runtime,
+            app_state: &task.app_state,
+            bus: &task.bus,
+            tx: &task.cmd_tx,
+            status: &status,
+            body: body.as_str(),
+            telemetry: telemetry.as_ref(),
             incident_id,
-            destination_hex.chars().take(8).collect::<String>(),
-            now
-        );
-        let record = canonicalize_chat_message(&MessageRecord {
-            message_id_hex: message_id_hex.clone(),
-            conversation_id: sdkmsg::MessagingStore::conversation_id_for(destination_hex.as_str()),
-            direction: MessageDirection::Outbound {},
-            destination_hex: destination_hex.clone(),
-            source_hex: Some(status.lxmf_destination_hex.clone()),
-            title: Some("SOS Emergency".to_string()),
-            body_utf8: body.clone(),
-            method: MessageMethod::Direct {},
-            state: MessageState::Queued {},
-            detail: Some(format!("sos:{}", crate::sos::sos_kind_label(kind))),
-            sent_at_ms: Some(now),
-            received_at_ms: None,
-            updated_at_ms: now,
-        });
-        if let Ok(invalidations) = app_state.upsert_message(&record) {
-            for invalidation in invalidations {
-                emit_projection_invalidation(&bus, invalidation);
+            trigger_source: current
+                .trigger_source
+                .unwrap_or(SosTriggerSource::Manual {}),
+            kind: SosMessageKind::Update {},
+            now,
+        };
+        if sos_update_due(&settings, &current, now) {
+            if let Ok(recipients) = resolve_sos_recipients(
+                &task.app_state,
+                &settings,
+                &status,
+                &task.peers_snapshot,
+                Some(&task.active_config),
+                &task.hub_directory_snapshot,
+            ) {
+                run_sos_update(&dispatch, recipients, &current);
             }
-            bus.emit(NodeEvent::MessageUpdated { message: record });
         }
+        retry_failed_sos_deliveries(&dispatch, &settings);
+    }
+}
+
+fn sos_alert_from_saved_peer(
+    alert: &SosAlertRecord,
+    saved_peers: &[SavedPeerRecord],
+    peers: &[PeerRecord],
+) -> bool {
+    let source_hex = alert.source_hex.as_str();
+    let saved_destinations = saved_peers
+        .iter()
+        .filter_map(|peer| normalize_hex_32(peer.destination_hex.as_str()))
+        .collect::<HashSet<_>>();
+    saved_destinations.contains(source_hex)
+        || peers.iter().any(|peer| {
+            peer.lxmf_destination_hex.as_deref() == Some(source_hex)
+                && (peer.saved || saved_destinations.contains(peer.destination_hex.as_str()))
+        })
+}
 
+// Auto answers carry only this device's position and battery. Nothing is
+// added to the conversation and no SOS event is raised, so the local user is
+// not alerted; the stored responder record doubles as the rate limit.
+fn send_sos_auto_answers(
+    task: &SosSchedulerTask,
+    status: &NodeStatus,
+    settings: &SosSettingsRecord,
+    telemetry: Option<&SosDeviceTelemetryRecord>,
+    now: u64,
+) {
+    let (Ok(alerts), Ok(saved_peers)) = (
+        task.app_state.list_sos_alerts(),
+        task.app_state.get_saved_peers(),
+    ) else {
+        return;
+    };
+    let peers = task
+        .peers_snapshot
+        .lock()
+        .map(|peers| peers.clone())
+        .unwrap_or_default();
+    for alert in alerts {
+        if !alert.active || !sos_alert_from_saved_peer(&alert, &saved_peers, &peers) {
+            continue;
+        }
+        let previous = task
+            .app_state
+            .list_sos_responders(alert.incident_id.as_str())
+            .ok()
+            .and_then(|responders| {
+                responders
+                    .into_iter()
+                    .find(|responder| responder.responder_hex == status.lxmf_destination_hex)
+            });
+        if !sos_auto_answer_due(settings, &alert, previous.as_ref(), now) {
+            continue;
+        }
+        let kind = SosResponseKind::AutoAnswer {};
+        let response = SosResponse {
+            kind,
+            incident_id: alert.incident_id.clone(),
+            eta_seconds: None,
+            sent_at_ms: now,
+        };
+        let Ok(fields) = build_sos_response_fields(&response, telemetry) else {
+            continue;
+        };
         let (resp_tx, _resp_rx) = cb::bounded(1);
-        if let Err(err) = dispatch_command(
-            &tx,
+        if dispatch_command(
+            &task.cmd_tx,
             Command::SendBytes {
-                destination_hex: destination_hex.clone(),
-                bytes: body.as_bytes().to_vec(),
+                destination_hex: alert.source_hex.clone(),
+                bytes: compose_sos_response_body(kind, None).into_bytes(),
                 fields_bytes: Some(fields),
                 send_mode: SendMode::Auto {},
                 resp: resp_tx,
             },
-        ) {
-            bus.emit(NodeEvent::Error {
-                code: "NotRunning".to_string(),
-                message: format!(
-                    "sos send enqueue failed destination={destination_hex} reason={err}"
-                ),
-            });
+        )
+        .is_err()
+        {
+            return;
+        }
+        let responder = responder_from_response(
+            alert.incident_id,
+            status.lxmf_destination_hex.clone(),
+            kind,
+            None,
+            telemetry,
+            None,
+            now,
+        );
+        if let Ok(invalidation) = task.app_state.upsert_sos_responder(&responder) {
+            emit_projection_invalidation(&task.bus, invalidation);
         }
     }
+}
 
-    let next = if matches!(kind, SosMessageKind::Cancelled {}) {
-        idle_status()
-    } else {
-        active_status(incident_id, trigger_source, now)
-    };
-    if emit_sos_status(&app_state, &bus, &next, "sos-fanout-complete").is_err() {
-        return None;
+fn run_sos_update(dispatch: &SosDispatch<'_>, recipients: Vec<String>, current: &SosStatusRecord) {
+    dispatch_sos_messages(dispatch, recipients);
+
+    // Deactivation may have landed while the fanout was being queued.
+    let still_active = matches!(
+        dispatch.app_state.get_sos_status(),
+        Ok(Some(latest))
+            if matches!(latest.state, SosState::Active {})
+                && latest.incident_id.as_deref() == Some(dispatch.incident_id)
+    );
+    if still_active {
+        let _ = emit_sos_status(
+            dispatch.app_state,
+            dispatch.bus,
+            &updated_status(current, dispatch.now),
+            "sos-update-sent",
+        );
     }
-    Some(next)
 }
 
 pub struct Node {
@@ -2023,11 +2876,16 @@ impl Node {
                     completed_at_ms: None,
                     messages_received: 0,
                     detail: None,
+                    last_trigger: None,
+                    consecutive_failures: 0,
+                    next_sync_at_ms: None,
                 })),
                 hub_directory_snapshot: Arc::new(Mutex::new(None)),
                 sos_device_telemetry: Arc::new(Mutex::new(None)),
                 sos_detector: Arc::new(Mutex::new(SosTriggerDetector::new())),
+                telemetry_publisher: Arc::new(Mutex::new(TelemetryPublisherState::default())),
                 active_config: None,
+                hub_client: None,
                 runtime: None,
                 cmd_tx: None,
             }),
@@ -2124,6 +2982,7 @@ impl Node {
 
         let runtime = build_node_runtime()?;
         let (cmd_tx, cmd_rx) = mpsc::channel(COMMAND_QUEUE_CAPACITY);
+        let hub_client = HubHttpClient::from_config(&config);
 
         runtime.spawn(run_node(
             config,
@@ -2133,10 +2992,33 @@ impl Node {
             inner.peers_snapshot.clone(),
             inner.sync_status_snapshot.clone(),
             inner.hub_directory_snapshot.clone(),
+            hub_client.clone(),
             inner.bus.clone(),
             cmd_rx,
         ));
-
+        runtime.spawn(run_telemetry_publisher(TelemetryPublisherTask {
+            app_state: inner.app_state.clone(),
+            status: inner.status.clone(),
+            peers_snapshot: inner.peers_snapshot.clone(),
+            hub_directory_snapshot: inner.hub_directory_snapshot.clone(),
+            publisher: inner.telemetry_publisher.clone(),
+            active_config: config_fingerprint.clone(),
+            hub_client: hub_client.clone(),
+            cmd_tx: cmd_tx.clone(),
+            bus: inner.bus.clone(),
+        }));
+        runtime.spawn(run_sos_scheduler(SosSchedulerTask {
+            app_state: inner.app_state.clone(),
+            status: inner.status.clone(),
+            peers_snapshot: inner.peers_snapshot.clone(),
+            hub_directory_snapshot: inner.hub_directory_snapshot.clone(),
+            active_config: config_fingerprint.clone(),
+            sos_device_telemetry: inner.sos_device_telemetry.clone(),
+            cmd_tx: cmd_tx.clone(),
+            bus: inner.bus.clone(),
+        }));
+
+        inner.hub_client = hub_client;
         inner.runtime = Some(runtime);
         inner.cmd_tx = Some(cmd_tx);
         inner.active_config = Some(config_fingerprint);
@@ -2176,6 +3058,7 @@ impl Node {
         ) = {
             let mut inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
             inner.active_config = None;
+            inner.hub_client = None;
             (
                 inner.runtime.take(),
                 inner.cmd_tx.take(),
@@ -2217,6 +3100,9 @@ impl Node {
                 completed_at_ms: None,
                 messages_received: 0,
                 detail: None,
+                last_trigger: None,
+                consecutive_failures: 0,
+                next_sync_at_ms: None,
             };
         }
         if let Ok(mut guard) = hub_directory_snapshot.lock() {
@@ -2373,6 +3259,42 @@ impl Node {
         resp_rx
             .recv_timeout(SEND_COMMAND_TIMEOUT)
             .unwrap_or(Err(NodeError::Timeout {}))
+            .map(|_| ())
+    }
+
+    // Connected-mode pushes go to the hub REST API first. LXMF is only the
+    // fallback: in Connected mode every LXMF target routes to the hub anyway, so
+    // the sends are handed back to the caller only when the HTTP push fails.
+    fn push_to_hub_or_lxmf(
+        &self,
+        push: HubPush,
+        scheduled_sends: Vec<ScheduledMissionSend>,
+    ) -> Result<Vec<ScheduledMissionSend>, NodeError> {
+        let (hub_client, handle, bus) = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            let (Some(hub_client), Some(runtime)) =
+                (inner.hub_client.clone(), inner.runtime.as_ref())
+            else {
+                return Ok(scheduled_sends);
+            };
+            (hub_client, runtime.handle().clone(), inner.bus.clone())
+        };
+
+        let (resp_tx, resp_rx) = cb::bounded(1);
+        let request = push.clone();
+        handle.spawn(async move {
+            let _ = resp_tx.send(hub_client.push(&request).await);
+        });
+        match resp_rx
+            .recv_timeout(SEND_COMMAND_TIMEOUT)
+            .unwrap_or(Err(NodeError::Timeout {}))
+        {
+            Ok(()) => Ok(Vec::new()),
+            Err(err) => {
+                emit_hub_push_fallback(&bus, &push, err);
+                Ok(scheduled_sends)
+            }
+        }
     }
 
     pub fn broadcast_bytes(&self, bytes: Vec<u8>) -> Result<(), NodeError> {
@@ -2513,6 +3435,50 @@ impl Node {
         )
     }
 
+    // Replaces the text of one of our own messages here and on every copy we
+    // sent out.
+    pub fn edit_message(&self, message_id_hex: String, body_utf8: String) -> Result<(), NodeError> {
+        self.apply_message_action(message_id_hex, MessageActionKind::Edit { body_utf8 })
+    }
+
+    // Leaves a tombstone in place of one of our own messages, here and on
+    // every copy we sent out.
+    pub fn delete_message(&self, message_id_hex: String) -> Result<(), NodeError> {
+        self.apply_message_action(message_id_hex, MessageActionKind::Delete)
+    }
+
+    pub fn react_to_message(
+        &self,
+        message_id_hex: String,
+        reaction: MessageReaction,
+    ) -> Result<(), NodeError> {
+        self.apply_message_action(message_id_hex, MessageActionKind::React { reaction })
+    }
+
+    fn apply_message_action(
+        &self,
+        message_id_hex: String,
+        kind: MessageActionKind,
+    ) -> Result<(), NodeError> {
+        let tx = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            inner.cmd_tx.clone().ok_or(NodeError::NotRunning {})?
+        };
+
+        let (resp_tx, resp_rx) = cb::bounded(1);
+        dispatch_command(
+            &tx,
+            Command::ApplyMessageAction {
+                message_id_hex,
+                kind,
+                resp: resp_tx,
+            },
+        )?;
+        resp_rx
+            .recv_timeout(Duration::from_secs(10))
+            .unwrap_or(Err(NodeError::Timeout {}))
+    }
+
     pub fn cancel_lxmf(&self, message_id_hex: String) -> Result<(), NodeError> {
         let tx = {
             let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
@@ -2573,6 +3539,41 @@ impl Node {
             .unwrap_or(Err(NodeError::Timeout {}))
     }
 
+    // Tells the runtime whether the device has a network, e.g. when airplane
+    // mode is toggled. Scheduled syncs pause while offline and one runs as
+    // soon as the network comes back.
+    pub fn set_network_available(&self, available: bool) -> Result<(), NodeError> {
+        let tx = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            inner.cmd_tx.clone().ok_or(NodeError::NotRunning {})?
+        };
+
+        let (resp_tx, resp_rx) = cb::bounded(1);
+        dispatch_command(
+            &tx,
+            Command::SetNetworkAvailable {
+                available,
+                resp: resp_tx,
+            },
+        )?;
+        resp_rx
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap_or(Err(NodeError::Timeout {}))
+    }
+
+    pub fn list_propagation_nodes(&self) -> Result<Vec<PropagationNodeRecord>, NodeError> {
+        let tx = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            inner.cmd_tx.clone().ok_or(NodeError::NotRunning {})?
+        };
+
+        let (resp_tx, resp_rx) = cb::bounded(1);
+        dispatch_command(&tx, Command::ListPropagationNodes { resp: resp_tx })?;
+        resp_rx
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap_or(Err(NodeError::Timeout {}))
+    }
+
     pub fn list_announces(&self) -> Result<Vec<AnnounceRecord>, NodeError> {
         let tx = {
             let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
@@ -2621,7 +3622,7 @@ impl Node {
                     .lock()
                     .map_err(|_| NodeError::InternalError {})?
                     .clone();
-                let resolver = conversation_peer_resolver(&peers);
+                let resolver = ConversationPeerResolver::from_peers(&peers);
                 return inner.app_state.list_conversations_resolved(&resolver);
             }
         };
@@ -2650,7 +3651,7 @@ impl Node {
                     .lock()
                     .map_err(|_| NodeError::InternalError {})?
                     .clone();
-                let resolver = conversation_peer_resolver(&peers);
+                let resolver = ConversationPeerResolver::from_peers(&peers);
                 return inner
                     .app_state
                     .list_messages_resolved(conversation_id.as_deref(), &resolver);
@@ -2670,6 +3671,50 @@ impl Node {
             .unwrap_or(Err(NodeError::Timeout {}))
     }
 
+    pub fn list_messages_page(
+        &self,
+        conversation_id: String,
+        before_ms: Option<u64>,
+        before_message_id_hex: Option<String>,
+        limit: u32,
+    ) -> Result<Vec<MessageRecord>, NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        let peers = inner
+            .peers_snapshot
+            .lock()
+            .map_err(|_| NodeError::InternalError {})?
+            .clone();
+        let resolver = ConversationPeerResolver::from_peers(&peers);
+        inner.app_state.list_messages_page_resolved(
+            conversation_id.as_str(),
+            before_ms,
+            before_message_id_hex.as_deref(),
+            limit,
+            &resolver,
+        )
+    }
+
+    pub fn search_messages(
+        &self,
+        query: String,
+        conversation_id: Option<String>,
+        limit: u32,
+    ) -> Result<Vec<MessageRecord>, NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        let peers = inner
+            .peers_snapshot
+            .lock()
+            .map_err(|_| NodeError::InternalError {})?
+            .clone();
+        let resolver = ConversationPeerResolver::from_peers(&peers);
+        inner.app_state.search_messages_resolved(
+            query.as_str(),
+            conversation_id.as_deref(),
+            limit,
+            &resolver,
+        )
+    }
+
     pub fn delete_conversation(&self, conversation_id: String) -> Result<(), NodeError> {
         let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
         let peers = inner
@@ -2677,14 +3722,164 @@ impl Node {
             .lock()
             .map_err(|_| NodeError::InternalError {})?
             .clone();
-        let resolver = conversation_peer_resolver(&peers);
+        let resolver = ConversationPeerResolver::from_peers(&peers);
+        for invalidation in inner
+            .app_state
+            .delete_conversation_resolved(conversation_id.as_str(), &resolver)?
+        {
+            emit_projection_invalidation(&inner.bus, invalidation);
+        }
+        Ok(())
+    }
+
+    // Marks everything received so far in the conversation as read. With
+    // `send_read_receipts`, each sender is told which of its messages were
+    // read; receipts are best effort and only go out while the node runs.
+    pub fn mark_conversation_read(
+        &self,
+        conversation_id: String,
+        send_read_receipts: bool,
+    ) -> Result<(), NodeError> {
+        let (app_state, bus, resolver, tx) = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            let peers = inner
+                .peers_snapshot
+                .lock()
+                .map_err(|_| NodeError::InternalError {})?
+                .clone();
+            (
+                inner.app_state.clone(),
+                inner.bus.clone(),
+                ConversationPeerResolver::from_peers(&peers),
+                inner.cmd_tx.clone(),
+            )
+        };
+        let read_at_ms = now_ms();
+        let (newly_read, invalidations) = app_state.mark_conversation_read_resolved(
+            conversation_id.as_str(),
+            read_at_ms,
+            &resolver,
+        )?;
+        for invalidation in invalidations {
+            emit_projection_invalidation(&bus, invalidation);
+        }
+        let Some(tx) = tx.filter(|_| send_read_receipts) else {
+            return Ok(());
+        };
+
+        let mut by_sender = BTreeMap::<String, Vec<String>>::new();
+        for message in newly_read {
+            if let Some(source_hex) = message.source_hex.as_deref().and_then(normalize_hex_32) {
+                by_sender
+                    .entry(source_hex)
+                    .or_default()
+                    .push(message.message_id_hex);
+            }
+        }
+        for (destination_hex, message_ids) in by_sender {
+            let Ok(fields) = build_read_receipt_fields(&ReadReceipt {
+                message_ids,
+                read_at_ms,
+            }) else {
+                continue;
+            };
+            let (resp_tx, _resp_rx) = cb::bounded(1);
+            dispatch_command(
+                &tx,
+                Command::SendBytes {
+                    destination_hex,
+                    bytes: Vec::new(),
+                    fields_bytes: Some(fields),
+                    send_mode: SendMode::Auto {},
+                    resp: resp_tx,
+                },
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn upsert_group_conversation(
+        &self,
+        group: GroupConversationRecord,
+    ) -> Result<GroupConversationRecord, NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        let mut group = normalize_group_conversation(group, now_ms())?;
+        if let Some(existing) = inner
+            .app_state
+            .get_group_conversation(group.conversation_id.as_str())?
+        {
+            group.created_at_ms = existing.created_at_ms;
+        }
+        let invalidation = inner.app_state.upsert_group_conversation(&group)?;
+        emit_projection_invalidation(&inner.bus, invalidation);
+        Ok(group)
+    }
+
+    pub fn list_group_conversations(&self) -> Result<Vec<GroupConversationRecord>, NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        inner.app_state.list_group_conversations()
+    }
+
+    pub fn delete_group_conversation(&self, conversation_id: String) -> Result<(), NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        let conversation_id = conversation_id.trim().to_ascii_lowercase();
+        if !is_group_conversation_id(conversation_id.as_str()) {
+            return Err(NodeError::InvalidConfig {});
+        }
         for invalidation in inner
             .app_state
-            .delete_conversation_resolved(conversation_id.as_str(), &resolver)?
-        {
-            emit_projection_invalidation(&inner.bus, invalidation);
-        }
-        Ok(())
+            .delete_group_conversation(conversation_id.as_str())?
+        {
+            emit_projection_invalidation(&inner.bus, invalidation);
+        }
+        Ok(())
+    }
+
+    // Returns once the room message is stored; per-member copies go out in the
+    // background and report through `GroupDeliveryUpdated`.
+    pub fn send_group_message(
+        &self,
+        conversation_id: String,
+        body_utf8: String,
+        title: Option<String>,
+        priority: Option<MessagePriority>,
+    ) -> Result<String, NodeError> {
+        let (tx, group) = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            let tx = inner.cmd_tx.clone().ok_or(NodeError::NotRunning {})?;
+            let group = inner
+                .app_state
+                .get_group_conversation(conversation_id.trim().to_ascii_lowercase().as_str())?
+                .ok_or(NodeError::InvalidConfig {})?;
+            (tx, group)
+        };
+        let message_id_hex = new_group_message_id();
+
+        let (resp_tx, resp_rx) = cb::bounded(1);
+        dispatch_command(
+            &tx,
+            Command::SendGroupLxmf {
+                group,
+                message_id_hex,
+                body_utf8,
+                title,
+                priority,
+                resp: resp_tx,
+            },
+        )?;
+        resp_rx
+            .recv_timeout(SEND_COMMAND_TIMEOUT)
+            .unwrap_or(Err(NodeError::Timeout {}))
+    }
+
+    pub fn list_group_deliveries(
+        &self,
+        message_id_hex: String,
+    ) -> Result<Vec<GroupMemberDeliveryRecord>, NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        inner
+            .app_state
+            .list_group_deliveries(message_id_hex.as_str())
     }
 
     pub fn get_lxmf_sync_status(&self) -> Result<SyncStatus, NodeError> {
@@ -2711,6 +3906,19 @@ impl Node {
             .unwrap_or(Err(NodeError::Timeout {}))
     }
 
+    pub fn get_send_queue_metrics(&self) -> Result<Vec<SendQueueClassMetrics>, NodeError> {
+        let tx = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            inner.cmd_tx.clone().ok_or(NodeError::NotRunning {})?
+        };
+
+        let (resp_tx, resp_rx) = cb::bounded(1);
+        dispatch_command(&tx, Command::GetSendQueueMetrics { resp: resp_tx })?;
+        resp_rx
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap_or(Err(NodeError::Timeout {}))
+    }
+
     pub fn list_telemetry_destinations(&self) -> Result<Vec<String>, NodeError> {
         let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
         let status = inner
@@ -4109,15 +5317,36 @@ impl Node {
     }
 
     pub fn upsert_eam(&self, record: EamProjectionRecord) -> Result<(), NodeError> {
+        self.upsert_eam_for_roll_call(record, None)
+    }
+
+    fn upsert_eam_for_roll_call(
+        &self,
+        record: EamProjectionRecord,
+        roll_call_uid: Option<&str>,
+    ) -> Result<(), NodeError> {
         let mut scheduled_sends = Vec::<(String, Vec<u8>, Vec<u8>, SendMode)>::new();
-        let bus = {
+        let (bus, normalized_record) = {
             let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
             let status = inner
                 .status
                 .lock()
                 .map_err(|_| NodeError::InternalError {})?
                 .clone();
-            let normalized_record = populate_eam_defaults(&status, &record);
+            let active_schema = inner.app_state.get_active_eam_schema()?;
+            if let Some(schema) = active_schema.as_ref() {
+                validate_eam_record(schema, &record)?;
+            }
+            // A reporter listed in the team registry files under their own
+            // team rather than the one implied by the colour.
+            let mut record = record;
+            if is_blank(record.team_uid.as_deref()) {
+                let teams = inner.app_state.list_teams()?;
+                record.team_uid = team_for_member(&teams, status.identity_hex.as_str())
+                    .map(|team| team.team_uid.clone());
+            }
+            let schema = active_schema.unwrap_or_else(built_in_eam_schema);
+            let normalized_record = populate_eam_defaults_with_schema(&status, &record, &schema);
             let invalidation = inner.app_state.upsert_eam(&normalized_record)?;
             emit_projection_invalidation(&inner.bus, invalidation);
             let summary = inner.app_state.bump_projection_revision(
@@ -4153,7 +5382,13 @@ impl Node {
                     hub_directory_snapshot.as_ref(),
                 )?;
                 for target in replication_targets {
-                    match build_eam_replication_payload(&status, &normalized_record, &target) {
+                    match build_eam_replication_payload(
+                        &status,
+                        &normalized_record,
+                        &schema,
+                        &target,
+                        roll_call_uid,
+                    ) {
                         Ok((body, fields)) => {
                             scheduled_sends.push((
                                 target.app_destination_hex.clone(),
@@ -4175,9 +5410,11 @@ impl Node {
                 }
             }
 
-            inner.bus.clone()
+            (inner.bus.clone(), normalized_record)
         };
 
+        let scheduled_sends =
+            self.push_to_hub_or_lxmf(HubPush::UpsertEam(normalized_record), scheduled_sends)?;
         for (destination_hex, body, fields_bytes, send_mode) in scheduled_sends {
             if let Err(err) =
                 self.send_bytes(destination_hex.clone(), body, Some(fields_bytes), send_mode)
@@ -4261,32 +5498,478 @@ impl Node {
                 }
             }
 
-            inner.bus.clone()
+            inner.bus.clone()
+        };
+
+        let scheduled_sends = self.push_to_hub_or_lxmf(
+            HubPush::DeleteEam {
+                callsign: callsign.clone(),
+            },
+            scheduled_sends,
+        )?;
+        for (destination_hex, body, fields_bytes, send_mode) in scheduled_sends {
+            if let Err(err) =
+                self.send_bytes_sync(destination_hex.clone(), body, Some(fields_bytes), send_mode)
+            {
+                bus.emit(NodeEvent::Error {
+                    code: "NotRunning".to_string(),
+                    message: format!(
+                        "eam delete replication enqueue failed destination={} callsign={} reason={}",
+                        destination_hex, callsign, err
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get_eam_team_summary(
+        &self,
+        team_uid: String,
+    ) -> Result<Option<EamTeamSummaryRecord>, NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        inner.app_state.get_eam_team_summary(&team_uid)
+    }
+
+    pub fn get_eam_history(&self, callsign: String) -> Result<Vec<EamRevisionRecord>, NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        inner.app_state.get_eam_history(&callsign)
+    }
+
+    pub fn list_eam_schemas(&self) -> Result<Vec<EamSchemaRecord>, NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        inner.app_state.list_eam_schemas()
+    }
+
+    pub fn get_active_eam_schema(&self) -> Result<EamSchemaRecord, NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        Ok(inner
+            .app_state
+            .get_active_eam_schema()?
+            .unwrap_or_else(built_in_eam_schema))
+    }
+
+    pub fn upsert_eam_schema(&self, mut schema: EamSchemaRecord) -> Result<(), NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        if schema.schema_uid.trim() == built_in_eam_schema().schema_uid {
+            return Err(NodeError::InvalidConfig {});
+        }
+        schema.updated_at_ms = now_ms();
+        let invalidation = inner.app_state.upsert_eam_schema(&schema)?;
+        emit_projection_invalidation(&inner.bus, invalidation);
+        Ok(())
+    }
+
+    pub fn set_active_eam_schema(&self, schema_uid: String) -> Result<(), NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        let invalidation = inner.app_state.set_active_eam_schema(&schema_uid)?;
+        emit_projection_invalidation(&inner.bus, invalidation);
+        let summary = inner.app_state.bump_projection_revision(
+            ProjectionScope::OperationalSummary {},
+            None,
+            Some("eam-schema-activated".to_string()),
+        )?;
+        emit_projection_invalidation(&inner.bus, summary);
+        Ok(())
+    }
+
+    pub fn publish_eam_schema(&self, schema_uid: String) -> Result<(), NodeError> {
+        let mut scheduled_sends = Vec::<(String, Vec<u8>, Vec<u8>, SendMode)>::new();
+        let bus = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            if inner.cmd_tx.is_none() {
+                return Err(NodeError::NotRunning {});
+            }
+            let schema = inner
+                .app_state
+                .list_eam_schemas()?
+                .into_iter()
+                .find(|schema| schema.schema_uid == schema_uid.trim())
+                .ok_or(NodeError::InvalidConfig {})?;
+            let status = inner
+                .status
+                .lock()
+                .map_err(|_| NodeError::InternalError {})?
+                .clone();
+            let peers = inner
+                .peers_snapshot
+                .lock()
+                .map_err(|_| NodeError::InternalError {})?
+                .clone();
+            let hub_directory_snapshot = inner
+                .hub_directory_snapshot
+                .lock()
+                .map_err(|_| NodeError::InternalError {})?
+                .clone();
+            let saved_peers = inner.app_state.get_saved_peers()?;
+            let sync_status = inner
+                .sync_status_snapshot
+                .lock()
+                .map_err(|_| NodeError::InternalError {})?
+                .clone();
+            let replication_targets = build_runtime_mission_replication_targets(
+                &status,
+                peers.as_slice(),
+                saved_peers.as_slice(),
+                sync_status.active_propagation_node_hex.as_deref(),
+                inner.active_config.as_ref(),
+                hub_directory_snapshot.as_ref(),
+            )?;
+            for target in replication_targets {
+                let (body, fields) = build_eam_schema_payload(&schema, &target)?;
+                scheduled_sends.push((target.app_destination_hex, body, fields, target.send_mode));
+            }
+            inner.bus.clone()
+        };
+
+        for (destination_hex, body, fields_bytes, send_mode) in scheduled_sends {
+            if let Err(err) =
+                self.send_bytes(destination_hex.clone(), body, Some(fields_bytes), send_mode)
+            {
+                bus.emit(NodeEvent::Error {
+                    code: "NotRunning".to_string(),
+                    message: format!(
+                        "eam schema enqueue failed destination={} schema={} reason={}",
+                        destination_hex, schema_uid, err
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn list_teams(&self) -> Result<Vec<TeamRecord>, NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        Ok(inner
+            .app_state
+            .list_teams()?
+            .into_iter()
+            .filter(|team| team.deleted_at_ms.is_none())
+            .collect())
+    }
+
+    pub fn upsert_team(&self, mut team: TeamRecord) -> Result<(), NodeError> {
+        {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            team.team_uid = team.team_uid.trim().to_string();
+            team.name = team.name.trim().to_string();
+            team.parent_team_uid = team
+                .parent_team_uid
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string);
+            for member in &mut team.members {
+                member.rns_identity = member.rns_identity.trim().to_ascii_lowercase();
+            }
+            team.updated_at_ms = now_ms();
+            team.deleted_at_ms = None;
+            validate_team(&team, inner.app_state.list_teams()?.as_slice())?;
+            if let Some(invalidation) = inner.app_state.upsert_team(&team)? {
+                emit_projection_invalidation(&inner.bus, invalidation);
+            }
+        }
+        self.replicate_team(&team)
+    }
+
+    // Teams with live children cannot be removed; the children would drop out
+    // of every roll-up above them.
+    pub fn delete_team(&self, team_uid: String, deleted_at_ms: u64) -> Result<(), NodeError> {
+        let team = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            let teams = inner.app_state.list_teams()?;
+            let mut team = teams
+                .iter()
+                .find(|team| team.team_uid == team_uid.trim() && team.deleted_at_ms.is_none())
+                .cloned()
+                .ok_or(NodeError::InvalidConfig {})?;
+            if team_subtree_uids(&teams, team.team_uid.as_str()).len() > 1 {
+                return Err(NodeError::InvalidConfig {});
+            }
+            team.deleted_at_ms = Some(deleted_at_ms);
+            team.updated_at_ms = now_ms().max(deleted_at_ms);
+            if let Some(invalidation) = inner.app_state.upsert_team(&team)? {
+                emit_projection_invalidation(&inner.bus, invalidation);
+            }
+            team
+        };
+        self.replicate_team(&team)
+    }
+
+    pub fn get_team_rollups(&self) -> Result<Vec<TeamRollupRecord>, NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        inner.app_state.get_team_rollups()
+    }
+
+    fn replicate_team(&self, team: &TeamRecord) -> Result<(), NodeError> {
+        let mut scheduled_sends = Vec::<(String, Vec<u8>, Vec<u8>, SendMode)>::new();
+        let bus = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            if inner.cmd_tx.is_some() {
+                let status = inner
+                    .status
+                    .lock()
+                    .map_err(|_| NodeError::InternalError {})?
+                    .clone();
+                let peers = inner
+                    .peers_snapshot
+                    .lock()
+                    .map_err(|_| NodeError::InternalError {})?
+                    .clone();
+                let hub_directory_snapshot = inner
+                    .hub_directory_snapshot
+                    .lock()
+                    .map_err(|_| NodeError::InternalError {})?
+                    .clone();
+                let saved_peers = inner.app_state.get_saved_peers()?;
+                let sync_status = inner
+                    .sync_status_snapshot
+                    .lock()
+                    .map_err(|_| NodeError::InternalError {})?
+                    .clone();
+                let replication_targets = build_runtime_mission_replication_targets(
+                    &status,
+                    peers.as_slice(),
+                    saved_peers.as_slice(),
+                    sync_status.active_propagation_node_hex.as_deref(),
+                    inner.active_config.as_ref(),
+                    hub_directory_snapshot.as_ref(),
+                )?;
+                for target in replication_targets {
+                    let (body, fields) = build_team_payload(team, &target)?;
+                    scheduled_sends.push((
+                        target.app_destination_hex,
+                        body,
+                        fields,
+                        target.send_mode,
+                    ));
+                }
+            }
+            inner.bus.clone()
+        };
+
+        for (destination_hex, body, fields_bytes, send_mode) in scheduled_sends {
+            if let Err(err) =
+                self.send_bytes(destination_hex.clone(), body, Some(fields_bytes), send_mode)
+            {
+                bus.emit(NodeEvent::Error {
+                    code: "NotRunning".to_string(),
+                    message: format!(
+                        "team enqueue failed destination={} team={} reason={}",
+                        destination_hex, team.team_uid, err
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn request_eam_roll_call(
+        &self,
+        request: EamRollCallRequest,
+    ) -> Result<EamRollCallRecord, NodeError> {
+        let mut scheduled_sends = Vec::<(String, Vec<u8>, Vec<u8>, SendMode)>::new();
+        let (bus, roll_call) = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            if inner.cmd_tx.is_none() {
+                return Err(NodeError::NotRunning {});
+            }
+            let status = inner
+                .status
+                .lock()
+                .map_err(|_| NodeError::InternalError {})?
+                .clone();
+            let team_uid = request
+                .team_uid
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string);
+            let eams = inner
+                .app_state
+                .get_eams()?
+                .into_iter()
+                .filter(|record| record.deleted_at_ms.is_none())
+                .collect::<Vec<_>>();
+            let self_member_uid = status.app_destination_hex.trim();
+            let mut member_uids = request
+                .team_member_uids
+                .iter()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+                .collect::<Vec<_>>();
+            if member_uids.is_empty() {
+                let Some(team_uid) = team_uid.as_deref() else {
+                    return Err(NodeError::InvalidConfig {});
+                };
+                // A section or team roll call reaches everyone below it, both
+                // those who have reported and those listed in the registry.
+                let teams = inner.app_state.list_teams()?;
+                let subtree = team_subtree_uids(&teams, team_uid);
+                let in_subtree = |value: Option<&str>| {
+                    value.is_some_and(|value| subtree.iter().any(|uid| uid == value))
+                };
+                member_uids = eams
+                    .iter()
+                    .filter(|record| in_subtree(record.team_uid.as_deref()))
+                    .filter_map(|record| record.team_member_uid.clone())
+                    .chain(
+                        teams
+                            .iter()
+                            .filter(|team| {
+                                team.deleted_at_ms.is_none()
+                                    && in_subtree(Some(team.team_uid.as_str()))
+                            })
+                            .flat_map(|team| team.members.iter())
+                            .filter_map(|member| member.team_member_uid.clone()),
+                    )
+                    .filter(|member_uid| !member_uid.eq_ignore_ascii_case(self_member_uid))
+                    .collect();
+            }
+            let mut seen = HashSet::new();
+            member_uids.retain(|member_uid| seen.insert(member_uid.to_ascii_lowercase()));
+            if member_uids.is_empty() {
+                return Err(NodeError::InvalidConfig {});
+            }
+
+            let requested_at_ms = now_ms();
+            let due_in_seconds = request
+                .due_in_seconds
+                .filter(|value| *value > 0)
+                .unwrap_or(DEFAULT_EAM_ROLL_CALL_DUE_SECONDS);
+            let roll_call = EamRollCallRecord {
+                roll_call_uid: format!(
+                    "rollcall-{}-{requested_at_ms}",
+                    &self_member_uid[..self_member_uid.len().min(8)]
+                ),
+                direction: EamRollCallDirection::Outbound {},
+                team_uid,
+                requested_by: (!status.name.trim().is_empty())
+                    .then(|| status.name.trim().to_string()),
+                requester_hex: (!self_member_uid.is_empty()).then(|| self_member_uid.to_string()),
+                requested_at_ms,
+                due_at_ms: requested_at_ms
+                    .saturating_add(u64::from(due_in_seconds).saturating_mul(1000)),
+                members: member_uids
+                    .iter()
+                    .map(|member_uid| {
+                        let callsign = eams
+                            .iter()
+                            .find(|record| {
+                                record
+                                    .team_member_uid
+                                    .as_deref()
+                                    .is_some_and(|value| value.eq_ignore_ascii_case(member_uid))
+                            })
+                            .map(|record| record.callsign.as_str());
+                        pending_member(member_uid, callsign)
+                    })
+                    .collect(),
+                updated_at_ms: requested_at_ms,
+            };
+            inner.app_state.upsert_eam_roll_call(&roll_call)?;
+
+            let peers = inner
+                .peers_snapshot
+                .lock()
+                .map_err(|_| NodeError::InternalError {})?
+                .clone();
+            let hub_directory_snapshot = inner
+                .hub_directory_snapshot
+                .lock()
+                .map_err(|_| NodeError::InternalError {})?
+                .clone();
+            let saved_peers = inner.app_state.get_saved_peers()?;
+            let sync_status = inner
+                .sync_status_snapshot
+                .lock()
+                .map_err(|_| NodeError::InternalError {})?
+                .clone();
+            let replication_targets = build_runtime_mission_replication_targets(
+                &status,
+                peers.as_slice(),
+                saved_peers.as_slice(),
+                sync_status.active_propagation_node_hex.as_deref(),
+                inner.active_config.as_ref(),
+                hub_directory_snapshot.as_ref(),
+            )?;
+            let command = EamRollCallCommand::from_record(&roll_call);
+            for target in replication_targets {
+                let (body, fields) = build_eam_roll_call_payload(&command, &target)?;
+                scheduled_sends.push((target.app_destination_hex, body, fields, target.send_mode));
+            }
+
+            (inner.bus.clone(), roll_call)
         };
 
+        bus.emit(NodeEvent::EamRollCallUpdated {
+            roll_call: roll_call.clone(),
+        });
         for (destination_hex, body, fields_bytes, send_mode) in scheduled_sends {
             if let Err(err) =
-                self.send_bytes_sync(destination_hex.clone(), body, Some(fields_bytes), send_mode)
+                self.send_bytes(destination_hex.clone(), body, Some(fields_bytes), send_mode)
             {
                 bus.emit(NodeEvent::Error {
                     code: "NotRunning".to_string(),
                     message: format!(
-                        "eam delete replication enqueue failed destination={} callsign={} reason={}",
-                        destination_hex, callsign, err
+                        "eam roll call enqueue failed destination={} roll_call={} reason={}",
+                        destination_hex, roll_call.roll_call_uid, err
                     ),
                 });
             }
         }
 
+        Ok(roll_call)
+    }
+
+    // Publishes the local report in answer to a received roll call. The
+    // report replicates like any other EAM, tagged with the roll-call uid,
+    // and the request is marked answered.
+    pub fn respond_to_eam_roll_call(
+        &self,
+        roll_call_uid: String,
+        record: EamProjectionRecord,
+    ) -> Result<(), NodeError> {
+        let roll_call = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            inner.app_state.get_eam_roll_call(&roll_call_uid)?
+        };
+        let Some(mut roll_call) = roll_call
+            .filter(|roll_call| matches!(roll_call.direction, EamRollCallDirection::Inbound {}))
+        else {
+            return Err(NodeError::InvalidConfig {});
+        };
+        self.upsert_eam_for_roll_call(record, Some(roll_call.roll_call_uid.as_str()))?;
+
+        let responded_at_ms = now_ms();
+        for member in &mut roll_call.members {
+            member.state = EamRollCallMemberState::Responded {};
+            member.responded_at_ms = Some(responded_at_ms);
+        }
+        roll_call.updated_at_ms = responded_at_ms;
+        let bus = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            inner.app_state.upsert_eam_roll_call(&roll_call)?;
+            inner.bus.clone()
+        };
+        bus.emit(NodeEvent::EamRollCallUpdated { roll_call });
         Ok(())
     }
 
-    pub fn get_eam_team_summary(
+    pub fn get_eam_roll_call(
         &self,
-        team_uid: String,
-    ) -> Result<Option<EamTeamSummaryRecord>, NodeError> {
+        roll_call_uid: String,
+    ) -> Result<Option<EamRollCallRecord>, NodeError> {
         let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
-        inner.app_state.get_eam_team_summary(&team_uid)
+        inner.app_state.get_eam_roll_call(&roll_call_uid)
+    }
+
+    pub fn list_eam_roll_calls(&self) -> Result<Vec<EamRollCallRecord>, NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        inner.app_state.list_eam_roll_calls()
     }
 
     pub fn get_events(&self) -> Result<Vec<EventProjectionRecord>, NodeError> {
@@ -4363,6 +6046,8 @@ impl Node {
             inner.bus.clone()
         };
 
+        let scheduled_sends =
+            self.push_to_hub_or_lxmf(HubPush::UpsertEvent(record.clone()), scheduled_sends)?;
         for (destination_hex, body, fields_bytes, send_mode) in scheduled_sends {
             if let Err(err) =
                 self.send_bytes(destination_hex.clone(), body, Some(fields_bytes), send_mode)
@@ -4398,6 +6083,29 @@ impl Node {
         inner.app_state.get_telemetry_positions()
     }
 
+    pub fn get_telemetry_track(
+        &self,
+        callsign: String,
+        since_ms: Option<u64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<TelemetryPositionRecord>, NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        inner
+            .app_state
+            .get_telemetry_track(&callsign, since_ms, limit)
+    }
+
+    pub fn export_telemetry_track(
+        &self,
+        callsign: String,
+        since_ms: Option<u64>,
+        limit: Option<u32>,
+        format: TelemetryTrackFormat,
+    ) -> Result<String, NodeError> {
+        let track = self.get_telemetry_track(callsign.clone(), since_ms, limit)?;
+        Ok(export_telemetry_track(callsign.as_str(), &track, format))
+    }
+
     pub fn record_local_telemetry_fix(
         &self,
         position: TelemetryPositionRecord,
@@ -4419,6 +6127,20 @@ impl Node {
             )?;
             emit_projection_invalidation(&inner.bus, summary);
 
+            let telemetry_settings = inner
+                .app_state
+                .get_app_settings()?
+                .map(|settings| settings.telemetry);
+            let policy = TelemetryPublishPolicy::from_settings(telemetry_settings.as_ref());
+            let publish_now = inner
+                .telemetry_publisher
+                .lock()
+                .map_err(|_| NodeError::InternalError {})?
+                .record_fix(&policy, &position, now_ms());
+            if !publish_now {
+                return Ok(());
+            }
+
             if inner.cmd_tx.is_some() {
                 let peers = inner
                     .peers_snapshot
@@ -4430,38 +6152,21 @@ impl Node {
                     .lock()
                     .map_err(|_| NodeError::InternalError {})?
                     .clone();
-                let telemetry_destinations = build_runtime_telemetry_destinations(
+                scheduled_sends = build_telemetry_scheduled_sends(
                     &status,
                     peers.as_slice(),
                     inner.active_config.as_ref(),
                     hub_directory_snapshot.as_ref(),
+                    &position,
+                    &inner.bus,
                 )?;
-                for destination_hex in telemetry_destinations {
-                    let target = MissionReplicationTarget {
-                        app_destination_hex: destination_hex,
-                        send_mode: SendMode::Auto {},
-                    };
-                    match build_telemetry_replication_payload(&position, &target) {
-                        Ok((body, fields)) => scheduled_sends.push((
-                            target.app_destination_hex,
-                            body,
-                            fields,
-                            target.send_mode,
-                        )),
-                        Err(err) => inner.bus.emit(NodeEvent::Error {
-                            code: "InvalidConfig".to_string(),
-                            message: format!(
-                                "telemetry replication skipped destination={} callsign={} reason={}",
-                                target.app_destination_hex, position.callsign, err
-                            ),
-                        }),
-                    }
-                }
             }
 
             inner.bus.clone()
         };
 
+        let scheduled_sends =
+            self.push_to_hub_or_lxmf(HubPush::Telemetry(position.clone()), scheduled_sends)?;
         for (destination_hex, body, fields_bytes, send_mode) in scheduled_sends {
             if let Err(err) =
                 self.send_bytes(destination_hex.clone(), body, Some(fields_bytes), send_mode)
@@ -4523,6 +6228,116 @@ impl Node {
             .unwrap_or_else(idle_status))
     }
 
+    pub fn get_sos_delivery_report(
+        &self,
+        incident_id: String,
+    ) -> Result<SosDeliveryReport, NodeError> {
+        let incident_id = incident_id.trim();
+        if incident_id.is_empty() {
+            return Err(NodeError::InvalidConfig {});
+        }
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        let recipients = inner.app_state.list_sos_deliveries(incident_id)?;
+        Ok(sos_delivery_report(incident_id, recipients))
+    }
+
+    // Answers a received alert directly to its originator; the responder's
+    // latest position rides along so the originator can see who is closest.
+    pub fn respond_to_sos(
+        &self,
+        incident_id: String,
+        kind: SosResponseKind,
+        eta_seconds: Option<u32>,
+    ) -> Result<(), NodeError> {
+        let incident_id = incident_id.trim().to_string();
+        if incident_id.is_empty() {
+            return Err(NodeError::InvalidConfig {});
+        }
+        let (app_state, bus, status, alert, telemetry) = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            let alert = inner
+                .app_state
+                .list_sos_alerts()?
+                .into_iter()
+                .find(|alert| alert.incident_id == incident_id)
+                .ok_or(NodeError::InvalidConfig {})?;
+            let status = inner
+                .status
+                .lock()
+                .map_err(|_| NodeError::InternalError {})?
+                .clone();
+            let telemetry = latest_sos_telemetry(&inner.sos_device_telemetry);
+            (
+                inner.app_state.clone(),
+                inner.bus.clone(),
+                status,
+                alert,
+                telemetry,
+            )
+        };
+
+        let now = now_ms();
+        let response = SosResponse {
+            kind,
+            incident_id: incident_id.clone(),
+            eta_seconds,
+            sent_at_ms: now,
+        };
+        let fields = build_sos_response_fields(&response, telemetry.as_ref())?;
+        let body = compose_sos_response_body(kind, eta_seconds);
+        self.send_bytes_sync(
+            alert.source_hex.clone(),
+            body.as_bytes().to_vec(),
+            Some(fields),
+            SendMode::Auto {},
+        )?;
+
+        let record = canonicalize_chat_message(&MessageRecord {
+            message_id_hex: format!("{incident_id}-response-{now}"),
+            conversation_id: alert.conversation_id.clone(),
+            direction: MessageDirection::Outbound {},
+            destination_hex: alert.source_hex.clone(),
+            source_hex: Some(status.lxmf_destination_hex.clone()),
+            title: None,
+            body_utf8: body,
+            method: MessageMethod::Direct {},
+            state: MessageState::SentDirect {},
+            detail: Some("sos-response".to_string()),
+            sent_at_ms: Some(now),
+            received_at_ms: None,
+            updated_at_ms: now,
+            attachments: Vec::new(),
+            edited_at_ms: None,
+            deleted_at_ms: None,
+            reactions: Vec::new(),
+        });
+        for invalidation in app_state.upsert_message(&record)? {
+            emit_projection_invalidation(&bus, invalidation);
+        }
+        bus.emit(NodeEvent::MessageUpdated { message: record });
+
+        let responder = responder_from_response(
+            incident_id,
+            status.lxmf_destination_hex,
+            kind,
+            eta_seconds,
+            telemetry.as_ref(),
+            None,
+            now,
+        );
+        emit_projection_invalidation(&bus, app_state.upsert_sos_responder(&responder)?);
+        bus.emit(NodeEvent::SosResponderUpdated { responder });
+        Ok(())
+    }
+
+    pub fn list_sos_responders(
+        &self,
+        incident_id: String,
+    ) -> Result<Vec<SosResponderRecord>, NodeError> {
+        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+        inner.app_state.list_sos_responders(incident_id.trim())
+    }
+
     pub fn list_sos_alerts(&self) -> Result<Vec<SosAlertRecord>, NodeError> {
         let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
         inner.app_state.list_sos_alerts()
@@ -4538,10 +6353,110 @@ impl Node {
         inner.app_state.list_sos_audio()
     }
 
+    // Clips recorded for our own live incident are also sent to the incident's
+    // recipients; any other clip is only kept locally.
     pub fn record_sos_audio(&self, audio: SosAudioRecord) -> Result<(), NodeError> {
-        let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
-        let invalidation = inner.app_state.upsert_sos_audio(&audio)?;
-        emit_projection_invalidation(&inner.bus, invalidation);
+        let (runtime, bus, tx, recipients) = {
+            let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
+            let invalidation = inner.app_state.upsert_sos_audio(&audio)?;
+            emit_projection_invalidation(&inner.bus, invalidation);
+            let current = inner
+                .app_state
+                .get_sos_status()?
+                .unwrap_or_else(idle_status);
+            if matches!(current.state, SosState::Idle {})
+                || current.incident_id.as_deref() != Some(audio.incident_id.as_str())
+            {
+                return Ok(());
+            }
+            let (Some(runtime), Some(tx)) = (inner.runtime.as_ref(), inner.cmd_tx.clone()) else {
+                return Ok(());
+            };
+            let settings = inner
+                .app_state
+                .get_sos_settings()?
+                .map(normalize_sos_settings)
+                .unwrap_or_else(default_sos_settings);
+            let status = inner
+                .status
+                .lock()
+                .map_err(|_| NodeError::InternalError {})?
+                .clone();
+            let recipients = resolve_sos_recipients(
+                &inner.app_state,
+                &settings,
+                &status,
+                &inner.peers_snapshot,
+                inner.active_config.as_ref(),
+                &inner.hub_directory_snapshot,
+            )?;
+            (runtime.handle().clone(), inner.bus.clone(), tx, recipients)
+        };
+
+        // The clip stays recorded either way; only what is sent is limited.
+        let prepared = fs_err::metadata(audio.path.as_str())
+            .map_err(|_| NodeError::IoError {})
+            .and_then(|metadata| validate_sos_audio(audio.mime_type.as_str(), metadata.len()))
+            .and_then(|extension| {
+                fs_err::read(audio.path.as_str())
+                    .map(|bytes| (extension, bytes))
+                    .map_err(|_| NodeError::IoError {})
+            });
+        let (extension, bytes) = match prepared {
+            Ok(prepared) => prepared,
+            Err(err) => {
+                bus.emit(NodeEvent::Error {
+                    code: match err {
+                        NodeError::IoError {} => "IoError",
+                        _ => "InvalidConfig",
+                    }
+                    .to_string(),
+                    message: format!(
+                        "sos audio not sent audio_id={} reason={err}",
+                        audio.audio_id
+                    ),
+                });
+                return Ok(());
+            }
+        };
+        let clip = SosAudioClip {
+            audio_id: audio.audio_id.clone(),
+            incident_id: audio.incident_id.clone(),
+            mime_type: audio.mime_type.clone(),
+            duration_seconds: audio.duration_seconds,
+            sent_at_ms: now_ms(),
+            bytes,
+        };
+        let fields = build_sos_audio_fields(
+            &clip,
+            sos_audio_file_name(audio.audio_id.as_str(), extension).as_str(),
+        )?;
+        let body = format!("Voice clip attached ({} s)", audio.duration_seconds);
+        for destination_hex in recipients {
+            let (resp_tx, resp_rx) = cb::bounded(1);
+            dispatch_command(
+                &tx,
+                Command::SendBytes {
+                    destination_hex: destination_hex.clone(),
+                    bytes: body.as_bytes().to_vec(),
+                    fields_bytes: Some(fields.clone()),
+                    send_mode: SendMode::Auto {},
+                    resp: resp_tx,
+                },
+            )?;
+            let bus = bus.clone();
+            let audio_id = audio.audio_id.clone();
+            runtime.spawn(async move {
+                if let Err(err) = await_command_reply(resp_rx, SEND_COMMAND_TIMEOUT).await {
+                    bus.emit(NodeEvent::Error {
+                        code: "NetworkError".to_string(),
+                        message: format!(
+                            "sos audio send failed audio_id={audio_id} destination={destination_hex} reason={err}"
+                        ),
+                    });
+                }
+            });
+        }
         Ok(())
     }
 
@@ -4607,7 +6522,7 @@ impl Node {
     }
 
     pub fn trigger_sos(&self, source: SosTriggerSource) -> Result<SosStatusRecord, NodeError> {
-        let (app_state, bus, tx, status, settings, saved_peers, telemetry_store) = {
+        let (runtime, app_state, bus, tx, status, settings, recipients, telemetry_store) = {
             let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
             let settings = inner
                 .app_state
@@ -4629,13 +6544,26 @@ impl Node {
                 .lock()
                 .map_err(|_| NodeError::InternalError {})?
                 .clone();
+            let recipients = resolve_sos_recipients(
+                &inner.app_state,
+                &settings,
+                &status,
+                &inner.peers_snapshot,
+                inner.active_config.as_ref(),
+                &inner.hub_directory_snapshot,
+            )?;
             let values = (
+                inner
+                    .runtime
+                    .as_ref()
+                    .map(|runtime| runtime.handle().clone())
+                    .ok_or(NodeError::NotRunning {})?,
                 inner.app_state.clone(),
                 inner.bus.clone(),
                 inner.cmd_tx.clone().ok_or(NodeError::NotRunning {})?,
                 status,
                 settings,
-                inner.app_state.get_saved_peers()?,
+                recipients,
                 inner.sos_device_telemetry.clone(),
             );
             values
@@ -4647,19 +6575,21 @@ impl Node {
             let deadline = now_ms().saturating_add(u64::from(countdown) * 1000);
             let countdown_record = countdown_status(incident_id.clone(), source, deadline);
             emit_sos_status(&app_state, &bus, &countdown_record, "sos-countdown")?;
-            std::thread::spawn(move || {
-                std::thread::sleep(Duration::from_secs(u64::from(countdown)));
+            let countdown_runtime = runtime.clone();
+            runtime.spawn(async move {
+                tokio::time::sleep(Duration::from_secs(u64::from(countdown))).await;
                 if !app_state_has_pending_sos_countdown(&app_state, incident_id.as_str()) {
                     return;
                 }
                 let telemetry = latest_sos_telemetry(&telemetry_store);
                 run_sos_fanout(
+                    &countdown_runtime,
                     app_state,
                     bus,
                     tx,
                     status,
                     settings,
-                    saved_peers,
+                    recipients,
                     telemetry,
                     incident_id,
                     source,
@@ -4671,12 +6601,13 @@ impl Node {
 
         let telemetry = latest_sos_telemetry(&telemetry_store);
         let active = run_sos_fanout(
+            &runtime,
             app_state,
             bus,
             tx,
             status,
             settings,
-            saved_peers,
+            recipients,
             telemetry,
             incident_id,
             source,
@@ -4687,7 +6618,7 @@ impl Node {
     }
 
     pub fn deactivate_sos(&self, pin: Option<String>) -> Result<SosStatusRecord, NodeError> {
-        let (app_state, bus, tx, status, settings, saved_peers, telemetry, current) = {
+        let (runtime, app_state, bus, tx, status, settings, recipients, telemetry, current) = {
             let inner = self.inner.lock().map_err(|_| NodeError::InternalError {})?;
             let settings = inner
                 .app_state
@@ -4711,13 +6642,26 @@ impl Node {
                 .app_state
                 .get_sos_status()?
                 .unwrap_or_else(idle_status);
+            let recipients = resolve_sos_recipients(
+                &inner.app_state,
+                &settings,
+                &status,
+                &inner.peers_snapshot,
+                inner.active_config.as_ref(),
+                &inner.hub_directory_snapshot,
+            )?;
             let values = (
+                inner
+                    .runtime
+                    .as_ref()
+                    .map(|runtime| runtime.handle().clone())
+                    .ok_or(NodeError::NotRunning {})?,
                 inner.app_state.clone(),
                 inner.bus.clone(),
                 inner.cmd_tx.clone().ok_or(NodeError::NotRunning {})?,
                 status,
                 settings,
-                inner.app_state.get_saved_peers()?,
+                recipients,
                 telemetry,
                 current,
             );
@@ -4728,12 +6672,13 @@ impl Node {
             .clone()
             .unwrap_or_else(|| new_incident_id(status.identity_hex.as_str()));
         run_sos_fanout(
+            &runtime,
             app_state.clone(),
             bus.clone(),
             tx,
             status,
             settings,
-            saved_peers,
+            recipients,
             telemetry,
             incident_id,
             SosTriggerSource::Manual {},
@@ -4844,10 +6789,11 @@ impl EventSubscription {
 mod tests {
     use super::*;
 
+    use crate::eam_roll_call::{parse_eam_roll_call_fields, parse_eam_roll_call_uid};
     use crate::mission_sync::parse_mission_sync_metadata;
     use crate::types::{
-        ChecklistTaskRecord, EamSourceRecord, HubSettingsRecord, MessageDirection, MessageMethod,
-        MessageState, TelemetrySettingsRecord,
+        sample_eam, ChecklistTaskRecord, EamSourceRecord, HubSettingsRecord, MessageDirection,
+        MessageMethod, MessageState, TelemetrySettingsRecord,
     };
     use crate::HubMode;
     use rmpv::Value as MsgPackValue;
@@ -5432,6 +7378,7 @@ mod tests {
                 refresh_interval_seconds: 3600,
             },
             checklists: crate::types::ChecklistSettingsRecord::default(),
+            propagation_sync: crate::types::PropagationSyncSettingsRecord::default(),
         }
     }
 
@@ -5589,6 +7536,102 @@ mod tests {
         assert!(matches!(err, NodeError::InvalidConfig {}));
     }
 
+    #[test]
+    fn sos_recipient_policy_combines_contacts_team_and_hub() {
+        let status = build_status_for_tests();
+        let config = build_config_fingerprint_for_tests(
+            HubMode::SemiAutonomous {},
+            Some("56565656565656565656565656565656"),
+        );
+        let saved_peers = vec![SavedPeerRecord {
+            destination_hex: "dddddddddddddddddddddddddddddddd".to_string(),
+            label: None,
+            saved_at_ms: 1,
+        }];
+        let peers = vec![
+            build_peer_record(
+                "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb",
+                false,
+                true,
+                true,
+            ),
+            build_peer_record(
+                "cccccccccccccccccccccccccccccccc",
+                "ffffffffffffffffffffffffffffffff",
+                false,
+                true,
+                true,
+            ),
+        ];
+        let mut local_eam = build_eam();
+        local_eam.source = Some(EamSourceRecord {
+            rns_identity: status.identity_hex.clone(),
+            display_name: None,
+        });
+        let mut member_eam = build_eam();
+        member_eam.callsign = "MEDIC".to_string();
+        member_eam.source = Some(EamSourceRecord {
+            rns_identity: "identity-aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_string(),
+            display_name: None,
+        });
+        let eams = vec![local_eam, member_eam];
+        let policy = SosRecipientPolicyRecord {
+            saved_peers: true,
+            contacts: vec![
+                "EEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEE".to_string(),
+                status.app_destination_hex.clone(),
+            ],
+            same_team: true,
+            team_uid: None,
+            hub: true,
+            r3akt_peers: false,
+        };
+        let sources = SosRecipientSources {
+            saved_peers: saved_peers.as_slice(),
+            peers: peers.as_slice(),
+            eams: eams.as_slice(),
+            active_config: Some(&config),
+            hub_directory_snapshot: None,
+        };
+
+        assert_eq!(
+            build_sos_recipient_destinations(&status, &policy, &sources),
+            vec![
+                "dddddddddddddddddddddddddddddddd".to_string(),
+                "eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee".to_string(),
+                "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_string(),
+                "56565656565656565656565656565656".to_string(),
+            ]
+        );
+
+        let r3akt_only = SosRecipientPolicyRecord {
+            saved_peers: false,
+            r3akt_peers: true,
+            ..SosRecipientPolicyRecord::default()
+        };
+        assert_eq!(
+            build_sos_recipient_destinations(&status, &r3akt_only, &sources),
+            vec![
+                "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_string(),
+                "cccccccccccccccccccccccccccccccc".to_string(),
+            ]
+        );
+
+        let connected = build_config_fingerprint_for_tests(
+            HubMode::Connected {},
+            Some("56565656565656565656565656565656"),
+        );
+        let connected_sources = SosRecipientSources {
+            active_config: Some(&connected),
+            ..sources
+        };
+        assert_eq!(
+            build_sos_recipient_destinations(&status, &r3akt_only, &connected_sources),
+            vec!["56565656565656565656565656565656".to_string()]
+        );
+    }
+
     #[test]
     fn semi_autonomous_telemetry_destinations_use_hub_snapshot() {
         let status = build_status_for_tests();
@@ -5679,7 +7722,6 @@ mod tests {
         EamProjectionRecord {
             callsign: "POCO".to_string(),
             group_name: "Blue".to_string(),
-            security_status: "Green".to_string(),
             capability_status: "Yellow".to_string(),
             preparedness_status: "Green".to_string(),
             medical_status: "Green".to_string(),
@@ -5687,23 +7729,18 @@ mod tests {
             comms_status: "Yellow".to_string(),
             notes: Some("pre-start eam".to_string()),
             updated_at_ms: 1_700_000_000_100,
-            deleted_at_ms: None,
-            eam_uid: Some("eam-1".to_string()),
-            team_member_uid: Some("member-1".to_string()),
-            team_uid: Some("team-1".to_string()),
             reported_at: Some("2026-03-25T00:00:00Z".to_string()),
             reported_by: Some("Atlas-1".to_string()),
             overall_status: Some("Yellow".to_string()),
             confidence: Some(0.9),
-            ttl_seconds: Some(3600),
             source: Some(EamSourceRecord {
                 rns_identity: "identity-1".to_string(),
                 display_name: Some("Atlas-1".to_string()),
             }),
             sync_state: Some("draft".to_string()),
-            sync_error: None,
             draft_created_at_ms: Some(1_700_000_000_100),
             last_synced_at_ms: None,
+            ..sample_eam()
         }
     }
 
@@ -5723,7 +7760,8 @@ mod tests {
         };
 
         let (_, fields) =
-            build_eam_replication_payload(&status, &record, &target).expect("eam fields");
+            build_eam_replication_payload(&status, &record, &built_in_eam_schema(), &target, None)
+                .expect("eam fields");
         let metadata = parse_mission_sync_metadata(&fields).expect("mission metadata");
 
         assert_eq!(
@@ -5738,6 +7776,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn eam_roll_calls_track_each_target_and_reports_carry_the_roll_call() {
+        let roll_call = EamRollCallRecord {
+            roll_call_uid: "rollcall-1".to_string(),
+            direction: EamRollCallDirection::Outbound {},
+            team_uid: Some("team-1".to_string()),
+            requested_by: Some("Atlas-1".to_string()),
+            requester_hex: None,
+            requested_at_ms: 1_000,
+            due_at_ms: 5_000,
+            members: vec![
+                pending_member("member-1", Some("POCO")),
+                pending_member("member-2", Some("ROMEO")),
+            ],
+            updated_at_ms: 1_000,
+        };
+        let command = EamRollCallCommand::from_record(&roll_call);
+        let target = |app_destination_hex: &str| MissionReplicationTarget {
+            app_destination_hex: app_destination_hex.to_string(),
+            send_mode: SendMode::Auto {},
+        };
+
+        let (_, first) =
+            build_eam_roll_call_payload(&command, &target("aaaaaaaa11")).expect("first payload");
+        let (_, second) =
+            build_eam_roll_call_payload(&command, &target("bbbbbbbb22")).expect("second payload");
+        let correlation_id = |fields: &[u8]| {
+            parse_mission_sync_metadata(fields).and_then(|metadata| metadata.correlation_id)
+        };
+        assert_ne!(correlation_id(&first), correlation_id(&second));
+        let parsed = parse_eam_roll_call_fields(first.as_slice()).expect("roll call");
+        assert_eq!(parsed, command);
+        assert!(parsed.addresses("MEMBER-2", None));
+        assert!(!parsed.addresses("member-3", Some("team-1")));
+        let team_wide = EamRollCallCommand {
+            team_member_uids: Vec::new(),
+            ..parsed
+        };
+        assert!(team_wide.addresses("member-3", Some("team-1")));
+
+        let status = build_status_for_tests();
+        let record = build_eam();
+        let (_, report) = build_eam_replication_payload(
+            &status,
+            &record,
+            &built_in_eam_schema(),
+            &target("aaaaaaaa11"),
+            Some("rollcall-1"),
+        )
+        .expect("report payload");
+        assert_eq!(
+            parse_eam_roll_call_uid(report.as_slice()).as_deref(),
+            Some("rollcall-1")
+        );
+        assert_ne!(correlation_id(&report).as_deref(), Some("rollcall-1"));
+        let (_, unsolicited) = build_eam_replication_payload(
+            &status,
+            &record,
+            &built_in_eam_schema(),
+            &target("cccccccc33"),
+            None,
+        )
+        .expect("unsolicited payload");
+        assert_eq!(parse_eam_roll_call_uid(unsolicited.as_slice()), None);
+    }
+
     #[test]
     fn populate_eam_defaults_uses_local_app_hash_and_team_color_hash() {
         let status = NodeStatus {
@@ -5811,6 +7915,10 @@ mod tests {
             sent_at_ms: Some(1_700_000_000_300),
             received_at_ms: None,
             updated_at_ms: 1_700_000_000_300,
+            attachments: Vec::new(),
+            edited_at_ms: None,
+            deleted_at_ms: None,
+            reactions: Vec::new(),
         }
     }
 
@@ -5824,6 +7932,7 @@ mod tests {
             speed: Some(3.5),
             accuracy: Some(5.0),
             updated_at_ms: 1_700_000_000_400,
+            stale: false,
         }
     }
 
@@ -5887,6 +7996,7 @@ mod tests {
                 refresh_interval_seconds: 0,
             },
             checklists: crate::types::ChecklistSettingsRecord::default(),
+            propagation_sync: crate::types::PropagationSyncSettingsRecord::default(),
         }
     }
 
@@ -5898,35 +8008,6 @@ mod tests {
         }
     }
 
-    fn sample_eam() -> EamProjectionRecord {
-        EamProjectionRecord {
-            callsign: "ALPHA-1".to_string(),
-            group_name: "Operations".to_string(),
-            security_status: "Green".to_string(),
-            capability_status: "Ready".to_string(),
-            preparedness_status: "Ready".to_string(),
-            medical_status: "Ready".to_string(),
-            mobility_status: "Ready".to_string(),
-            comms_status: "Ready".to_string(),
-            notes: Some("pre-start import".to_string()),
-            updated_at_ms: 1,
-            deleted_at_ms: None,
-            eam_uid: Some("eam-1".to_string()),
-            team_member_uid: Some("member-1".to_string()),
-            team_uid: Some("team-1".to_string()),
-            reported_at: None,
-            reported_by: None,
-            overall_status: Some("Green".to_string()),
-            confidence: Some(1.0),
-            ttl_seconds: Some(3600),
-            source: None,
-            sync_state: Some("Synced".to_string()),
-            sync_error: None,
-            draft_created_at_ms: Some(1),
-            last_synced_at_ms: Some(1),
-        }
-    }
-
     fn sample_event() -> EventProjectionRecord {
         EventProjectionRecord {
             uid: "event-1".to_string(),
@@ -5964,6 +8045,10 @@ mod tests {
             sent_at_ms: Some(1),
             received_at_ms: None,
             updated_at_ms: 1,
+            attachments: Vec::new(),
+            edited_at_ms: None,
+            deleted_at_ms: None,
+            reactions: Vec::new(),
         }
     }
 
@@ -5977,6 +8062,7 @@ mod tests {
             speed: Some(3.0),
             accuracy: Some(5.0),
             updated_at_ms: 1,
+            stale: false,
         }
     }
 
@@ -6064,6 +8150,10 @@ mod tests {
                 body_utf8: "hello".to_string(),
                 title: Some("test".to_string()),
                 send_mode: SendMode::Auto {},
+                attachments: Vec::new(),
+                send_at_ms: None,
+                expires_at_ms: None,
+                priority: None,
             }),
             Err(NodeError::NotRunning {})
         ));
@@ -6204,6 +8294,10 @@ mod tests {
                 body_utf8: body.to_string(),
                 title: Some("chat".to_string()),
                 send_mode: SendMode::Auto {},
+                attachments: Vec::new(),
+                send_at_ms: None,
+                expires_at_ms: None,
+                priority: None,
             })
             .expect("send chat message");
         let event = wait_for_event(&subscription, TEST_TIMEOUT, |event| {
@@ -6340,6 +8434,10 @@ mod tests {
                 body_utf8: "hello".to_string(),
                 title: None,
                 send_mode: SendMode::Auto {},
+                attachments: Vec::new(),
+                send_at_ms: None,
+                expires_at_ms: None,
+                priority: None,
             }),
             Err(NodeError::NotRunning {})
         ));
@@ -6574,13 +8672,21 @@ mod tests {
             sync_error: None,
             draft_created_at_ms: Some(now_ms()),
             last_synced_at_ms: None,
+            expired: false,
+            custom_statuses: Vec::new(),
         };
         let target = MissionReplicationTarget {
             app_destination_hex: node_b_status.app_destination_hex.clone(),
             send_mode: SendMode::Auto {},
         };
-        let (body, fields) =
-            build_eam_replication_payload(&node_a_status, &record, &target).expect("eam payload");
+        let (body, fields) = build_eam_replication_payload(
+            &node_a_status,
+            &record,
+            &built_in_eam_schema(),
+            &target,
+            None,
+        )
+        .expect("eam payload");
 
         node_a
             .send_bytes(
@@ -6635,6 +8741,7 @@ mod tests {
             speed: Some(0.0),
             accuracy: Some(5.0),
             updated_at_ms: now_ms(),
+            stale: false,
         };
         let target = MissionReplicationTarget {
             app_destination_hex: node_b_status.app_destination_hex.clone(),
@@ -6697,6 +8804,10 @@ mod tests {
                 body_utf8: "warm telemetry link".to_string(),
                 title: Some("warmup".to_string()),
                 send_mode: SendMode::Auto {},
+                attachments: Vec::new(),
+                send_at_ms: None,
+                expires_at_ms: None,
+                priority: None,
             })
             .expect("warm telemetry link");
         wait_for_event(&warm_link_subscription, TEST_TIMEOUT, |event| {
@@ -6731,6 +8842,7 @@ mod tests {
             speed: Some(0.0),
             accuracy: Some(5.0),
             updated_at_ms: now_ms(),
+            stale: false,
         };
 
         node_a
@@ -6830,6 +8942,10 @@ mod tests {
                 body_utf8: "warm eam link".to_string(),
                 title: Some("warmup".to_string()),
                 send_mode: SendMode::Auto {},
+                attachments: Vec::new(),
+                send_at_ms: None,
+                expires_at_ms: None,
+                priority: None,
             })
             .expect("warm eam link");
         wait_for_event(&warm_link_subscription, TEST_TIMEOUT, |event| {
@@ -6908,6 +9024,8 @@ mod tests {
             sync_error: None,
             draft_created_at_ms: Some(now_ms()),
             last_synced_at_ms: None,
+            expired: false,
+            custom_statuses: Vec::new(),
         };
 
         node_a.upsert_eam(record.clone()).expect("upsert local eam");
@@ -6978,6 +9096,10 @@ mod tests {
                 body_utf8: "warm eam defaults link".to_string(),
                 title: Some("warmup".to_string()),
                 send_mode: SendMode::Auto {},
+                attachments: Vec::new(),
+                send_at_ms: None,
+                expires_at_ms: None,
+                priority: None,
             })
             .expect("warm eam defaults link");
         wait_for_event(&warm_link_subscription, TEST_TIMEOUT, |event| {
@@ -7028,6 +9150,8 @@ mod tests {
             sync_error: None,
             draft_created_at_ms: Some(now_ms()),
             last_synced_at_ms: None,
+            expired: false,
+            custom_statuses: Vec::new(),
         };
 
         node_a.upsert_eam(record.clone()).expect("upsert local eam");
@@ -7098,6 +9222,10 @@ mod tests {
                 body_utf8: "warm eam delete link".to_string(),
                 title: Some("warmup".to_string()),
                 send_mode: SendMode::Auto {},
+                attachments: Vec::new(),
+                send_at_ms: None,
+                expires_at_ms: None,
+                priority: None,
             })
             .expect("warm eam delete link");
         wait_for_event(&warm_link_subscription, TEST_TIMEOUT, |event| {
@@ -7151,6 +9279,8 @@ mod tests {
             sync_error: None,
             draft_created_at_ms: Some(now_ms()),
             last_synced_at_ms: None,
+            expired: false,
+            custom_statuses: Vec::new(),
         };
 
         node_a.upsert_eam(record.clone()).expect("upsert local eam");
@@ -7230,6 +9360,10 @@ mod tests {
                 body_utf8: "warm event link".to_string(),
                 title: Some("warmup".to_string()),
                 send_mode: SendMode::Auto {},
+                attachments: Vec::new(),
+                send_at_ms: None,
+                expires_at_ms: None,
+                priority: None,
             })
             .expect("warm event link");
         wait_for_event(&warm_link_subscription, TEST_TIMEOUT, |event| {
@@ -7354,6 +9488,10 @@ mod tests {
                 body_utf8: "warm repeated eam link".to_string(),
                 title: Some("warmup".to_string()),
                 send_mode: SendMode::Auto {},
+                attachments: Vec::new(),
+                send_at_ms: None,
+                expires_at_ms: None,
+                priority: None,
             })
             .expect("warm repeated eam link");
         wait_for_event(&warm_link_subscription, TEST_TIMEOUT, |event| {
@@ -7407,6 +9545,8 @@ mod tests {
             sync_error: None,
             draft_created_at_ms: Some(now_ms()),
             last_synced_at_ms: None,
+            expired: false,
+            custom_statuses: Vec::new(),
         };
 
         node_a
@@ -0,0 +1,211 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+use crate::send_priority::priority_for_fields;
+use crate::types::{MessagePriority, SendLxmfRequest, SendMode};
+
+// A message that keeps failing to go out is dropped after this many attempts,
+// whether they failed in this process or were cut short by a restart.
+pub(crate) const OUTBOUND_QUEUE_MAX_ATTEMPTS: u32 = 5;
+
+const OUTBOUND_RETRY_BASE_DELAY_MS: u64 = 15_000;
+const OUTBOUND_RETRY_MAX_DELAY_MS: u64 = 10 * 60 * 1000;
+
+static OUTBOUND_QUEUE_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct QueuedBytes {
+    pub(crate) destination_hex: String,
+    #[serde(with = "base64_bytes")]
+    pub(crate) bytes: Vec<u8>,
+    #[serde(with = "base64_optional_bytes")]
+    pub(crate) fields_bytes: Option<Vec<u8>>,
+    pub(crate) send_mode: SendMode,
+}
+
+// One member's copy of a group message. The room message and its delivery
+// ledger are already stored, so only the copy itself has to be resumed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct QueuedGroupCopy {
+    pub(crate) message_id_hex: String,
+    pub(crate) member_hex: String,
+    pub(crate) body_utf8: String,
+    pub(crate) title: Option<String>,
+    #[serde(with = "base64_bytes")]
+    pub(crate) fields_bytes: Vec<u8>,
+    pub(crate) priority: MessagePriority,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub(crate) enum OutboundPayload {
+    Lxmf { request: SendLxmfRequest },
+    Bytes { job: QueuedBytes },
+    GroupCopy { copy: QueuedGroupCopy },
+}
+
+impl OutboundPayload {
+    pub(crate) fn destination_hex(&self) -> &str {
+        match self {
+            Self::Lxmf { request } => request.destination_hex.as_str(),
+            Self::Bytes { job } => job.destination_hex.as_str(),
+            Self::GroupCopy { copy } => copy.member_hex.as_str(),
+        }
+    }
+}
+
+// One row of the durable outbound queue. A row lives from the moment the
+// send is accepted until the delivery policy has finished with it, so a
+// process kill in between leaves it behind to be resumed on the next start.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct OutboundQueueRecord {
+    pub(crate) queue_id: String,
+    pub(crate) payload: OutboundPayload,
+    pub(crate) attempts: u32,
+    pub(crate) enqueued_at_ms: u64,
+    pub(crate) updated_at_ms: u64,
+    #[serde(default)]
+    pub(crate) next_attempt_at_ms: u64,
+}
+
+impl OutboundQueueRecord {
+    pub(crate) fn new(payload: OutboundPayload, now_ms: u64) -> Self {
+        let sequence = OUTBOUND_QUEUE_SEQUENCE.fetch_add(1, Ordering::Relaxed);
+        Self {
+            queue_id: format!("out-{now_ms}-{sequence}"),
+            payload,
+            attempts: 0,
+            enqueued_at_ms: now_ms,
+            updated_at_ms: now_ms,
+            next_attempt_at_ms: now_ms,
+        }
+    }
+}
+
+// Doubles with every failed attempt, from 15 seconds up to ten minutes.
+pub(crate) fn outbound_retry_delay_ms(attempts: u32) -> u64 {
+    let doublings = attempts.saturating_sub(1).min(16);
+    OUTBOUND_RETRY_BASE_DELAY_MS
+        .saturating_mul(1 << doublings)
+        .min(OUTBOUND_RETRY_MAX_DELAY_MS)
+}
+
+// Only LXMF sends are queued. SOS traffic already has its own delivery ledger
+// and scheduler, and telemetry is superseded by the next publish, so neither
+// is replayed after a failure or a restart.
+pub(crate) fn is_durable_bytes_send(fields_bytes: Option<&[u8]>) -> bool {
+    fields_bytes.is_some_and(|fields| {
+        !matches!(
+            priority_for_fields(Some(fields)),
+            MessagePriority::Sos {} | MessagePriority::Telemetry {}
+        )
+    })
+}
+
+// Payload bytes are kept as base64 strings inside the queue row's JSON rather
+// than as arrays of numbers.
+mod base64_bytes {
+    use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+    use base64::Engine;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(BASE64_STANDARD.encode(bytes).as_str())
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        BASE64_STANDARD
+            .decode(encoded.as_bytes())
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+mod base64_optional_bytes {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        bytes: &Option<Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match bytes {
+            Some(bytes) => super::base64_bytes::serialize(bytes, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<u8>>, D::Error> {
+        #[derive(Deserialize)]
+        struct Encoded(#[serde(with = "super::base64_bytes")] Vec<u8>);
+
+        Ok(Option::<Encoded>::deserialize(deserializer)?.map(|Encoded(bytes)| bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lxmf_fields::FIELD_COMMANDS;
+    use rmpv::Value as MsgPackValue;
+
+    fn command_fields(command_type: &str) -> Vec<u8> {
+        rmp_serde::to_vec(&MsgPackValue::Map(vec![(
+            MsgPackValue::from(FIELD_COMMANDS),
+            MsgPackValue::Array(vec![MsgPackValue::Map(vec![(
+                MsgPackValue::from("command_type"),
+                MsgPackValue::from(command_type),
+            )])]),
+        )]))
+        .expect("fields")
+    }
+
+    #[test]
+    fn sos_and_telemetry_sends_are_not_durable() {
+        let mission_fields = command_fields("mission.registry.log_entry.upsert");
+        let telemetry_fields = command_fields("mission.registry.telemetry.upsert");
+        let sos_fields = rmp_serde::to_vec(&MsgPackValue::Map(vec![(
+            MsgPackValue::from(FIELD_COMMANDS),
+            MsgPackValue::Array(vec![MsgPackValue::Map(vec![(
+                MsgPackValue::from("sos_state"),
+                MsgPackValue::from("active"),
+            )])]),
+        )]))
+        .expect("fields");
+
+        assert!(is_durable_bytes_send(Some(mission_fields.as_slice())));
+        assert!(!is_durable_bytes_send(Some(sos_fields.as_slice())));
+        assert!(!is_durable_bytes_send(Some(telemetry_fields.as_slice())));
+        assert!(!is_durable_bytes_send(None));
+    }
+
+    #[test]
+    fn queued_bytes_are_stored_as_base64() {
+        let job = QueuedBytes {
+            destination_hex: "a".repeat(32),
+            bytes: vec![1, 2, 3],
+            fields_bytes: Some(vec![0xff]),
+            send_mode: SendMode::Auto {},
+        };
+
+        let json = serde_json::to_value(&job).expect("json");
+
+        assert_eq!(json["bytes"], "AQID");
+        assert_eq!(json["fields_bytes"], "/w==");
+        assert_eq!(
+            serde_json::from_value::<QueuedBytes>(json).expect("job"),
+            job
+        );
+    }
+
+    #[test]
+    fn retry_delay_backs_off_to_a_cap() {
+        assert_eq!(outbound_retry_delay_ms(1), 15_000);
+        assert_eq!(outbound_retry_delay_ms(2), 30_000);
+        assert_eq!(outbound_retry_delay_ms(30), 600_000);
+    }
+}
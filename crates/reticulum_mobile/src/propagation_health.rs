@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+use crate::messaging_compat as sdkmsg;
+use crate::types::PropagationNodeRecord;
+
+// A relay that fails this many syncs or relayed sends in a row is demoted, so
+// auto selection moves on to the next candidate instead of retrying it.
+pub(crate) const PROPAGATION_NODE_FAILURE_THRESHOLD: u32 = 3;
+// A demoted relay becomes eligible again after this long; one more failure
+// after that demotes it straight away.
+pub(crate) const PROPAGATION_NODE_DEMOTION_MS: u64 = 10 * 60 * 1000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct PropagationNodeHealth {
+    pub(crate) destination_hex: String,
+    pub(crate) last_success_at_ms: Option<u64>,
+    pub(crate) last_failure_at_ms: Option<u64>,
+    pub(crate) consecutive_failures: u32,
+    pub(crate) last_latency_ms: Option<u64>,
+    pub(crate) demoted_until_ms: Option<u64>,
+}
+
+impl PropagationNodeHealth {
+    pub(crate) fn new(destination_hex: &str) -> Self {
+        Self {
+            destination_hex: destination_hex.trim().to_ascii_lowercase(),
+            last_success_at_ms: None,
+            last_failure_at_ms: None,
+            consecutive_failures: 0,
+            last_latency_ms: None,
+            demoted_until_ms: None,
+        }
+    }
+
+    pub(crate) fn record_success(&mut self, latency_ms: u64, now_ms: u64) {
+        self.last_success_at_ms = Some(now_ms);
+        self.last_latency_ms = Some(latency_ms);
+        self.consecutive_failures = 0;
+        self.demoted_until_ms = None;
+    }
+
+    // Returns true when this failure is the one that demoted the relay.
+    pub(crate) fn record_failure(&mut self, now_ms: u64) -> bool {
+        let was_demoted = self.is_demoted(now_ms);
+        self.last_failure_at_ms = Some(now_ms);
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        if self.consecutive_failures < PROPAGATION_NODE_FAILURE_THRESHOLD {
+            return false;
+        }
+        self.demoted_until_ms = Some(now_ms.saturating_add(PROPAGATION_NODE_DEMOTION_MS));
+        !was_demoted
+    }
+
+    pub(crate) fn is_demoted(&self, now_ms: u64) -> bool {
+        self.demoted_until_ms
+            .is_some_and(|demoted_until_ms| demoted_until_ms > now_ms)
+    }
+}
+
+pub(crate) fn propagation_node_record(
+    announce: &sdkmsg::AnnounceRecord,
+    health: Option<&PropagationNodeHealth>,
+    active_destination_hex: Option<&str>,
+    preferred_destination_hex: Option<&str>,
+    now_ms: u64,
+) -> PropagationNodeRecord {
+    PropagationNodeRecord {
+        destination_hex: announce.destination_hex.clone(),
+        identity_hex: announce.identity_hex.clone(),
+        display_name: announce.display_name.clone(),
+        hops: announce.hops,
+        last_announce_at_ms: announce.received_at_ms,
+        last_sync_success_at_ms: health.and_then(|health| health.last_success_at_ms),
+        last_sync_failure_at_ms: health.and_then(|health| health.last_failure_at_ms),
+        consecutive_failures: health.map_or(0, |health| health.consecutive_failures),
+        last_latency_ms: health.and_then(|health| health.last_latency_ms),
+        demoted_until_ms: health
+            .filter(|health| health.is_demoted(now_ms))
+            .and_then(|health| health.demoted_until_ms),
+        active: active_destination_hex
+            .is_some_and(|active| active.eq_ignore_ascii_case(announce.destination_hex.as_str())),
+        preferred: preferred_destination_hex.is_some_and(|preferred| {
+            preferred.eq_ignore_ascii_case(announce.destination_hex.as_str())
+                || preferred.eq_ignore_ascii_case(announce.identity_hex.as_str())
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_failures_demote_until_a_success() {
+        let mut health = PropagationNodeHealth::new("AA");
+
+        assert!(!health.record_failure(1_000));
+        assert!(!health.record_failure(2_000));
+        assert!(health.record_failure(3_000));
+        assert!(health.is_demoted(3_001));
+        assert!(!health.record_failure(4_000));
+        assert!(!health.is_demoted(4_000 + PROPAGATION_NODE_DEMOTION_MS));
+
+        health.record_success(420, 5_000);
+
+        assert_eq!(health.consecutive_failures, 0);
+        assert_eq!(health.last_latency_ms, Some(420));
+        assert!(!health.is_demoted(5_001));
+        assert_eq!(health.destination_hex, "aa");
+    }
+}
@@ -0,0 +1,192 @@
+use std::time::Duration;
+
+use crate::types::PropagationSyncSettingsRecord;
+
+pub(crate) const PROPAGATION_SYNC_TICK: Duration = Duration::from_secs(15);
+
+// A failed sync is retried after 30 s, doubling per failure up to 30 min, so a
+// dead relay is not hammered while the device is out of range.
+const PROPAGATION_SYNC_BACKOFF_BASE_MS: u64 = 30_000;
+const PROPAGATION_SYNC_BACKOFF_MAX_MS: u64 = 30 * 60 * 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PropagationSyncTrigger {
+    Manual,
+    Interval,
+    Reconnect,
+    NetworkRestored,
+}
+
+impl PropagationSyncTrigger {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Manual => "manual",
+            Self::Interval => "interval",
+            Self::Reconnect => "reconnect",
+            Self::NetworkRestored => "network_restored",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PropagationSyncPolicy {
+    pub(crate) enabled: bool,
+    pub(crate) interval_ms: u64,
+    pub(crate) sync_on_reconnect: bool,
+}
+
+impl PropagationSyncPolicy {
+    // Without stored settings the host drives syncing itself, as it did before
+    // the schedule existed.
+    pub(crate) fn from_settings(settings: Option<&PropagationSyncSettingsRecord>) -> Self {
+        let Some(settings) = settings else {
+            return Self {
+                enabled: false,
+                interval_ms: 0,
+                sync_on_reconnect: false,
+            };
+        };
+        Self {
+            enabled: settings.enabled && settings.interval_seconds > 0,
+            interval_ms: u64::from(settings.interval_seconds) * 1000,
+            sync_on_reconnect: settings.sync_on_reconnect,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct PropagationSyncSchedule {
+    in_flight: bool,
+    network_available: bool,
+    last_attempt_at_ms: Option<u64>,
+    consecutive_failures: u32,
+    retry_at_ms: Option<u64>,
+}
+
+impl Default for PropagationSyncSchedule {
+    fn default() -> Self {
+        Self {
+            in_flight: false,
+            network_available: true,
+            last_attempt_at_ms: None,
+            consecutive_failures: 0,
+            retry_at_ms: None,
+        }
+    }
+}
+
+impl PropagationSyncSchedule {
+    pub(crate) fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    // While failing, the backoff decides the next attempt; otherwise it is one
+    // interval after the last one.
+    pub(crate) fn next_sync_at_ms(&self, policy: &PropagationSyncPolicy) -> Option<u64> {
+        if !policy.enabled || !self.network_available {
+            return None;
+        }
+        if let Some(retry_at_ms) = self.retry_at_ms {
+            return Some(retry_at_ms);
+        }
+        Some(
+            self.last_attempt_at_ms
+                .map_or(0, |last| last.saturating_add(policy.interval_ms)),
+        )
+    }
+
+    // Returns whether a sync should start now. Manual requests always run;
+    // reconnects skip the backoff because whatever broke the last sync may
+    // have been fixed by them.
+    pub(crate) fn begin(
+        &mut self,
+        trigger: PropagationSyncTrigger,
+        policy: &PropagationSyncPolicy,
+        now_ms: u64,
+    ) -> bool {
+        if self.in_flight {
+            return false;
+        }
+        let due = match trigger {
+            PropagationSyncTrigger::Manual => true,
+            PropagationSyncTrigger::Interval => self
+                .next_sync_at_ms(policy)
+                .is_some_and(|next_sync_at_ms| next_sync_at_ms <= now_ms),
+            PropagationSyncTrigger::Reconnect | PropagationSyncTrigger::NetworkRestored => {
+                policy.enabled && policy.sync_on_reconnect && self.network_available
+            }
+        };
+        if due {
+            self.in_flight = true;
+            self.last_attempt_at_ms = Some(now_ms);
+        }
+        due
+    }
+
+    pub(crate) fn finish(&mut self, succeeded: bool, now_ms: u64) {
+        self.in_flight = false;
+        if succeeded {
+            self.consecutive_failures = 0;
+            self.retry_at_ms = None;
+            return;
+        }
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        let exponent = self.consecutive_failures.saturating_sub(1).min(16);
+        let backoff_ms = PROPAGATION_SYNC_BACKOFF_BASE_MS
+            .saturating_mul(1 << exponent)
+            .min(PROPAGATION_SYNC_BACKOFF_MAX_MS);
+        self.retry_at_ms = Some(now_ms.saturating_add(backoff_ms));
+    }
+
+    // Returns true when the network has just come back.
+    pub(crate) fn set_network_available(&mut self, available: bool) -> bool {
+        let restored = available && !self.network_available;
+        self.network_available = available;
+        restored
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> PropagationSyncPolicy {
+        PropagationSyncPolicy::from_settings(Some(&PropagationSyncSettingsRecord {
+            enabled: true,
+            interval_seconds: 600,
+            sync_on_reconnect: true,
+        }))
+    }
+
+    #[test]
+    fn failures_back_off_until_a_reconnect() {
+        let policy = policy();
+        let mut schedule = PropagationSyncSchedule::default();
+
+        assert!(schedule.begin(PropagationSyncTrigger::Interval, &policy, 1_000));
+        assert!(!schedule.begin(PropagationSyncTrigger::Manual, &policy, 1_001));
+        schedule.finish(false, 2_000);
+        assert_eq!(schedule.next_sync_at_ms(&policy), Some(32_000));
+        schedule.finish(false, 2_000);
+        assert_eq!(schedule.next_sync_at_ms(&policy), Some(62_000));
+        assert!(!schedule.begin(PropagationSyncTrigger::Interval, &policy, 40_000));
+
+        assert!(schedule.begin(PropagationSyncTrigger::Reconnect, &policy, 40_000));
+        schedule.finish(true, 41_000);
+        assert_eq!(schedule.consecutive_failures(), 0);
+        assert_eq!(schedule.next_sync_at_ms(&policy), Some(640_000));
+    }
+
+    #[test]
+    fn offline_devices_only_sync_on_request() {
+        let policy = policy();
+        let mut schedule = PropagationSyncSchedule::default();
+
+        assert!(!schedule.set_network_available(false));
+        assert_eq!(schedule.next_sync_at_ms(&policy), None);
+        assert!(!schedule.begin(PropagationSyncTrigger::Interval, &policy, 1_000));
+        assert!(schedule.set_network_available(true));
+        assert!(schedule.begin(PropagationSyncTrigger::NetworkRestored, &policy, 1_000));
+        assert!(!PropagationSyncPolicy::from_settings(None).enabled);
+    }
+}
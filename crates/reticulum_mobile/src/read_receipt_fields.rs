@@ -0,0 +1,103 @@
+use rmpv::Value as MsgPackValue;
+
+use crate::lxmf_fields::{
+    build_command_envelope, msgpack_get_named, msgpack_u64, normalize_lxmf_message_id,
+    parse_command_envelope,
+};
+use crate::types::NodeError;
+
+// One receipt covers a batch of messages from the same sender. Anything past
+// this is dropped rather than letting a peer make us walk an unbounded list.
+const READ_RECEIPT_MAX_MESSAGES: usize = 256;
+
+// Sent back to the author of chat messages once they are read, tagged
+// `read_receipt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ReadReceipt {
+    pub(crate) message_ids: Vec<String>,
+    pub(crate) read_at_ms: u64,
+}
+
+pub(crate) fn build_read_receipt_fields(receipt: &ReadReceipt) -> Result<Vec<u8>, NodeError> {
+    let message_ids = receipt
+        .message_ids
+        .iter()
+        .filter_map(|value| normalize_lxmf_message_id(value))
+        .take(READ_RECEIPT_MAX_MESSAGES)
+        .map(MsgPackValue::from)
+        .collect::<Vec<_>>();
+    if message_ids.is_empty() {
+        return Err(NodeError::InvalidConfig {});
+    }
+    build_command_envelope(vec![
+        (
+            MsgPackValue::from("read_receipt"),
+            MsgPackValue::Array(message_ids),
+        ),
+        (
+            MsgPackValue::from("read_at_ms"),
+            MsgPackValue::from(receipt.read_at_ms),
+        ),
+    ])
+}
+
+pub(crate) fn parse_read_receipt_fields(fields_bytes: &[u8]) -> Option<ReadReceipt> {
+    let envelope = parse_command_envelope(fields_bytes, "read_receipt")?;
+    let message_ids = match msgpack_get_named(&envelope, &["read_receipt"])? {
+        MsgPackValue::Array(items) => items
+            .iter()
+            .filter_map(|item| item.as_str().and_then(normalize_lxmf_message_id))
+            .take(READ_RECEIPT_MAX_MESSAGES)
+            .collect::<Vec<_>>(),
+        _ => return None,
+    };
+    if message_ids.is_empty() {
+        return None;
+    }
+    Some(ReadReceipt {
+        message_ids,
+        read_at_ms: msgpack_get_named(&envelope, &["read_at_ms"])
+            .and_then(msgpack_u64)
+            .unwrap_or(0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lxmf_fields::FIELD_COMMANDS;
+
+    #[test]
+    fn read_receipt_round_trips_only_lxmf_message_ids() {
+        let wire_id = "AB".repeat(32);
+        let receipt = ReadReceipt {
+            message_ids: vec![wire_id.clone(), "sos-local-1".to_string()],
+            read_at_ms: 1_700_000_000_000,
+        };
+
+        let fields = build_read_receipt_fields(&receipt).expect("fields");
+        let parsed = parse_read_receipt_fields(fields.as_slice()).expect("receipt");
+
+        assert_eq!(parsed.message_ids, vec![wire_id.to_ascii_lowercase()]);
+        assert_eq!(parsed.read_at_ms, 1_700_000_000_000);
+        assert!(build_read_receipt_fields(&ReadReceipt {
+            message_ids: vec!["local".to_string()],
+            read_at_ms: 1,
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn sos_command_fields_are_not_read_receipts() {
+        let fields = rmp_serde::to_vec(&MsgPackValue::Map(vec![(
+            MsgPackValue::from(FIELD_COMMANDS),
+            MsgPackValue::Array(vec![MsgPackValue::Map(vec![(
+                MsgPackValue::from("sos_state"),
+                MsgPackValue::from("active"),
+            )])]),
+        )]))
+        .expect("fields");
+
+        assert!(parse_read_receipt_fields(fields.as_slice()).is_none());
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -6,16 +6,57 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use crate::announce_compat::{
     display_name_from_delivery_app_data, encode_delivery_display_name_app_data,
 };
-use crate::lxmf_fields::FIELD_COMMANDS;
+use crate::attachment_fields::{
+    build_attachment_fields, load_outbound_attachments, parse_attachment_fields, AttachmentPayload,
+    ATTACHMENT_MAX_TOTAL_BYTES,
+};
+use crate::eam_freshness::EAM_SWEEP_INTERVAL;
+use crate::eam_roll_call::{
+    parse_eam_roll_call_fields, parse_eam_roll_call_uid, pending_member, EAM_ROLL_CALL_COMMAND_TYPE,
+};
+use crate::eam_schema::{
+    built_in_eam_schema, derive_eam_overall_status, validate_eam_schema, EAM_SCHEMA_COMMAND_TYPE,
+};
+use crate::group_chat::{
+    build_group_fields, group_message_state, is_group_conversation_id, is_group_member,
+    normalize_member_hex, parse_group_fields, GroupEnvelope,
+};
+use crate::lxmf_fields::{
+    msgpack_bool, msgpack_f64, msgpack_get_indexed, msgpack_get_named, msgpack_map_entries,
+    msgpack_string, msgpack_u64, FIELD_COMMANDS,
+};
+use crate::message_action_fields::{
+    apply_message_action, build_message_action_fields, parse_message_action_fields, MessageAction,
+    MessageActionKind,
+};
+use crate::message_expiry::{is_expired, parse_expiry_field, with_expiry_field};
 use crate::messaging_compat as sdkmsg;
 use crate::mission_sync::{parse_mission_sync_metadata, MissionSyncMetadata};
-use crate::sos::{location_from_alert, received_alert_from_sos};
-use crate::sos_fields::{extract_text_coordinates, looks_like_sos_text, parse_sos_fields};
+use crate::outbound_queue::{
+    is_durable_bytes_send, OutboundPayload, OutboundQueueRecord, QueuedBytes, QueuedGroupCopy,
+    OUTBOUND_QUEUE_MAX_ATTEMPTS,
+};
+use crate::propagation_health::{propagation_node_record, PropagationNodeHealth};
+use crate::propagation_sync::{
+    PropagationSyncPolicy, PropagationSyncSchedule, PropagationSyncTrigger, PROPAGATION_SYNC_TICK,
+};
+use crate::read_receipt_fields::{parse_read_receipt_fields, ReadReceipt};
+use crate::send_priority::{
+    priority_for_fields, PrioritySendPermit, PrioritySendPool, SendQueueStats,
+};
+use crate::sos::{
+    location_from_alert, received_alert_from_sos, received_sos_audio_id, responder_from_response,
+    sos_audio_file_name, validate_sos_audio,
+};
+use crate::sos_fields::{
+    extract_text_coordinates, looks_like_sos_text, parse_sos_fields, SosAudioClip,
+};
+use crate::team_registry::{
+    validate_team, validate_team_fields, validate_team_without_parent, TEAM_UPSERT_COMMAND_TYPE,
+};
 use crossbeam_channel as cb;
 use fs_err as fs;
-#[cfg(feature = "legacy-lxmf-runtime")]
-use log::error;
-use log::{debug, info};
+use log::{debug, error, info};
 use lxmf::message::Message as LxmfMessage;
 use lxmf::message::WireMessage as LxmfWireMessage;
 use rand_core::OsRng;
@@ -34,8 +75,8 @@ use reticulum::transport::{
 };
 use rmpv::Value as MsgPackValue;
 use serde::de::DeserializeOwned;
-use serde::Deserialize;
-use tokio::sync::{mpsc, Mutex as TokioMutex, OwnedSemaphorePermit, Semaphore};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, Mutex as TokioMutex};
 
 #[path = "runtime_projection.rs"]
 mod runtime_projection;
@@ -43,20 +84,25 @@ mod runtime_projection;
 use crate::app_state::{
     canonicalize_chat_message, checklist_task_status_for, find_checklist_task_mut,
     normalize_checklist_record, normalize_optional_string, set_checklist_last_changed_by,
-    AppStateStore,
+    AppStateStore, ConversationPeerResolver,
 };
 use crate::event_bus::EventBus;
+use crate::hub_client::{registration_capabilities, HubHttpClient, HubRegistration};
 use crate::sdk_bridge::{RuntimeLxmfSdk, SdkTransportState};
 use crate::types::{
     AnnounceClass, AnnounceRecord, ChecklistCellRecord, ChecklistColumnRecord, ChecklistColumnType,
     ChecklistRecord, ChecklistSyncState, ChecklistTaskRecord, ChecklistTaskStatus,
-    ChecklistUserTaskStatus, ConversationRecord, EamProjectionRecord, EamSourceRecord,
-    EventProjectionRecord, HubDirectoryPeerRecord, HubDirectorySnapshot, HubMode, LogLevel,
-    LxmfDeliveryMethod, LxmfDeliveryRepresentation, LxmfDeliveryStatus, LxmfDeliveryUpdate,
-    LxmfFallbackStage, MessageDirection, MessageMethod, MessageRecord, MessageState, NodeConfig,
-    NodeError, NodeEvent, NodeStatus, OperationalNotice, PeerChange, PeerRecord, PeerState,
-    ProjectionScope, SendLxmfRequest, SendMode, SendOutcome, SosDeviceTelemetryRecord,
-    SosMessageKind, SyncPhase, SyncStatus, TelemetryPositionRecord,
+    ChecklistUserTaskStatus, ConversationRecord, EamProjectionRecord, EamRollCallDirection,
+    EamRollCallRecord, EamSchemaRecord, EamSourceRecord, EamStatusEntry, EventProjectionRecord,
+    GroupConversationRecord, GroupMemberDeliveryRecord, HubDirectoryPeerRecord,
+    HubDirectorySnapshot, HubMode, LogLevel, LxmfAttachmentRequest, LxmfDeliveryMethod,
+    LxmfDeliveryRepresentation, LxmfDeliveryStatus, LxmfDeliveryUpdate, LxmfFallbackStage,
+    MessageAttachmentKind, MessageAttachmentRecord, MessageDirection, MessageMethod,
+    MessagePriority, MessageReaction, MessageReactionRecord, MessageRecord, MessageState,
+    NodeConfig, NodeError, NodeEvent, NodeStatus, OperationalNotice, PeerChange, PeerRecord,
+    PeerState, ProjectionScope, PropagationNodeRecord, SendLxmfRequest, SendMode, SendOutcome,
+    SendQueueClassMetrics, SosAudioRecord, SosDeviceTelemetryRecord, SosMessageKind, SyncPhase,
+    SyncStatus, TeamRecord, TelemetryPositionRecord,
 };
 
 use self::runtime_projection::RuntimeProjectionJournal;
@@ -78,6 +124,7 @@ const DEFAULT_IDENTITY_WAIT_TIMEOUT: Duration = Duration::from_secs(12);
 const DEFAULT_LXMF_ACK_TIMEOUT: Duration = Duration::from_secs(90);
 const DEFAULT_BUFFERED_ACK_TTL: Duration = Duration::from_secs(5 * 60);
 const DEFAULT_RECEIPT_TRACKING_TTL: Duration = Duration::from_secs(10 * 60);
+const TELEMETRY_SWEEP_INTERVAL: Duration = Duration::from_secs(30);
 const SEND_TASK_CONCURRENCY_LIMIT: usize = 8;
 const MISSION_SEND_TASK_RESERVED_LIMIT: usize = 2;
 const MISSION_PROPAGATION_SEND_TASK_RESERVED_LIMIT: usize = 1;
@@ -133,6 +180,7 @@ fn telemetry_position_from_sos(
         } else {
             fallback_updated_at_ms
         },
+        stale: false,
     })
 }
 
@@ -151,32 +199,17 @@ fn civil_from_days(days_since_epoch: i64) -> (i64, i64, i64) {
     (year, month, day)
 }
 
-fn eam_status_rank(value: &str) -> u8 {
-    match value {
-        "Green" => 1,
-        "Yellow" => 2,
-        "Red" => 3,
-        _ => 0,
-    }
-}
-
-fn derive_eam_overall_status(record: &EamProjectionRecord) -> Option<String> {
-    let mut best_status: Option<&str> = None;
-    for value in [
-        record.security_status.as_str(),
-        record.capability_status.as_str(),
-        record.preparedness_status.as_str(),
-        record.medical_status.as_str(),
-        record.mobility_status.as_str(),
-        record.comms_status.as_str(),
-    ] {
-        if eam_status_rank(value) >= eam_status_rank(best_status.unwrap_or_default()) {
-            best_status = Some(value);
-        }
-    }
-    best_status
-        .filter(|value| !value.is_empty() && *value != "Unknown")
-        .map(str::to_string)
+fn eam_custom_statuses_from_msgpack(value: &MsgPackValue) -> Vec<EamStatusEntry> {
+    msgpack_map_entries(value)
+        .into_iter()
+        .flatten()
+        .filter_map(|(dimension, value)| {
+            Some(EamStatusEntry {
+                dimension: msgpack_string(dimension)?,
+                value: msgpack_string(value)?,
+            })
+        })
+        .collect()
 }
 
 #[derive(Debug)]
@@ -206,12 +239,11 @@ fn eam_command_action_from_command(
         } else {
             projection.group_name.trim().to_string()
         };
-        if projection.overall_status.is_none() {
-            projection.overall_status = derive_eam_overall_status(&projection);
-        }
         projection.sync_state = Some("synced".to_string());
         projection.sync_error = None;
         projection.last_synced_at_ms = Some(received_at_ms);
+        // Expiry is judged against the local clock by the sweep.
+        projection.expired = false;
         projection.updated_at_ms = projection.updated_at_ms.max(received_at_ms);
         return Some(EamCommandAction::Upsert(projection));
     }
@@ -223,7 +255,7 @@ fn eam_command_action_from_command(
         return None;
     }
 
-    let mut record = EamProjectionRecord {
+    let record = EamProjectionRecord {
         callsign: envelope.args.callsign.trim().to_string(),
         group_name: DEFAULT_EAM_GROUP_NAME.to_string(),
         security_status: envelope.args.security_status,
@@ -263,8 +295,14 @@ fn eam_command_action_from_command(
         sync_error: None,
         draft_created_at_ms: None,
         last_synced_at_ms: Some(received_at_ms),
+        expired: false,
+        custom_statuses: envelope
+            .args
+            .custom_statuses
+            .into_iter()
+            .map(|(dimension, value)| EamStatusEntry { dimension, value })
+            .collect(),
     };
-    record.overall_status = derive_eam_overall_status(&record);
     Some(EamCommandAction::Upsert(record))
 }
 
@@ -302,7 +340,7 @@ fn eam_command_action_from_fields(
         }
         let args = msgpack_get_named(command_map, &["args"]).and_then(msgpack_map_entries)?;
         let source = msgpack_get_named(args, &["source"]).and_then(msgpack_map_entries);
-        let mut record = EamProjectionRecord {
+        let record = EamProjectionRecord {
             callsign: msgpack_get_named(args, &["callsign"]).and_then(msgpack_string)?,
             group_name: DEFAULT_EAM_GROUP_NAME.to_string(),
             security_status: msgpack_get_named(args, &["security_status"])
@@ -345,17 +383,225 @@ fn eam_command_action_from_fields(
             sync_error: None,
             draft_created_at_ms: None,
             last_synced_at_ms: Some(received_at_ms),
+            expired: false,
+            custom_statuses: msgpack_get_named(args, &["custom_statuses"])
+                .map(eam_custom_statuses_from_msgpack)
+                .unwrap_or_default(),
         };
         if record.callsign.trim().is_empty() {
             return None;
         }
-        record.overall_status = derive_eam_overall_status(&record);
         return Some(EamCommandAction::Upsert(record));
     }
 
     None
 }
 
+// Stores an EAM schema template shared by a peer. Received templates are never
+// activated automatically; the operator chooses when to switch.
+fn persist_received_eam_schema_if_present(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    metadata: Option<&MissionSyncMetadata>,
+    fields_bytes: Option<&[u8]>,
+) {
+    if metadata.and_then(|value| value.command_type.as_deref()) != Some(EAM_SCHEMA_COMMAND_TYPE) {
+        return;
+    }
+    let Some(schema) = fields_bytes.and_then(eam_schema_from_fields) else {
+        return;
+    };
+    if validate_eam_schema(&schema).is_err() {
+        debug!(
+            "[lxmf][mission] ignoring invalid eam schema uid={}",
+            schema.schema_uid
+        );
+        return;
+    }
+    match state.app_state.store_received_eam_schema(&schema) {
+        Ok(Some(invalidation)) => bus.emit(NodeEvent::ProjectionInvalidated { invalidation }),
+        Ok(None) => {}
+        Err(err) => bus.emit(NodeEvent::Error {
+            code: "IoError".to_string(),
+            message: format!(
+                "failed to persist inbound eam schema uid={} reason={}",
+                schema.schema_uid, err
+            ),
+        }),
+    }
+}
+
+fn eam_schema_from_fields(fields_bytes: &[u8]) -> Option<EamSchemaRecord> {
+    let fields = rmp_serde::from_slice::<MsgPackValue>(fields_bytes).ok()?;
+    let field_entries = msgpack_map_entries(&fields)?;
+    let MsgPackValue::Array(command_entries) = msgpack_get_indexed(field_entries, FIELD_COMMANDS)?
+    else {
+        return None;
+    };
+    command_entries.iter().find_map(|command| {
+        let command_map = msgpack_map_entries(command)?;
+        if msgpack_get_named(command_map, &["command_type"]).and_then(msgpack_string)?
+            != EAM_SCHEMA_COMMAND_TYPE
+        {
+            return None;
+        }
+        let args = msgpack_get_named(command_map, &["args"]).and_then(msgpack_map_entries)?;
+        let schema_json = msgpack_get_named(args, &["schema_json"]).and_then(msgpack_string)?;
+        let mut schema = serde_json::from_str::<EamSchemaRecord>(&schema_json).ok()?;
+        schema.active = false;
+        schema.updated_at_ms = now_ms();
+        Some(schema)
+    })
+}
+
+// Applies a replicated team edit or removal. A team whose parent has not
+// arrived yet is kept as-is so out-of-order delivery does not lose it; the
+// parent's level is checked once it is known. A sender's clock running ahead
+// is capped at the receive time so its edit cannot outlive later ones.
+fn persist_received_team_if_present(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    metadata: Option<&MissionSyncMetadata>,
+    fields_bytes: Option<&[u8]>,
+) {
+    if metadata.and_then(|value| value.command_type.as_deref()) != Some(TEAM_UPSERT_COMMAND_TYPE) {
+        return;
+    }
+    let Some(mut team) = fields_bytes.and_then(team_from_fields) else {
+        return;
+    };
+    team.updated_at_ms = team.updated_at_ms.min(now_ms());
+    let teams = match state.app_state.list_teams() {
+        Ok(teams) => teams,
+        Err(_) => return,
+    };
+    let parent_known = team
+        .parent_team_uid
+        .as_deref()
+        .is_some_and(|parent_team_uid| {
+            teams
+                .iter()
+                .any(|stored| stored.team_uid == parent_team_uid)
+        });
+    let valid = if team.deleted_at_ms.is_some() {
+        validate_team_fields(&team)
+    } else if parent_known {
+        validate_team(&team, teams.as_slice())
+    } else {
+        validate_team_without_parent(&team, teams.as_slice())
+    };
+    if valid.is_err() {
+        debug!(
+            "[lxmf][mission] ignoring invalid team uid={}",
+            team.team_uid
+        );
+        return;
+    }
+    match state.app_state.upsert_team(&team) {
+        Ok(Some(invalidation)) => bus.emit(NodeEvent::ProjectionInvalidated { invalidation }),
+        Ok(None) => debug!(
+            "[lxmf][mission] ignoring stale team uid={} updated_at_ms={}",
+            team.team_uid, team.updated_at_ms
+        ),
+        Err(err) => bus.emit(NodeEvent::Error {
+            code: "IoError".to_string(),
+            message: format!(
+                "failed to persist inbound team uid={} reason={}",
+                team.team_uid, err
+            ),
+        }),
+    }
+}
+
+fn team_from_fields(fields_bytes: &[u8]) -> Option<TeamRecord> {
+    let fields = rmp_serde::from_slice::<MsgPackValue>(fields_bytes).ok()?;
+    let field_entries = msgpack_map_entries(&fields)?;
+    let MsgPackValue::Array(command_entries) = msgpack_get_indexed(field_entries, FIELD_COMMANDS)?
+    else {
+        return None;
+    };
+    command_entries.iter().find_map(|command| {
+        let command_map = msgpack_map_entries(command)?;
+        if msgpack_get_named(command_map, &["command_type"]).and_then(msgpack_string)?
+            != TEAM_UPSERT_COMMAND_TYPE
+        {
+            return None;
+        }
+        let args = msgpack_get_named(command_map, &["args"]).and_then(msgpack_map_entries)?;
+        let team_json = msgpack_get_named(args, &["team_json"]).and_then(msgpack_string)?;
+        serde_json::from_str::<TeamRecord>(&team_json).ok()
+    })
+}
+
+// Stores a roll-call request addressed to this node and prompts the UI for a
+// report. Re-deliveries of the same request are ignored.
+fn persist_received_eam_roll_call_if_present(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    metadata: Option<&MissionSyncMetadata>,
+    fields_bytes: Option<&[u8]>,
+    source_hex: Option<&str>,
+) {
+    if metadata.and_then(|value| value.command_type.as_deref()) != Some(EAM_ROLL_CALL_COMMAND_TYPE)
+    {
+        return;
+    }
+    let Some(command) = fields_bytes.and_then(parse_eam_roll_call_fields) else {
+        return;
+    };
+    let own_member_uid = state.app_destination_hex.as_str();
+    let own_team_uid = state.app_state.get_eams().ok().and_then(|records| {
+        records
+            .into_iter()
+            .find(|record| {
+                record
+                    .team_member_uid
+                    .as_deref()
+                    .is_some_and(|value| value.eq_ignore_ascii_case(own_member_uid))
+            })
+            .and_then(|record| record.team_uid)
+    });
+    if !command.addresses(own_member_uid, own_team_uid.as_deref()) {
+        return;
+    }
+    if matches!(
+        state.app_state.get_eam_roll_call(&command.roll_call_uid),
+        Ok(Some(_))
+    ) {
+        return;
+    }
+
+    let received_at_ms = now_ms();
+    let roll_call = EamRollCallRecord {
+        roll_call_uid: command.roll_call_uid,
+        direction: EamRollCallDirection::Inbound {},
+        team_uid: command.team_uid,
+        requested_by: command.requested_by,
+        requester_hex: source_hex.map(str::to_string),
+        requested_at_ms: command.requested_at_ms,
+        due_at_ms: command.due_at_ms,
+        members: vec![pending_member(own_member_uid, None)],
+        updated_at_ms: received_at_ms,
+    };
+    if let Err(err) = state.app_state.upsert_eam_roll_call(&roll_call) {
+        bus.emit(NodeEvent::Error {
+            code: "IoError".to_string(),
+            message: format!(
+                "failed to persist inbound eam roll call uid={} reason={}",
+                roll_call.roll_call_uid, err
+            ),
+        });
+        return;
+    }
+    info!(
+        "[lxmf][mission] eam roll call requested uid={} by={} due_at_ms={}",
+        roll_call.roll_call_uid,
+        roll_call.requested_by.as_deref().unwrap_or("-"),
+        roll_call.due_at_ms,
+    );
+    bus.emit(NodeEvent::EamReportRequested { roll_call });
+}
+
 async fn persist_received_eam_if_present(
     state: &NodeRuntimeState,
     bus: &EventBus,
@@ -391,9 +637,22 @@ async fn persist_received_eam_if_present(
         })
         .or(parsed_from_fields);
 
-    let Some(action) = parsed else {
+    let Some(mut action) = parsed else {
         return;
     };
+    // Reports are ranked with the local taxonomy; a sender's own overall
+    // status is kept when it sent one.
+    if let EamCommandAction::Upsert(record) = &mut action {
+        if record.overall_status.is_none() {
+            let schema = state
+                .app_state
+                .get_active_eam_schema()
+                .ok()
+                .flatten()
+                .unwrap_or_else(built_in_eam_schema);
+            record.overall_status = derive_eam_overall_status(&schema, record);
+        }
+    }
 
     match action {
         EamCommandAction::Upsert(record) => match state.app_state.upsert_eam(&record) {
@@ -408,6 +667,20 @@ async fn persist_received_eam_if_present(
                         invalidation: summary,
                     });
                 }
+                match state.app_state.record_eam_roll_call_response(
+                    &record,
+                    fields_bytes.and_then(parse_eam_roll_call_uid).as_deref(),
+                    received_at_ms,
+                ) {
+                    Ok(roll_calls) => {
+                        for roll_call in roll_calls {
+                            bus.emit(NodeEvent::EamRollCallUpdated { roll_call });
+                        }
+                    }
+                    Err(err) => {
+                        debug!("[lxmf][mission] eam roll call response not recorded: {err}")
+                    }
+                }
             }
             Err(err) => {
                 bus.emit(NodeEvent::Error {
@@ -570,6 +843,7 @@ fn telemetry_position_from_fields(
             updated_at_ms: msgpack_get_named(args, &["updated_at_ms", "updatedAt"])
                 .and_then(msgpack_u64)
                 .unwrap_or(received_at_ms),
+            stale: false,
         });
     }
 
@@ -2222,6 +2496,8 @@ struct EamUpsertCommandArgs {
     confidence: Option<f64>,
     ttl_seconds: Option<u64>,
     source: Option<MissionWireSource>,
+    #[serde(default)]
+    custom_statuses: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -2238,55 +2514,6 @@ struct EamWireBody {
     projection: Option<EamProjectionRecord>,
 }
 
-fn msgpack_map_entries(value: &MsgPackValue) -> Option<&[(MsgPackValue, MsgPackValue)]> {
-    match value {
-        MsgPackValue::Map(entries) => Some(entries.as_slice()),
-        _ => None,
-    }
-}
-
-fn msgpack_get_indexed<'a>(
-    entries: &'a [(MsgPackValue, MsgPackValue)],
-    key: i64,
-) -> Option<&'a MsgPackValue> {
-    let key_string = key.to_string();
-    for (entry_key, entry_value) in entries {
-        match entry_key {
-            MsgPackValue::Integer(value) if value.as_i64() == Some(key) => {
-                return Some(entry_value)
-            }
-            MsgPackValue::String(value) if value.as_str() == Some(key_string.as_str()) => {
-                return Some(entry_value)
-            }
-            _ => {}
-        }
-    }
-    None
-}
-
-fn msgpack_get_named<'a>(
-    entries: &'a [(MsgPackValue, MsgPackValue)],
-    keys: &[&str],
-) -> Option<&'a MsgPackValue> {
-    for wanted in keys {
-        for (entry_key, entry_value) in entries {
-            if matches!(entry_key, MsgPackValue::String(actual) if actual.as_str() == Some(*wanted))
-            {
-                return Some(entry_value);
-            }
-        }
-    }
-    None
-}
-
-fn msgpack_string(value: &MsgPackValue) -> Option<String> {
-    match value {
-        MsgPackValue::String(value) => value.as_str().map(str::to_string),
-        MsgPackValue::Binary(value) => String::from_utf8(value.clone()).ok(),
-        _ => None,
-    }
-}
-
 fn msgpack_string_vec(value: &MsgPackValue) -> Option<Vec<String>> {
     let MsgPackValue::Array(entries) = value else {
         return None;
@@ -2294,33 +2521,6 @@ fn msgpack_string_vec(value: &MsgPackValue) -> Option<Vec<String>> {
     Some(entries.iter().filter_map(msgpack_string).collect())
 }
 
-fn msgpack_bool(value: &MsgPackValue) -> Option<bool> {
-    match value {
-        MsgPackValue::Boolean(value) => Some(*value),
-        _ => None,
-    }
-}
-
-fn msgpack_f64(value: &MsgPackValue) -> Option<f64> {
-    match value {
-        MsgPackValue::F32(value) => Some(f64::from(*value)),
-        MsgPackValue::F64(value) => Some(*value),
-        MsgPackValue::Integer(value) => value.as_i64().map(|entry| entry as f64),
-        _ => None,
-    }
-}
-
-fn msgpack_u64(value: &MsgPackValue) -> Option<u64> {
-    match value {
-        MsgPackValue::Integer(value) => value.as_u64().or_else(|| {
-            value
-                .as_i64()
-                .and_then(|entry| (entry >= 0).then_some(entry as u64))
-        }),
-        _ => None,
-    }
-}
-
 pub(crate) fn lxmf_private_identity(
     identity: &PrivateIdentity,
 ) -> Result<lxmf::identity::PrivateIdentity, NodeError> {
@@ -2653,6 +2853,32 @@ fn from_sdk_message_method(method: sdkmsg::MessageMethod) -> MessageMethod {
     }
 }
 
+fn to_sdk_attachment(attachment: MessageAttachmentRecord) -> sdkmsg::MessageAttachment {
+    sdkmsg::MessageAttachment {
+        kind: match attachment.kind {
+            MessageAttachmentKind::File {} => sdkmsg::AttachmentKind::File,
+            MessageAttachmentKind::Image {} => sdkmsg::AttachmentKind::Image,
+        },
+        file_name: attachment.file_name,
+        mime_type: attachment.mime_type,
+        size_bytes: attachment.size_bytes,
+        path: attachment.path,
+    }
+}
+
+fn from_sdk_attachment(attachment: sdkmsg::MessageAttachment) -> MessageAttachmentRecord {
+    MessageAttachmentRecord {
+        kind: match attachment.kind {
+            sdkmsg::AttachmentKind::File => MessageAttachmentKind::File {},
+            sdkmsg::AttachmentKind::Image => MessageAttachmentKind::Image {},
+        },
+        file_name: attachment.file_name,
+        mime_type: attachment.mime_type,
+        size_bytes: attachment.size_bytes,
+        path: attachment.path,
+    }
+}
+
 fn to_sdk_message_state(state: MessageState) -> sdkmsg::MessageState {
     match state {
         MessageState::Queued {} => sdkmsg::MessageState::Queued,
@@ -2666,6 +2892,9 @@ fn to_sdk_message_state(state: MessageState) -> sdkmsg::MessageState {
         MessageState::TimedOut {} => sdkmsg::MessageState::TimedOut,
         MessageState::Cancelled {} => sdkmsg::MessageState::Cancelled,
         MessageState::Received {} => sdkmsg::MessageState::Received,
+        MessageState::Read {} => sdkmsg::MessageState::Read,
+        MessageState::Scheduled {} => sdkmsg::MessageState::Scheduled,
+        MessageState::Expired {} => sdkmsg::MessageState::Expired,
     }
 }
 
@@ -2682,6 +2911,9 @@ fn from_sdk_message_state(state: sdkmsg::MessageState) -> MessageState {
         sdkmsg::MessageState::TimedOut => MessageState::TimedOut {},
         sdkmsg::MessageState::Cancelled => MessageState::Cancelled {},
         sdkmsg::MessageState::Received => MessageState::Received {},
+        sdkmsg::MessageState::Read => MessageState::Read {},
+        sdkmsg::MessageState::Scheduled => MessageState::Scheduled {},
+        sdkmsg::MessageState::Expired => MessageState::Expired {},
     }
 }
 
@@ -2763,6 +2995,26 @@ fn to_sdk_message_record(record: MessageRecord) -> sdkmsg::MessageRecord {
         sent_at_ms: record.sent_at_ms,
         received_at_ms: record.received_at_ms,
         updated_at_ms: record.updated_at_ms,
+        attachments: record
+            .attachments
+            .into_iter()
+            .map(to_sdk_attachment)
+            .collect(),
+        edited_at_ms: record.edited_at_ms,
+        deleted_at_ms: record.deleted_at_ms,
+        reactions: record
+            .reactions
+            .into_iter()
+            .map(|reaction| sdkmsg::MessageReaction {
+                reactor_hex: reaction.reactor_hex,
+                reaction: match reaction.reaction {
+                    MessageReaction::ThumbsUp {} => sdkmsg::ReactionKind::ThumbsUp,
+                    MessageReaction::Seen {} => sdkmsg::ReactionKind::Seen,
+                    MessageReaction::Copy {} => sdkmsg::ReactionKind::Copy,
+                },
+                reacted_at_ms: reaction.reacted_at_ms,
+            })
+            .collect(),
     }
 }
 
@@ -2781,6 +3033,26 @@ fn from_sdk_message_record(record: sdkmsg::MessageRecord) -> MessageRecord {
         sent_at_ms: record.sent_at_ms,
         received_at_ms: record.received_at_ms,
         updated_at_ms: record.updated_at_ms,
+        attachments: record
+            .attachments
+            .into_iter()
+            .map(from_sdk_attachment)
+            .collect(),
+        edited_at_ms: record.edited_at_ms,
+        deleted_at_ms: record.deleted_at_ms,
+        reactions: record
+            .reactions
+            .into_iter()
+            .map(|reaction| MessageReactionRecord {
+                reactor_hex: reaction.reactor_hex,
+                reaction: match reaction.reaction {
+                    sdkmsg::ReactionKind::ThumbsUp => MessageReaction::ThumbsUp {},
+                    sdkmsg::ReactionKind::Seen => MessageReaction::Seen {},
+                    sdkmsg::ReactionKind::Copy => MessageReaction::Copy {},
+                },
+                reacted_at_ms: reaction.reacted_at_ms,
+            })
+            .collect(),
     }
 }
 
@@ -2844,6 +3116,9 @@ fn from_sdk_sync_status(status: sdkmsg::SyncStatus) -> SyncStatus {
         completed_at_ms: status.completed_at_ms,
         messages_received: status.messages_received,
         detail: status.detail,
+        last_trigger: status.last_trigger,
+        consecutive_failures: status.consecutive_failures,
+        next_sync_at_ms: status.next_sync_at_ms,
     }
 }
 
@@ -2853,17 +3128,26 @@ fn to_sdk_sync_status(status: SyncStatus) -> Option<sdkmsg::SyncStatus> {
         .and_then(|value| serde_json::from_value(value).ok())
 }
 
-fn to_sdk_send_request(request: &SendLxmfRequest) -> sdkmsg::SendMessageRequest {
+// Attachments are remembered by their stored copies so a retry resends the
+// same bytes even if the host has since removed its original file.
+fn to_sdk_send_request(
+    request: &SendLxmfRequest,
+    attachments: &[MessageAttachmentRecord],
+) -> sdkmsg::SendMessageRequest {
     sdkmsg::SendMessageRequest {
         destination_hex: request.destination_hex.clone(),
         body_utf8: request.body_utf8.clone(),
         title: request.title.clone(),
         send_mode: to_sdk_send_mode(request.send_mode),
         use_propagation_node: matches!(request.send_mode, SendMode::PropagationOnly {}),
+        attachments: attachments.iter().cloned().map(to_sdk_attachment).collect(),
+        expires_at_ms: request.expires_at_ms,
     }
 }
 
-#[derive(Debug, Clone)]
+// Persisted alongside the outbound queue so an acknowledgement or timeout that
+// lands after a restart still resolves the delivery.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct PendingLxmfDelivery {
     message_id_hex: String,
     destination_hex: String,
@@ -2912,6 +3196,12 @@ struct RuntimeReceiptBridge {
     tx: mpsc::UnboundedSender<String>,
 }
 
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SendBytesReceipt {
+    pub(crate) message_id_hex: Option<String>,
+    pub(crate) used_propagation_node: bool,
+}
+
 #[derive(Debug, Clone)]
 struct ReceiptMessageTracking {
     message_id_hex: String,
@@ -2962,53 +3252,55 @@ impl SendTaskClass {
 
 #[derive(Clone)]
 struct SendTaskPermits {
-    general: Arc<Semaphore>,
-    mission: Arc<Semaphore>,
-    mission_propagation: Arc<Semaphore>,
+    general: PrioritySendPool,
+    mission: PrioritySendPool,
+    mission_propagation: PrioritySendPool,
+    stats: SendQueueStats,
 }
 
 impl SendTaskPermits {
     fn new() -> Self {
-        Self {
-            general: Arc::new(Semaphore::new(GENERAL_SEND_TASK_CONCURRENCY_LIMIT)),
-            mission: Arc::new(Semaphore::new(MISSION_SEND_TASK_RESERVED_LIMIT)),
-            mission_propagation: Arc::new(Semaphore::new(
-                MISSION_PROPAGATION_SEND_TASK_RESERVED_LIMIT,
-            )),
-        }
+        Self::with_limits_and_propagation(
+            GENERAL_SEND_TASK_CONCURRENCY_LIMIT,
+            MISSION_SEND_TASK_RESERVED_LIMIT,
+            MISSION_PROPAGATION_SEND_TASK_RESERVED_LIMIT,
+        )
     }
 
     #[cfg(test)]
     fn with_limits(general: usize, mission: usize) -> Self {
+        Self::with_limits_and_propagation(general, mission, mission)
+    }
+
+    fn with_limits_and_propagation(
+        general: usize,
+        mission: usize,
+        mission_propagation: usize,
+    ) -> Self {
+        let stats = SendQueueStats::default();
         Self {
-            general: Arc::new(Semaphore::new(general)),
-            mission: Arc::new(Semaphore::new(mission)),
-            mission_propagation: Arc::new(Semaphore::new(mission)),
+            general: PrioritySendPool::new(general, stats.clone()),
+            mission: PrioritySendPool::new(mission, stats.clone()),
+            mission_propagation: PrioritySendPool::new(mission_propagation, stats.clone()),
+            stats,
         }
     }
 
-    async fn acquire(&self, class: SendTaskClass) -> Result<OwnedSemaphorePermit, NodeError> {
+    async fn acquire(
+        &self,
+        class: SendTaskClass,
+        priority: MessagePriority,
+    ) -> Result<PrioritySendPermit, NodeError> {
         match class {
-            SendTaskClass::Mission => self
-                .mission
-                .clone()
-                .acquire_owned()
-                .await
-                .map_err(|_| NodeError::InternalError {}),
-            SendTaskClass::MissionPropagation => self
-                .mission_propagation
-                .clone()
-                .acquire_owned()
-                .await
-                .map_err(|_| NodeError::InternalError {}),
-            SendTaskClass::General => self
-                .general
-                .clone()
-                .acquire_owned()
-                .await
-                .map_err(|_| NodeError::InternalError {}),
+            SendTaskClass::Mission => self.mission.acquire(priority).await,
+            SendTaskClass::MissionPropagation => self.mission_propagation.acquire(priority).await,
+            SendTaskClass::General => self.general.acquire(priority).await,
         }
     }
+
+    fn metrics(&self) -> Vec<SendQueueClassMetrics> {
+        self.stats.snapshot()
+    }
 }
 
 fn log_send_task(class: SendTaskClass, message: String) {
@@ -3486,7 +3778,8 @@ async fn has_active_propagation_relay(state: &NodeRuntimeState) -> bool {
 fn propagation_candidate_sort_key(
     announce: &sdkmsg::AnnounceRecord,
     preferred_destination_hex: Option<&str>,
-) -> (u8, u8, u64, String) {
+    demoted: bool,
+) -> (u8, u8, u8, u64, String) {
     let preferred_rank = if preferred_destination_hex.is_some_and(|preferred| {
         preferred == announce.destination_hex || preferred == announce.identity_hex
     }) {
@@ -3494,7 +3787,10 @@ fn propagation_candidate_sort_key(
     } else {
         1
     };
+    // A demoted relay is only picked when nothing healthier is known, even if
+    // it is the configured hub.
     (
+        u8::from(demoted),
         preferred_rank,
         announce.hops,
         u64::MAX - announce.received_at_ms,
@@ -3507,11 +3803,24 @@ async fn sync_auto_propagation_node(state: &NodeRuntimeState, bus: &EventBus) {
         let messaging = state.messaging.lock().await;
         messaging.list_announces()
     };
+    let now = now_ms();
+    let health = state
+        .propagation_health
+        .lock()
+        .map(|guard| guard.clone())
+        .unwrap_or_default();
     let desired_destination = announces
         .iter()
         .filter(|record| record.destination_kind == "lxmf_propagation")
         .min_by_key(|record| {
-            propagation_candidate_sort_key(record, state.preferred_propagation_node_hex.as_deref())
+            let demoted = health
+                .get(record.destination_hex.to_ascii_lowercase().as_str())
+                .is_some_and(|health| health.is_demoted(now));
+            propagation_candidate_sort_key(
+                record,
+                state.preferred_propagation_node_hex.as_deref(),
+                demoted,
+            )
         })
         .map(|record| record.destination_hex.clone());
 
@@ -3541,26 +3850,275 @@ async fn sync_auto_propagation_node(state: &NodeRuntimeState, bus: &EventBus) {
     }
 }
 
-async fn resolve_peer_route(
+fn note_propagation_node_success(state: &NodeRuntimeState, destination_hex: &str, latency_ms: u64) {
+    let health = {
+        let Ok(mut guard) = state.propagation_health.lock() else {
+            return;
+        };
+        let key = destination_hex.trim().to_ascii_lowercase();
+        let health = guard
+            .entry(key)
+            .or_insert_with(|| PropagationNodeHealth::new(destination_hex));
+        health.record_success(latency_ms, now_ms());
+        health.clone()
+    };
+    let _ = state.app_state.upsert_propagation_node_health(&health);
+}
+
+// Returns the relay's health when this failure is the one that demoted it.
+fn record_propagation_node_failure(
+    state: &NodeRuntimeState,
+    destination_hex: &str,
+) -> Option<PropagationNodeHealth> {
+    let (health, demoted) = {
+        let mut guard = state.propagation_health.lock().ok()?;
+        let key = destination_hex.trim().to_ascii_lowercase();
+        let health = guard
+            .entry(key)
+            .or_insert_with(|| PropagationNodeHealth::new(destination_hex));
+        let demoted = health.record_failure(now_ms());
+        (health.clone(), demoted)
+    };
+    let _ = state.app_state.upsert_propagation_node_health(&health);
+    demoted.then_some(health)
+}
+
+// A relay demoted by this failure stops being the active relay straight away
+// rather than on the next announce.
+async fn note_propagation_node_failure(
     state: &NodeRuntimeState,
     bus: &EventBus,
     destination_hex: &str,
-) -> Result<(), NodeError> {
-    let destination = parse_address_hash(destination_hex)?;
-    let attempted_at_ms = now_ms();
-    {
-        let mut messaging = state.messaging.lock().await;
-        messaging.record_resolution_attempt(destination_hex, attempted_at_ms);
-    }
-    emit_peer_changed(state, bus, destination_hex).await;
+) {
+    let Some(health) = record_propagation_node_failure(state, destination_hex) else {
+        return;
+    };
+    emit_operational_notice(
+        bus,
+        LogLevel::Warn {},
+        format!(
+            "propagation node {} demoted after {} consecutive failures",
+            health.destination_hex, health.consecutive_failures
+        ),
+    );
+    sync_auto_propagation_node(state, bus).await;
+}
 
-    state.transport.request_path(&destination, None, None).await;
-    let desc = ensure_destination_desc(state, destination, None).await?;
-    let identity_hex = desc.identity.address_hash.to_hex_string();
-    let lxmf_desc = SingleOutputDestination::new(
-        desc.identity.clone(),
-        DestinationName::new(LXMF_DELIVERY_NAME.0, LXMF_DELIVERY_NAME.1),
-    )
+// Relayed sends count towards the active relay's health too. They have no
+// event bus to hand, so a demotion they cause is acted on by the next sync or
+// announce.
+async fn send_via_propagation_relay(
+    state: &NodeRuntimeState,
+    destination: AddressHash,
+    body: &[u8],
+    title: Option<String>,
+    fields_bytes: Option<Vec<u8>>,
+    metadata: Option<MissionSyncMetadata>,
+) -> Result<LxmfSendReport, NodeError> {
+    let relay_hex = state.active_propagation_node_hex.lock().await.clone();
+    let started_at_ms = now_ms();
+    let result = state
+        .sdk
+        .send_lxmf(
+            destination,
+            body,
+            title,
+            fields_bytes,
+            metadata,
+            SendMode::PropagationOnly {},
+        )
+        .await;
+    if let Some(relay_hex) = relay_hex {
+        match &result {
+            Ok(report) if lxmf_send_succeeded(report.outcome) => {
+                note_propagation_node_success(
+                    state,
+                    relay_hex.as_str(),
+                    now_ms().saturating_sub(started_at_ms),
+                );
+            }
+            result if is_relay_failure(result) => {
+                let _ = record_propagation_node_failure(state, relay_hex.as_str());
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+// Only a relay that could not be reached or did not take the message counts
+// against it; a message the SDK rejects, for size or a bad destination, would
+// fail on any relay.
+fn is_relay_failure(result: &Result<LxmfSendReport, NodeError>) -> bool {
+    match result {
+        Ok(report) => !lxmf_send_succeeded(report.outcome),
+        Err(err) => matches!(err, NodeError::NetworkError {} | NodeError::Timeout {}),
+    }
+}
+
+async fn list_propagation_nodes(state: &NodeRuntimeState) -> Vec<PropagationNodeRecord> {
+    let announces = state.messaging.lock().await.list_announces();
+    let active = state.active_propagation_node_hex.lock().await.clone();
+    let health = state
+        .propagation_health
+        .lock()
+        .map(|guard| guard.clone())
+        .unwrap_or_default();
+    let now = now_ms();
+    let mut records = announces
+        .iter()
+        .filter(|record| record.destination_kind == "lxmf_propagation")
+        .map(|record| {
+            propagation_node_record(
+                record,
+                health.get(record.destination_hex.to_ascii_lowercase().as_str()),
+                active.as_deref(),
+                state.preferred_propagation_node_hex.as_deref(),
+                now,
+            )
+        })
+        .collect::<Vec<_>>();
+    records.sort_by_key(|record| {
+        (
+            !record.active,
+            record.demoted_until_ms.is_some(),
+            !record.preferred,
+            record.hops,
+            u64::MAX - record.last_announce_at_ms,
+        )
+    });
+    records
+}
+
+fn publish_sync_status(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    messaging: &mut sdkmsg::MessagingStore,
+    update: impl FnOnce(&mut sdkmsg::SyncStatus),
+) {
+    let status = from_sdk_sync_status(messaging.update_sync_status(update));
+    if refresh_sync_status_snapshot(state, &status) {
+        bus.emit(NodeEvent::SyncUpdated { status });
+    }
+}
+
+fn propagation_sync_policy(state: &NodeRuntimeState) -> PropagationSyncPolicy {
+    let settings = state.app_state.get_app_settings().ok().flatten();
+    PropagationSyncPolicy::from_settings(settings.as_ref().map(|value| &value.propagation_sync))
+}
+
+// Starts a sync in the background unless one is already running or the
+// trigger is not due under the schedule. Returns whether one was started.
+fn start_propagation_sync(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    trigger: PropagationSyncTrigger,
+) -> bool {
+    let policy = propagation_sync_policy(state);
+    let started = state
+        .propagation_sync
+        .lock()
+        .map(|mut schedule| schedule.begin(trigger, &policy, now_ms()))
+        .unwrap_or(false);
+    if !started {
+        return false;
+    }
+    info!(
+        "[sync] propagation sync started trigger={}",
+        trigger.as_str()
+    );
+    let state = state.clone();
+    let bus = bus.clone();
+    tokio::spawn(async move {
+        run_propagation_sync(&state, &bus, trigger).await;
+    });
+    true
+}
+
+// The one sync path, whether the host app asked for it or the schedule did:
+// check the relay link and record the result and the next due time.
+async fn run_propagation_sync(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    trigger: PropagationSyncTrigger,
+) {
+    let requested_at_ms = now_ms();
+    let outcome = probe_active_propagation_node(state, bus).await;
+    let policy = propagation_sync_policy(state);
+    let (consecutive_failures, next_sync_at_ms) = state
+        .propagation_sync
+        .lock()
+        .map(|mut schedule| {
+            schedule.finish(outcome.is_ok(), now_ms());
+            (
+                schedule.consecutive_failures(),
+                schedule.next_sync_at_ms(&policy),
+            )
+        })
+        .unwrap_or((0, None));
+    publish_sync_status(state, bus, &mut *state.messaging.lock().await, |status| {
+        status.phase = if outcome.is_ok() {
+            sdkmsg::SyncPhase::Idle
+        } else {
+            sdkmsg::SyncPhase::Failed
+        };
+        status.requested_at_ms = Some(requested_at_ms);
+        status.completed_at_ms = Some(now_ms());
+        status.messages_received = 0;
+        status.detail = outcome.err();
+        status.last_trigger = Some(trigger.as_str().to_string());
+        status.consecutive_failures = consecutive_failures;
+        status.next_sync_at_ms = next_sync_at_ms;
+    });
+}
+
+// Brings up the link to the active relay. Along with relayed sends, that
+// round trip is what the relay's health, and failover away from it, is judged
+// on.
+async fn probe_active_propagation_node(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+) -> Result<(), String> {
+    let Some(relay_hex) = state.active_propagation_node_hex.lock().await.clone() else {
+        return Err("no propagation node selected".to_string());
+    };
+    let probe = match parse_address_hash(relay_hex.as_str()) {
+        Ok(destination) => state.sdk.probe_propagation_node(destination).await,
+        Err(err) => Err(err),
+    };
+    match probe {
+        Ok(latency_ms) => {
+            note_propagation_node_success(state, relay_hex.as_str(), latency_ms);
+            Ok(())
+        }
+        Err(err) => {
+            info!("[sync] propagation node {relay_hex} unreachable err={err}");
+            note_propagation_node_failure(state, bus, relay_hex.as_str()).await;
+            Err(format!("propagation node {relay_hex} unreachable"))
+        }
+    }
+}
+
+async fn resolve_peer_route(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    destination_hex: &str,
+) -> Result<(), NodeError> {
+    let destination = parse_address_hash(destination_hex)?;
+    let attempted_at_ms = now_ms();
+    {
+        let mut messaging = state.messaging.lock().await;
+        messaging.record_resolution_attempt(destination_hex, attempted_at_ms);
+    }
+    emit_peer_changed(state, bus, destination_hex).await;
+
+    state.transport.request_path(&destination, None, None).await;
+    let desc = ensure_destination_desc(state, destination, None).await?;
+    let identity_hex = desc.identity.address_hash.to_hex_string();
+    let lxmf_desc = SingleOutputDestination::new(
+        desc.identity.clone(),
+        DestinationName::new(LXMF_DELIVERY_NAME.0, LXMF_DELIVERY_NAME.1),
+    )
     .desc;
     let lxmf_destination_hex = lxmf_desc.address_hash.to_hex_string();
     {
@@ -3755,7 +4313,25 @@ async fn message_records_snapshot(
         .collect()
 }
 
+// The in-memory store counts every inbound message; unread counts come from
+// the persisted read marks instead.
 async fn conversation_records_snapshot(state: &NodeRuntimeState) -> Vec<ConversationRecord> {
+    let resolver = state
+        .peers_snapshot
+        .lock()
+        .map(|peers| ConversationPeerResolver::from_peers(&peers))
+        .unwrap_or_default();
+    let unread_counts = state
+        .app_state
+        .unread_counts_by_peer(&resolver)
+        .unwrap_or_default();
+    let group_titles = state
+        .app_state
+        .list_group_conversations()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|group| (group.conversation_id, group.title))
+        .collect::<HashMap<_, _>>();
     state
         .messaging
         .lock()
@@ -3763,6 +4339,16 @@ async fn conversation_records_snapshot(state: &NodeRuntimeState) -> Vec<Conversa
         .list_conversations()
         .into_iter()
         .map(from_sdk_conversation_record)
+        .map(|mut record| {
+            record.unread_count = unread_counts
+                .get(&record.conversation_id)
+                .copied()
+                .unwrap_or(0);
+            if let Some(title) = group_titles.get(&record.conversation_id) {
+                record.peer_display_name = Some(title.clone());
+            }
+            record
+        })
         .collect()
 }
 
@@ -3784,7 +4370,7 @@ pub enum Command {
         bytes: Vec<u8>,
         fields_bytes: Option<Vec<u8>>,
         send_mode: SendMode,
-        resp: cb::Sender<Result<(), NodeError>>,
+        resp: cb::Sender<Result<SendBytesReceipt, NodeError>>,
     },
     BroadcastBytes {
         bytes: Vec<u8>,
@@ -3798,6 +4384,19 @@ pub enum Command {
         request: SendLxmfRequest,
         resp: cb::Sender<Result<String, NodeError>>,
     },
+    SendGroupLxmf {
+        group: GroupConversationRecord,
+        message_id_hex: String,
+        body_utf8: String,
+        title: Option<String>,
+        priority: Option<MessagePriority>,
+        resp: cb::Sender<Result<String, NodeError>>,
+    },
+    ApplyMessageAction {
+        message_id_hex: String,
+        kind: MessageActionKind,
+        resp: cb::Sender<Result<(), NodeError>>,
+    },
     RetryLxmf {
         message_id_hex: String,
         resp: cb::Sender<Result<(), NodeError>>,
@@ -3830,6 +4429,16 @@ pub enum Command {
     GetLxmfSyncStatus {
         resp: cb::Sender<Result<SyncStatus, NodeError>>,
     },
+    GetSendQueueMetrics {
+        resp: cb::Sender<Result<Vec<SendQueueClassMetrics>, NodeError>>,
+    },
+    ListPropagationNodes {
+        resp: cb::Sender<Result<Vec<PropagationNodeRecord>, NodeError>>,
+    },
+    SetNetworkAvailable {
+        available: bool,
+        resp: cb::Sender<Result<(), NodeError>>,
+    },
     SetAnnounceCapabilities {
         capability_string: String,
         resp: cb::Sender<Result<(), NodeError>>,
@@ -3864,6 +4473,8 @@ struct NodeRuntimeState {
     sdk: Arc<RuntimeLxmfSdk>,
     active_propagation_node_hex: Arc<TokioMutex<Option<String>>>,
     preferred_propagation_node_hex: Option<String>,
+    propagation_health: Arc<Mutex<HashMap<String, PropagationNodeHealth>>>,
+    propagation_sync: Arc<Mutex<PropagationSyncSchedule>>,
     send_task_permits: SendTaskPermits,
 }
 
@@ -3893,8 +4504,9 @@ fn prune_expired_receipt_tracking(
 async fn acquire_send_task_permit(
     permits: &SendTaskPermits,
     class: SendTaskClass,
-) -> Result<OwnedSemaphorePermit, NodeError> {
-    permits.acquire(class).await
+    priority: MessagePriority,
+) -> Result<PrioritySendPermit, NodeError> {
+    permits.acquire(class, priority).await
 }
 
 async fn ensure_destination_desc(
@@ -4132,6 +4744,11 @@ async fn register_pending_lxmf_delivery(
         sent_at_ms: now_ms(),
     };
 
+    let _ = state.app_state.upsert_pending_lxmf_delivery(
+        tracking_key.as_str(),
+        pending.sent_at_ms,
+        &pending,
+    );
     state
         .pending_lxmf_deliveries
         .lock()
@@ -4163,7 +4780,7 @@ fn lxmf_delivery_status_for(report: &LxmfSendReport) -> LxmfDeliveryStatus {
     }
 }
 
-fn node_error_code(err: &NodeError) -> &'static str {
+pub(crate) fn node_error_code(err: &NodeError) -> &'static str {
     match err {
         NodeError::InvalidConfig {} => "InvalidConfig",
         NodeError::IoError {} => "IoError",
@@ -4191,6 +4808,7 @@ fn is_retriable_lxmf_error(err: &NodeError) -> bool {
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn send_lxmf_with_delivery_policy(
     state: &NodeRuntimeState,
     requested_destination_hex: &str,
@@ -4200,6 +4818,7 @@ async fn send_lxmf_with_delivery_policy(
     metadata: Option<MissionSyncMetadata>,
     send_mode: SendMode,
     send_task_class: SendTaskClass,
+    priority: MessagePriority,
 ) -> Result<LxmfSendReport, NodeError> {
     const DIRECT_ATTEMPTS: usize = 5;
     const RETRY_DELAY: Duration = Duration::from_secs(10);
@@ -4227,7 +4846,8 @@ async fn send_lxmf_with_delivery_policy(
             ),
         );
         let _permit =
-            acquire_send_task_permit(&state.send_task_permits, propagation_task_class).await?;
+            acquire_send_task_permit(&state.send_task_permits, propagation_task_class, priority)
+                .await?;
         log_send_task(
             propagation_task_class,
             format!(
@@ -4236,16 +4856,7 @@ async fn send_lxmf_with_delivery_policy(
                 requested_destination_hex,
             ),
         );
-        return state
-            .sdk
-            .send_lxmf(
-                destination,
-                body,
-                title,
-                fields_bytes,
-                metadata,
-                SendMode::PropagationOnly {},
-            )
+        return send_via_propagation_relay(state, destination, body, title, fields_bytes, metadata)
             .await;
     }
 
@@ -4266,7 +4877,8 @@ async fn send_lxmf_with_delivery_policy(
         );
         let send_result = {
             let _permit =
-                acquire_send_task_permit(&state.send_task_permits, send_task_class).await?;
+                acquire_send_task_permit(&state.send_task_permits, send_task_class, priority)
+                    .await?;
             log_send_task(
                 send_task_class,
                 format!(
@@ -4353,7 +4965,8 @@ async fn send_lxmf_with_delivery_policy(
         ),
     );
     let _permit =
-        acquire_send_task_permit(&state.send_task_permits, propagation_task_class).await?;
+        acquire_send_task_permit(&state.send_task_permits, propagation_task_class, priority)
+            .await?;
     log_send_task(
         propagation_task_class,
         format!(
@@ -4362,17 +4975,8 @@ async fn send_lxmf_with_delivery_policy(
             requested_destination_hex,
         ),
     );
-    let mut report = state
-        .sdk
-        .send_lxmf(
-            destination,
-            body,
-            title,
-            fields_bytes,
-            metadata,
-            SendMode::PropagationOnly {},
-        )
-        .await?;
+    let mut report =
+        send_via_propagation_relay(state, destination, body, title, fields_bytes, metadata).await?;
     report.fallback_stage = Some(LxmfFallbackStage::AfterDirectRetryBudget {});
     Ok(report)
 }
@@ -4396,6 +5000,16 @@ async fn emit_received_payload(
         let fields_bytes = message
             .fields
             .and_then(|value| rmp_serde::to_vec(&value).ok());
+        let expires_at_ms = fields_bytes.as_deref().and_then(parse_expiry_field);
+        if is_expired(expires_at_ms, now_ms()) {
+            info!(
+                "[lxmf][rx] dropped expired message source={} destination={} expires_at_ms={}",
+                source_hex.as_deref().unwrap_or("-"),
+                destination_hex,
+                expires_at_ms.unwrap_or_default(),
+            );
+            return;
+        }
         let sos_fields = fields_bytes.as_deref().and_then(parse_sos_fields);
         let mut sos_telemetry = sos_fields
             .as_ref()
@@ -4418,11 +5032,26 @@ async fn emit_received_payload(
         let sos_command = sos_fields
             .as_ref()
             .and_then(|fields| fields.command.clone());
-        let is_sos_message = sos_command.is_some() || looks_like_sos_text(body_utf8.as_str());
+        let sos_response = sos_fields
+            .as_ref()
+            .and_then(|fields| fields.response.clone());
+        let sos_audio = sos_fields.as_ref().and_then(|fields| fields.audio.clone());
+        let read_receipt = fields_bytes.as_deref().and_then(parse_read_receipt_fields);
+        let message_action = fields_bytes
+            .as_deref()
+            .and_then(parse_message_action_fields);
+        let is_sos_followup = sos_response.is_some() || sos_audio.is_some();
+        let is_sos_message =
+            sos_command.is_some() || (!is_sos_followup && looks_like_sos_text(body_utf8.as_str()));
         let metadata = fields_bytes
             .as_deref()
             .and_then(parse_mission_sync_metadata);
-        if let Some(metadata) = metadata.as_ref().filter(|_| !is_sos_message) {
+        if let Some(metadata) = metadata.as_ref().filter(|_| {
+            !is_sos_message
+                && !is_sos_followup
+                && read_receipt.is_none()
+                && message_action.is_none()
+        }) {
             if metadata.is_mission_related() {
                 info!(
                     "[lxmf][mission] received kind={} name={} source={} destination={} event_uid={} mission_uid={} correlation={}",
@@ -4436,6 +5065,20 @@ async fn emit_received_payload(
                 );
             }
             ack_pending_lxmf_delivery(state, bus, source_hex.as_deref(), &metadata).await;
+            persist_received_eam_roll_call_if_present(
+                state,
+                bus,
+                Some(metadata),
+                fields_bytes.as_deref(),
+                source_hex.as_deref(),
+            );
+            persist_received_eam_schema_if_present(
+                state,
+                bus,
+                Some(metadata),
+                fields_bytes.as_deref(),
+            );
+            persist_received_team_if_present(state, bus, Some(metadata), fields_bytes.as_deref());
             persist_received_eam_if_present(
                 state,
                 bus,
@@ -4491,6 +5134,10 @@ async fn emit_received_payload(
                 sent_at_ms: None,
                 received_at_ms: Some(received_at_ms),
                 updated_at_ms: received_at_ms,
+                attachments: Vec::new(),
+                edited_at_ms: None,
+                deleted_at_ms: None,
+                reactions: Vec::new(),
             };
             upsert_message_record(state, bus, record, true).await;
             let alert = received_alert_from_sos(
@@ -4524,6 +5171,56 @@ async fn emit_received_payload(
                 }
             }
             bus.emit(NodeEvent::SosAlertChanged { alert });
+        } else if let Some(response) = sos_response {
+            let peer_hex = source_hex
+                .clone()
+                .unwrap_or_else(|| destination_hex.clone());
+            let message_id_hex = LxmfWireMessage::unpack(payload.as_slice())
+                .map(|wire| hex::encode(wire.message_id()))
+                .unwrap_or_else(|_| format!("sos-response-{}-{}", peer_hex, now_ms()));
+            let received_at_ms = now_ms();
+            let record = MessageRecord {
+                message_id_hex: message_id_hex.clone(),
+                conversation_id: conversation_id_for(peer_hex.as_str()),
+                direction: MessageDirection::Inbound {},
+                destination_hex: peer_hex.clone(),
+                source_hex: source_hex.clone(),
+                title: title.clone(),
+                body_utf8: body_utf8.clone(),
+                method: MessageMethod::Direct {},
+                state: MessageState::Received {},
+                detail: Some("sos-response".to_string()),
+                sent_at_ms: None,
+                received_at_ms: Some(received_at_ms),
+                updated_at_ms: received_at_ms,
+                attachments: Vec::new(),
+                edited_at_ms: None,
+                deleted_at_ms: None,
+                reactions: Vec::new(),
+            };
+            upsert_message_record(state, bus, record, true).await;
+            let responder = responder_from_response(
+                response.incident_id,
+                peer_hex,
+                response.kind,
+                response.eta_seconds,
+                sos_telemetry.as_ref(),
+                Some(message_id_hex),
+                received_at_ms,
+            );
+            if let Ok(invalidation) = state.app_state.upsert_sos_responder(&responder) {
+                bus.emit(NodeEvent::ProjectionInvalidated { invalidation });
+            }
+            bus.emit(NodeEvent::SosResponderUpdated { responder });
+        } else if let Some(clip) = sos_audio {
+            let peer_hex = source_hex
+                .clone()
+                .unwrap_or_else(|| destination_hex.clone());
+            persist_received_sos_audio(state, bus, peer_hex.as_str(), clip);
+        } else if let Some(receipt) = read_receipt {
+            apply_read_receipt(state, bus, source_hex.as_deref(), receipt).await;
+        } else if let Some(action) = message_action {
+            apply_remote_message_action(state, bus, source_hex.as_deref(), action).await;
         } else if !metadata
             .as_ref()
             .is_some_and(MissionSyncMetadata::is_mission_related)
@@ -4534,11 +5231,23 @@ async fn emit_received_payload(
             let message_id_hex = LxmfWireMessage::unpack(payload.as_slice())
                 .map(|wire| hex::encode(wire.message_id()))
                 .unwrap_or_else(|_| hex::encode(destination_hex.as_bytes()));
+            let attachments = fields_bytes
+                .as_deref()
+                .map(parse_attachment_fields)
+                .unwrap_or_default();
+            let attachments =
+                store_message_attachments(state, message_id_hex.as_str(), &attachments);
+            let group_conversation_id = fields_bytes
+                .as_deref()
+                .and_then(parse_group_fields)
+                .and_then(|envelope| accept_group_envelope(state, bus, envelope, &peer_hex));
             let record = MessageRecord {
                 message_id_hex,
-                conversation_id: conversation_id_for(peer_hex.as_str()),
+                conversation_id: group_conversation_id
+                    .clone()
+                    .unwrap_or_else(|| conversation_id_for(peer_hex.as_str())),
                 direction: MessageDirection::Inbound {},
-                destination_hex: peer_hex.clone(),
+                destination_hex: group_conversation_id.unwrap_or_else(|| peer_hex.clone()),
                 source_hex: source_hex.clone(),
                 title,
                 body_utf8,
@@ -4548,6 +5257,10 @@ async fn emit_received_payload(
                 sent_at_ms: None,
                 received_at_ms: Some(now_ms()),
                 updated_at_ms: now_ms(),
+                attachments,
+                edited_at_ms: None,
+                deleted_at_ms: None,
+                reactions: Vec::new(),
             };
             upsert_message_record(state, bus, record, true).await;
         }
@@ -4580,62 +5293,455 @@ async fn emit_received_payload(
     });
 }
 
-async fn ack_pending_lxmf_delivery(
+// Only messages we sent to the peer that issued the receipt move to Read.
+async fn apply_read_receipt(
     state: &NodeRuntimeState,
     bus: &EventBus,
     source_hex: Option<&str>,
-    metadata: &MissionSyncMetadata,
+    receipt: ReadReceipt,
 ) {
     let Some(source_hex) = source_hex else {
         return;
     };
-
-    let detail = metadata.ack_detail().map(ToOwned::to_owned);
-    let mut guard = state.pending_lxmf_deliveries.lock().await;
-    let mut matched: Option<PendingLxmfDelivery> = None;
-
-    for key in [
-        metadata.correlation_id.as_deref(),
-        metadata.command_id.as_deref(),
-    ]
-    .into_iter()
-    .flatten()
-    {
-        if let Some(candidate) = guard.remove(key) {
-            matched = Some(candidate);
-            break;
+    for message_id_hex in receipt.message_ids {
+        if let Ok(Some(delivery)) = state
+            .app_state
+            .get_group_delivery_by_lxmf_id(message_id_hex.as_str())
+        {
+            if delivery.member_hex.eq_ignore_ascii_case(source_hex) {
+                if let Ok(Some((delivery, invalidation))) =
+                    state.app_state.mark_group_delivery_state(
+                        message_id_hex.as_str(),
+                        MessageState::Read {},
+                        now_ms(),
+                    )
+                {
+                    bus.emit(NodeEvent::ProjectionInvalidated { invalidation });
+                    apply_group_delivery(state, bus, delivery).await;
+                }
+            }
+            continue;
+        }
+        let Ok(Some(mut record)) = state.app_state.get_message(message_id_hex.as_str()) else {
+            continue;
+        };
+        if !matches!(record.direction, MessageDirection::Outbound {})
+            || !record.destination_hex.eq_ignore_ascii_case(source_hex)
+            || matches!(record.state, MessageState::Read {})
+        {
+            continue;
         }
+        record.state = MessageState::Read {};
+        record.detail = Some("read receipt".to_string());
+        record.updated_at_ms = now_ms();
+        upsert_message_record(state, bus, record, false).await;
     }
+}
 
-    drop(guard);
-
-    let Some(pending) = matched else {
-        if let Some(tracking_key) = metadata.tracking_key().map(ToOwned::to_owned) {
-            state.pending_lxmf_acknowledgements.lock().await.insert(
-                tracking_key.clone(),
-                PendingLxmfAcknowledgement {
-                    source_hex: source_hex.to_string(),
-                    detail: detail.clone(),
-                    buffered_at_ms: now_ms(),
-                },
-            );
-            info!(
-                "[lxmf][mission] buffered acknowledgement source={} command={} correlation={} detail={}",
-                source_hex,
-                metadata.command_type.as_deref().unwrap_or("-"),
-                metadata.correlation_id.as_deref().unwrap_or("-"),
-                detail.as_deref().unwrap_or("-"),
-            );
-        }
+// Edits and deletions only land on messages the sender wrote; reactions land
+// on anything in a thread the sender is part of. A reaction to one of our room
+// messages names the LXMF id of that member's copy.
+async fn apply_remote_message_action(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    source_hex: Option<&str>,
+    action: MessageAction,
+) {
+    let Some(source_hex) = source_hex else {
         return;
     };
-    if !peer_destinations_equivalent(state, pending.destination_hex.as_str(), source_hex).await {
-        if let Some(tracking_key) = pending
-            .correlation_id
+    let group_delivery = state
+        .app_state
+        .get_group_delivery_by_lxmf_id(action.target_message_id.as_str())
+        .ok()
+        .flatten()
+        .filter(|delivery| delivery.member_hex.eq_ignore_ascii_case(source_hex));
+    let message_id_hex = group_delivery
+        .as_ref()
+        .map(|delivery| delivery.message_id_hex.clone())
+        .unwrap_or_else(|| action.target_message_id.clone());
+    let Ok(Some(mut record)) = state.app_state.get_message(message_id_hex.as_str()) else {
+        return;
+    };
+    let is_participant = group_delivery.is_some()
+        || record.destination_hex.eq_ignore_ascii_case(source_hex)
+        || record
+            .source_hex
             .as_deref()
-            .or(pending.command_id.as_deref())
-            .map(ToOwned::to_owned)
-        {
+            .is_some_and(|record_source| record_source.eq_ignore_ascii_case(source_hex));
+    if !is_participant || !apply_message_action(&mut record, &action, source_hex) {
+        return;
+    }
+    upsert_message_record(state, bus, record, false).await;
+}
+
+// Applies an edit, deletion or reaction locally, then tells every peer holding
+// a copy. Room messages are addressed per member by that member's LXMF id.
+async fn apply_local_message_action(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    receipt_message_ids: &Arc<Mutex<HashMap<String, ReceiptMessageTracking>>>,
+    message_id_hex: &str,
+    kind: MessageActionKind,
+) -> Result<(), NodeError> {
+    let mut record = state
+        .app_state
+        .get_message(message_id_hex)?
+        .ok_or(NodeError::InvalidConfig {})?;
+    let local_hex = address_hash_to_hex(&state.lxmf_destination.lock().await.desc.address_hash);
+    let action = MessageAction {
+        target_message_id: record.message_id_hex.clone(),
+        kind,
+        acted_at_ms: now_ms(),
+    };
+    if !apply_message_action(&mut record, &action, local_hex.as_str()) {
+        return Err(NodeError::InvalidConfig {});
+    }
+    let targets = if is_group_conversation_id(record.conversation_id.as_str())
+        && matches!(record.direction, MessageDirection::Outbound {})
+    {
+        state
+            .app_state
+            .list_group_deliveries(message_id_hex)?
+            .into_iter()
+            .filter_map(|delivery| {
+                delivery
+                    .lxmf_message_id_hex
+                    .map(|lxmf_message_id_hex| (delivery.member_hex, lxmf_message_id_hex))
+            })
+            .collect::<Vec<_>>()
+    } else {
+        let peer_hex = match record.direction {
+            MessageDirection::Inbound {} => record.source_hex.clone(),
+            MessageDirection::Outbound {} => Some(record.destination_hex.clone()),
+        };
+        peer_hex
+            .map(|peer_hex| (peer_hex, record.message_id_hex.clone()))
+            .into_iter()
+            .collect()
+    };
+    upsert_message_record(state, bus, record, false).await;
+
+    for (destination_hex, target_message_id) in targets {
+        // Locally generated ids never reached the peer, so there is nothing
+        // on their side to update.
+        let Ok(fields_bytes) = build_message_action_fields(&MessageAction {
+            target_message_id,
+            ..action.clone()
+        }) else {
+            continue;
+        };
+        let job = QueuedBytes {
+            destination_hex,
+            bytes: Vec::new(),
+            fields_bytes: Some(fields_bytes),
+            send_mode: SendMode::Auto {},
+        };
+        let queue_id = enqueue_outbound(state, OutboundPayload::Bytes { job: job.clone() });
+        let (resp_tx, _resp_rx) = cb::bounded(1);
+        spawn_send_bytes(
+            state.clone(),
+            bus.clone(),
+            receipt_message_ids.clone(),
+            job,
+            queue_id,
+            resp_tx,
+        );
+    }
+    Ok(())
+}
+
+// Publishes a member's delivery and moves the room message to the state the
+// whole fan-out has reached.
+async fn apply_group_delivery(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    delivery: GroupMemberDeliveryRecord,
+) {
+    let message_id_hex = delivery.message_id_hex.clone();
+    bus.emit(NodeEvent::GroupDeliveryUpdated { delivery });
+    let Ok(deliveries) = state
+        .app_state
+        .list_group_deliveries(message_id_hex.as_str())
+    else {
+        return;
+    };
+    let Ok(Some(mut record)) = state.app_state.get_message(message_id_hex.as_str()) else {
+        return;
+    };
+    let next_state = group_message_state(&deliveries);
+    if record.state == next_state {
+        return;
+    }
+    let failed = deliveries
+        .iter()
+        .filter(|delivery| matches!(delivery.state, MessageState::Failed {}))
+        .count();
+    record.state = next_state;
+    record.detail =
+        (failed > 0).then(|| format!("{failed} of {} members failed", deliveries.len()));
+    record.updated_at_ms = now_ms();
+    upsert_message_record(state, bus, record, false).await;
+}
+
+// Files an inbound group copy into its room. Rooms we have not seen yet are
+// learned from the envelope; copies from senders outside a known room fall
+// back to the sender's direct thread.
+fn accept_group_envelope(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    envelope: GroupEnvelope,
+    sender_hex: &str,
+) -> Option<String> {
+    let sender_hex = normalize_member_hex(sender_hex)?;
+    match state
+        .app_state
+        .get_group_conversation(envelope.conversation_id.as_str())
+    {
+        Ok(Some(group)) => {
+            is_group_member(&group, sender_hex.as_str()).then_some(group.conversation_id)
+        }
+        // A room this node has not seen is only learned from a saved peer;
+        // otherwise one message from anyone could plant a room and its member
+        // list, so the copy stays in the sender's direct thread instead.
+        Ok(None) if is_saved_group_sender(state, sender_hex.as_str()) => {
+            let mut group = envelope.to_group(now_ms());
+            if !group.member_hexes.contains(&sender_hex) {
+                group.member_hexes.push(sender_hex);
+                group.member_hexes.sort();
+            }
+            let invalidation = state.app_state.upsert_group_conversation(&group).ok()?;
+            bus.emit(NodeEvent::ProjectionInvalidated { invalidation });
+            Some(group.conversation_id)
+        }
+        Ok(None) | Err(_) => None,
+    }
+}
+
+fn is_saved_group_sender(state: &NodeRuntimeState, sender_hex: &str) -> bool {
+    let saved = state
+        .app_state
+        .get_saved_peers()
+        .unwrap_or_default()
+        .iter()
+        .any(|peer| peer.destination_hex.eq_ignore_ascii_case(sender_hex));
+    saved
+        || state.peers_snapshot.lock().is_ok_and(|peers| {
+            peers.iter().any(|peer| {
+                peer.saved
+                    && (peer.destination_hex.eq_ignore_ascii_case(sender_hex)
+                        || peer
+                            .lxmf_destination_hex
+                            .as_deref()
+                            .is_some_and(|hex| hex.eq_ignore_ascii_case(sender_hex)))
+            })
+        })
+}
+
+fn sent_message_state(report: &LxmfSendReport) -> MessageState {
+    let sent = matches!(
+        report.outcome,
+        RnsSendOutcome::SentDirect | RnsSendOutcome::SentBroadcast
+    );
+    if sent && report.used_propagation_node {
+        MessageState::SentToPropagation {}
+    } else if sent {
+        MessageState::SentDirect {}
+    } else {
+        MessageState::Failed {}
+    }
+}
+
+// Attachments are written under the message they travel with. Inbound bytes
+// past the size cap keep their metadata but are never written to storage.
+fn store_message_attachments(
+    state: &NodeRuntimeState,
+    message_id_hex: &str,
+    attachments: &[AttachmentPayload],
+) -> Vec<MessageAttachmentRecord> {
+    let mut stored_bytes = 0usize;
+    attachments
+        .iter()
+        .map(|attachment| {
+            stored_bytes = stored_bytes.saturating_add(attachment.bytes.len());
+            if stored_bytes > ATTACHMENT_MAX_TOTAL_BYTES {
+                info!(
+                    "[lxmf][attachments] skipped oversized attachment message_id={} file={} bytes={}",
+                    message_id_hex,
+                    attachment.file_name,
+                    attachment.bytes.len(),
+                );
+                return attachment.record(None);
+            }
+            let path = state
+                .app_state
+                .write_message_attachment(
+                    message_id_hex,
+                    attachment.file_name.as_str(),
+                    attachment.bytes.as_slice(),
+                )
+                .map_err(|err| {
+                    error!(
+                        "[lxmf][attachments] write failed message_id={} file={} err={err}",
+                        message_id_hex, attachment.file_name,
+                    );
+                })
+                .ok();
+            attachment.record(path)
+        })
+        .collect()
+}
+
+fn retry_attachment_requests(records: &[MessageAttachmentRecord]) -> Vec<LxmfAttachmentRequest> {
+    records
+        .iter()
+        .filter_map(|record| {
+            Some(LxmfAttachmentRequest {
+                kind: record.kind,
+                path: record.path.clone()?,
+                file_name: Some(record.file_name.clone()),
+                mime_type: record.mime_type.clone(),
+            })
+        })
+        .collect()
+}
+
+// Clips are only kept for an active alert already received from the same
+// sender, and clips outside the size limit or codec whitelist are dropped
+// before anything touches the filesystem.
+fn persist_received_sos_audio(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    source_hex: &str,
+    clip: SosAudioClip,
+) {
+    let alert = state.app_state.list_sos_alerts().ok().and_then(|alerts| {
+        alerts.into_iter().find(|alert| {
+            alert.active && alert.incident_id == clip.incident_id && alert.source_hex == source_hex
+        })
+    });
+    let Some(mut alert) = alert else {
+        info!(
+            "[sos] ignored audio clip without an active alert incident_id={} source={}",
+            clip.incident_id, source_hex,
+        );
+        return;
+    };
+    let extension = match validate_sos_audio(clip.mime_type.as_str(), clip.bytes.len() as u64) {
+        Ok(extension) => extension,
+        Err(_) => {
+            info!(
+                "[sos] rejected audio clip audio_id={} source={} mime={} bytes={}",
+                clip.audio_id,
+                source_hex,
+                clip.mime_type,
+                clip.bytes.len(),
+            );
+            return;
+        }
+    };
+    let audio_id = received_sos_audio_id(
+        source_hex,
+        clip.incident_id.as_str(),
+        clip.audio_id.as_str(),
+    );
+    let file_name = sos_audio_file_name(audio_id.as_str(), extension);
+    let path = match state
+        .app_state
+        .write_sos_audio_file(file_name.as_str(), clip.bytes.as_slice())
+    {
+        Ok(path) => path,
+        Err(err) => {
+            error!(
+                "[sos] audio clip write failed audio_id={} err={err}",
+                clip.audio_id
+            );
+            return;
+        }
+    };
+    let audio = SosAudioRecord {
+        audio_id: audio_id.clone(),
+        incident_id: clip.incident_id.clone(),
+        source_hex: source_hex.to_string(),
+        path,
+        mime_type: clip.mime_type,
+        duration_seconds: clip.duration_seconds,
+        created_at_ms: now_ms(),
+    };
+    if let Ok(invalidation) = state.app_state.upsert_sos_audio(&audio) {
+        bus.emit(NodeEvent::ProjectionInvalidated { invalidation });
+    }
+
+    // Link the clip to the alert it belongs to so the alert view can play it.
+    alert.audio_id = Some(audio_id);
+    if let Ok(invalidation) = state.app_state.upsert_sos_alert(&alert) {
+        bus.emit(NodeEvent::ProjectionInvalidated { invalidation });
+    }
+    bus.emit(NodeEvent::SosAlertChanged { alert });
+}
+
+async fn ack_pending_lxmf_delivery(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    source_hex: Option<&str>,
+    metadata: &MissionSyncMetadata,
+) {
+    let Some(source_hex) = source_hex else {
+        return;
+    };
+
+    let detail = metadata.ack_detail().map(ToOwned::to_owned);
+    let mut guard = state.pending_lxmf_deliveries.lock().await;
+    let mut matched: Option<PendingLxmfDelivery> = None;
+
+    for key in [
+        metadata.correlation_id.as_deref(),
+        metadata.command_id.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        if let Some(candidate) = guard.remove(key) {
+            let _ = state.app_state.delete_pending_lxmf_delivery(key);
+            matched = Some(candidate);
+            break;
+        }
+    }
+
+    drop(guard);
+
+    let Some(pending) = matched else {
+        if let Some(tracking_key) = metadata.tracking_key().map(ToOwned::to_owned) {
+            state.pending_lxmf_acknowledgements.lock().await.insert(
+                tracking_key.clone(),
+                PendingLxmfAcknowledgement {
+                    source_hex: source_hex.to_string(),
+                    detail: detail.clone(),
+                    buffered_at_ms: now_ms(),
+                },
+            );
+            info!(
+                "[lxmf][mission] buffered acknowledgement source={} command={} correlation={} detail={}",
+                source_hex,
+                metadata.command_type.as_deref().unwrap_or("-"),
+                metadata.correlation_id.as_deref().unwrap_or("-"),
+                detail.as_deref().unwrap_or("-"),
+            );
+        }
+        return;
+    };
+    if !peer_destinations_equivalent(state, pending.destination_hex.as_str(), source_hex).await {
+        if let Some(tracking_key) = pending
+            .correlation_id
+            .as_deref()
+            .or(pending.command_id.as_deref())
+            .map(ToOwned::to_owned)
+        {
+            let _ = state.app_state.upsert_pending_lxmf_delivery(
+                tracking_key.as_str(),
+                pending.sent_at_ms,
+                &pending,
+            );
             state
                 .pending_lxmf_deliveries
                 .lock()
@@ -4929,6 +6035,808 @@ async fn refresh_hub_directory_lxmf(
     }
 }
 
+async fn refresh_hub_directory(
+    config: &NodeConfig,
+    state: &NodeRuntimeState,
+    hub_client: Option<&HubHttpClient>,
+) -> Result<HubDirectorySnapshot, NodeError> {
+    if let Some(client) = hub_client {
+        match client.fetch_directory().await {
+            Ok(snapshot) => return Ok(snapshot),
+            Err(err) => {
+                info!("[hub][http] directory fetch failed, falling back to lxmf: {err}");
+            }
+        }
+    }
+    refresh_hub_directory_lxmf(config, state).await
+}
+
+async fn register_with_hub(
+    config: &NodeConfig,
+    state: &NodeRuntimeState,
+    hub_client: &HubHttpClient,
+) -> Result<(), NodeError> {
+    let registration = HubRegistration {
+        identity: state.identity.address_hash().to_hex_string(),
+        destination_hash: state.app_destination_hex.clone(),
+        display_name: config.name.clone(),
+        announce_capabilities: registration_capabilities(config.announce_capabilities.as_str()),
+    };
+    hub_client.register(&registration).await
+}
+
+fn spawn_send_bytes(
+    state: NodeRuntimeState,
+    bus: EventBus,
+    receipt_message_ids: Arc<Mutex<HashMap<String, ReceiptMessageTracking>>>,
+    job: QueuedBytes,
+    queue_id: Option<String>,
+    resp: cb::Sender<Result<SendBytesReceipt, NodeError>>,
+) {
+    let QueuedBytes {
+        destination_hex,
+        bytes,
+        fields_bytes,
+        send_mode,
+    } = job;
+    let transport = state.transport.clone();
+    let metadata = fields_bytes
+        .as_deref()
+        .and_then(parse_mission_sync_metadata);
+    let send_task_class =
+        SendTaskClass::from_lxmf_request(fields_bytes.is_some(), metadata.as_ref(), &send_mode);
+    let priority = priority_for_fields(fields_bytes.as_deref());
+    log_send_task(
+        send_task_class,
+        format!(
+            "[lxmf][queue] enqueued {} send destination={} mode={:?} priority={:?} has_fields={}",
+            send_task_class.label(),
+            destination_hex,
+            send_mode,
+            priority,
+            fields_bytes.is_some(),
+        ),
+    );
+    tokio::spawn(async move {
+        note_outbound_attempt(&state, queue_id.as_deref());
+        let result = async {
+            let lxmf_report = if fields_bytes.is_some() {
+                Some(
+                    send_lxmf_with_delivery_policy(
+                        &state,
+                        &destination_hex,
+                        &bytes,
+                        None,
+                        fields_bytes.clone(),
+                        metadata.clone(),
+                        send_mode,
+                        send_task_class,
+                        priority,
+                    )
+                    .await?,
+                )
+            } else {
+                None
+            };
+            let outcome = if let Some(report) = lxmf_report.as_ref() {
+                report.outcome
+            } else {
+                log_send_task(
+                    SendTaskClass::General,
+                    format!(
+                        "[lxmf][queue] waiting for general send slot destination={} mode=transport-bytes",
+                        destination_hex,
+                    ),
+                );
+                let _permit = acquire_send_task_permit(
+                    &state.send_task_permits,
+                    SendTaskClass::General,
+                    priority,
+                )
+                .await?;
+                log_send_task(
+                    SendTaskClass::General,
+                    format!(
+                        "[lxmf][queue] acquired general send slot destination={} mode=transport-bytes",
+                        destination_hex,
+                    ),
+                );
+                let dest = parse_address_hash(&destination_hex)?;
+                send_transport_packet_with_path_retry(&transport, dest, &bytes).await
+            };
+            let mapped = send_outcome_to_udl(outcome);
+            bus.emit(NodeEvent::PacketSent {
+                destination_hex: destination_hex.clone(),
+                bytes: bytes.clone(),
+                outcome: mapped,
+            });
+
+            if let Some(report) = lxmf_report.as_ref() {
+                if let Some(metadata) = report.metadata.as_ref() {
+                    if metadata.is_mission_related() {
+                        info!(
+                            "[lxmf][mission] outbound kind={} name={} destination={} message_id={} event_uid={} mission_uid={} correlation={}",
+                            metadata.primary_kind(),
+                            metadata.primary_name().unwrap_or("-"),
+                            report.resolved_destination_hex.as_str(),
+                            report.message_id_hex,
+                            metadata.event_uid.as_deref().unwrap_or("-"),
+                            metadata.mission_uid.as_deref().unwrap_or("-"),
+                            metadata.correlation_id.as_deref().unwrap_or("-"),
+                        );
+                    }
+                }
+
+                if let Some(registered) = register_pending_lxmf_delivery(&state, report).await {
+                    let pending = &registered.pending;
+                    if matches!(
+                        report.outcome,
+                        RnsSendOutcome::SentDirect | RnsSendOutcome::SentBroadcast
+                    ) {
+                        state.sdk.record_delivery_sent(
+                            &pending.message_id_hex,
+                            &pending.destination_hex,
+                            pending.correlation_id.as_deref(),
+                            pending.command_id.as_deref(),
+                            pending.command_type.as_deref(),
+                            pending.event_uid.as_deref(),
+                            pending.mission_uid.as_deref(),
+                        );
+                        emit_lxmf_delivery(
+                            &bus,
+                            &pending,
+                            lxmf_delivery_status_for(report),
+                            None,
+                        );
+                        info!(
+                            "[lxmf][mission] sent message_id={} destination={} command={} correlation={}",
+                            pending.message_id_hex,
+                            pending.destination_hex,
+                            pending.command_type.as_deref().unwrap_or("-"),
+                            pending.correlation_id.as_deref().unwrap_or("-"),
+                        );
+                        if let Some(buffered_ack) = registered.buffered_ack {
+                            let tracking_key = pending
+                                .correlation_id
+                                .as_deref()
+                                .or(pending.command_id.as_deref())
+                                .map(ToOwned::to_owned);
+                            if peer_destinations_equivalent(
+                                &state,
+                                pending.destination_hex.as_str(),
+                                buffered_ack.source_hex.as_str(),
+                            )
+                            .await
+                            {
+                                if let Some(tracking_key) = tracking_key.as_deref() {
+                                    state
+                                        .pending_lxmf_deliveries
+                                        .lock()
+                                        .await
+                                        .remove(tracking_key);
+                                    let _ = state.app_state.delete_pending_lxmf_delivery(tracking_key);
+                                }
+                                state.sdk.record_delivery_acknowledged(
+                                    &pending.message_id_hex,
+                                    &pending.destination_hex,
+                                    Some(buffered_ack.source_hex.as_str()),
+                                    pending.correlation_id.as_deref(),
+                                    pending.command_id.as_deref(),
+                                    pending.command_type.as_deref(),
+                                    pending.event_uid.as_deref(),
+                                    pending.mission_uid.as_deref(),
+                                    buffered_ack.detail.as_deref(),
+                                );
+                                emit_lxmf_delivery_with_source(
+                                    &bus,
+                                    pending,
+                                    Some(buffered_ack.source_hex.clone()),
+                                    LxmfDeliveryStatus::Acknowledged {},
+                                    buffered_ack.detail.clone(),
+                                );
+                                info!(
+                                    "[lxmf][mission] acknowledged buffered message_id={} destination={} command={} correlation={} detail={}",
+                                    pending.message_id_hex,
+                                    pending.destination_hex,
+                                    pending.command_type.as_deref().unwrap_or("-"),
+                                    pending.correlation_id.as_deref().unwrap_or("-"),
+                                    buffered_ack.detail.as_deref().unwrap_or("-"),
+                                );
+                            } else {
+                                if let Some(tracking_key) = tracking_key {
+                                    state
+                                        .pending_lxmf_acknowledgements
+                                        .lock()
+                                        .await
+                                        .insert(tracking_key, buffered_ack.clone());
+                                }
+                                info!(
+                                    "[lxmf][mission] buffered acknowledgement source mismatch message_id={} destination={} source={}",
+                                    pending.message_id_hex,
+                                    pending.destination_hex,
+                                    buffered_ack.source_hex,
+                                );
+                            }
+                        }
+                    } else {
+                        let failure_detail = format!("{mapped:?}");
+                        {
+                            let tracking_key = pending
+                                .correlation_id
+                                .as_deref()
+                                .or(pending.command_id.as_deref())
+                                .map(ToOwned::to_owned);
+                            if let Some(tracking_key) = tracking_key {
+                                state.pending_lxmf_deliveries.lock().await.remove(&tracking_key);
+                                let _ = state.app_state.delete_pending_lxmf_delivery(&tracking_key);
+                            }
+                        }
+                        state.sdk.record_delivery_failed(
+                            &pending.message_id_hex,
+                            &pending.destination_hex,
+                            pending.correlation_id.as_deref(),
+                            pending.command_id.as_deref(),
+                            pending.command_type.as_deref(),
+                            pending.event_uid.as_deref(),
+                            pending.mission_uid.as_deref(),
+                            Some(failure_detail.as_str()),
+                        );
+                        emit_lxmf_delivery(
+                            &bus,
+                            &pending,
+                            LxmfDeliveryStatus::Failed {},
+                            Some(failure_detail.clone()),
+                        );
+                        info!(
+                            "[lxmf][mission] failed message_id={} destination={} command={} correlation={} outcome={:?}",
+                            pending.message_id_hex,
+                            pending.destination_hex,
+                            pending.command_type.as_deref().unwrap_or("-"),
+                            pending.correlation_id.as_deref().unwrap_or("-"),
+                            mapped,
+                        );
+                    }
+                }
+            }
+
+            if !matches!(
+                outcome,
+                RnsSendOutcome::SentDirect | RnsSendOutcome::SentBroadcast
+            ) {
+                return Err(NodeError::NetworkError {});
+            }
+            let Some(report) = lxmf_report else {
+                return Ok(SendBytesReceipt::default());
+            };
+            if let Some(receipt_hash_hex) = report.receipt_hash_hex.as_ref() {
+                if let Ok(mut guard) = receipt_message_ids.lock() {
+                    guard.insert(
+                        receipt_hash_hex.clone(),
+                        ReceiptMessageTracking {
+                            message_id_hex: report.message_id_hex.clone(),
+                            recorded_at_ms: now_ms(),
+                        },
+                    );
+                }
+            }
+            Ok(SendBytesReceipt {
+                message_id_hex: Some(report.message_id_hex),
+                used_propagation_node: report.used_propagation_node,
+            })
+        }
+        .await;
+        if let Err(err) = &result {
+            bus.emit(NodeEvent::Error {
+                code: node_error_code(err).to_string(),
+                message: format!(
+                    "send_bytes failed destination={} reason={}",
+                    destination_hex, err
+                ),
+            });
+        }
+        finish_outbound(
+            &state,
+            &bus,
+            &receipt_message_ids,
+            queue_id.as_deref(),
+            result.as_ref().err(),
+        );
+        let _ = resp.send(result);
+    });
+}
+
+fn spawn_send_lxmf(
+    state: NodeRuntimeState,
+    bus: EventBus,
+    receipt_message_ids: Arc<Mutex<HashMap<String, ReceiptMessageTracking>>>,
+    request: SendLxmfRequest,
+    queue_id: Option<String>,
+    resp: cb::Sender<Result<String, NodeError>>,
+) {
+    let priority = request.priority.unwrap_or(MessagePriority::Routine {});
+    log_send_task(
+        SendTaskClass::General,
+        format!(
+            "[lxmf][queue] enqueued general send destination={} mode={:?} priority={:?} has_fields={}",
+            request.destination_hex,
+            request.send_mode,
+            priority,
+            !request.attachments.is_empty(),
+        ),
+    );
+    tokio::spawn(async move {
+        let mut resp = Some(resp);
+        // A scheduled message is shown under its queue id until it goes out;
+        // the caller gets that id straight away instead of waiting on the delay.
+        let placeholder_id = request.send_at_ms.and(queue_id.clone());
+        let delay_ms = request
+            .send_at_ms
+            .map(|send_at_ms| send_at_ms.saturating_sub(now_ms()))
+            .unwrap_or(0);
+        if delay_ms > 0 {
+            // Without a queue row the message could neither be shown nor
+            // cancelled while it waits, so it is refused straight away.
+            let reply = placeholder_id.clone().ok_or(NodeError::IoError {});
+            let refused = reply.is_err();
+            if let Some(resp) = resp.take() {
+                let _ = resp.send(reply);
+            }
+            if refused {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+        }
+        if !note_outbound_attempt(&state, queue_id.as_deref()) {
+            // Cancelled while it was waiting for its send time.
+            return;
+        }
+        let result = async {
+            if is_expired(request.expires_at_ms, now_ms()) {
+                return expire_outbound_message(&state, &bus, &request, placeholder_id.as_deref())
+                    .await;
+            }
+            let body_bytes = request.body_utf8.as_bytes().to_vec();
+            let attachments = load_outbound_attachments(&request.attachments)?;
+            let send = send_lxmf_with_delivery_policy(
+                &state,
+                request.destination_hex.as_str(),
+                body_bytes.as_slice(),
+                request.title.clone(),
+                with_expiry_field(
+                    build_attachment_fields(&attachments)?,
+                    request.expires_at_ms,
+                )?,
+                None,
+                request.send_mode,
+                SendTaskClass::General,
+                priority,
+            );
+            // The deadline cancels whatever the delivery policy is still doing:
+            // waiting on a path, sleeping between direct attempts, or falling
+            // back to a propagation node.
+            let report = match request.expires_at_ms {
+                Some(expires_at_ms) => {
+                    let remaining = Duration::from_millis(expires_at_ms.saturating_sub(now_ms()));
+                    match tokio::time::timeout(remaining, send).await {
+                        Ok(report) => report?,
+                        Err(_) => {
+                            return expire_outbound_message(
+                                &state,
+                                &bus,
+                                &request,
+                                placeholder_id.as_deref(),
+                            )
+                            .await;
+                        }
+                    }
+                }
+                None => send.await?,
+            };
+            if let Some(placeholder_id) = placeholder_id.as_deref() {
+                remove_message_record(&state, &bus, placeholder_id, "scheduled-message-sent").await;
+            }
+            let method = match (report.method, report.representation) {
+                (LxmfDeliveryMethod::Propagated {}, _) => MessageMethod::Propagated {},
+                (LxmfDeliveryMethod::Opportunistic {}, _) => MessageMethod::Opportunistic {},
+                (_, LxmfDeliveryRepresentation::Resource {}) => MessageMethod::Resource {},
+                _ => MessageMethod::Direct {},
+            };
+            let state_value = sent_message_state(&report);
+            let detail = if matches!(state_value, MessageState::Failed {}) {
+                Some(format!("{:?}", send_outcome_to_udl(report.outcome)))
+            } else {
+                None
+            };
+            let conversation_id = conversation_id_for(report.resolved_destination_hex.as_str());
+            let attachment_records =
+                store_message_attachments(&state, report.message_id_hex.as_str(), &attachments);
+            let record = MessageRecord {
+                message_id_hex: report.message_id_hex.clone(),
+                conversation_id,
+                direction: MessageDirection::Outbound {},
+                destination_hex: report.resolved_destination_hex.clone(),
+                source_hex: Some(address_hash_to_hex(
+                    &state.lxmf_destination.lock().await.desc.address_hash,
+                )),
+                title: request.title.clone(),
+                body_utf8: request.body_utf8.clone(),
+                method,
+                state: state_value,
+                detail: detail.clone(),
+                sent_at_ms: Some(now_ms()),
+                received_at_ms: None,
+                updated_at_ms: now_ms(),
+                attachments: attachment_records.clone(),
+                edited_at_ms: None,
+                deleted_at_ms: None,
+                reactions: Vec::new(),
+            };
+            upsert_message_record(&state, &bus, record, false).await;
+            let _ = state
+                .app_state
+                .set_message_priority(report.message_id_hex.as_str(), priority);
+            state
+                .messaging
+                .lock()
+                .await
+                .store_outbound(sdkmsg::StoredOutboundMessage {
+                    request: to_sdk_send_request(&request, &attachment_records),
+                    message_id_hex: report.message_id_hex.clone(),
+                });
+            if let Some(receipt_hash_hex) = report.receipt_hash_hex.as_ref() {
+                if let Ok(mut guard) = receipt_message_ids.lock() {
+                    guard.insert(
+                        receipt_hash_hex.clone(),
+                        ReceiptMessageTracking {
+                            message_id_hex: report.message_id_hex.clone(),
+                            recorded_at_ms: now_ms(),
+                        },
+                    );
+                }
+            }
+            Ok::<String, NodeError>(report.message_id_hex)
+        }
+        .await;
+        if let Err(err) = &result {
+            bus.emit(NodeEvent::Error {
+                code: node_error_code(err).to_string(),
+                message: format!(
+                    "send_lxmf failed destination={} reason={}",
+                    request.destination_hex, err
+                ),
+            });
+        }
+        finish_outbound(
+            &state,
+            &bus,
+            &receipt_message_ids,
+            queue_id.as_deref(),
+            result.as_ref().err(),
+        );
+        if let Some(resp) = resp {
+            let _ = resp.send(result);
+        }
+    });
+}
+
+// Sends one member's copy of a group message and folds the outcome into the
+// room message.
+fn spawn_send_group_copy(
+    state: NodeRuntimeState,
+    bus: EventBus,
+    receipt_message_ids: Arc<Mutex<HashMap<String, ReceiptMessageTracking>>>,
+    copy: QueuedGroupCopy,
+    queue_id: Option<String>,
+) {
+    tokio::spawn(async move {
+        if !note_outbound_attempt(&state, queue_id.as_deref()) {
+            return;
+        }
+        let QueuedGroupCopy {
+            message_id_hex,
+            member_hex,
+            body_utf8,
+            title,
+            fields_bytes,
+            priority,
+        } = copy;
+        let report = send_lxmf_with_delivery_policy(
+            &state,
+            member_hex.as_str(),
+            body_utf8.as_bytes(),
+            title,
+            Some(fields_bytes),
+            None,
+            SendMode::Auto {},
+            SendTaskClass::General,
+            priority,
+        )
+        .await;
+        let error = report.as_ref().err().copied();
+        let delivery = match report {
+            Ok(report) => {
+                if let Some(receipt_hash_hex) = report.receipt_hash_hex.as_ref() {
+                    if let Ok(mut guard) = receipt_message_ids.lock() {
+                        guard.insert(
+                            receipt_hash_hex.clone(),
+                            ReceiptMessageTracking {
+                                message_id_hex: report.message_id_hex.clone(),
+                                recorded_at_ms: now_ms(),
+                            },
+                        );
+                    }
+                }
+                let delivery_state = sent_message_state(&report);
+                GroupMemberDeliveryRecord {
+                    message_id_hex,
+                    member_hex,
+                    lxmf_message_id_hex: Some(report.message_id_hex),
+                    detail: matches!(delivery_state, MessageState::Failed {})
+                        .then(|| format!("{:?}", send_outcome_to_udl(report.outcome))),
+                    state: delivery_state,
+                    updated_at_ms: now_ms(),
+                }
+            }
+            Err(err) => GroupMemberDeliveryRecord {
+                message_id_hex,
+                member_hex,
+                lxmf_message_id_hex: None,
+                state: MessageState::Failed {},
+                detail: Some(err.to_string()),
+                updated_at_ms: now_ms(),
+            },
+        };
+        if let Ok(invalidation) = state.app_state.upsert_group_delivery(&delivery) {
+            bus.emit(NodeEvent::ProjectionInvalidated { invalidation });
+        }
+        apply_group_delivery(&state, &bus, delivery).await;
+        finish_outbound(
+            &state,
+            &bus,
+            &receipt_message_ids,
+            queue_id.as_deref(),
+            error.as_ref(),
+        );
+    });
+}
+
+// Records a scheduled message under its queue id so it shows up in the thread
+// before it is sent.
+async fn record_scheduled_message(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    request: &SendLxmfRequest,
+    queue_id: &str,
+) {
+    let now = now_ms();
+    let record = MessageRecord {
+        message_id_hex: queue_id.to_string(),
+        conversation_id: conversation_id_for(request.destination_hex.as_str()),
+        direction: MessageDirection::Outbound {},
+        destination_hex: request.destination_hex.clone(),
+        source_hex: Some(address_hash_to_hex(
+            &state.lxmf_destination.lock().await.desc.address_hash,
+        )),
+        title: request.title.clone(),
+        body_utf8: request.body_utf8.clone(),
+        method: MessageMethod::Direct {},
+        state: MessageState::Scheduled {},
+        detail: None,
+        sent_at_ms: None,
+        received_at_ms: None,
+        updated_at_ms: now,
+        attachments: Vec::new(),
+        edited_at_ms: None,
+        deleted_at_ms: None,
+        reactions: Vec::new(),
+    };
+    upsert_message_record(state, bus, record, false).await;
+}
+
+// An expired message is never handed to the delivery policy again. It stays
+// in the thread as `Expired` so the sender can see the order did not go out.
+async fn expire_outbound_message(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    request: &SendLxmfRequest,
+    placeholder_id: Option<&str>,
+) -> Result<String, NodeError> {
+    let now = now_ms();
+    let message_id_hex = placeholder_id
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("expired-{now}"));
+    let detail = Some("expired before delivery".to_string());
+    let record = MessageRecord {
+        message_id_hex: message_id_hex.clone(),
+        conversation_id: conversation_id_for(request.destination_hex.as_str()),
+        direction: MessageDirection::Outbound {},
+        destination_hex: request.destination_hex.clone(),
+        source_hex: Some(address_hash_to_hex(
+            &state.lxmf_destination.lock().await.desc.address_hash,
+        )),
+        title: request.title.clone(),
+        body_utf8: request.body_utf8.clone(),
+        method: MessageMethod::Direct {},
+        state: MessageState::Expired {},
+        detail: detail.clone(),
+        sent_at_ms: None,
+        received_at_ms: None,
+        updated_at_ms: now,
+        attachments: Vec::new(),
+        edited_at_ms: None,
+        deleted_at_ms: None,
+        reactions: Vec::new(),
+    };
+    upsert_message_record(state, bus, record, false).await;
+    bus.emit(NodeEvent::LxmfDelivery {
+        update: LxmfDeliveryUpdate {
+            message_id_hex: message_id_hex.clone(),
+            destination_hex: request.destination_hex.clone(),
+            source_hex: None,
+            correlation_id: None,
+            command_id: None,
+            command_type: None,
+            event_uid: None,
+            mission_uid: None,
+            status: LxmfDeliveryStatus::Expired {},
+            method: LxmfDeliveryMethod::Direct {},
+            representation: LxmfDeliveryRepresentation::Packet {},
+            relay_destination_hex: None,
+            fallback_stage: None,
+            detail,
+            sent_at_ms: now,
+            updated_at_ms: now,
+        },
+    });
+    info!(
+        "[lxmf][queue] expired before delivery message_id={} destination={}",
+        message_id_hex, request.destination_hex,
+    );
+    Ok(message_id_hex)
+}
+
+async fn remove_message_record(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    message_id_hex: &str,
+    reason: &str,
+) {
+    if let Ok(invalidations) = state.app_state.delete_message(message_id_hex, reason) {
+        for invalidation in invalidations {
+            bus.emit(NodeEvent::ProjectionInvalidated { invalidation });
+        }
+    }
+    state
+        .projection_journal
+        .forget_message(message_id_hex, Some(reason));
+    state.messaging.lock().await.remove_message(message_id_hex);
+}
+
+fn enqueue_outbound(state: &NodeRuntimeState, payload: OutboundPayload) -> Option<String> {
+    let record = OutboundQueueRecord::new(payload, now_ms());
+    match state.app_state.enqueue_outbound(&record) {
+        Ok(()) => Some(record.queue_id),
+        Err(err) => {
+            error!(
+                "[lxmf][queue] could not persist send destination={} err={err}",
+                record.payload.destination_hex(),
+            );
+            None
+        }
+    }
+}
+
+// Returns false once the queue entry is gone, which is how a cancelled
+// scheduled send is told apart from one that is due.
+fn note_outbound_attempt(state: &NodeRuntimeState, queue_id: Option<&str>) -> bool {
+    let Some(queue_id) = queue_id else {
+        return true;
+    };
+    !matches!(
+        state.app_state.record_outbound_attempt(queue_id, now_ms()),
+        Ok(None)
+    )
+}
+
+// A send that went out, or failed in a way another attempt cannot fix, leaves
+// the queue. Anything else stays queued and is tried again after a backoff.
+fn finish_outbound(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    receipt_message_ids: &Arc<Mutex<HashMap<String, ReceiptMessageTracking>>>,
+    queue_id: Option<&str>,
+    error: Option<&NodeError>,
+) {
+    let Some(queue_id) = queue_id else {
+        return;
+    };
+    if !error.is_some_and(is_retriable_lxmf_error) {
+        let _ = state.app_state.remove_outbound(queue_id);
+        return;
+    }
+    if let Ok(Some(record)) = state.app_state.schedule_outbound_retry(queue_id, now_ms()) {
+        spawn_outbound_record(state, bus, receipt_message_ids, record);
+    }
+}
+
+// Picks the durable queue back up after a restart. Entries go through the same
+// delivery policy as a fresh send; nobody is waiting on their reply any more.
+fn resume_outbound_queue(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    receipt_message_ids: &Arc<Mutex<HashMap<String, ReceiptMessageTracking>>>,
+) {
+    let Ok(records) = state.app_state.list_outbound_queue() else {
+        return;
+    };
+    for record in records {
+        spawn_outbound_record(state, bus, receipt_message_ids, record);
+    }
+}
+
+// Starts the next attempt for a queue entry once its backoff has passed, or
+// drops it when it has used up its attempts.
+fn spawn_outbound_record(
+    state: &NodeRuntimeState,
+    bus: &EventBus,
+    receipt_message_ids: &Arc<Mutex<HashMap<String, ReceiptMessageTracking>>>,
+    record: OutboundQueueRecord,
+) {
+    let destination_hex = record.payload.destination_hex().to_string();
+    if record.attempts >= OUTBOUND_QUEUE_MAX_ATTEMPTS {
+        let _ = state.app_state.remove_outbound(record.queue_id.as_str());
+        emit_operational_notice(
+            bus,
+            LogLevel::Warn {},
+            format!(
+                "Dropped queued message to {destination_hex} after {} attempts.",
+                record.attempts
+            ),
+        );
+        return;
+    }
+    info!(
+        "[lxmf][queue] queued send pending queue_id={} destination={} attempts={} next_attempt_at_ms={}",
+        record.queue_id, destination_hex, record.attempts, record.next_attempt_at_ms,
+    );
+    let state = state.clone();
+    let bus = bus.clone();
+    let receipt_message_ids = receipt_message_ids.clone();
+    tokio::spawn(async move {
+        let delay_ms = record.next_attempt_at_ms.saturating_sub(now_ms());
+        if delay_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+        }
+        match record.payload {
+            OutboundPayload::Lxmf { request } => {
+                let (resp, _) = cb::bounded(1);
+                spawn_send_lxmf(
+                    state,
+                    bus,
+                    receipt_message_ids,
+                    request,
+                    Some(record.queue_id),
+                    resp,
+                );
+            }
+            OutboundPayload::Bytes { job } => {
+                let (resp, _) = cb::bounded(1);
+                spawn_send_bytes(
+                    state,
+                    bus,
+                    receipt_message_ids,
+                    job,
+                    Some(record.queue_id),
+                    resp,
+                );
+            }
+            OutboundPayload::GroupCopy { copy } => {
+                spawn_send_group_copy(state, bus, receipt_message_ids, copy, Some(record.queue_id));
+            }
+        }
+    });
+}
+
 pub async fn run_node(
     config: NodeConfig,
     identity: PrivateIdentity,
@@ -4937,6 +6845,7 @@ pub async fn run_node(
     peers_snapshot: Arc<Mutex<Vec<PeerRecord>>>,
     sync_status_snapshot: Arc<Mutex<SyncStatus>>,
     hub_directory_snapshot: Arc<Mutex<Option<HubDirectorySnapshot>>>,
+    hub_client: Option<HubHttpClient>,
     bus: EventBus,
     mut cmd_rx: mpsc::Receiver<Command>,
 ) {
@@ -5021,7 +6930,13 @@ pub async fn run_node(
     let peer_connect_inflight: Arc<TokioMutex<HashSet<String>>> =
         Arc::new(TokioMutex::new(HashSet::new()));
     let pending_lxmf_deliveries: Arc<TokioMutex<HashMap<String, PendingLxmfDelivery>>> =
-        Arc::new(TokioMutex::new(HashMap::new()));
+        Arc::new(TokioMutex::new(
+            app_state
+                .list_pending_lxmf_deliveries::<PendingLxmfDelivery>()
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
+        ));
     let pending_lxmf_acknowledgements: Arc<
         TokioMutex<HashMap<String, PendingLxmfAcknowledgement>>,
     > = Arc::new(TokioMutex::new(HashMap::new()));
@@ -5048,6 +6963,14 @@ pub async fn run_node(
         },
     ));
 
+    let propagation_health = Arc::new(Mutex::new(
+        app_state
+            .list_propagation_node_health()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|health| (health.destination_hex.clone(), health))
+            .collect::<HashMap<_, _>>(),
+    ));
     let state = NodeRuntimeState {
         app_state,
         identity: identity.clone(),
@@ -5071,6 +6994,8 @@ pub async fn run_node(
             .hub_identity_hash
             .as_ref()
             .and_then(|value| normalize_hex_32(value)),
+        propagation_health,
+        propagation_sync: Arc::new(Mutex::new(PropagationSyncSchedule::default())),
         send_task_permits: send_task_permits.clone(),
     };
 
@@ -5146,11 +7071,30 @@ pub async fn run_node(
                     .await
                     .update_message(
                         message_id_hex.as_str(),
-                        sdkmsg::MessageState::Delivered,
-                        Some("transport receipt".to_string()),
+                        sdkmsg::MessageState::Delivered,
+                        Some("transport receipt".to_string()),
+                        now_ms(),
+                    )
+                    .map(from_sdk_message_record);
+
+                if let Ok(Some((delivery, invalidation))) = state
+                    .app_state
+                    .mark_sos_delivery_delivered(message_id_hex.as_str(), now_ms())
+                {
+                    bus.emit(NodeEvent::ProjectionInvalidated { invalidation });
+                    bus.emit(NodeEvent::SosDeliveryUpdated { delivery });
+                }
+
+                if let Ok(Some((delivery, invalidation))) =
+                    state.app_state.mark_group_delivery_state(
+                        message_id_hex.as_str(),
+                        MessageState::Delivered {},
                         now_ms(),
                     )
-                    .map(from_sdk_message_record);
+                {
+                    bus.emit(NodeEvent::ProjectionInvalidated { invalidation });
+                    apply_group_delivery(&state, &bus, delivery).await;
+                }
 
                 if let Some(record) = maybe_record {
                     sdk.record_delivery_acknowledged(
@@ -5445,6 +7389,7 @@ pub async fn run_node(
     {
         let bus = bus.clone();
         let sdk = sdk.clone();
+        let app_state = state.app_state.clone();
         let pending_lxmf_deliveries = pending_lxmf_deliveries.clone();
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(Duration::from_secs(1));
@@ -5464,6 +7409,7 @@ pub async fn run_node(
                         .collect::<Vec<_>>();
                     for key in expired_keys {
                         if let Some(pending) = guard.remove(&key) {
+                            let _ = app_state.delete_pending_lxmf_delivery(&key);
                             expired.push(pending);
                         }
                     }
@@ -5525,6 +7471,75 @@ pub async fn run_node(
         });
     }
 
+    // Background propagation sync on the configured schedule.
+    {
+        let bus = bus.clone();
+        let state = state.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(PROPAGATION_SYNC_TICK);
+            loop {
+                interval.tick().await;
+                if has_active_propagation_relay(&state).await {
+                    start_propagation_sync(&state, &bus, PropagationSyncTrigger::Interval);
+                }
+            }
+        });
+    }
+
+    // Telemetry stale/expiry sweep.
+    {
+        let bus = bus.clone();
+        let state = state.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(TELEMETRY_SWEEP_INTERVAL);
+            loop {
+                interval.tick().await;
+                let Ok(Some(settings)) = state.app_state.get_app_settings() else {
+                    continue;
+                };
+                match state.app_state.sweep_telemetry_positions(
+                    u64::from(settings.telemetry.stale_after_minutes) * 60_000,
+                    u64::from(settings.telemetry.expire_after_minutes) * 60_000,
+                    now_ms(),
+                ) {
+                    Ok(invalidations) => {
+                        for invalidation in invalidations {
+                            bus.emit(NodeEvent::ProjectionInvalidated { invalidation });
+                        }
+                    }
+                    Err(err) => debug!("[runtime] telemetry sweep failed: {err}"),
+                }
+            }
+        });
+    }
+
+    // EAM TTL expiry and roll-call deadline sweep.
+    {
+        let bus = bus.clone();
+        let state = state.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(EAM_SWEEP_INTERVAL);
+            loop {
+                interval.tick().await;
+                match state.app_state.sweep_expired_eams(now_ms()) {
+                    Ok(Some(invalidation)) => {
+                        bus.emit(NodeEvent::ProjectionInvalidated { invalidation });
+                    }
+                    Ok(None) => {}
+                    Err(err) => debug!("[runtime] eam sweep failed: {err}"),
+                }
+                match state.app_state.sweep_overdue_eam_roll_calls(now_ms()) {
+                    Ok(roll_calls) => {
+                        for roll_call in roll_calls {
+                            bus.emit(NodeEvent::EamRollCallUpdated { roll_call });
+                        }
+                    }
+                    Err(err) => debug!("[runtime] eam roll call sweep failed: {err}"),
+                }
+            }
+        });
+    }
+
     // Link events.
     {
         let transport = transport.clone();
@@ -5563,6 +7578,21 @@ pub async fn run_node(
                                 }
                                 emit_peer_changed(&state, &bus, &canonical_destination_hex).await;
                                 sync_auto_propagation_node(&state, &bus).await;
+                                let relay_link = state
+                                    .active_propagation_node_hex
+                                    .lock()
+                                    .await
+                                    .as_deref()
+                                    .is_some_and(|relay_hex| {
+                                        relay_hex.eq_ignore_ascii_case(destination_hex.as_str())
+                                    });
+                                if relay_link {
+                                    start_propagation_sync(
+                                        &state,
+                                        &bus,
+                                        PropagationSyncTrigger::Reconnect,
+                                    );
+                                }
                             }
                             LinkEvent::Closed => {
                                 connected_peers.lock().await.remove(&event.address_hash);
@@ -5597,6 +7627,35 @@ pub async fn run_node(
         });
     }
 
+    // Connected-mode hub registration over HTTP; LXMF announces remain the fallback.
+    if let Some(hub_client) = hub_client.clone() {
+        let bus = bus.clone();
+        let config = config.clone();
+        let state = state.clone();
+        tokio::spawn(async move {
+            match register_with_hub(&config, &state, &hub_client).await {
+                Ok(()) => {
+                    info!("[hub][http] registered with hub");
+                    if let Ok(invalidation) = state.app_state.bump_projection_revision(
+                        ProjectionScope::HubRegistration {},
+                        None,
+                        Some("hub-http-registered".to_string()),
+                    ) {
+                        bus.emit(NodeEvent::ProjectionInvalidated { invalidation });
+                    }
+                }
+                Err(err) => {
+                    info!("[hub][http] registration failed, using lxmf fallback: {err}");
+                    emit_operational_notice(
+                        &bus,
+                        LogLevel::Warn {},
+                        format!("Hub HTTP registration failed ({err}); continuing over LXMF."),
+                    );
+                }
+            }
+        });
+    }
+
     // Optional periodic hub refresh.
     if matches!(
         config.hub_mode,
@@ -5606,18 +7665,23 @@ pub async fn run_node(
         let bus = bus.clone();
         let config = config.clone();
         let state = state.clone();
+        let hub_client = hub_client.clone();
         let interval_secs = config.hub_refresh_interval_seconds;
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(Duration::from_secs(interval_secs as u64));
             loop {
                 interval.tick().await;
-                if let Ok(snapshot) = refresh_hub_directory_lxmf(&config, &state).await {
+                if let Ok(snapshot) =
+                    refresh_hub_directory(&config, &state, hub_client.as_ref()).await
+                {
                     publish_hub_directory_snapshot(&state, &bus, snapshot).await;
                 }
             }
         });
     }
 
+    resume_outbound_queue(&state, &bus, &receipt_message_ids);
+
     while let Some(cmd) = cmd_rx.recv().await {
         match cmd {
             Command::Stop { resp } => {
@@ -5744,351 +7808,150 @@ pub async fn run_node(
                 send_mode,
                 resp,
             } => {
-                let state = state.clone();
-                let bus = bus.clone();
-                let transport = transport.clone();
-                let metadata = fields_bytes
-                    .as_deref()
-                    .and_then(parse_mission_sync_metadata);
-                let send_task_class = SendTaskClass::from_lxmf_request(
-                    fields_bytes.is_some(),
-                    metadata.as_ref(),
-                    &send_mode,
-                );
-                log_send_task(
-                    send_task_class,
-                    format!(
-                        "[lxmf][queue] enqueued {} send destination={} mode={:?} has_fields={}",
-                        send_task_class.label(),
-                        destination_hex,
-                        send_mode,
-                        fields_bytes.is_some(),
-                    ),
+                let job = QueuedBytes {
+                    destination_hex,
+                    bytes,
+                    fields_bytes,
+                    send_mode,
+                };
+                let queue_id = is_durable_bytes_send(job.fields_bytes.as_deref())
+                    .then(|| enqueue_outbound(&state, OutboundPayload::Bytes { job: job.clone() }))
+                    .flatten();
+                spawn_send_bytes(
+                    state.clone(),
+                    bus.clone(),
+                    receipt_message_ids.clone(),
+                    job,
+                    queue_id,
+                    resp,
                 );
-                tokio::spawn(async move {
-                    let result = async {
-                        let lxmf_report = if fields_bytes.is_some() {
-                            Some(
-                                send_lxmf_with_delivery_policy(
-                                    &state,
-                                    &destination_hex,
-                                    &bytes,
-                                    None,
-                                    fields_bytes.clone(),
-                                    metadata.clone(),
-                                    send_mode,
-                                    send_task_class,
-                                )
-                                .await?,
-                            )
-                        } else {
-                            None
-                        };
-                        let outcome = if let Some(report) = lxmf_report.as_ref() {
-                            report.outcome
-                        } else {
-                            log_send_task(
-                                SendTaskClass::General,
-                                format!(
-                                    "[lxmf][queue] waiting for general send slot destination={} mode=transport-bytes",
-                                    destination_hex,
-                                ),
-                            );
-                            let _permit = acquire_send_task_permit(
-                                &state.send_task_permits,
-                                SendTaskClass::General,
-                            )
-                            .await?;
-                            log_send_task(
-                                SendTaskClass::General,
-                                format!(
-                                    "[lxmf][queue] acquired general send slot destination={} mode=transport-bytes",
-                                    destination_hex,
-                                ),
-                            );
-                            let dest = parse_address_hash(&destination_hex)?;
-                            send_transport_packet_with_path_retry(&transport, dest, &bytes).await
-                        };
-                        let mapped = send_outcome_to_udl(outcome);
-                        bus.emit(NodeEvent::PacketSent {
-                            destination_hex: destination_hex.clone(),
-                            bytes: bytes.clone(),
-                            outcome: mapped,
-                        });
-
-                        if let Some(report) = lxmf_report.as_ref() {
-                            if let Some(metadata) = report.metadata.as_ref() {
-                                if metadata.is_mission_related() {
-                                    info!(
-                                        "[lxmf][mission] outbound kind={} name={} destination={} message_id={} event_uid={} mission_uid={} correlation={}",
-                                        metadata.primary_kind(),
-                                        metadata.primary_name().unwrap_or("-"),
-                                        report.resolved_destination_hex.as_str(),
-                                        report.message_id_hex,
-                                        metadata.event_uid.as_deref().unwrap_or("-"),
-                                        metadata.mission_uid.as_deref().unwrap_or("-"),
-                                        metadata.correlation_id.as_deref().unwrap_or("-"),
-                                    );
-                                }
-                            }
-
-                            if let Some(registered) = register_pending_lxmf_delivery(&state, report).await {
-                                let pending = &registered.pending;
-                                if matches!(
-                                    report.outcome,
-                                    RnsSendOutcome::SentDirect | RnsSendOutcome::SentBroadcast
-                                ) {
-                                    state.sdk.record_delivery_sent(
-                                        &pending.message_id_hex,
-                                        &pending.destination_hex,
-                                        pending.correlation_id.as_deref(),
-                                        pending.command_id.as_deref(),
-                                        pending.command_type.as_deref(),
-                                        pending.event_uid.as_deref(),
-                                        pending.mission_uid.as_deref(),
-                                    );
-                                    emit_lxmf_delivery(
-                                        &bus,
-                                        &pending,
-                                        lxmf_delivery_status_for(report),
-                                        None,
-                                    );
-                                    info!(
-                                        "[lxmf][mission] sent message_id={} destination={} command={} correlation={}",
-                                        pending.message_id_hex,
-                                        pending.destination_hex,
-                                        pending.command_type.as_deref().unwrap_or("-"),
-                                        pending.correlation_id.as_deref().unwrap_or("-"),
-                                    );
-                                    if let Some(buffered_ack) = registered.buffered_ack {
-                                        let tracking_key = pending
-                                            .correlation_id
-                                            .as_deref()
-                                            .or(pending.command_id.as_deref())
-                                            .map(ToOwned::to_owned);
-                                        if peer_destinations_equivalent(
-                                            &state,
-                                            pending.destination_hex.as_str(),
-                                            buffered_ack.source_hex.as_str(),
-                                        )
-                                        .await
-                                        {
-                                            if let Some(tracking_key) = tracking_key.as_deref() {
-                                                state
-                                                    .pending_lxmf_deliveries
-                                                    .lock()
-                                                    .await
-                                                    .remove(tracking_key);
-                                            }
-                                            state.sdk.record_delivery_acknowledged(
-                                                &pending.message_id_hex,
-                                                &pending.destination_hex,
-                                                Some(buffered_ack.source_hex.as_str()),
-                                                pending.correlation_id.as_deref(),
-                                                pending.command_id.as_deref(),
-                                                pending.command_type.as_deref(),
-                                                pending.event_uid.as_deref(),
-                                                pending.mission_uid.as_deref(),
-                                                buffered_ack.detail.as_deref(),
-                                            );
-                                            emit_lxmf_delivery_with_source(
-                                                &bus,
-                                                pending,
-                                                Some(buffered_ack.source_hex.clone()),
-                                                LxmfDeliveryStatus::Acknowledged {},
-                                                buffered_ack.detail.clone(),
-                                            );
-                                            info!(
-                                                "[lxmf][mission] acknowledged buffered message_id={} destination={} command={} correlation={} detail={}",
-                                                pending.message_id_hex,
-                                                pending.destination_hex,
-                                                pending.command_type.as_deref().unwrap_or("-"),
-                                                pending.correlation_id.as_deref().unwrap_or("-"),
-                                                buffered_ack.detail.as_deref().unwrap_or("-"),
-                                            );
-                                        } else {
-                                            if let Some(tracking_key) = tracking_key {
-                                                state
-                                                    .pending_lxmf_acknowledgements
-                                                    .lock()
-                                                    .await
-                                                    .insert(tracking_key, buffered_ack.clone());
-                                            }
-                                            info!(
-                                                "[lxmf][mission] buffered acknowledgement source mismatch message_id={} destination={} source={}",
-                                                pending.message_id_hex,
-                                                pending.destination_hex,
-                                                buffered_ack.source_hex,
-                                            );
-                                        }
-                                    }
-                                } else {
-                                    let failure_detail = format!("{mapped:?}");
-                                    {
-                                        let tracking_key = pending
-                                            .correlation_id
-                                            .as_deref()
-                                            .or(pending.command_id.as_deref())
-                                            .map(ToOwned::to_owned);
-                                        if let Some(tracking_key) = tracking_key {
-                                            state.pending_lxmf_deliveries.lock().await.remove(&tracking_key);
-                                        }
-                                    }
-                                    state.sdk.record_delivery_failed(
-                                        &pending.message_id_hex,
-                                        &pending.destination_hex,
-                                        pending.correlation_id.as_deref(),
-                                        pending.command_id.as_deref(),
-                                        pending.command_type.as_deref(),
-                                        pending.event_uid.as_deref(),
-                                        pending.mission_uid.as_deref(),
-                                        Some(failure_detail.as_str()),
-                                    );
-                                    emit_lxmf_delivery(
-                                        &bus,
-                                        &pending,
-                                        LxmfDeliveryStatus::Failed {},
-                                        Some(failure_detail.clone()),
-                                    );
-                                    info!(
-                                        "[lxmf][mission] failed message_id={} destination={} command={} correlation={} outcome={:?}",
-                                        pending.message_id_hex,
-                                        pending.destination_hex,
-                                        pending.command_type.as_deref().unwrap_or("-"),
-                                        pending.correlation_id.as_deref().unwrap_or("-"),
-                                        mapped,
-                                    );
-                                }
-                            }
-                        }
-
-                        if matches!(
-                            outcome,
-                            RnsSendOutcome::SentDirect | RnsSendOutcome::SentBroadcast
-                        ) {
-                            Ok(())
-                        } else {
-                            Err(NodeError::NetworkError {})
-                        }
-                    }
-                    .await;
-                    if let Err(err) = &result {
-                        bus.emit(NodeEvent::Error {
-                            code: node_error_code(err).to_string(),
-                            message: format!(
-                                "send_bytes failed destination={} reason={}",
-                                destination_hex, err
-                            ),
-                        });
-                    }
-                    let _ = resp.send(result);
-                });
             }
             Command::SendLxmf { request, resp } => {
+                let queue_id = enqueue_outbound(
+                    &state,
+                    OutboundPayload::Lxmf {
+                        request: request.clone(),
+                    },
+                );
+                if let Some(queue_id) = queue_id.as_deref().filter(|_| {
+                    request
+                        .send_at_ms
+                        .is_some_and(|send_at_ms| send_at_ms > now_ms())
+                }) {
+                    record_scheduled_message(&state, &bus, &request, queue_id).await;
+                }
+                spawn_send_lxmf(
+                    state.clone(),
+                    bus.clone(),
+                    receipt_message_ids.clone(),
+                    request,
+                    queue_id,
+                    resp,
+                );
+            }
+            Command::SendGroupLxmf {
+                group,
+                message_id_hex,
+                body_utf8,
+                title,
+                priority,
+                resp,
+            } => {
+                let priority = priority.unwrap_or(MessagePriority::Routine {});
                 let state = state.clone();
                 let bus = bus.clone();
                 let receipt_message_ids = receipt_message_ids.clone();
                 log_send_task(
                     SendTaskClass::General,
                     format!(
-                        "[lxmf][queue] enqueued general send destination={} mode={:?} has_fields=false",
-                        request.destination_hex,
-                        request.send_mode,
+                        "[lxmf][queue] enqueued group send conversation={} members={}",
+                        group.conversation_id,
+                        group.member_hexes.len(),
                     ),
                 );
                 tokio::spawn(async move {
-                    let result = async {
-                        let body_bytes = request.body_utf8.as_bytes().to_vec();
-                        let report = send_lxmf_with_delivery_policy(
-                            &state,
-                            request.destination_hex.as_str(),
-                            body_bytes.as_slice(),
-                            request.title.clone(),
-                            None,
-                            None,
-                            request.send_mode,
-                            SendTaskClass::General,
-                        )
-                        .await?;
-                        let method = match (report.method, report.representation) {
-                            (LxmfDeliveryMethod::Propagated {}, _) => MessageMethod::Propagated {},
-                            (LxmfDeliveryMethod::Opportunistic {}, _) => {
-                                MessageMethod::Opportunistic {}
-                            }
-                            (_, LxmfDeliveryRepresentation::Resource {}) => {
-                                MessageMethod::Resource {}
-                            }
-                            _ => MessageMethod::Direct {},
-                        };
-                        let state_value = if report.used_propagation_node
-                            && matches!(
-                                report.outcome,
-                                RnsSendOutcome::SentDirect | RnsSendOutcome::SentBroadcast
-                            ) {
-                            MessageState::SentToPropagation {}
-                        } else if matches!(
-                            report.outcome,
-                            RnsSendOutcome::SentDirect | RnsSendOutcome::SentBroadcast
-                        ) {
-                            MessageState::SentDirect {}
-                        } else {
-                            MessageState::Failed {}
-                        };
-                        let detail = if matches!(state_value, MessageState::Failed {}) {
-                            Some(format!("{:?}", send_outcome_to_udl(report.outcome)))
-                        } else {
-                            None
-                        };
-                        let conversation_id =
-                            conversation_id_for(report.resolved_destination_hex.as_str());
-                        let record = MessageRecord {
-                            message_id_hex: report.message_id_hex.clone(),
-                            conversation_id,
-                            direction: MessageDirection::Outbound {},
-                            destination_hex: report.resolved_destination_hex.clone(),
-                            source_hex: Some(address_hash_to_hex(
-                                &state.lxmf_destination.lock().await.desc.address_hash,
-                            )),
-                            title: request.title.clone(),
-                            body_utf8: request.body_utf8.clone(),
-                            method,
-                            state: state_value,
-                            detail: detail.clone(),
-                            sent_at_ms: Some(now_ms()),
-                            received_at_ms: None,
-                            updated_at_ms: now_ms(),
-                        };
-                        upsert_message_record(&state, &bus, record, false).await;
-                        state.messaging.lock().await.store_outbound(
-                            sdkmsg::StoredOutboundMessage {
-                                request: to_sdk_send_request(&request),
-                                message_id_hex: report.message_id_hex.clone(),
-                            },
-                        );
-                        if let Some(receipt_hash_hex) = report.receipt_hash_hex.as_ref() {
-                            if let Ok(mut guard) = receipt_message_ids.lock() {
-                                guard.insert(
-                                    receipt_hash_hex.clone(),
-                                    ReceiptMessageTracking {
-                                        message_id_hex: report.message_id_hex.clone(),
-                                        recorded_at_ms: now_ms(),
-                                    },
-                                );
-                            }
+                    let local_hex =
+                        address_hash_to_hex(&state.lxmf_destination.lock().await.desc.address_hash);
+                    let fields = build_group_fields(&GroupEnvelope::for_group(
+                        &group,
+                        message_id_hex.as_str(),
+                    ));
+                    let fields = match fields {
+                        Ok(fields) => fields,
+                        Err(err) => {
+                            let _ = resp.send(Err(err));
+                            return;
                         }
-                        Ok::<String, NodeError>(report.message_id_hex)
+                    };
+                    let sent_at_ms = now_ms();
+                    let record = MessageRecord {
+                        message_id_hex: message_id_hex.clone(),
+                        conversation_id: group.conversation_id.clone(),
+                        direction: MessageDirection::Outbound {},
+                        destination_hex: group.conversation_id.clone(),
+                        source_hex: Some(local_hex.clone()),
+                        title: title.clone(),
+                        body_utf8: body_utf8.clone(),
+                        method: MessageMethod::Direct {},
+                        state: MessageState::Sending {},
+                        detail: None,
+                        sent_at_ms: Some(sent_at_ms),
+                        received_at_ms: None,
+                        updated_at_ms: sent_at_ms,
+                        attachments: Vec::new(),
+                        edited_at_ms: None,
+                        deleted_at_ms: None,
+                        reactions: Vec::new(),
+                    };
+                    upsert_message_record(&state, &bus, record, false).await;
+                    let _ = state
+                        .app_state
+                        .set_message_priority(message_id_hex.as_str(), priority);
+                    let members = group
+                        .member_hexes
+                        .iter()
+                        .filter(|member| !member.eq_ignore_ascii_case(local_hex.as_str()))
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    // Every member starts out pending so the room message does
+                    // not read as delivered off the first copy that lands.
+                    for member_hex in &members {
+                        let _ = state
+                            .app_state
+                            .upsert_group_delivery(&GroupMemberDeliveryRecord {
+                                message_id_hex: message_id_hex.clone(),
+                                member_hex: member_hex.clone(),
+                                lxmf_message_id_hex: None,
+                                state: MessageState::Sending {},
+                                detail: None,
+                                updated_at_ms: sent_at_ms,
+                            });
                     }
-                    .await;
-                    if let Err(err) = &result {
-                        bus.emit(NodeEvent::Error {
-                            code: node_error_code(err).to_string(),
-                            message: format!(
-                                "send_lxmf failed destination={} reason={}",
-                                request.destination_hex, err
-                            ),
-                        });
+                    let _ = resp.send(Ok(message_id_hex.clone()));
+
+                    // Each member gets its own LXMF copy so delivery and read
+                    // receipts can be tracked per member.
+                    for member_hex in members {
+                        let copy = QueuedGroupCopy {
+                            message_id_hex: message_id_hex.clone(),
+                            member_hex,
+                            body_utf8: body_utf8.clone(),
+                            title: title.clone(),
+                            fields_bytes: fields.clone(),
+                            priority,
+                        };
+                        let queue_id = enqueue_outbound(
+                            &state,
+                            OutboundPayload::GroupCopy { copy: copy.clone() },
+                        );
+                        spawn_send_group_copy(
+                            state.clone(),
+                            bus.clone(),
+                            receipt_message_ids.clone(),
+                            copy,
+                            queue_id,
+                        );
                     }
-                    let _ = resp.send(result);
                 });
             }
             Command::RetryLxmf {
@@ -6112,12 +7975,48 @@ pub async fn run_node(
                             .await
                             .outbound(message_id_hex.as_str())
                             .ok_or(NodeError::InvalidConfig {})?;
+                        if is_expired(outbound.request.expires_at_ms, now_ms()) {
+                            let expired = state
+                                .messaging
+                                .lock()
+                                .await
+                                .update_message(
+                                    message_id_hex.as_str(),
+                                    sdkmsg::MessageState::Expired,
+                                    Some("expired before delivery".to_string()),
+                                    now_ms(),
+                                )
+                                .map(from_sdk_message_record);
+                            if let Some(expired) = expired {
+                                upsert_message_record(&state, &bus, expired, false).await;
+                            }
+                            return Err(NodeError::Timeout {});
+                        }
+                        let attachment_records = outbound
+                            .request
+                            .attachments
+                            .iter()
+                            .cloned()
+                            .map(from_sdk_attachment)
+                            .collect::<Vec<_>>();
+                        let attachments = load_outbound_attachments(&retry_attachment_requests(
+                            &attachment_records,
+                        ))?;
+                        let priority = state
+                            .app_state
+                            .get_message_priority(message_id_hex.as_str())
+                            .ok()
+                            .flatten()
+                            .unwrap_or(MessagePriority::Routine {});
                         let report = send_lxmf_with_delivery_policy(
                             &state,
                             outbound.request.destination_hex.as_str(),
                             outbound.request.body_utf8.as_bytes(),
                             outbound.request.title.clone(),
-                            None,
+                            with_expiry_field(
+                                build_attachment_fields(&attachments)?,
+                                outbound.request.expires_at_ms,
+                            )?,
                             None,
                             match outbound.request.effective_send_mode() {
                                 sdkmsg::SendMode::Auto => SendMode::Auto {},
@@ -6125,6 +8024,7 @@ pub async fn run_node(
                                 sdkmsg::SendMode::PropagationOnly => SendMode::PropagationOnly {},
                             },
                             SendTaskClass::General,
+                            priority,
                         )
                         .await?;
                         let retried_state = if report.used_propagation_node
@@ -6165,8 +8065,15 @@ pub async fn run_node(
                             sent_at_ms: Some(now_ms()),
                             received_at_ms: None,
                             updated_at_ms: now_ms(),
+                            attachments: attachment_records,
+                            edited_at_ms: None,
+                            deleted_at_ms: None,
+                            reactions: Vec::new(),
                         };
                         upsert_message_record(&state, &bus, retried, false).await;
+                        let _ = state
+                            .app_state
+                            .set_message_priority(report.message_id_hex.as_str(), priority);
                         state.messaging.lock().await.store_outbound(
                             sdkmsg::StoredOutboundMessage {
                                 request: outbound.request,
@@ -6188,10 +8095,28 @@ pub async fn run_node(
                     let _ = resp.send(result);
                 });
             }
+            Command::ApplyMessageAction {
+                message_id_hex,
+                kind,
+                resp,
+            } => {
+                let result = apply_local_message_action(
+                    &state,
+                    &bus,
+                    &receipt_message_ids,
+                    message_id_hex.as_str(),
+                    kind,
+                )
+                .await;
+                let _ = resp.send(result);
+            }
             Command::CancelLxmf {
                 message_id_hex,
                 resp,
             } => {
+                // A scheduled send is keyed by its queue id; dropping the row
+                // stops it from going out when its send time comes round.
+                let _ = state.app_state.remove_outbound(message_id_hex.as_str());
                 let result = async {
                     let updated = state
                         .messaging
@@ -6231,29 +8156,13 @@ pub async fn run_node(
                 let _ = resp.send(Ok(()));
             }
             Command::RequestLxmfSync { limit, resp } => {
-                let requested_at_ms = now_ms();
-                let status_update = from_sdk_sync_status(
-                    state.messaging.lock().await.update_sync_status(|status| {
-                        status.phase = sdkmsg::SyncPhase::Idle;
-                        status.requested_at_ms = Some(requested_at_ms);
-                        status.completed_at_ms = Some(now_ms());
-                        status.messages_received = 0;
-                        status.detail = None;
-                    }),
-                );
-                if refresh_sync_status_snapshot(&state, &status_update) {
-                    bus.emit(NodeEvent::SyncUpdated {
-                        status: status_update,
-                    });
-                }
                 if let Some(value) = limit {
-                    info!(
-                        "[sync] propagation sync request ignored in mobile runtime requested_limit={value}"
-                    );
+                    info!("[sync] propagation sync requested requested_limit={value}");
                 } else {
-                    info!("[sync] propagation sync request ignored in mobile runtime");
+                    info!("[sync] propagation sync requested");
                 }
                 let _ = resp.send(Ok(()));
+                start_propagation_sync(&state, &bus, PropagationSyncTrigger::Manual);
             }
             Command::ListAnnounces { resp } => {
                 let records = state
@@ -6287,6 +8196,24 @@ pub async fn run_node(
                     state.messaging.lock().await.sync_status(),
                 )));
             }
+            Command::GetSendQueueMetrics { resp } => {
+                let _ = resp.send(Ok(state.send_task_permits.metrics()));
+            }
+            Command::ListPropagationNodes { resp } => {
+                let _ = resp.send(Ok(list_propagation_nodes(&state).await));
+            }
+            Command::SetNetworkAvailable { available, resp } => {
+                let restored = state
+                    .propagation_sync
+                    .lock()
+                    .map(|mut schedule| schedule.set_network_available(available))
+                    .unwrap_or(false);
+                info!("[sync] network available={available}");
+                if restored && has_active_propagation_relay(&state).await {
+                    start_propagation_sync(&state, &bus, PropagationSyncTrigger::NetworkRestored);
+                }
+                let _ = resp.send(Ok(()));
+            }
             Command::BroadcastBytes { bytes, resp } => {
                 let result = async {
                     let peers = connected_peers
@@ -6331,11 +8258,12 @@ pub async fn run_node(
                 let state = state.clone();
                 let bus = bus.clone();
                 let config = config.clone();
+                let hub_client = hub_client.clone();
                 tokio::spawn(async move {
                     let result = match config.hub_mode {
                         HubMode::Autonomous {} => Err(NodeError::InvalidConfig {}),
                         HubMode::SemiAutonomous {} | HubMode::Connected {} => {
-                            refresh_hub_directory_lxmf(&config, &state).await
+                            refresh_hub_directory(&config, &state, hub_client.as_ref()).await
                         }
                     }
                     .map(|snapshot| async {
@@ -7314,9 +9242,13 @@ mod tests {
 
         tokio::spawn(async move {
             {
-                let _permit = acquire_send_task_permit(&permits_for_retry, SendTaskClass::General)
-                    .await
-                    .expect("first attempt permit");
+                let _permit = acquire_send_task_permit(
+                    &permits_for_retry,
+                    SendTaskClass::General,
+                    MessagePriority::Routine {},
+                )
+                .await
+                .expect("first attempt permit");
             }
             let _ = sleeping_tx.send(());
             tokio::time::sleep(Duration::from_millis(100)).await;
@@ -7325,7 +9257,11 @@ mod tests {
         sleeping_rx.await.expect("retry task entered backoff");
         let permit = tokio::time::timeout(
             Duration::from_millis(50),
-            acquire_send_task_permit(&permits, SendTaskClass::General),
+            acquire_send_task_permit(
+                &permits,
+                SendTaskClass::General,
+                MessagePriority::Routine {},
+            ),
         )
         .await
         .expect("general permit should be available during retry sleep")
@@ -7336,13 +9272,21 @@ mod tests {
     #[tokio::test]
     async fn mission_sends_keep_reserved_capacity_when_general_pool_is_full() {
         let permits = SendTaskPermits::with_limits(1, 1);
-        let _general = acquire_send_task_permit(&permits, SendTaskClass::General)
-            .await
-            .expect("saturate general pool");
+        let _general = acquire_send_task_permit(
+            &permits,
+            SendTaskClass::General,
+            MessagePriority::Routine {},
+        )
+        .await
+        .expect("saturate general pool");
 
         let mission = tokio::time::timeout(
             Duration::from_millis(50),
-            acquire_send_task_permit(&permits, SendTaskClass::Mission),
+            acquire_send_task_permit(
+                &permits,
+                SendTaskClass::Mission,
+                MessagePriority::Routine {},
+            ),
         )
         .await
         .expect("mission permit should not wait on general pool saturation")
@@ -7351,7 +9295,11 @@ mod tests {
 
         let blocked_general = tokio::time::timeout(
             Duration::from_millis(50),
-            acquire_send_task_permit(&permits, SendTaskClass::General),
+            acquire_send_task_permit(
+                &permits,
+                SendTaskClass::General,
+                MessagePriority::Routine {},
+            ),
         )
         .await;
         assert!(
@@ -7363,13 +9311,21 @@ mod tests {
     #[tokio::test]
     async fn propagation_mission_sends_do_not_block_direct_mission_capacity() {
         let permits = SendTaskPermits::with_limits(1, 1);
-        let _propagation = acquire_send_task_permit(&permits, SendTaskClass::MissionPropagation)
-            .await
-            .expect("saturate propagation mission pool");
+        let _propagation = acquire_send_task_permit(
+            &permits,
+            SendTaskClass::MissionPropagation,
+            MessagePriority::Routine {},
+        )
+        .await
+        .expect("saturate propagation mission pool");
 
         let direct = tokio::time::timeout(
             Duration::from_millis(50),
-            acquire_send_task_permit(&permits, SendTaskClass::Mission),
+            acquire_send_task_permit(
+                &permits,
+                SendTaskClass::Mission,
+                MessagePriority::Routine {},
+            ),
         )
         .await
         .expect("direct mission permit should not wait on propagation pool saturation")
@@ -7378,7 +9334,11 @@ mod tests {
 
         let blocked_propagation = tokio::time::timeout(
             Duration::from_millis(50),
-            acquire_send_task_permit(&permits, SendTaskClass::MissionPropagation),
+            acquire_send_task_permit(
+                &permits,
+                SendTaskClass::MissionPropagation,
+                MessagePriority::Routine {},
+            ),
         )
         .await;
         assert!(
@@ -10,8 +10,9 @@ use serde::{Deserialize, Serialize};
 use crate::event_bus::EventBus;
 use crate::runtime::now_ms;
 use crate::types::{
-    MessageDirection, MessageMethod, MessageRecord, MessageState, NodeEvent, PeerRecord, PeerState,
-    ProjectionInvalidation, ProjectionScope, SyncPhase, SyncStatus,
+    MessageAttachmentRecord, MessageDirection, MessageMethod, MessageReactionRecord, MessageRecord,
+    MessageState, NodeEvent, PeerRecord, PeerState, ProjectionInvalidation, ProjectionScope,
+    SyncPhase, SyncStatus,
 };
 
 pub(crate) const PERSIST_FILENAME: &str = "runtime_projection.json";
@@ -55,6 +56,14 @@ struct PersistedMessageRecord {
     sent_at_ms: Option<u64>,
     received_at_ms: Option<u64>,
     updated_at_ms: u64,
+    #[serde(default)]
+    attachments: Vec<MessageAttachmentRecord>,
+    #[serde(default)]
+    edited_at_ms: Option<u64>,
+    #[serde(default)]
+    deleted_at_ms: Option<u64>,
+    #[serde(default)]
+    reactions: Vec<MessageReactionRecord>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,6 +74,12 @@ struct PersistedSyncStatus {
     completed_at_ms: Option<u64>,
     messages_received: u32,
     detail: Option<String>,
+    #[serde(default)]
+    last_trigger: Option<String>,
+    #[serde(default)]
+    consecutive_failures: u32,
+    #[serde(default)]
+    next_sync_at_ms: Option<u64>,
 }
 
 impl Default for PersistedSyncStatus {
@@ -76,6 +91,9 @@ impl Default for PersistedSyncStatus {
             completed_at_ms: None,
             messages_received: 0,
             detail: None,
+            last_trigger: None,
+            consecutive_failures: 0,
+            next_sync_at_ms: None,
         }
     }
 }
@@ -239,6 +257,9 @@ mod tests {
                 completed_at_ms: Some(789),
                 messages_received: 0,
                 detail: Some("none".to_string()),
+                last_trigger: None,
+                consecutive_failures: 0,
+                next_sync_at_ms: None,
             },
             updated_at_ms: 999,
             ..RuntimeProjectionSnapshot::default()
@@ -362,6 +383,10 @@ fn persisted_message_from_runtime(record: &MessageRecord) -> Option<PersistedMes
         sent_at_ms: record.sent_at_ms,
         received_at_ms: record.received_at_ms,
         updated_at_ms: record.updated_at_ms,
+        attachments: record.attachments.clone(),
+        edited_at_ms: record.edited_at_ms,
+        deleted_at_ms: record.deleted_at_ms,
+        reactions: record.reactions.clone(),
     })
 }
 
@@ -373,6 +398,9 @@ fn persisted_sync_from_runtime(status: &SyncStatus) -> Option<PersistedSyncStatu
         completed_at_ms: status.completed_at_ms,
         messages_received: status.messages_received,
         detail: status.detail.clone(),
+        last_trigger: status.last_trigger.clone(),
+        consecutive_failures: status.consecutive_failures,
+        next_sync_at_ms: status.next_sync_at_ms,
     })
 }
 
@@ -417,6 +445,10 @@ fn runtime_message_from_persisted(record: PersistedMessageRecord) -> MessageReco
         sent_at_ms: record.sent_at_ms,
         received_at_ms: record.received_at_ms,
         updated_at_ms: record.updated_at_ms,
+        attachments: record.attachments,
+        edited_at_ms: record.edited_at_ms,
+        deleted_at_ms: record.deleted_at_ms,
+        reactions: record.reactions,
     }
 }
 
@@ -428,6 +460,9 @@ fn runtime_sync_from_persisted(status: PersistedSyncStatus) -> SyncStatus {
         completed_at_ms: status.completed_at_ms,
         messages_received: status.messages_received,
         detail: status.detail,
+        last_trigger: status.last_trigger,
+        consecutive_failures: status.consecutive_failures,
+        next_sync_at_ms: status.next_sync_at_ms,
     }
 }
 
@@ -486,6 +521,9 @@ fn message_state_to_string(state: MessageState) -> String {
         MessageState::TimedOut {} => "timed-out".to_string(),
         MessageState::Cancelled {} => "cancelled".to_string(),
         MessageState::Received {} => "received".to_string(),
+        MessageState::Read {} => "read".to_string(),
+        MessageState::Scheduled {} => "scheduled".to_string(),
+        MessageState::Expired {} => "expired".to_string(),
     }
 }
 
@@ -502,6 +540,9 @@ fn message_state_from_string(value: String) -> Option<MessageState> {
         "timed-out" => Some(MessageState::TimedOut {}),
         "cancelled" => Some(MessageState::Cancelled {}),
         "received" => Some(MessageState::Received {}),
+        "read" => Some(MessageState::Read {}),
+        "scheduled" => Some(MessageState::Scheduled {}),
+        "expired" => Some(MessageState::Expired {}),
         _ => None,
     }
 }
@@ -667,6 +708,29 @@ impl RuntimeProjectionJournal {
         true
     }
 
+    pub(crate) fn forget_message(&self, message_id_hex: &str, reason: Option<&str>) -> bool {
+        let mut guard = match self.snapshot.lock() {
+            Ok(v) => v,
+            Err(_) => return false,
+        };
+        let before = guard.messages.len();
+        guard
+            .messages
+            .retain(|candidate| candidate.message_id_hex != message_id_hex);
+        if guard.messages.len() == before {
+            return false;
+        }
+        guard.updated_at_ms = now_ms();
+        drop(guard);
+
+        self.invalidate(
+            ProjectionScope::Messages {},
+            Some(message_id_hex.to_string()),
+            reason.unwrap_or("message-projection-removed"),
+        );
+        true
+    }
+
     #[cfg(test)]
     pub(crate) fn current_peers(&self) -> Option<Vec<PeerRecord>> {
         self.snapshot.lock().ok().map(|snapshot| {
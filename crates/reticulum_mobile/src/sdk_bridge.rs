@@ -722,6 +722,23 @@ impl RuntimeLxmfSdk {
             .map_err(|_| NodeError::InternalError {})
     }
 
+    // Brings up the link to a propagation node and reports how long that took.
+    pub(crate) async fn probe_propagation_node(
+        &self,
+        destination: AddressHash,
+    ) -> Result<u64, NodeError> {
+        let state = self
+            .client
+            .backend()
+            .transport
+            .as_ref()
+            .ok_or(NodeError::InternalError {})?;
+        let started_at_ms = crate::runtime::now_ms();
+        let desc = resolve_propagation_destination_desc(state, destination).await?;
+        ensure_lxmf_output_link(state, desc).await?;
+        Ok(crate::runtime::now_ms().saturating_sub(started_at_ms))
+    }
+
     pub(crate) async fn send_lxmf(
         &self,
         destination: AddressHash,
@@ -0,0 +1,360 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::Notify;
+
+use crate::mission_sync::parse_mission_sync_metadata;
+use crate::sos_fields::parse_sos_fields;
+use crate::types::{MessagePriority, NodeError, SendQueueClassMetrics};
+
+// A queued send is promoted one class for every interval it has waited, so a
+// steady stream of flash traffic cannot hold routine or telemetry sends back
+// forever. The top rank stays reserved for SOS.
+pub(crate) const PRIORITY_AGING_INTERVAL: Duration = Duration::from_secs(15);
+
+const TELEMETRY_UPSERT_COMMAND_TYPE: &str = "mission.registry.telemetry.upsert";
+
+const PRIORITY_CLASSES: [MessagePriority; 5] = [
+    MessagePriority::Sos {},
+    MessagePriority::Flash {},
+    MessagePriority::Mission {},
+    MessagePriority::Routine {},
+    MessagePriority::Telemetry {},
+];
+
+fn priority_rank(priority: MessagePriority) -> usize {
+    match priority {
+        MessagePriority::Sos {} => 0,
+        MessagePriority::Flash {} => 1,
+        MessagePriority::Mission {} => 2,
+        MessagePriority::Routine {} => 3,
+        MessagePriority::Telemetry {} => 4,
+    }
+}
+
+pub(crate) fn effective_rank(priority: MessagePriority, waited: Duration) -> usize {
+    let rank = priority_rank(priority);
+    if rank == 0 {
+        return 0;
+    }
+    let promotions = (waited.as_millis() / PRIORITY_AGING_INTERVAL.as_millis()) as usize;
+    rank.saturating_sub(promotions).max(1)
+}
+
+// Internal sends carry no explicit priority; it is read off the fields the
+// same way the receiving side tells SOS, telemetry and mission traffic apart.
+pub(crate) fn priority_for_fields(fields_bytes: Option<&[u8]>) -> MessagePriority {
+    let Some(fields_bytes) = fields_bytes else {
+        return MessagePriority::Routine {};
+    };
+    if parse_sos_fields(fields_bytes).is_some() {
+        return MessagePriority::Sos {};
+    }
+    match parse_mission_sync_metadata(fields_bytes) {
+        Some(metadata)
+            if metadata.command_type.as_deref() == Some(TELEMETRY_UPSERT_COMMAND_TYPE) =>
+        {
+            MessagePriority::Telemetry {}
+        }
+        Some(metadata) if metadata.is_mission_related() => MessagePriority::Mission {},
+        _ => MessagePriority::Routine {},
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct ClassCounters {
+    queued: u32,
+    in_flight: u32,
+    dispatched: u64,
+    promoted: u64,
+    max_wait_ms: u64,
+}
+
+// Queue depth and dispatch counters per priority class, shared by every pool
+// so the totals cover general and mission traffic alike.
+#[derive(Clone, Default)]
+pub(crate) struct SendQueueStats {
+    counters: Arc<Mutex<[ClassCounters; PRIORITY_CLASSES.len()]>>,
+}
+
+impl SendQueueStats {
+    fn update(&self, priority: MessagePriority, apply: impl FnOnce(&mut ClassCounters)) {
+        if let Ok(mut counters) = self.counters.lock() {
+            apply(&mut counters[priority_rank(priority)]);
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> Vec<SendQueueClassMetrics> {
+        let counters = self.counters.lock().map(|guard| *guard).unwrap_or_default();
+        PRIORITY_CLASSES
+            .iter()
+            .zip(counters.iter())
+            .map(|(priority, counters)| SendQueueClassMetrics {
+                priority: *priority,
+                queued: counters.queued,
+                in_flight: counters.in_flight,
+                dispatched: counters.dispatched,
+                promoted: counters.promoted,
+                max_wait_ms: counters.max_wait_ms,
+            })
+            .collect()
+    }
+}
+
+struct Waiter {
+    ticket: u64,
+    priority: MessagePriority,
+    enqueued_at: Instant,
+}
+
+struct PoolState {
+    in_flight: usize,
+    next_ticket: u64,
+    waiters: Vec<Waiter>,
+}
+
+struct PoolInner {
+    capacity: usize,
+    state: Mutex<PoolState>,
+    notify: Notify,
+    stats: SendQueueStats,
+}
+
+// A counting pool like a semaphore, except that a freed slot goes to the
+// highest effective priority waiting rather than to whoever asked first.
+#[derive(Clone)]
+pub(crate) struct PrioritySendPool {
+    inner: Arc<PoolInner>,
+}
+
+impl PrioritySendPool {
+    pub(crate) fn new(capacity: usize, stats: SendQueueStats) -> Self {
+        Self {
+            inner: Arc::new(PoolInner {
+                capacity,
+                state: Mutex::new(PoolState {
+                    in_flight: 0,
+                    next_ticket: 0,
+                    waiters: Vec::new(),
+                }),
+                notify: Notify::new(),
+                stats,
+            }),
+        }
+    }
+
+    pub(crate) async fn acquire(
+        &self,
+        priority: MessagePriority,
+    ) -> Result<PrioritySendPermit, NodeError> {
+        let ticket = {
+            let mut state = self
+                .inner
+                .state
+                .lock()
+                .map_err(|_| NodeError::InternalError {})?;
+            let ticket = state.next_ticket;
+            state.next_ticket += 1;
+            state.waiters.push(Waiter {
+                ticket,
+                priority,
+                enqueued_at: Instant::now(),
+            });
+            ticket
+        };
+        self.inner
+            .stats
+            .update(priority, |counters| counters.queued += 1);
+        let mut pending = PendingWaiter {
+            inner: self.inner.as_ref(),
+            ticket,
+            priority,
+            admitted: false,
+        };
+        loop {
+            let notified = self.inner.notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            if pending.try_admit()? {
+                return Ok(PrioritySendPermit {
+                    inner: self.inner.clone(),
+                    priority,
+                });
+            }
+            notified.await;
+        }
+    }
+}
+
+// Removes the waiter again if the acquiring future is dropped before it gets
+// a slot, e.g. when an expiring message times out in the queue.
+struct PendingWaiter<'a> {
+    inner: &'a PoolInner,
+    ticket: u64,
+    priority: MessagePriority,
+    admitted: bool,
+}
+
+impl PendingWaiter<'_> {
+    fn try_admit(&mut self) -> Result<bool, NodeError> {
+        let now = Instant::now();
+        let (waiter, has_room) = {
+            let mut state = self
+                .inner
+                .state
+                .lock()
+                .map_err(|_| NodeError::InternalError {})?;
+            if state.in_flight >= self.inner.capacity {
+                return Ok(false);
+            }
+            let next = state
+                .waiters
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, waiter)| {
+                    (
+                        effective_rank(waiter.priority, now.duration_since(waiter.enqueued_at)),
+                        waiter.ticket,
+                    )
+                })
+                .map(|(index, waiter)| (index, waiter.ticket));
+            let Some(index) = next
+                .filter(|(_, ticket)| *ticket == self.ticket)
+                .map(|(index, _)| index)
+            else {
+                return Ok(false);
+            };
+            let waiter = state.waiters.remove(index);
+            state.in_flight += 1;
+            let has_room = state.in_flight < self.inner.capacity && !state.waiters.is_empty();
+            (waiter, has_room)
+        };
+        self.admitted = true;
+        let waited = now.duration_since(waiter.enqueued_at);
+        let promoted = effective_rank(waiter.priority, waited) < priority_rank(waiter.priority);
+        self.inner.stats.update(self.priority, |counters| {
+            counters.queued = counters.queued.saturating_sub(1);
+            counters.in_flight += 1;
+            counters.dispatched += 1;
+            if promoted {
+                counters.promoted += 1;
+            }
+            counters.max_wait_ms = counters.max_wait_ms.max(waited.as_millis() as u64);
+        });
+        if has_room {
+            self.inner.notify.notify_waiters();
+        }
+        Ok(true)
+    }
+}
+
+impl Drop for PendingWaiter<'_> {
+    fn drop(&mut self) {
+        if self.admitted {
+            return;
+        }
+        if let Ok(mut state) = self.inner.state.lock() {
+            state.waiters.retain(|waiter| waiter.ticket != self.ticket);
+        }
+        self.inner.stats.update(self.priority, |counters| {
+            counters.queued = counters.queued.saturating_sub(1);
+        });
+        self.inner.notify.notify_waiters();
+    }
+}
+
+pub(crate) struct PrioritySendPermit {
+    inner: Arc<PoolInner>,
+    priority: MessagePriority,
+}
+
+impl Drop for PrioritySendPermit {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.inner.state.lock() {
+            state.in_flight = state.in_flight.saturating_sub(1);
+        }
+        self.inner.stats.update(self.priority, |counters| {
+            counters.in_flight = counters.in_flight.saturating_sub(1);
+        });
+        self.inner.notify.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn freed_slot_goes_to_the_highest_priority_waiter() {
+        let stats = SendQueueStats::default();
+        let pool = PrioritySendPool::new(1, stats.clone());
+        let held = pool
+            .acquire(MessagePriority::Routine {})
+            .await
+            .expect("first permit");
+
+        let (order_tx, mut order_rx) = tokio::sync::mpsc::unbounded_channel();
+        for priority in [
+            MessagePriority::Telemetry {},
+            MessagePriority::Mission {},
+            MessagePriority::Sos {},
+        ] {
+            let pool = pool.clone();
+            let order_tx = order_tx.clone();
+            tokio::spawn(async move {
+                let _permit = pool.acquire(priority).await.expect("queued permit");
+                let _ = order_tx.send(priority);
+            });
+        }
+        drop(order_tx);
+        while stats
+            .snapshot()
+            .iter()
+            .map(|class| class.queued)
+            .sum::<u32>()
+            < 3
+        {
+            tokio::task::yield_now().await;
+        }
+        let depth = stats.snapshot();
+        assert_eq!(depth[priority_rank(MessagePriority::Sos {})].queued, 1);
+        assert_eq!(
+            depth[priority_rank(MessagePriority::Routine {})].in_flight,
+            1
+        );
+
+        drop(held);
+        let mut order = Vec::new();
+        while let Some(priority) = order_rx.recv().await {
+            order.push(priority);
+        }
+
+        assert_eq!(
+            order,
+            vec![
+                MessagePriority::Sos {},
+                MessagePriority::Mission {},
+                MessagePriority::Telemetry {},
+            ]
+        );
+        assert!(stats.snapshot().iter().all(|class| class.queued == 0));
+    }
+
+    #[test]
+    fn long_waits_are_promoted_but_never_reach_sos() {
+        assert_eq!(
+            effective_rank(MessagePriority::Telemetry {}, Duration::ZERO),
+            4
+        );
+        assert_eq!(
+            effective_rank(MessagePriority::Telemetry {}, PRIORITY_AGING_INTERVAL * 2),
+            2
+        );
+        assert_eq!(
+            effective_rank(MessagePriority::Routine {}, PRIORITY_AGING_INTERVAL * 10),
+            1
+        );
+        assert_eq!(effective_rank(MessagePriority::Sos {}, Duration::ZERO), 0);
+        assert_eq!(priority_for_fields(None), MessagePriority::Routine {});
+    }
+}
@@ -1,14 +1,23 @@
 use sha2::{Digest, Sha256};
 
+use crate::lxmf_fields::attachment_file_name;
 use crate::runtime::now_ms;
 use crate::sos_fields::{sos_kind_to_str, trigger_source_to_str};
 use crate::types::{
-    NodeError, SosAlertRecord, SosDeviceTelemetryRecord, SosLocationRecord, SosMessageKind,
-    SosSettingsRecord, SosState, SosStatusRecord, SosTriggerSource,
+    NodeError, SendMode, SosAlertRecord, SosDeliveryRecord, SosDeliveryReport, SosDeliveryState,
+    SosDeviceTelemetryRecord, SosLocationRecord, SosMessageKind, SosRecipientPolicyRecord,
+    SosResponderRecord, SosResponseKind, SosSettingsRecord, SosState, SosStatusRecord,
+    SosTriggerSource,
 };
 
 const DEFAULT_TEMPLATE: &str = "SOS! I need help. This is an emergency distress signal.";
 const CANCEL_BODY: &str = "SOS Cancelled - I am safe.";
+const DELIVERY_RETRY_BASE_MS: u64 = 30_000;
+const DELIVERY_RETRY_MAX_MS: u64 = 300_000;
+const AUTO_ANSWER_INTERVAL_MS: u64 = 300_000;
+// Roughly two minutes of 32 kbit/s Opus; anything larger is too slow to push
+// through a congested mesh while the incident is still active.
+pub(crate) const SOS_AUDIO_MAX_BYTES: u64 = 512 * 1024;
 
 pub(crate) fn default_sos_settings() -> SosSettingsRecord {
     SosSettingsRecord {
@@ -33,6 +42,7 @@ pub(crate) fn default_sos_settings() -> SosSettingsRecord {
         floating_button_y: 420.0,
         active_pill_x: 16.0,
         active_pill_y: 72.0,
+        recipients: SosRecipientPolicyRecord::default(),
     }
 }
 
@@ -61,6 +71,20 @@ pub(crate) fn normalize_sos_settings(mut settings: SosSettingsRecord) -> SosSett
     settings.shake_sensitivity = settings.shake_sensitivity.clamp(1.0, 8.0);
     settings.audio_duration_seconds = settings.audio_duration_seconds.clamp(15, 60);
     settings.update_interval_seconds = settings.update_interval_seconds.clamp(30, 3_600);
+    let mut contacts = Vec::new();
+    for contact in &settings.recipients.contacts {
+        let contact = contact.trim().to_ascii_lowercase();
+        if !contact.is_empty() && !contacts.contains(&contact) {
+            contacts.push(contact);
+        }
+    }
+    settings.recipients.contacts = contacts;
+    settings.recipients.team_uid = settings
+        .recipients
+        .team_uid
+        .take()
+        .map(|team_uid| team_uid.trim().to_string())
+        .filter(|team_uid| !team_uid.is_empty());
     if settings
         .deactivation_pin_hash
         .as_deref()
@@ -143,6 +167,75 @@ pub(crate) fn active_status(
     }
 }
 
+pub(crate) fn sos_update_due(
+    settings: &SosSettingsRecord,
+    status: &SosStatusRecord,
+    now_ms: u64,
+) -> bool {
+    if !settings.periodic_updates
+        || !matches!(status.state, SosState::Active {})
+        || status.incident_id.is_none()
+    {
+        return false;
+    }
+    let last_update_at_ms = status
+        .last_update_at_ms
+        .or(status.last_sent_at_ms)
+        .or(status.activated_at_ms)
+        .unwrap_or_default();
+    now_ms.saturating_sub(last_update_at_ms) >= u64::from(settings.update_interval_seconds) * 1000
+}
+
+pub(crate) fn updated_status(current: &SosStatusRecord, sent_at_ms: u64) -> SosStatusRecord {
+    SosStatusRecord {
+        last_sent_at_ms: Some(sent_at_ms),
+        last_update_at_ms: Some(sent_at_ms),
+        updated_at_ms: sent_at_ms,
+        ..current.clone()
+    }
+}
+
+// Failed recipients back off exponentially up to five minutes; there is no
+// attempt cap because retries stop only once the incident is cancelled.
+pub(crate) fn sos_delivery_retry_due(delivery: &SosDeliveryRecord, now_ms: u64) -> bool {
+    if !matches!(delivery.state, SosDeliveryState::Failed {}) {
+        return false;
+    }
+    let exponent = delivery.attempts.saturating_sub(1).min(8);
+    let backoff_ms = (DELIVERY_RETRY_BASE_MS << exponent).min(DELIVERY_RETRY_MAX_MS);
+    now_ms.saturating_sub(delivery.last_attempt_at_ms) >= backoff_ms
+}
+
+// Retries alternate between the propagation node and the regular direct-first
+// policy so a recipient that is briefly unreachable still gets the alert.
+pub(crate) fn sos_retry_send_mode(previous_attempts: u32) -> SendMode {
+    if previous_attempts % 2 == 1 {
+        SendMode::PropagationOnly {}
+    } else {
+        SendMode::Auto {}
+    }
+}
+
+pub(crate) fn sos_delivery_report(
+    incident_id: &str,
+    recipients: Vec<SosDeliveryRecord>,
+) -> SosDeliveryReport {
+    let count = |state: SosDeliveryState| {
+        recipients
+            .iter()
+            .filter(|delivery| delivery.state == state)
+            .count() as u32
+    };
+    SosDeliveryReport {
+        incident_id: incident_id.to_string(),
+        queued: count(SosDeliveryState::Queued {}),
+        sent: count(SosDeliveryState::Sent {}),
+        delivered: count(SosDeliveryState::Delivered {}),
+        failed: count(SosDeliveryState::Failed {}),
+        recipients,
+    }
+}
+
 pub(crate) fn countdown_status(
     incident_id: String,
     trigger_source: SosTriggerSource,
@@ -222,6 +315,107 @@ pub(crate) fn received_alert_from_sos(
     }
 }
 
+// Kept free of the legacy SOS prefixes so older clients that only look at the
+// text never mistake an acknowledgement for a new alert.
+pub(crate) fn compose_sos_response_body(kind: SosResponseKind, eta_seconds: Option<u32>) -> String {
+    if matches!(kind, SosResponseKind::AutoAnswer {}) {
+        return "Automatic reply: your alert was received.".to_string();
+    }
+    let mut body = format!(
+        "Responding to your alert: {}",
+        sos_response_label(kind).to_ascii_lowercase()
+    );
+    if let Some(eta_seconds) = eta_seconds {
+        let minutes = eta_seconds.div_ceil(60).max(1);
+        body.push_str(format!(" (ETA {minutes} min)").as_str());
+    }
+    body
+}
+
+pub(crate) fn responder_from_response(
+    incident_id: String,
+    responder_hex: String,
+    kind: SosResponseKind,
+    eta_seconds: Option<u32>,
+    telemetry: Option<&SosDeviceTelemetryRecord>,
+    message_id_hex: Option<String>,
+    responded_at_ms: u64,
+) -> SosResponderRecord {
+    SosResponderRecord {
+        incident_id,
+        responder_hex,
+        kind,
+        eta_seconds,
+        lat: telemetry.and_then(|value| value.lat),
+        lon: telemetry.and_then(|value| value.lon),
+        accuracy: telemetry.and_then(|value| value.accuracy),
+        battery_percent: telemetry.and_then(|value| value.battery_percent),
+        message_id_hex,
+        responded_at_ms,
+        updated_at_ms: responded_at_ms,
+    }
+}
+
+// Auto answers go out at most once per interval per incident, and only again
+// once the originator has sent something newer. A manual response from this
+// device takes over and stops them.
+pub(crate) fn sos_auto_answer_due(
+    settings: &SosSettingsRecord,
+    alert: &SosAlertRecord,
+    previous: Option<&SosResponderRecord>,
+    now_ms: u64,
+) -> bool {
+    if !settings.silent_auto_answer || !alert.active {
+        return false;
+    }
+    let Some(previous) = previous else {
+        return true;
+    };
+    matches!(previous.kind, SosResponseKind::AutoAnswer {})
+        && alert.updated_at_ms > previous.updated_at_ms
+        && now_ms.saturating_sub(previous.updated_at_ms) >= AUTO_ANSWER_INTERVAL_MS
+}
+
+// Only Opus and AMR clips are sent or accepted, and the file extension is
+// derived from the MIME type rather than trusted from the sender.
+pub(crate) fn sos_audio_extension(mime_type: &str) -> Option<&'static str> {
+    let essence = mime_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    match essence.as_str() {
+        "audio/ogg" => Some("ogg"),
+        "audio/opus" => Some("opus"),
+        "audio/amr" => Some("amr"),
+        "audio/amr-wb" => Some("awb"),
+        _ => None,
+    }
+}
+
+pub(crate) fn validate_sos_audio(mime_type: &str, size: u64) -> Result<&'static str, NodeError> {
+    if size == 0 || size > SOS_AUDIO_MAX_BYTES {
+        return Err(NodeError::InvalidConfig {});
+    }
+    sos_audio_extension(mime_type).ok_or(NodeError::InvalidConfig {})
+}
+
+// Received clips are stored under an id derived from the sender, so one peer
+// cannot overwrite another's clip by reusing its audio id.
+pub(crate) fn received_sos_audio_id(source_hex: &str, incident_id: &str, audio_id: &str) -> String {
+    let mut hasher = Sha256::new();
+    for part in [source_hex, incident_id, audio_id] {
+        hasher.update(part.as_bytes());
+        hasher.update(b":");
+    }
+    format!("sos-audio-{}", hex::encode(&hasher.finalize()[..16]))
+}
+
+pub(crate) fn sos_audio_file_name(audio_id: &str, extension: &str) -> String {
+    attachment_file_name(format!("{audio_id}.{extension}").as_str())
+}
+
 pub(crate) fn location_from_alert(alert: &SosAlertRecord) -> Option<SosLocationRecord> {
     Some(SosLocationRecord {
         incident_id: alert.incident_id.clone(),
@@ -244,6 +438,24 @@ pub(crate) fn sos_status_label(state: SosState) -> &'static str {
     }
 }
 
+pub(crate) fn sos_delivery_label(state: SosDeliveryState) -> &'static str {
+    match state {
+        SosDeliveryState::Queued {} => "Queued",
+        SosDeliveryState::Sent {} => "Sent",
+        SosDeliveryState::Delivered {} => "Delivered",
+        SosDeliveryState::Failed {} => "Failed",
+    }
+}
+
+pub(crate) fn sos_response_label(kind: SosResponseKind) -> &'static str {
+    match kind {
+        SosResponseKind::Acknowledged {} => "Acknowledged",
+        SosResponseKind::EnRoute {} => "En route",
+        SosResponseKind::OnScene {} => "On scene",
+        SosResponseKind::AutoAnswer {} => "Auto answer",
+    }
+}
+
 pub(crate) fn sos_trigger_label(source: SosTriggerSource) -> &'static str {
     trigger_source_to_str(source)
 }
@@ -265,6 +477,149 @@ mod tests {
         assert!(!verify_pin(&settings, Some("9999")));
     }
 
+    #[test]
+    fn periodic_update_is_due_after_interval_while_active() {
+        let mut settings = default_sos_settings();
+        let active = active_status(
+            "sos-local-1".to_string(),
+            SosTriggerSource::Manual {},
+            10_000,
+        );
+        assert!(!sos_update_due(&settings, &active, 10_000 + 600_000));
+
+        settings.periodic_updates = true;
+        assert!(!sos_update_due(&settings, &active, 10_000 + 119_000));
+        assert!(sos_update_due(&settings, &active, 10_000 + 120_000));
+
+        let updated = updated_status(&active, 130_000);
+        assert_eq!(updated.activated_at_ms, Some(10_000));
+        assert_eq!(updated.last_update_at_ms, Some(130_000));
+        assert!(!sos_update_due(&settings, &updated, 130_000 + 60_000));
+        assert!(!sos_update_due(&settings, &idle_status(), 10_000 + 600_000));
+    }
+
+    #[test]
+    fn failed_deliveries_retry_with_backoff_and_propagation_fallback() {
+        let mut delivery = SosDeliveryRecord {
+            incident_id: "sos-local-1".to_string(),
+            destination_hex: "a".repeat(32),
+            kind: SosMessageKind::Active {},
+            state: SosDeliveryState::Failed {},
+            attempts: 1,
+            lxmf_message_id_hex: None,
+            via_propagation: false,
+            detail: Some("timeout".to_string()),
+            last_attempt_at_ms: 10_000,
+            updated_at_ms: 10_000,
+        };
+        assert!(!sos_delivery_retry_due(&delivery, 39_000));
+        assert!(sos_delivery_retry_due(&delivery, 40_000));
+        assert_eq!(
+            sos_retry_send_mode(delivery.attempts),
+            SendMode::PropagationOnly {}
+        );
+
+        delivery.attempts = 2;
+        assert!(!sos_delivery_retry_due(&delivery, 69_000));
+        assert!(sos_delivery_retry_due(&delivery, 70_000));
+        assert_eq!(sos_retry_send_mode(delivery.attempts), SendMode::Auto {});
+
+        delivery.attempts = 20;
+        assert!(sos_delivery_retry_due(&delivery, 310_000));
+
+        delivery.state = SosDeliveryState::Sent {};
+        assert!(!sos_delivery_retry_due(&delivery, 10_000_000));
+
+        let report = sos_delivery_report("sos-local-1", vec![delivery]);
+        assert_eq!((report.queued, report.sent, report.failed), (0, 1, 0));
+    }
+
+    #[test]
+    fn response_body_is_not_detected_as_an_alert() {
+        let body = compose_sos_response_body(SosResponseKind::EnRoute {}, Some(400));
+        assert_eq!(body, "Responding to your alert: en route (ETA 7 min)");
+        assert!(!crate::sos_fields::looks_like_sos_text(body.as_str()));
+    }
+
+    #[test]
+    fn auto_answer_is_rate_limited_per_incident() {
+        let mut settings = default_sos_settings();
+        settings.silent_auto_answer = true;
+        let mut alert = received_alert_from_sos(
+            "incident-1".to_string(),
+            "a".repeat(32),
+            "conversation".to_string(),
+            SosMessageKind::Active {},
+            "SOS".to_string(),
+            None,
+            None,
+            None,
+            1_000,
+        );
+        assert!(sos_auto_answer_due(&settings, &alert, None, 1_000));
+
+        let answered = responder_from_response(
+            "incident-1".to_string(),
+            "b".repeat(32),
+            SosResponseKind::AutoAnswer {},
+            None,
+            None,
+            None,
+            2_000,
+        );
+        alert.updated_at_ms = 60_000;
+        assert!(!sos_auto_answer_due(
+            &settings,
+            &alert,
+            Some(&answered),
+            60_000
+        ));
+        assert!(sos_auto_answer_due(
+            &settings,
+            &alert,
+            Some(&answered),
+            302_000
+        ));
+
+        let manual = SosResponderRecord {
+            kind: SosResponseKind::EnRoute {},
+            ..answered.clone()
+        };
+        assert!(!sos_auto_answer_due(
+            &settings,
+            &alert,
+            Some(&manual),
+            900_000
+        ));
+
+        alert.active = false;
+        assert!(!sos_auto_answer_due(&settings, &alert, None, 900_000));
+        alert.active = true;
+        settings.silent_auto_answer = false;
+        assert!(!sos_auto_answer_due(&settings, &alert, None, 900_000));
+    }
+
+    #[test]
+    fn sos_audio_accepts_only_small_clips_in_known_codecs() {
+        assert_eq!(
+            validate_sos_audio("audio/ogg; codecs=opus", 1_024).ok(),
+            Some("ogg")
+        );
+        assert_eq!(validate_sos_audio("AUDIO/AMR", 1_024).ok(), Some("amr"));
+        assert!(validate_sos_audio("audio/mp4", 1_024).is_err());
+        assert!(validate_sos_audio("audio/mpeg", 1_024).is_err());
+        assert!(validate_sos_audio("audio/opus", 0).is_err());
+        assert!(validate_sos_audio("audio/opus", SOS_AUDIO_MAX_BYTES + 1).is_err());
+        assert_eq!(
+            sos_audio_file_name("../../etc/passwd", "opus"),
+            "passwd.opus"
+        );
+        assert_ne!(
+            received_sos_audio_id("aa", "sos-1", "clip-1"),
+            received_sos_audio_id("bb", "sos-1", "clip-1")
+        );
+    }
+
     #[test]
     fn cancel_body_is_legacy_detectable() {
         let body = compose_sos_body(&default_sos_settings(), SosMessageKind::Cancelled {}, None);
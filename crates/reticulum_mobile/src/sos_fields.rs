@@ -1,7 +1,12 @@
 use rmpv::Value as MsgPackValue;
 
-use crate::lxmf_fields::FIELD_COMMANDS;
-use crate::types::{NodeError, SosDeviceTelemetryRecord, SosMessageKind, SosTriggerSource};
+use crate::lxmf_fields::{
+    msgpack_bool, msgpack_f64, msgpack_get_indexed, msgpack_get_named, msgpack_map_entries,
+    msgpack_string, msgpack_u64, FIELD_COMMANDS, FIELD_FILE_ATTACHMENTS,
+};
+use crate::types::{
+    NodeError, SosDeviceTelemetryRecord, SosMessageKind, SosResponseKind, SosTriggerSource,
+};
 
 pub(crate) const LXMF_FIELD_TELEMETRY: i64 = 0x02;
 pub(crate) const SID_TIME: i64 = 0x01;
@@ -17,9 +22,35 @@ pub(crate) struct SosCommand {
     pub(crate) audio_id: Option<String>,
 }
 
+// Sent back to the originator by someone answering an alert. It travels in the
+// same command field but carries `sos_response` instead of `sos_state`, so
+// older clients skip it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SosResponse {
+    pub(crate) kind: SosResponseKind,
+    pub(crate) incident_id: String,
+    pub(crate) eta_seconds: Option<u32>,
+    pub(crate) sent_at_ms: u64,
+}
+
+// A recorded clip travels in its own message: the `sos_audio` envelope in the
+// command field names it and the bytes ride in the standard LXMF attachment
+// field, so large clips are carried as a Reticulum resource.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SosAudioClip {
+    pub(crate) audio_id: String,
+    pub(crate) incident_id: String,
+    pub(crate) mime_type: String,
+    pub(crate) duration_seconds: u32,
+    pub(crate) sent_at_ms: u64,
+    pub(crate) bytes: Vec<u8>,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct SosFields {
     pub(crate) command: Option<SosCommand>,
+    pub(crate) response: Option<SosResponse>,
+    pub(crate) audio: Option<SosAudioClip>,
     pub(crate) telemetry: Option<SosDeviceTelemetryRecord>,
 }
 
@@ -42,14 +73,86 @@ pub(crate) fn build_sos_fields(
     rmp_serde::to_vec(&MsgPackValue::Map(entries)).map_err(|_| NodeError::InternalError {})
 }
 
+pub(crate) fn build_sos_response_fields(
+    response: &SosResponse,
+    telemetry: Option<&SosDeviceTelemetryRecord>,
+) -> Result<Vec<u8>, NodeError> {
+    let mut entries = vec![(
+        MsgPackValue::from(FIELD_COMMANDS),
+        MsgPackValue::Array(vec![response_to_msgpack(response)]),
+    )];
+
+    if let Some(telemetry) = telemetry {
+        entries.push((
+            MsgPackValue::from(LXMF_FIELD_TELEMETRY),
+            MsgPackValue::Binary(build_telemeter_payload(telemetry)?),
+        ));
+    }
+
+    rmp_serde::to_vec(&MsgPackValue::Map(entries)).map_err(|_| NodeError::InternalError {})
+}
+
+pub(crate) fn build_sos_audio_fields(
+    clip: &SosAudioClip,
+    file_name: &str,
+) -> Result<Vec<u8>, NodeError> {
+    let envelope = MsgPackValue::Map(vec![
+        (
+            MsgPackValue::from("sos_audio"),
+            MsgPackValue::from(clip.audio_id.as_str()),
+        ),
+        (
+            MsgPackValue::from("incident_id"),
+            MsgPackValue::from(clip.incident_id.as_str()),
+        ),
+        (
+            MsgPackValue::from("mime_type"),
+            MsgPackValue::from(clip.mime_type.as_str()),
+        ),
+        (
+            MsgPackValue::from("duration_seconds"),
+            MsgPackValue::from(clip.duration_seconds),
+        ),
+        (
+            MsgPackValue::from("sent_at_ms"),
+            MsgPackValue::from(clip.sent_at_ms),
+        ),
+    ]);
+    let attachment = MsgPackValue::Array(vec![
+        MsgPackValue::from(file_name),
+        MsgPackValue::Binary(clip.bytes.clone()),
+    ]);
+    let entries = vec![
+        (
+            MsgPackValue::from(FIELD_COMMANDS),
+            MsgPackValue::Array(vec![envelope]),
+        ),
+        (
+            MsgPackValue::from(FIELD_FILE_ATTACHMENTS),
+            MsgPackValue::Array(vec![attachment]),
+        ),
+    ];
+    rmp_serde::to_vec(&MsgPackValue::Map(entries)).map_err(|_| NodeError::InternalError {})
+}
+
 pub(crate) fn parse_sos_fields(fields_bytes: &[u8]) -> Option<SosFields> {
     let fields = rmp_serde::from_slice::<MsgPackValue>(fields_bytes).ok()?;
     let entries = msgpack_map_entries(&fields)?;
+    let commands = msgpack_get_indexed(entries, FIELD_COMMANDS);
     let parsed = SosFields {
-        command: parse_command_field(msgpack_get_indexed(entries, FIELD_COMMANDS)),
+        command: parse_command_field(commands),
+        response: parse_response_field(commands),
+        audio: parse_audio_field(
+            commands,
+            msgpack_get_indexed(entries, FIELD_FILE_ATTACHMENTS),
+        ),
         telemetry: parse_telemetry_field(msgpack_get_indexed(entries, LXMF_FIELD_TELEMETRY)),
     };
-    (parsed.command.is_some() || parsed.telemetry.is_some()).then_some(parsed)
+    (parsed.command.is_some()
+        || parsed.response.is_some()
+        || parsed.audio.is_some()
+        || parsed.telemetry.is_some())
+    .then_some(parsed)
 }
 
 pub(crate) fn looks_like_sos_text(body: &str) -> bool {
@@ -111,6 +214,30 @@ fn command_to_msgpack(command: &SosCommand) -> MsgPackValue {
     MsgPackValue::Map(entries)
 }
 
+fn response_to_msgpack(response: &SosResponse) -> MsgPackValue {
+    let mut entries = vec![
+        (
+            MsgPackValue::from("sos_response"),
+            MsgPackValue::from(sos_response_kind_to_str(response.kind)),
+        ),
+        (
+            MsgPackValue::from("incident_id"),
+            MsgPackValue::from(response.incident_id.as_str()),
+        ),
+        (
+            MsgPackValue::from("sent_at_ms"),
+            MsgPackValue::from(response.sent_at_ms),
+        ),
+    ];
+    if let Some(eta_seconds) = response.eta_seconds {
+        entries.push((
+            MsgPackValue::from("eta_seconds"),
+            MsgPackValue::from(eta_seconds),
+        ));
+    }
+    MsgPackValue::Map(entries)
+}
+
 fn build_telemeter_payload(telemetry: &SosDeviceTelemetryRecord) -> Result<Vec<u8>, NodeError> {
     let mut entries = vec![(
         MsgPackValue::from(SID_TIME),
@@ -184,6 +311,76 @@ fn parse_command_map(value: &MsgPackValue) -> Option<SosCommand> {
     })
 }
 
+fn parse_response_field(value: Option<&MsgPackValue>) -> Option<SosResponse> {
+    match value? {
+        MsgPackValue::Array(items) => items.iter().find_map(parse_response_map),
+        other => parse_response_map(other),
+    }
+}
+
+fn parse_response_map(value: &MsgPackValue) -> Option<SosResponse> {
+    let entries = msgpack_map_entries(value)?;
+    let kind = parse_sos_response_kind(msgpack_get_named(
+        entries,
+        &["sos_response", "sosResponse"],
+    )?)?;
+    let incident_id = msgpack_get_named(entries, &["incident_id", "incidentId"])
+        .and_then(msgpack_string)
+        .filter(|value| !value.trim().is_empty())?;
+    Some(SosResponse {
+        kind,
+        incident_id,
+        eta_seconds: msgpack_get_named(entries, &["eta_seconds", "etaSeconds"])
+            .and_then(msgpack_u64)
+            .map(|value| value.min(u64::from(u32::MAX)) as u32),
+        sent_at_ms: msgpack_get_named(entries, &["sent_at_ms", "sentAtMs"])
+            .and_then(msgpack_u64)
+            .unwrap_or(0),
+    })
+}
+
+fn parse_audio_field(
+    commands: Option<&MsgPackValue>,
+    attachments: Option<&MsgPackValue>,
+) -> Option<SosAudioClip> {
+    let entries = match commands? {
+        MsgPackValue::Array(items) => items.iter().find_map(|item| {
+            msgpack_map_entries(item)
+                .filter(|entries| msgpack_get_named(entries, &["sos_audio", "sosAudio"]).is_some())
+        }),
+        other => msgpack_map_entries(other),
+    }?;
+    let audio_id = msgpack_get_named(entries, &["sos_audio", "sosAudio"])
+        .and_then(msgpack_string)
+        .filter(|value| !value.trim().is_empty())?;
+    let bytes = match attachments? {
+        MsgPackValue::Array(items) => items.iter().find_map(|item| match item {
+            MsgPackValue::Array(parts) => match parts.get(1) {
+                Some(MsgPackValue::Binary(bytes)) => Some(bytes.clone()),
+                _ => None,
+            },
+            _ => None,
+        }),
+        _ => None,
+    }?;
+    Some(SosAudioClip {
+        audio_id,
+        incident_id: msgpack_get_named(entries, &["incident_id", "incidentId"])
+            .and_then(msgpack_string)?,
+        mime_type: msgpack_get_named(entries, &["mime_type", "mimeType"])
+            .and_then(msgpack_string)
+            .unwrap_or_default(),
+        duration_seconds: msgpack_get_named(entries, &["duration_seconds", "durationSeconds"])
+            .and_then(msgpack_u64)
+            .map(|value| value.min(u64::from(u32::MAX)) as u32)
+            .unwrap_or(0),
+        sent_at_ms: msgpack_get_named(entries, &["sent_at_ms", "sentAtMs"])
+            .and_then(msgpack_u64)
+            .unwrap_or(0),
+        bytes,
+    })
+}
+
 fn parse_telemetry_field(value: Option<&MsgPackValue>) -> Option<SosDeviceTelemetryRecord> {
     let value = value?;
     let payload = match value {
@@ -239,74 +436,6 @@ fn parse_telemetry_field(value: Option<&MsgPackValue>) -> Option<SosDeviceTeleme
         .then_some(telemetry)
 }
 
-fn msgpack_map_entries(value: &MsgPackValue) -> Option<&[(MsgPackValue, MsgPackValue)]> {
-    match value {
-        MsgPackValue::Map(entries) => Some(entries.as_slice()),
-        _ => None,
-    }
-}
-
-fn msgpack_get_indexed<'a>(
-    entries: &'a [(MsgPackValue, MsgPackValue)],
-    key: i64,
-) -> Option<&'a MsgPackValue> {
-    let key_string = key.to_string();
-    entries
-        .iter()
-        .find_map(|(entry_key, entry_value)| match entry_key {
-            MsgPackValue::Integer(value) if value.as_i64() == Some(key) => Some(entry_value),
-            MsgPackValue::String(value) if value.as_str() == Some(key_string.as_str()) => {
-                Some(entry_value)
-            }
-            _ => None,
-        })
-}
-
-fn msgpack_get_named<'a>(
-    entries: &'a [(MsgPackValue, MsgPackValue)],
-    keys: &[&str],
-) -> Option<&'a MsgPackValue> {
-    keys.iter().find_map(|wanted| {
-        entries.iter().find_map(|(entry_key, entry_value)| {
-            matches!(entry_key, MsgPackValue::String(actual) if actual.as_str() == Some(*wanted))
-                .then_some(entry_value)
-        })
-    })
-}
-
-fn msgpack_string(value: &MsgPackValue) -> Option<String> {
-    match value {
-        MsgPackValue::String(value) => value.as_str().map(str::to_string),
-        MsgPackValue::Binary(value) => String::from_utf8(value.clone()).ok(),
-        _ => None,
-    }
-}
-
-fn msgpack_bool(value: &MsgPackValue) -> Option<bool> {
-    match value {
-        MsgPackValue::Boolean(value) => Some(*value),
-        _ => None,
-    }
-}
-
-fn msgpack_f64(value: &MsgPackValue) -> Option<f64> {
-    match value {
-        MsgPackValue::F32(value) => Some(f64::from(*value)),
-        MsgPackValue::F64(value) => Some(*value),
-        MsgPackValue::Integer(value) => value.as_i64().map(|entry| entry as f64),
-        _ => None,
-    }
-}
-
-fn msgpack_u64(value: &MsgPackValue) -> Option<u64> {
-    match value {
-        MsgPackValue::Integer(value) => value
-            .as_u64()
-            .or_else(|| value.as_i64().map(|v| v.max(0) as u64)),
-        _ => None,
-    }
-}
-
 fn parse_sos_kind(value: &MsgPackValue) -> Option<SosMessageKind> {
     match msgpack_string(value)?.trim().to_ascii_lowercase().as_str() {
         "active" => Some(SosMessageKind::Active {}),
@@ -324,6 +453,25 @@ pub(crate) fn sos_kind_to_str(value: SosMessageKind) -> &'static str {
     }
 }
 
+fn parse_sos_response_kind(value: &MsgPackValue) -> Option<SosResponseKind> {
+    match msgpack_string(value)?.trim().to_ascii_lowercase().as_str() {
+        "acknowledged" | "ack" => Some(SosResponseKind::Acknowledged {}),
+        "en_route" | "enroute" | "en-route" => Some(SosResponseKind::EnRoute {}),
+        "on_scene" | "onscene" | "on-scene" => Some(SosResponseKind::OnScene {}),
+        "auto_answer" | "autoanswer" | "auto-answer" => Some(SosResponseKind::AutoAnswer {}),
+        _ => None,
+    }
+}
+
+pub(crate) fn sos_response_kind_to_str(value: SosResponseKind) -> &'static str {
+    match value {
+        SosResponseKind::Acknowledged {} => "acknowledged",
+        SosResponseKind::EnRoute {} => "en_route",
+        SosResponseKind::OnScene {} => "on_scene",
+        SosResponseKind::AutoAnswer {} => "auto_answer",
+    }
+}
+
 fn parse_trigger_source(value: &MsgPackValue) -> Option<SosTriggerSource> {
     match msgpack_string(value)?.trim().to_ascii_lowercase().as_str() {
         "manual" => Some(SosTriggerSource::Manual {}),
@@ -427,6 +575,58 @@ mod tests {
         assert!(msgpack_get_indexed(telemetry_entries, SID_BATTERY).is_some());
     }
 
+    #[test]
+    fn sos_audio_clip_round_trips_as_lxmf_attachment() {
+        let clip = SosAudioClip {
+            audio_id: "audio-1".to_string(),
+            incident_id: "incident-1".to_string(),
+            mime_type: "audio/ogg".to_string(),
+            duration_seconds: 15,
+            sent_at_ms: 99,
+            bytes: vec![0x4F, 0x67, 0x67, 0x53, 0x00, 0x02],
+        };
+
+        let encoded = build_sos_audio_fields(&clip, "audio-1.ogg").expect("encoded");
+        let fields = rmp_serde::from_slice::<MsgPackValue>(&encoded).expect("field map");
+        let entries = msgpack_map_entries(&fields).expect("map entries");
+        let parsed = parse_sos_fields(&encoded).expect("parsed fields");
+
+        assert!(msgpack_get_indexed(entries, FIELD_FILE_ATTACHMENTS).is_some());
+        assert!(parsed.command.is_none());
+        assert!(parsed.response.is_none());
+        assert_eq!(parsed.audio.expect("audio clip"), clip);
+    }
+
+    #[test]
+    fn sos_response_round_trips_without_looking_like_an_alert() {
+        let response = SosResponse {
+            kind: SosResponseKind::EnRoute {},
+            incident_id: "incident-1".to_string(),
+            eta_seconds: Some(420),
+            sent_at_ms: 77,
+        };
+        let telemetry = SosDeviceTelemetryRecord {
+            lat: Some(44.65),
+            lon: Some(-63.57),
+            alt: None,
+            speed: None,
+            course: None,
+            accuracy: Some(8.0),
+            battery_percent: None,
+            battery_charging: None,
+            updated_at_ms: 1_700_000_000_000,
+        };
+
+        let encoded = build_sos_response_fields(&response, Some(&telemetry)).expect("encoded");
+        let parsed = parse_sos_fields(&encoded).expect("parsed fields");
+
+        assert!(parsed.command.is_none());
+        assert_eq!(parsed.response.expect("response"), response);
+        let position = parsed.telemetry.expect("responder position");
+        assert_eq!(position.lat, Some(44.65));
+        assert_eq!(position.accuracy, Some(8.0));
+    }
+
     #[test]
     fn text_detection_accepts_legacy_prefixes() {
         assert!(looks_like_sos_text("SOS! I need help"));
@@ -0,0 +1,241 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::types::{NodeError, TeamLevel, TeamRecord};
+
+pub(crate) const TEAM_UPSERT_COMMAND_TYPE: &str = "mission.registry.team.upsert";
+
+pub(crate) const TEAM_UID_YELLOW: &str = "d6b6e188b910d6bdd24d04b7a7ec5444";
+pub(crate) const TEAM_UID_RED: &str = "65ce79a3a3e4b51ec0ec52d1d3d2b0b9";
+pub(crate) const TEAM_UID_BLUE: &str = "43341e5c822d99857fa6e8641f2ca9c0";
+pub(crate) const TEAM_UID_ORANGE: &str = "a83eb640e4c4884be14831e3d7ef5ae0";
+pub(crate) const TEAM_UID_MAGENTA: &str = "7ac50a910f42b06cd9cb68dad3def681";
+pub(crate) const TEAM_UID_MAROON: &str = "372824ef4f15881291455562f7570233";
+pub(crate) const TEAM_UID_PURPLE: &str = "4bf2a1d2217c8668942658137f2a6824";
+pub(crate) const TEAM_UID_DARK_BLUE: &str = "cbb35fc9a8f5a91d7bd2b5e5b644edcd";
+pub(crate) const TEAM_UID_CYAN: &str = "d4cd5030b68df059ec6beabe416dd6a6";
+pub(crate) const TEAM_UID_TEAL: &str = "4d7a7a974beec395bf83491604768499";
+pub(crate) const TEAM_UID_GREEN: &str = "612a32262163b73a80eca944c2158546";
+pub(crate) const TEAM_UID_DARK_GREEN: &str = "341653613d4c76d56bee99c1f38177b1";
+pub(crate) const TEAM_UID_BROWN: &str = "4efe72ac30f5b85142fdcab6d96c7631";
+
+const COLOR_TEAMS: [(&str, &str, &str); 13] = [
+    ("YELLOW", "Yellow", TEAM_UID_YELLOW),
+    ("RED", "Red", TEAM_UID_RED),
+    ("BLUE", "Blue", TEAM_UID_BLUE),
+    ("ORANGE", "Orange", TEAM_UID_ORANGE),
+    ("MAGENTA", "Magenta", TEAM_UID_MAGENTA),
+    ("MAROON", "Maroon", TEAM_UID_MAROON),
+    ("PURPLE", "Purple", TEAM_UID_PURPLE),
+    ("DARK_BLUE", "Dark Blue", TEAM_UID_DARK_BLUE),
+    ("CYAN", "Cyan", TEAM_UID_CYAN),
+    ("TEAL", "Teal", TEAM_UID_TEAL),
+    ("GREEN", "Green", TEAM_UID_GREEN),
+    ("DARK_GREEN", "Dark Green", TEAM_UID_DARK_GREEN),
+    ("BROWN", "Brown", TEAM_UID_BROWN),
+];
+
+// The fixed colour teams EAMs have always been filed under. They are seeded
+// with `updated_at_ms = 0` so any replicated edit (a rename, a parent section,
+// members) replaces the seed.
+pub(crate) fn color_team_records() -> Vec<TeamRecord> {
+    COLOR_TEAMS
+        .iter()
+        .map(|(color, name, team_uid)| TeamRecord {
+            team_uid: (*team_uid).to_string(),
+            name: (*name).to_string(),
+            level: TeamLevel::Team {},
+            parent_team_uid: None,
+            color: Some((*color).to_string()),
+            members: Vec::new(),
+            updated_at_ms: 0,
+            deleted_at_ms: None,
+        })
+        .collect()
+}
+
+fn level_depth(level: TeamLevel) -> u8 {
+    match level {
+        TeamLevel::Section {} => 0,
+        TeamLevel::Team {} => 1,
+        TeamLevel::Squad {} => 2,
+    }
+}
+
+pub(crate) fn validate_team_fields(team: &TeamRecord) -> Result<(), NodeError> {
+    let mut identities = HashSet::new();
+    let valid = !team.team_uid.trim().is_empty()
+        && !team.name.trim().is_empty()
+        && team.members.iter().all(|member| {
+            !member.rns_identity.trim().is_empty()
+                && identities.insert(member.rns_identity.trim().to_ascii_lowercase())
+        });
+    if valid {
+        Ok(())
+    } else {
+        Err(NodeError::InvalidConfig {})
+    }
+}
+
+// Every check but the parent's: the live teams already filed under this one
+// must stay at a lower level, so re-levelling a team cannot invert the
+// hierarchy below it.
+pub(crate) fn validate_team_without_parent(
+    team: &TeamRecord,
+    teams: &[TeamRecord],
+) -> Result<(), NodeError> {
+    validate_team_fields(team)?;
+    let inverted = teams.iter().any(|child| {
+        child.deleted_at_ms.is_none()
+            && child.team_uid != team.team_uid
+            && child.parent_team_uid.as_deref() == Some(team.team_uid.as_str())
+            && level_depth(child.level) <= level_depth(team.level)
+    });
+    if inverted {
+        Err(NodeError::InvalidConfig {})
+    } else {
+        Ok(())
+    }
+}
+
+// A parent must be a live team at a higher level (section above team above
+// squad), which also rules out cycles.
+pub(crate) fn validate_team(team: &TeamRecord, teams: &[TeamRecord]) -> Result<(), NodeError> {
+    validate_team_without_parent(team, teams)?;
+    let Some(parent_team_uid) = team.parent_team_uid.as_deref() else {
+        return Ok(());
+    };
+    let parent = teams.iter().find(|candidate| {
+        candidate.team_uid == parent_team_uid && candidate.deleted_at_ms.is_none()
+    });
+    match parent {
+        Some(parent) if level_depth(parent.level) < level_depth(team.level) => Ok(()),
+        _ => Err(NodeError::InvalidConfig {}),
+    }
+}
+
+// The team itself followed by every live team below it, breadth first.
+pub(crate) fn team_subtree_uids(teams: &[TeamRecord], root_team_uid: &str) -> Vec<String> {
+    let mut seen = HashSet::from([root_team_uid.to_string()]);
+    let mut subtree = vec![root_team_uid.to_string()];
+    let mut queue = VecDeque::from([root_team_uid.to_string()]);
+    while let Some(team_uid) = queue.pop_front() {
+        for child in teams.iter().filter(|candidate| {
+            candidate.deleted_at_ms.is_none()
+                && candidate.parent_team_uid.as_deref() == Some(team_uid.as_str())
+        }) {
+            if seen.insert(child.team_uid.clone()) {
+                subtree.push(child.team_uid.clone());
+                queue.push_back(child.team_uid.clone());
+            }
+        }
+    }
+    subtree
+}
+
+pub(crate) fn team_depth(teams: &[TeamRecord], team_uid: &str) -> u32 {
+    let mut depth = 0;
+    let mut seen = HashSet::new();
+    let mut current = teams.iter().find(|team| team.team_uid == team_uid);
+    while let Some(parent_team_uid) = current.and_then(|team| team.parent_team_uid.as_deref()) {
+        if !seen.insert(parent_team_uid) {
+            break;
+        }
+        depth += 1;
+        current = teams.iter().find(|team| team.team_uid == parent_team_uid);
+    }
+    depth
+}
+
+// The most specific team (deepest level) that lists the identity as a member.
+pub(crate) fn team_for_member<'a>(
+    teams: &'a [TeamRecord],
+    rns_identity: &str,
+) -> Option<&'a TeamRecord> {
+    teams
+        .iter()
+        .filter(|team| team.deleted_at_ms.is_none())
+        .filter(|team| {
+            team.members.iter().any(|member| {
+                member
+                    .rns_identity
+                    .eq_ignore_ascii_case(rns_identity.trim())
+            })
+        })
+        .max_by_key(|team| level_depth(team.level))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{TeamMemberRecord, TeamMemberRole};
+
+    fn team(team_uid: &str, level: TeamLevel, parent_team_uid: Option<&str>) -> TeamRecord {
+        TeamRecord {
+            team_uid: team_uid.to_string(),
+            name: team_uid.to_uppercase(),
+            level,
+            parent_team_uid: parent_team_uid.map(str::to_string),
+            color: None,
+            members: Vec::new(),
+            updated_at_ms: 1,
+            deleted_at_ms: None,
+        }
+    }
+
+    #[test]
+    fn hierarchy_rejects_inverted_levels_and_walks_subtrees() {
+        let mut squad = team("squad-1", TeamLevel::Squad {}, Some("team-1"));
+        squad.members.push(TeamMemberRecord {
+            rns_identity: "AA11".to_string(),
+            team_member_uid: Some("member-1".to_string()),
+            callsign: Some("POCO".to_string()),
+            role: TeamMemberRole::Leader {},
+        });
+        let mut teams = vec![
+            team("section-1", TeamLevel::Section {}, None),
+            team("team-1", TeamLevel::Team {}, Some("section-1")),
+            squad,
+        ];
+        teams[1].members.push(TeamMemberRecord {
+            rns_identity: "aa11".to_string(),
+            team_member_uid: None,
+            callsign: None,
+            role: TeamMemberRole::Member {},
+        });
+
+        assert!(validate_team(&teams[2], &teams).is_ok());
+        assert!(validate_team(
+            &team("section-2", TeamLevel::Section {}, Some("team-1")),
+            &teams
+        )
+        .is_err());
+        assert!(validate_team(
+            &team("squad-2", TeamLevel::Squad {}, Some("missing")),
+            &teams
+        )
+        .is_err());
+        assert!(validate_team(
+            &team("team-1", TeamLevel::Squad {}, Some("section-1")),
+            &teams
+        )
+        .is_err());
+        assert!(validate_team_without_parent(
+            &team("section-1", TeamLevel::Squad {}, None),
+            &teams
+        )
+        .is_err());
+
+        assert_eq!(
+            team_subtree_uids(&teams, "section-1"),
+            vec!["section-1", "team-1", "squad-1"]
+        );
+        assert_eq!(team_depth(&teams, "squad-1"), 2);
+        assert_eq!(
+            team_for_member(&teams, "AA11").map(|team| team.team_uid.as_str()),
+            Some("squad-1")
+        );
+
+        teams[1].deleted_at_ms = Some(2);
+        assert_eq!(team_subtree_uids(&teams, "section-1"), vec!["section-1"]);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::types::{TelemetryPositionRecord, TelemetrySettingsRecord};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TelemetryPublishPolicy {
+    pub(crate) enabled: bool,
+    pub(crate) publish_interval_ms: u64,
+    pub(crate) accuracy_threshold_meters: Option<f64>,
+    pub(crate) expire_after_ms: u64,
+}
+
+impl TelemetryPublishPolicy {
+    // Without stored settings the host drives publishing itself, so fixes are
+    // replicated as they arrive and never republished.
+    pub(crate) fn from_settings(settings: Option<&TelemetrySettingsRecord>) -> Self {
+        let Some(settings) = settings else {
+            return Self {
+                enabled: true,
+                publish_interval_ms: 0,
+                accuracy_threshold_meters: None,
+                expire_after_ms: 0,
+            };
+        };
+        Self {
+            enabled: settings.enabled,
+            publish_interval_ms: u64::from(settings.publish_interval_seconds) * 1000,
+            accuracy_threshold_meters: settings
+                .accuracy_threshold_meters
+                .filter(|value| value.is_finite() && *value > 0.0),
+            expire_after_ms: u64::from(settings.expire_after_minutes) * 60_000,
+        }
+    }
+
+    pub(crate) fn accepts(&self, position: &TelemetryPositionRecord) -> bool {
+        match (self.accuracy_threshold_meters, position.accuracy) {
+            (Some(threshold), Some(accuracy)) => accuracy.is_finite() && accuracy <= threshold,
+            _ => true,
+        }
+    }
+
+    fn expired(&self, position: &TelemetryPositionRecord, now_ms: u64) -> bool {
+        self.expire_after_ms > 0
+            && now_ms.saturating_sub(position.updated_at_ms) > self.expire_after_ms
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TelemetryPublisherState {
+    latest_fix: Option<TelemetryPositionRecord>,
+    last_published_at_ms: Option<u64>,
+}
+
+impl TelemetryPublisherState {
+    // Returns whether the fix should be published right away.
+    pub(crate) fn record_fix(
+        &mut self,
+        policy: &TelemetryPublishPolicy,
+        position: &TelemetryPositionRecord,
+        now_ms: u64,
+    ) -> bool {
+        if !policy.accepts(position) {
+            return false;
+        }
+        self.latest_fix = Some(position.clone());
+        if !policy.enabled {
+            return false;
+        }
+        self.last_published_at_ms = Some(now_ms);
+        true
+    }
+
+    pub(crate) fn due_republish(
+        &mut self,
+        policy: &TelemetryPublishPolicy,
+        now_ms: u64,
+    ) -> Option<TelemetryPositionRecord> {
+        if !policy.enabled || policy.publish_interval_ms == 0 {
+            return None;
+        }
+        let fix = self.latest_fix.as_ref()?;
+        if !policy.accepts(fix) || policy.expired(fix, now_ms) {
+            return None;
+        }
+        if self.last_published_at_ms.is_some_and(|published_at_ms| {
+            now_ms.saturating_sub(published_at_ms) < policy.publish_interval_ms
+        }) {
+            return None;
+        }
+        self.last_published_at_ms = Some(now_ms);
+        Some(fix.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(enabled: bool) -> TelemetrySettingsRecord {
+        TelemetrySettingsRecord {
+            enabled,
+            publish_interval_seconds: 15,
+            accuracy_threshold_meters: Some(10.0),
+            stale_after_minutes: 30,
+            expire_after_minutes: 180,
+        }
+    }
+
+    fn fix(accuracy: Option<f64>, updated_at_ms: u64) -> TelemetryPositionRecord {
+        TelemetryPositionRecord {
+            callsign: "Atlas-1".to_string(),
+            lat: 44.6488,
+            lon: -63.5752,
+            alt: None,
+            course: None,
+            speed: None,
+            accuracy,
+            updated_at_ms,
+            stale: false,
+        }
+    }
+
+    #[test]
+    fn missing_settings_publish_immediately_without_republish() {
+        let policy = TelemetryPublishPolicy::from_settings(None);
+        let mut state = TelemetryPublisherState::default();
+
+        assert!(state.record_fix(&policy, &fix(Some(500.0), 1_000), 1_000));
+        assert!(state.due_republish(&policy, 60_000).is_none());
+    }
+
+    #[test]
+    fn republishes_latest_fix_on_interval() {
+        let policy = TelemetryPublishPolicy::from_settings(Some(&settings(true)));
+        let mut state = TelemetryPublisherState::default();
+
+        assert!(state.record_fix(&policy, &fix(Some(5.0), 1_000), 1_000));
+        assert!(state.due_republish(&policy, 10_000).is_none());
+        let republished = state
+            .due_republish(&policy, 16_000)
+            .expect("fix should be republished after the interval");
+        assert_eq!(republished.updated_at_ms, 1_000);
+        assert!(state.due_republish(&policy, 20_000).is_none());
+        assert!(state.due_republish(&policy, 31_000).is_some());
+    }
+
+    #[test]
+    fn inaccurate_fixes_are_dropped() {
+        let policy = TelemetryPublishPolicy::from_settings(Some(&settings(true)));
+        let mut state = TelemetryPublisherState::default();
+
+        assert!(state.record_fix(&policy, &fix(Some(5.0), 1_000), 1_000));
+        assert!(!state.record_fix(&policy, &fix(Some(25.0), 2_000), 2_000));
+        assert_eq!(
+            state.latest_fix.as_ref().map(|fix| fix.updated_at_ms),
+            Some(1_000)
+        );
+        assert!(state.record_fix(&policy, &fix(None, 3_000), 3_000));
+    }
+
+    #[test]
+    fn disabled_settings_pause_publishing_but_keep_latest_fix() {
+        let disabled = TelemetryPublishPolicy::from_settings(Some(&settings(false)));
+        let mut state = TelemetryPublisherState::default();
+
+        assert!(!state.record_fix(&disabled, &fix(Some(5.0), 1_000), 1_000));
+        assert!(state.due_republish(&disabled, 60_000).is_none());
+
+        let enabled = TelemetryPublishPolicy::from_settings(Some(&settings(true)));
+        assert!(state.due_republish(&enabled, 60_000).is_some());
+    }
+
+    #[test]
+    fn expired_fixes_are_not_republished() {
+        let policy = TelemetryPublishPolicy::from_settings(Some(&settings(true)));
+        let mut state = TelemetryPublisherState::default();
+
+        assert!(state.record_fix(&policy, &fix(Some(5.0), 1_000), 1_000));
+        assert!(state.due_republish(&policy, 1_000 + 181 * 60_000).is_none());
+    }
+}
@@ -0,0 +1,150 @@
+use serde_json::{json, Value as JsonValue};
+
+use crate::app_state::format_rfc3339_from_epoch_seconds;
+use crate::types::{TelemetryPositionRecord, TelemetryTrackFormat};
+
+pub(crate) fn export_telemetry_track(
+    callsign: &str,
+    track: &[TelemetryPositionRecord],
+    format: TelemetryTrackFormat,
+) -> String {
+    match format {
+        TelemetryTrackFormat::Gpx {} => telemetry_track_gpx(callsign, track),
+        TelemetryTrackFormat::GeoJson {} => telemetry_track_geojson(callsign, track).to_string(),
+    }
+}
+
+fn track_point_time(position: &TelemetryPositionRecord) -> String {
+    format_rfc3339_from_epoch_seconds((position.updated_at_ms / 1000) as i64)
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+fn telemetry_track_gpx(callsign: &str, track: &[TelemetryPositionRecord]) -> String {
+    let name = escape_xml(callsign.trim());
+    let mut gpx = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <gpx version=\"1.1\" creator=\"reticulum_mobile\" xmlns=\"http://www.topografix.com/GPX/1/1\">\n",
+    );
+    gpx.push_str(&format!("  <trk>\n    <name>{name}</name>\n    <trkseg>\n"));
+    for position in track {
+        gpx.push_str(&format!(
+            "      <trkpt lat=\"{:.7}\" lon=\"{:.7}\">\n",
+            position.lat, position.lon
+        ));
+        if let Some(alt) = position.alt.filter(|value| value.is_finite()) {
+            gpx.push_str(&format!("        <ele>{alt:.2}</ele>\n"));
+        }
+        gpx.push_str(&format!(
+            "        <time>{}</time>\n",
+            track_point_time(position)
+        ));
+        gpx.push_str("      </trkpt>\n");
+    }
+    gpx.push_str("    </trkseg>\n  </trk>\n</gpx>\n");
+    gpx
+}
+
+fn telemetry_track_geojson(callsign: &str, track: &[TelemetryPositionRecord]) -> JsonValue {
+    let coordinates = track
+        .iter()
+        .map(
+            |position| match position.alt.filter(|value| value.is_finite()) {
+                Some(alt) => json!([position.lon, position.lat, alt]),
+                None => json!([position.lon, position.lat]),
+            },
+        )
+        .collect::<Vec<_>>();
+    let times = track.iter().map(track_point_time).collect::<Vec<_>>();
+    let geometry = match coordinates.as_slice() {
+        [single] => json!({ "type": "Point", "coordinates": single }),
+        _ => json!({ "type": "LineString", "coordinates": coordinates }),
+    };
+    json!({
+        "type": "FeatureCollection",
+        "features": [{
+            "type": "Feature",
+            "geometry": geometry,
+            "properties": {
+                "callsign": callsign.trim(),
+                "coordTimes": times,
+                "pointCount": track.len(),
+            },
+        }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(updated_at_ms: u64, alt: Option<f64>) -> TelemetryPositionRecord {
+        TelemetryPositionRecord {
+            callsign: "Atlas & Co".to_string(),
+            lat: 44.6488,
+            lon: -63.5752,
+            alt,
+            course: None,
+            speed: None,
+            accuracy: Some(4.0),
+            updated_at_ms,
+            stale: false,
+        }
+    }
+
+    #[test]
+    fn gpx_export_writes_escaped_track_points() {
+        let track = vec![
+            point(1_700_000_000_000, Some(12.5)),
+            point(1_700_000_060_000, None),
+        ];
+
+        let gpx = export_telemetry_track("Atlas & Co", &track, TelemetryTrackFormat::Gpx {});
+
+        assert!(gpx.contains("<name>Atlas &amp; Co</name>"));
+        assert_eq!(gpx.matches("<trkpt ").count(), 2);
+        assert!(gpx.contains("<ele>12.50</ele>"));
+        assert!(gpx.contains("<time>2023-11-14T22:13:20Z</time>"));
+        assert!(gpx.trim_end().ends_with("</gpx>"));
+    }
+
+    #[test]
+    fn geojson_export_builds_line_string_in_lon_lat_order() {
+        let track = vec![
+            point(1_700_000_000_000, Some(12.5)),
+            point(1_700_000_060_000, None),
+        ];
+
+        let exported =
+            export_telemetry_track("Atlas & Co", &track, TelemetryTrackFormat::GeoJson {});
+        let value: JsonValue = serde_json::from_str(exported.as_str()).expect("geojson");
+
+        let feature = &value["features"][0];
+        assert_eq!(feature["geometry"]["type"], "LineString");
+        assert_eq!(
+            feature["geometry"]["coordinates"][0],
+            json!([-63.5752, 44.6488, 12.5])
+        );
+        assert_eq!(
+            feature["geometry"]["coordinates"][1],
+            json!([-63.5752, 44.6488])
+        );
+        assert_eq!(
+            feature["properties"]["coordTimes"][1],
+            "2023-11-14T22:14:20Z"
+        );
+    }
+}
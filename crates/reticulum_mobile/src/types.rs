@@ -140,6 +140,7 @@ pub enum LxmfDeliveryStatus {
     Acknowledged {},
     Failed {},
     TimedOut {},
+    Expired {},
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -149,6 +150,15 @@ pub enum SendMode {
     PropagationOnly {},
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessagePriority {
+    Sos {},
+    Flash {},
+    Mission {},
+    Routine {},
+    Telemetry {},
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LxmfDeliveryMethod {
     Direct {},
@@ -188,6 +198,9 @@ pub enum MessageState {
     TimedOut {},
     Cancelled {},
     Received {},
+    Read {},
+    Scheduled {},
+    Expired {},
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -233,6 +246,28 @@ pub enum SosMessageKind {
     Cancelled {},
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SosResponseKind {
+    Acknowledged {},
+    EnRoute {},
+    OnScene {},
+    AutoAnswer {},
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SosDeliveryState {
+    Queued {},
+    Sent {},
+    Delivered {},
+    Failed {},
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TelemetryTrackFormat {
+    Gpx {},
+    GeoJson {},
+}
+
 string_enum! {
     pub enum ChecklistMode {
         Online => "ONLINE",
@@ -319,6 +354,25 @@ impl Default for ChecklistSettingsRecord {
     }
 }
 
+pub const DEFAULT_PROPAGATION_SYNC_INTERVAL_SECONDS: u32 = 15 * 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PropagationSyncSettingsRecord {
+    pub enabled: bool,
+    pub interval_seconds: u32,
+    pub sync_on_reconnect: bool,
+}
+
+impl Default for PropagationSyncSettingsRecord {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_seconds: DEFAULT_PROPAGATION_SYNC_INTERVAL_SECONDS,
+            sync_on_reconnect: true,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
 pub enum NodeError {
     #[error("invalid config")]
@@ -471,6 +525,72 @@ pub struct MessageRecord {
     pub sent_at_ms: Option<u64>,
     pub received_at_ms: Option<u64>,
     pub updated_at_ms: u64,
+    #[serde(default)]
+    pub attachments: Vec<MessageAttachmentRecord>,
+    #[serde(default)]
+    pub edited_at_ms: Option<u64>,
+    #[serde(default)]
+    pub deleted_at_ms: Option<u64>,
+    #[serde(default)]
+    pub reactions: Vec<MessageReactionRecord>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessageReaction {
+    ThumbsUp {},
+    Seen {},
+    Copy {},
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageReactionRecord {
+    pub reactor_hex: String,
+    pub reaction: MessageReaction,
+    pub reacted_at_ms: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessageAttachmentKind {
+    File {},
+    Image {},
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageAttachmentRecord {
+    pub kind: MessageAttachmentKind,
+    pub file_name: String,
+    pub mime_type: Option<String>,
+    pub size_bytes: u64,
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LxmfAttachmentRequest {
+    pub kind: MessageAttachmentKind,
+    pub path: String,
+    pub file_name: Option<String>,
+    pub mime_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupConversationRecord {
+    pub conversation_id: String,
+    pub title: String,
+    pub team_uid: Option<String>,
+    pub mission_uid: Option<String>,
+    pub member_hexes: Vec<String>,
+    pub created_at_ms: u64,
+    pub updated_at_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMemberDeliveryRecord {
+    pub message_id_hex: String,
+    pub member_hex: String,
+    pub lxmf_message_id_hex: Option<String>,
+    pub state: MessageState,
+    pub detail: Option<String>,
+    pub updated_at_ms: u64,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -481,6 +601,35 @@ pub struct SyncStatus {
     pub completed_at_ms: Option<u64>,
     pub messages_received: u32,
     pub detail: Option<String>,
+    pub last_trigger: Option<String>,
+    pub consecutive_failures: u32,
+    pub next_sync_at_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PropagationNodeRecord {
+    pub destination_hex: String,
+    pub identity_hex: String,
+    pub display_name: Option<String>,
+    pub hops: u8,
+    pub last_announce_at_ms: u64,
+    pub last_sync_success_at_ms: Option<u64>,
+    pub last_sync_failure_at_ms: Option<u64>,
+    pub consecutive_failures: u32,
+    pub last_latency_ms: Option<u64>,
+    pub demoted_until_ms: Option<u64>,
+    pub active: bool,
+    pub preferred: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SendQueueClassMetrics {
+    pub priority: MessagePriority,
+    pub queued: u32,
+    pub in_flight: u32,
+    pub dispatched: u64,
+    pub promoted: u64,
+    pub max_wait_ms: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -502,12 +651,20 @@ pub struct HubDirectorySnapshot {
     pub received_at_ms: u64,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SendLxmfRequest {
     pub destination_hex: String,
     pub body_utf8: String,
     pub title: Option<String>,
     pub send_mode: SendMode,
+    #[serde(default)]
+    pub attachments: Vec<LxmfAttachmentRequest>,
+    #[serde(default)]
+    pub send_at_ms: Option<u64>,
+    #[serde(default)]
+    pub expires_at_ms: Option<u64>,
+    #[serde(default)]
+    pub priority: Option<MessagePriority>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -528,6 +685,39 @@ pub struct TelemetrySettingsRecord {
     pub expire_after_minutes: u32,
 }
 
+fn default_sos_saved_peer_recipients() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SosRecipientPolicyRecord {
+    #[serde(default = "default_sos_saved_peer_recipients")]
+    pub saved_peers: bool,
+    #[serde(default)]
+    pub contacts: Vec<String>,
+    #[serde(default)]
+    pub same_team: bool,
+    #[serde(default)]
+    pub team_uid: Option<String>,
+    #[serde(default)]
+    pub hub: bool,
+    #[serde(default)]
+    pub r3akt_peers: bool,
+}
+
+impl Default for SosRecipientPolicyRecord {
+    fn default() -> Self {
+        Self {
+            saved_peers: default_sos_saved_peer_recipients(),
+            contacts: Vec::new(),
+            same_team: false,
+            team_uid: None,
+            hub: false,
+            r3akt_peers: false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SosSettingsRecord {
     pub enabled: bool,
@@ -552,6 +742,8 @@ pub struct SosSettingsRecord {
     pub floating_button_y: f64,
     pub active_pill_x: f64,
     pub active_pill_y: f64,
+    #[serde(default)]
+    pub recipients: SosRecipientPolicyRecord,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -619,6 +811,46 @@ pub struct SosAudioRecord {
     pub created_at_ms: u64,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SosResponderRecord {
+    pub incident_id: String,
+    pub responder_hex: String,
+    pub kind: SosResponseKind,
+    pub eta_seconds: Option<u32>,
+    pub lat: Option<f64>,
+    pub lon: Option<f64>,
+    pub accuracy: Option<f64>,
+    #[serde(default)]
+    pub battery_percent: Option<f64>,
+    pub message_id_hex: Option<String>,
+    pub responded_at_ms: u64,
+    pub updated_at_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SosDeliveryRecord {
+    pub incident_id: String,
+    pub destination_hex: String,
+    pub kind: SosMessageKind,
+    pub state: SosDeliveryState,
+    pub attempts: u32,
+    pub lxmf_message_id_hex: Option<String>,
+    pub via_propagation: bool,
+    pub detail: Option<String>,
+    pub last_attempt_at_ms: u64,
+    pub updated_at_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SosDeliveryReport {
+    pub incident_id: String,
+    pub queued: u32,
+    pub sent: u32,
+    pub delivered: u32,
+    pub failed: u32,
+    pub recipients: Vec<SosDeliveryRecord>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppSettingsRecord {
     pub display_name: String,
@@ -631,6 +863,8 @@ pub struct AppSettingsRecord {
     pub hub: HubSettingsRecord,
     #[serde(default)]
     pub checklists: ChecklistSettingsRecord,
+    #[serde(default)]
+    pub propagation_sync: PropagationSyncSettingsRecord,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -672,6 +906,148 @@ pub struct EamProjectionRecord {
     pub sync_error: Option<String>,
     pub draft_created_at_ms: Option<u64>,
     pub last_synced_at_ms: Option<u64>,
+    #[serde(default)]
+    pub expired: bool,
+    #[serde(default)]
+    pub custom_statuses: Vec<EamStatusEntry>,
+}
+
+// Shared EAM fixture for unit tests; override fields with struct update syntax.
+#[cfg(test)]
+pub(crate) fn sample_eam() -> EamProjectionRecord {
+    EamProjectionRecord {
+        callsign: "ALPHA-1".to_string(),
+        group_name: "Operations".to_string(),
+        security_status: "Green".to_string(),
+        capability_status: "Ready".to_string(),
+        preparedness_status: "Ready".to_string(),
+        medical_status: "Ready".to_string(),
+        mobility_status: "Ready".to_string(),
+        comms_status: "Ready".to_string(),
+        notes: Some("pre-start import".to_string()),
+        updated_at_ms: 1,
+        deleted_at_ms: None,
+        eam_uid: Some("eam-1".to_string()),
+        team_member_uid: Some("member-1".to_string()),
+        team_uid: Some("team-1".to_string()),
+        reported_at: None,
+        reported_by: None,
+        overall_status: Some("Green".to_string()),
+        confidence: Some(1.0),
+        ttl_seconds: Some(3600),
+        source: None,
+        sync_state: Some("Synced".to_string()),
+        sync_error: None,
+        draft_created_at_ms: Some(1),
+        last_synced_at_ms: Some(1),
+        expired: false,
+        custom_statuses: Vec::new(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EamStatusEntry {
+    pub dimension: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EamStatusDimensionRecord {
+    pub key: String,
+    pub label: String,
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EamStatusValueRecord {
+    pub value: String,
+    pub rank: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EamOverallStatusRule {
+    WorstOf {},
+    BestOf {},
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EamSchemaRecord {
+    pub schema_uid: String,
+    pub name: String,
+    pub version: u32,
+    pub dimensions: Vec<EamStatusDimensionRecord>,
+    pub values: Vec<EamStatusValueRecord>,
+    pub unknown_value: String,
+    pub overall_rule: EamOverallStatusRule,
+    pub active: bool,
+    pub updated_at_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EamStatusTotal {
+    pub status: String,
+    pub total: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EamFieldChange {
+    pub field: String,
+    pub previous_value: Option<String>,
+    pub current_value: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EamRevisionRecord {
+    pub revision: u64,
+    pub callsign: String,
+    pub recorded_at_ms: u64,
+    pub reported_at: Option<String>,
+    pub reported_by: Option<String>,
+    pub source_identity: Option<String>,
+    pub deleted: bool,
+    pub changes: Vec<EamFieldChange>,
+    pub record: EamProjectionRecord,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EamRollCallDirection {
+    Outbound {},
+    Inbound {},
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EamRollCallMemberState {
+    Pending {},
+    Responded {},
+    Overdue {},
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EamRollCallMemberRecord {
+    pub team_member_uid: String,
+    pub callsign: Option<String>,
+    pub state: EamRollCallMemberState,
+    pub responded_at_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EamRollCallRecord {
+    pub roll_call_uid: String,
+    pub direction: EamRollCallDirection,
+    pub team_uid: Option<String>,
+    pub requested_by: Option<String>,
+    pub requester_hex: Option<String>,
+    pub requested_at_ms: u64,
+    pub due_at_ms: u64,
+    pub members: Vec<EamRollCallMemberRecord>,
+    pub updated_at_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EamRollCallRequest {
+    pub team_uid: Option<String>,
+    pub team_member_uids: Vec<String>,
+    pub due_in_seconds: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -684,9 +1060,59 @@ pub struct EamTeamSummaryRecord {
     pub green_total: u32,
     pub yellow_total: u32,
     pub red_total: u32,
+    #[serde(default)]
+    pub expired_total: u32,
+    #[serde(default)]
+    pub not_reporting_total: u32,
+    #[serde(default)]
+    pub low_confidence_total: u32,
+    #[serde(default)]
+    pub status_totals: Vec<EamStatusTotal>,
     pub updated_at_ms: u64,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TeamLevel {
+    Section {},
+    Team {},
+    Squad {},
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TeamMemberRole {
+    Leader {},
+    Deputy {},
+    Member {},
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamMemberRecord {
+    pub rns_identity: String,
+    pub team_member_uid: Option<String>,
+    pub callsign: Option<String>,
+    pub role: TeamMemberRole,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamRecord {
+    pub team_uid: String,
+    pub name: String,
+    pub level: TeamLevel,
+    pub parent_team_uid: Option<String>,
+    pub color: Option<String>,
+    pub members: Vec<TeamMemberRecord>,
+    pub updated_at_ms: u64,
+    pub deleted_at_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamRollupRecord {
+    pub team: TeamRecord,
+    pub child_team_uids: Vec<String>,
+    pub depth: u32,
+    pub summary: Option<EamTeamSummaryRecord>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventProjectionRecord {
     pub uid: String,
@@ -935,6 +1361,8 @@ pub struct TelemetryPositionRecord {
     pub speed: Option<f64>,
     pub accuracy: Option<f64>,
     pub updated_at_ms: u64,
+    #[serde(default)]
+    pub stale: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -963,6 +1391,7 @@ pub enum ProjectionScope {
     Messages {},
     Telemetry {},
     Sos {},
+    Teams {},
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1031,6 +1460,9 @@ pub enum NodeEvent {
     MessageUpdated {
         message: MessageRecord,
     },
+    GroupDeliveryUpdated {
+        delivery: GroupMemberDeliveryRecord,
+    },
     SyncUpdated {
         status: SyncStatus,
     },
@@ -1049,11 +1481,23 @@ pub enum NodeEvent {
     SosAlertChanged {
         alert: SosAlertRecord,
     },
+    SosDeliveryUpdated {
+        delivery: SosDeliveryRecord,
+    },
+    SosResponderUpdated {
+        responder: SosResponderRecord,
+    },
     SosTelemetryRequested {},
     SosAudioRecordingRequested {
         incident_id: String,
         duration_seconds: u32,
     },
+    EamReportRequested {
+        roll_call: EamRollCallRecord,
+    },
+    EamRollCallUpdated {
+        roll_call: EamRollCallRecord,
+    },
     Log {
         level: LogLevel,
         message: String,
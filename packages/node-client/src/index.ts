@@ -40,6 +40,7 @@ export type ProjectionScope =
   | "SyncStatus"
   | "HubRegistration"
   | "Eams"
+  | "Teams"
   | "Events"
   | "Conversations"
   | "Messages"